@@ -0,0 +1,160 @@
+use milost_core::compression::*;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use std::hint::black_box;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+const INPUT_SIZE: usize = 256 * 1024;
+const MIN_DURATION: Duration = Duration::from_millis(200);
+
+type Encoder = Box<dyn Fn(&[u8]) -> Vec<u8>>;
+type Decoder = Box<dyn Fn(&[u8]) -> Vec<u8>>;
+
+struct Candidate {
+    name: &'static str,
+    encode: Encoder,
+    decode: Decoder,
+}
+
+fn text_input(rng: &mut StdRng) -> Vec<u8> {
+    let words = [
+        "the", "graph", "compression", "stream", "of", "and", "value", "matrix", "signal", "window",
+        "frame", "a", "to", "is", "data", "block", "encoder", "decoder", "with", "in",
+    ];
+    let mut text = Vec::with_capacity(INPUT_SIZE);
+    
+    while text.len() < INPUT_SIZE {
+        text.extend_from_slice(words[rng.gen_range(0..words.len())].as_bytes());
+        text.push(if rng.gen_ratio(1, 12) { b'\n' } else { b' ' });
+    }
+    text.truncate(INPUT_SIZE);
+    text
+}
+
+fn binary_input(rng: &mut StdRng) -> Vec<u8> {
+    let mut data = Vec::with_capacity(INPUT_SIZE);
+    let mut value = 0.0f32;
+    
+    while data.len() < INPUT_SIZE {
+        value += rng.gen_range(-0.5..0.5);
+        data.extend_from_slice(&((value * 100.0).round() / 100.0).to_le_bytes());
+    }
+    data.truncate(INPUT_SIZE);
+    data
+}
+
+fn random_input(rng: &mut StdRng) -> Vec<u8> {
+    let mut data = vec![0u8; INPUT_SIZE];
+    rng.fill(&mut data[..]);
+    data
+}
+
+fn brotli_encode(data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    {
+        let mut writer = brotli::CompressorWriter::new(&mut output, 4096, 6, 22);
+        writer.write_all(data).unwrap();
+    }
+    output
+}
+
+fn candidates() -> Vec<Candidate> {
+    vec![
+        Candidate {
+            name: "gzip",
+            encode: Box::new(|d| compress_gzip(d, None).unwrap()),
+            decode: Box::new(|d| decompress_gzip(d).unwrap()),
+        },
+        Candidate {
+            name: "zlib",
+            encode: Box::new(|d| compress_zlib(d, None).unwrap()),
+            decode: Box::new(|d| decompress_zlib(d).unwrap()),
+        },
+        Candidate {
+            name: "deflate",
+            encode: Box::new(|d| compress_deflate(d, None).unwrap()),
+            decode: Box::new(|d| decompress_deflate(d).unwrap()),
+        },
+        Candidate {
+            name: "lz4",
+            encode: Box::new(|d| compress_lz4(d, None).unwrap()),
+            decode: Box::new(|d| decompress_lz4(d).unwrap()),
+        },
+        Candidate {
+            name: "lz4-block",
+            encode: Box::new(compress_lz4_block),
+            decode: Box::new(|d| decompress_lz4_block(d).unwrap()),
+        },
+        Candidate {
+            name: "zstd",
+            encode: Box::new(|d| compress_zstd(d, None).unwrap()),
+            decode: Box::new(|d| decompress_zstd(d).unwrap()),
+        },
+        Candidate {
+            name: "brotli*",
+            encode: Box::new(brotli_encode),
+            decode: Box::new(|d| decompress_brotli(d).unwrap()),
+        },
+        Candidate {
+            name: "lz77",
+            encode: Box::new(|d| compress_lz77(d, None)),
+            decode: Box::new(decompress_lz77),
+        },
+        Candidate {
+            name: "huffman",
+            encode: Box::new(|d| compress_framed(d, Codec::Huffman, None).unwrap()),
+            decode: Box::new(|d| decompress_framed(d).unwrap()),
+        },
+    ]
+}
+
+fn throughput(bytes: usize, run: impl Fn()) -> f64 {
+    let start = Instant::now();
+    let mut iterations = 0u32;
+    
+    while iterations == 0 || start.elapsed() < MIN_DURATION {
+        run();
+        iterations += 1;
+    }
+    
+    let seconds = start.elapsed().as_secs_f64() / iterations as f64;
+    bytes as f64 / seconds / (1024.0 * 1024.0)
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let inputs = [
+        ("text", text_input(&mut rng)),
+        ("binary", binary_input(&mut rng)),
+        ("random", random_input(&mut rng)),
+    ];
+    
+    println!("{:<8} {:<10} {:>10} {:>8} {:>14} {:>14}", "input", "codec", "size", "ratio", "encode MB/s", "decode MB/s");
+    
+    for (input_name, input) in &inputs {
+        for candidate in candidates() {
+            let encoded = (candidate.encode)(input);
+            assert_eq!(&(candidate.decode)(&encoded), input, "{} round trip failed", candidate.name);
+            
+            let encode_speed = throughput(input.len(), || {
+                black_box((candidate.encode)(black_box(input)));
+            });
+            let decode_speed = throughput(input.len(), || {
+                black_box((candidate.decode)(black_box(&encoded)));
+            });
+            
+            println!(
+                "{:<8} {:<10} {:>10} {:>8.3} {:>14.1} {:>14.1}",
+                input_name,
+                candidate.name,
+                encoded.len(),
+                encoded.len() as f64 / input.len() as f64,
+                encode_speed,
+                decode_speed
+            );
+        }
+    }
+    
+    println!("\n* brotli is encoded with the reference encoder; milost-core only decodes brotli");
+}
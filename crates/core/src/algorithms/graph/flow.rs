@@ -0,0 +1,264 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use super::{EdgeWeight, Graph, VertexId};
+
+const EPSILON: f64 = 1e-12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlowAlgorithm {
+    EdmondsKarp,
+    #[default]
+    Dinic,
+}
+
+#[derive(Debug, Clone)]
+pub struct MaxFlow<V> {
+    pub value: f64,
+    pub flows: Vec<(V, V, f64)>,
+    pub min_cut: Vec<V>,
+}
+
+struct Arc {
+    to: VertexId,
+    capacity: f64,
+    reverse: usize,
+    original: Option<f64>,
+}
+
+struct Residual {
+    arcs: Vec<Vec<Arc>>,
+}
+
+impl Residual {
+    fn add(&mut self, from: VertexId, to: VertexId, capacity: f64) {
+        let forward = self.arcs[from].len();
+        let reverse = self.arcs[to].len();
+        
+        self.arcs[from].push(Arc { to, capacity, reverse, original: Some(capacity) });
+        self.arcs[to].push(Arc { to: from, capacity: 0.0, reverse: forward, original: None });
+    }
+
+    fn push(&mut self, from: VertexId, index: usize, amount: f64) {
+        let (to, reverse) = {
+            let arc = &mut self.arcs[from][index];
+            arc.capacity -= amount;
+            (arc.to, arc.reverse)
+        };
+        self.arcs[to][reverse].capacity += amount;
+    }
+
+    fn levels(&self, source: VertexId) -> Vec<Option<usize>> {
+        let mut level = vec![None; self.arcs.len()];
+        let mut queue = VecDeque::from([source]);
+        level[source] = Some(0);
+        
+        while let Some(vertex) = queue.pop_front() {
+            for arc in &self.arcs[vertex] {
+                if arc.capacity > EPSILON && level[arc.to].is_none() {
+                    level[arc.to] = level[vertex].map(|l| l + 1);
+                    queue.push_back(arc.to);
+                }
+            }
+        }
+        
+        level
+    }
+
+    fn edmonds_karp(&mut self, source: VertexId, sink: VertexId) -> f64 {
+        let mut total = 0.0;
+        
+        loop {
+            let mut parent: Vec<Option<(VertexId, usize)>> = vec![None; self.arcs.len()];
+            let mut queue = VecDeque::from([source]);
+            let mut found = false;
+            
+            while let Some(vertex) = queue.pop_front() {
+                for (index, arc) in self.arcs[vertex].iter().enumerate() {
+                    if arc.capacity > EPSILON && arc.to != source && parent[arc.to].is_none() {
+                        parent[arc.to] = Some((vertex, index));
+                        if arc.to == sink {
+                            found = true;
+                            break;
+                        }
+                        queue.push_back(arc.to);
+                    }
+                }
+                if found {
+                    break;
+                }
+            }
+            
+            if !found {
+                return total;
+            }
+            
+            let mut bottleneck = f64::INFINITY;
+            let mut vertex = sink;
+            while let Some((from, index)) = parent[vertex] {
+                bottleneck = bottleneck.min(self.arcs[from][index].capacity);
+                vertex = from;
+            }
+            
+            let mut vertex = sink;
+            while let Some((from, index)) = parent[vertex] {
+                self.push(from, index, bottleneck);
+                vertex = from;
+            }
+            
+            total += bottleneck;
+        }
+    }
+
+    fn dinic(&mut self, source: VertexId, sink: VertexId) -> f64 {
+        let mut total = 0.0;
+        
+        loop {
+            let level = self.levels(source);
+            if level[sink].is_none() {
+                return total;
+            }
+            
+            let mut next = vec![0usize; self.arcs.len()];
+            loop {
+                let pushed = self.blocking_flow(source, sink, &level, &mut next);
+                if pushed <= EPSILON {
+                    break;
+                }
+                total += pushed;
+            }
+        }
+    }
+
+    fn blocking_flow(&mut self, source: VertexId, sink: VertexId, level: &[Option<usize>], next: &mut [usize]) -> f64 {
+        let mut path: Vec<(VertexId, usize)> = Vec::new();
+        let mut vertex = source;
+        
+        loop {
+            if vertex == sink {
+                let pushed = path.iter()
+                    .map(|&(from, index)| self.arcs[from][index].capacity)
+                    .fold(f64::INFINITY, f64::min);
+                for &(from, index) in &path {
+                    self.push(from, index, pushed);
+                }
+                return pushed;
+            }
+            
+            let mut advanced = None;
+            while next[vertex] < self.arcs[vertex].len() {
+                let arc = &self.arcs[vertex][next[vertex]];
+                if arc.capacity > EPSILON && level[arc.to] == level[vertex].map(|l| l + 1) {
+                    advanced = Some(arc.to);
+                    break;
+                }
+                next[vertex] += 1;
+            }
+            
+            match advanced {
+                Some(to) => {
+                    path.push((vertex, next[vertex]));
+                    vertex = to;
+                },
+                None => {
+                    let Some((parent, _)) = path.pop() else {
+                        return 0.0;
+                    };
+                    next[parent] += 1;
+                    vertex = parent;
+                },
+            }
+        }
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: EdgeWeight> Graph<V, E> {
+    pub fn max_flow(&self, source: &V, sink: &V, algorithm: FlowAlgorithm) -> Option<MaxFlow<V>> {
+        let source = self.id(source)?;
+        let sink = self.id(sink)?;
+        
+        let mut residual = Residual {
+            arcs: (0..self.capacity()).map(|_| Vec::new()).collect(),
+        };
+        
+        for from in self.vertex_ids() {
+            for (to, edge) in self.out_edges(from) {
+                let capacity = edge.weight();
+                if capacity > 0.0 && from != *to {
+                    residual.add(from, *to, capacity);
+                }
+            }
+        }
+        
+        let value = if source == sink {
+            0.0
+        } else {
+            match algorithm {
+                FlowAlgorithm::EdmondsKarp => residual.edmonds_karp(source, sink),
+                FlowAlgorithm::Dinic => residual.dinic(source, sink),
+            }
+        };
+        
+        let mut flows = Vec::new();
+        for from in self.vertex_ids() {
+            for arc in &residual.arcs[from] {
+                let Some(original) = arc.original else { continue };
+                let flow = original - arc.capacity;
+                
+                if flow > EPSILON {
+                    if let (Some(a), Some(b)) = (self.vertex(from), self.vertex(arc.to)) {
+                        flows.push((a.clone(), b.clone(), flow));
+                    }
+                }
+            }
+        }
+        
+        let level = residual.levels(source);
+        let min_cut = self.vertex_ids()
+            .filter(|&id| level[id].is_some())
+            .filter_map(|id| self.vertex(id).cloned())
+            .collect();
+        
+        Some(MaxFlow { value, flows, min_cut })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALGORITHMS: [FlowAlgorithm; 2] = [FlowAlgorithm::EdmondsKarp, FlowAlgorithm::Dinic];
+
+    #[test]
+    fn finds_maximum_flow() {
+        let mut graph: Graph<&str, f64> = Graph::directed();
+        for (from, to, capacity) in [
+            ("s", "a", 10.0), ("s", "c", 10.0), ("a", "b", 4.0), ("a", "c", 2.0),
+            ("a", "d", 8.0), ("c", "d", 9.0), ("d", "b", 6.0), ("b", "t", 10.0), ("d", "t", 10.0),
+        ] {
+            graph.add_edge(from, to, capacity);
+        }
+
+        for algorithm in ALGORITHMS {
+            let flow = graph.max_flow(&"s", &"t", algorithm).unwrap();
+            assert_eq!(flow.value, 19.0);
+            assert!(flow.min_cut.contains(&"s") && !flow.min_cut.contains(&"t"));
+
+            let out_of_source: f64 = flow.flows.iter().filter(|(from, _, _)| *from == "s").map(|f| f.2).sum();
+            assert_eq!(out_of_source, 19.0);
+        }
+    }
+
+    #[test]
+    fn long_paths_do_not_recurse() {
+        let n = 200_000;
+        let mut graph: Graph<usize, f64> = Graph::directed();
+        for i in 0..n - 1 {
+            graph.add_edge(i, i + 1, 1.0 + (i % 3) as f64);
+        }
+
+        for algorithm in ALGORITHMS {
+            assert_eq!(graph.max_flow(&0, &(n - 1), algorithm).unwrap().value, 1.0);
+        }
+    }
+}
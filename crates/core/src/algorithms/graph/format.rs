@@ -0,0 +1,415 @@
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+
+use anyhow::{anyhow, bail, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::{EdgeWeight, Graph, GraphKind};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjacencyList<V, E> {
+    pub directed: bool,
+    pub adjacency: Vec<AdjacencyEntry<V, E>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjacencyEntry<V, E> {
+    pub vertex: V,
+    #[serde(default = "Vec::new")]
+    pub edges: Vec<Neighbor<V, E>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Neighbor<V, E> {
+    pub vertex: V,
+    pub weight: E,
+}
+
+impl<V: Clone + Eq + Hash, E: Clone> Graph<V, E> {
+    pub fn to_adjacency_list(&self) -> AdjacencyList<V, E> {
+        let adjacency = self.vertex_ids()
+            .filter_map(|id| {
+                let edges = self.out_edges(id).iter()
+                    .filter_map(|(to, e)| Some(Neighbor { vertex: self.vertex(*to)?.clone(), weight: e.clone() }))
+                    .collect();
+                Some(AdjacencyEntry { vertex: self.vertex(id)?.clone(), edges })
+            })
+            .collect();
+        
+        AdjacencyList { directed: self.is_directed(), adjacency }
+    }
+
+    pub fn from_adjacency_list(list: AdjacencyList<V, E>) -> Self {
+        let kind = if list.directed { GraphKind::Directed } else { GraphKind::Undirected };
+        let mut graph = Graph::new(kind);
+        
+        for entry in list.adjacency {
+            graph.add_vertex(entry.vertex.clone());
+            for neighbor in entry.edges {
+                graph.add_edge(entry.vertex.clone(), neighbor.vertex, neighbor.weight);
+            }
+        }
+        
+        graph
+    }
+}
+
+impl<V: Clone + Eq + Hash + Serialize, E: Clone + Serialize> Graph<V, E> {
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(&self.to_adjacency_list()).map_err(|e| anyhow!("Failed to serialize graph: {}", e))
+    }
+}
+
+impl<V: Clone + Eq + Hash + DeserializeOwned, E: Clone + DeserializeOwned> Graph<V, E> {
+    pub fn from_json(json: &str) -> Result<Self> {
+        let list: AdjacencyList<V, E> = serde_json::from_str(json)
+            .map_err(|e| anyhow!("Invalid graph JSON: {}", e))?;
+        
+        Ok(Self::from_adjacency_list(list))
+    }
+}
+
+impl<V: Clone + Eq + Hash + Display, E: EdgeWeight> Graph<V, E> {
+    pub fn to_dot(&self) -> String {
+        let (keyword, connector) = match self.kind() {
+            GraphKind::Directed => ("digraph", "->"),
+            GraphKind::Undirected => ("graph", "--"),
+        };
+        
+        let mut dot = format!("{} {{\n", keyword);
+        
+        for vertex in self.vertices() {
+            let _ = writeln!(dot, "  {};", quote(&vertex.to_string()));
+        }
+        for (from, to, edge) in self.edges() {
+            let _ = writeln!(
+                dot,
+                "  {} {} {} [weight={}];",
+                quote(&from.to_string()),
+                connector,
+                quote(&to.to_string()),
+                edge.weight()
+            );
+        }
+        
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Id(String),
+    Symbol(&'static str),
+}
+
+fn tokenize_dot(source: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '#' || (c == '/' && next == Some('/')) {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            if i >= chars.len() {
+                bail!("Unterminated comment in DOT source");
+            }
+            i += 2;
+        } else if c == '-' && (next == Some('>') || next == Some('-')) {
+            tokens.push(Token::Symbol(if next == Some('>') { "->" } else { "--" }));
+            i += 2;
+        } else if let Some(symbol) = ["{", "}", "[", "]", ";", ",", "=", ":"].iter().find(|s| s.starts_with(c)) {
+            tokens.push(Token::Symbol(symbol));
+            i += 1;
+        } else if c == '"' {
+            let mut value = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => bail!("Unterminated string in DOT source"),
+                    Some('"') => break,
+                    Some('\\') if chars.get(i + 1) == Some(&'"') => {
+                        value.push('"');
+                        i += 2;
+                    },
+                    Some('\\') if chars.get(i + 1) == Some(&'\\') => {
+                        value.push('\\');
+                        i += 2;
+                    },
+                    Some('\\') if chars.get(i + 1) == Some(&'\n') => i += 2,
+                    Some(&ch) => {
+                        value.push(ch);
+                        i += 1;
+                    },
+                }
+            }
+            i += 1;
+            tokens.push(Token::Id(value));
+        } else if c == '<' {
+            let mut depth = 0;
+            let start = i + 1;
+            loop {
+                match chars.get(i) {
+                    None => bail!("Unterminated HTML string in DOT source"),
+                    Some('<') => depth += 1,
+                    Some('>') => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    },
+                    _ => {},
+                }
+                i += 1;
+            }
+            tokens.push(Token::Id(chars[start..i].iter().collect()));
+            i += 1;
+        } else if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+                || (i == start && chars[i] == '-')
+            {
+                i += 1;
+            }
+            tokens.push(Token::Id(chars[start..i].iter().collect()));
+        } else {
+            bail!("Unexpected character '{}' in DOT source", c);
+        }
+    }
+    
+    Ok(tokens)
+}
+
+struct DotParser {
+    tokens: Vec<Token>,
+    position: usize,
+    graph: Graph<String, f64>,
+    default_weight: f64,
+}
+
+impl DotParser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn peek_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Token::Symbol(s)) if *s == symbol)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Id(id)) if id.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let found = self.peek_symbol(symbol);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<()> {
+        if !self.eat_symbol(symbol) {
+            bail!("Expected '{}' in DOT source, found {}", symbol, self.describe());
+        }
+        Ok(())
+    }
+
+    fn expect_id(&mut self) -> Result<String> {
+        match self.peek().cloned() {
+            Some(Token::Id(id)) => {
+                self.position += 1;
+                Ok(id)
+            },
+            _ => bail!("Expected identifier in DOT source, found {}", self.describe()),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self.peek() {
+            Some(Token::Id(id)) => format!("'{}'", id),
+            Some(Token::Symbol(s)) => format!("'{}'", s),
+            None => "end of input".to_string(),
+        }
+    }
+
+    fn parse_graph(&mut self) -> Result<()> {
+        if self.peek_keyword("strict") {
+            self.position += 1;
+        }
+        
+        let kind = match self.expect_id()?.to_ascii_lowercase().as_str() {
+            "digraph" => GraphKind::Directed,
+            "graph" => GraphKind::Undirected,
+            other => bail!("Expected 'graph' or 'digraph', found '{}'", other),
+        };
+        self.graph = Graph::new(kind);
+        
+        if matches!(self.peek(), Some(Token::Id(_))) {
+            self.position += 1;
+        }
+        
+        self.expect_symbol("{")?;
+        self.parse_statements()?;
+        
+        if self.peek().is_some() {
+            bail!("Unexpected {} after end of DOT graph", self.describe());
+        }
+        
+        Ok(())
+    }
+
+    fn parse_statements(&mut self) -> Result<()> {
+        while !self.eat_symbol("}") {
+            if self.peek().is_none() {
+                bail!("Unexpected end of DOT source, expected '}}'");
+            }
+            
+            self.parse_statement()?;
+            
+            if !self.eat_symbol(";") {
+                self.eat_symbol(",");
+            }
+        }
+        
+        Ok(())
+    }
+
+    fn parse_statement(&mut self) -> Result<()> {
+        if self.peek_keyword("subgraph") || self.peek_symbol("{") {
+            if self.eat_symbol("{") {
+                return self.parse_statements();
+            }
+            
+            self.position += 1;
+            if matches!(self.peek(), Some(Token::Id(_))) {
+                self.position += 1;
+            }
+            self.expect_symbol("{")?;
+            return self.parse_statements();
+        }
+        
+        if self.peek_keyword("graph") || self.peek_keyword("node") || self.peek_keyword("edge") {
+            let target = self.expect_id()?.to_ascii_lowercase();
+            let attributes = self.parse_attributes()?;
+            
+            if target == "edge" {
+                if let Some(weight) = Self::weight(&attributes)? {
+                    self.default_weight = weight;
+                }
+            }
+            return Ok(());
+        }
+        
+        let first = self.parse_node_id()?;
+        
+        if self.eat_symbol("=") {
+            self.expect_id()?;
+            return Ok(());
+        }
+        
+        let mut chain = vec![first];
+        while self.peek_symbol("->") || self.peek_symbol("--") {
+            let directed_edge = self.peek_symbol("->");
+            if directed_edge != self.graph.is_directed() {
+                bail!(
+                    "Edge operator '{}' is not valid in a {}",
+                    if directed_edge { "->" } else { "--" },
+                    if self.graph.is_directed() { "digraph" } else { "graph" }
+                );
+            }
+            
+            self.position += 1;
+            if self.peek_keyword("subgraph") || self.peek_symbol("{") {
+                bail!("Subgraphs as edge endpoints are not supported");
+            }
+            chain.push(self.parse_node_id()?);
+        }
+        
+        let attributes = self.parse_attributes()?;
+        
+        if chain.len() == 1 {
+            self.graph.add_vertex(chain.remove(0));
+            return Ok(());
+        }
+        
+        let weight = Self::weight(&attributes)?.unwrap_or(self.default_weight);
+        for pair in chain.windows(2) {
+            self.graph.set_edge(pair[0].clone(), pair[1].clone(), weight);
+        }
+        
+        Ok(())
+    }
+
+    fn parse_node_id(&mut self) -> Result<String> {
+        let id = self.expect_id()?;
+        
+        while self.eat_symbol(":") {
+            self.expect_id()?;
+        }
+        
+        Ok(id)
+    }
+
+    fn parse_attributes(&mut self) -> Result<Vec<(String, String)>> {
+        let mut attributes = Vec::new();
+        
+        while self.eat_symbol("[") {
+            while !self.eat_symbol("]") {
+                let key = self.expect_id()?;
+                self.expect_symbol("=")?;
+                let value = self.expect_id()?;
+                attributes.push((key, value));
+                
+                if !self.eat_symbol(",") {
+                    self.eat_symbol(";");
+                }
+            }
+        }
+        
+        Ok(attributes)
+    }
+
+    fn weight(attributes: &[(String, String)]) -> Result<Option<f64>> {
+        let find = |name: &str| attributes.iter().rev().find(|(key, _)| key == name).map(|(_, v)| v);
+        
+        if let Some(value) = find("weight") {
+            return value.parse::<f64>()
+                .map(Some)
+                .map_err(|_| anyhow!("Invalid edge weight '{}' in DOT source", value));
+        }
+        
+        Ok(find("label").and_then(|value| value.parse::<f64>().ok()))
+    }
+}
+
+impl Graph<String, f64> {
+    pub fn from_dot(source: &str) -> Result<Self> {
+        let mut parser = DotParser {
+            tokens: tokenize_dot(source)?,
+            position: 0,
+            graph: Graph::directed(),
+            default_weight: 1.0,
+        };
+        
+        parser.parse_graph()?;
+        Ok(parser.graph)
+    }
+}
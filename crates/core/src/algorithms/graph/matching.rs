@@ -0,0 +1,118 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use super::{Graph, VertexId};
+
+impl<V: Clone + Eq + Hash, E> Graph<V, E> {
+    fn coloring(&self, neighbors: &[Vec<VertexId>]) -> Option<Vec<Option<bool>>> {
+        let mut color = vec![None; self.capacity()];
+        
+        for start in self.vertex_ids() {
+            if color[start].is_some() {
+                continue;
+            }
+            
+            color[start] = Some(false);
+            let mut queue = VecDeque::from([start]);
+            
+            while let Some(vertex) = queue.pop_front() {
+                let side = color[vertex]?;
+                for &neighbor in &neighbors[vertex] {
+                    match color[neighbor] {
+                        None => {
+                            color[neighbor] = Some(!side);
+                            queue.push_back(neighbor);
+                        },
+                        Some(c) if c == side => return None,
+                        Some(_) => {},
+                    }
+                }
+            }
+        }
+        
+        Some(color)
+    }
+
+    pub fn bipartition(&self) -> Option<(Vec<V>, Vec<V>)> {
+        let color = self.coloring(&self.undirected_ids())?;
+        let side = |wanted: bool| self.vertex_ids()
+            .filter(|&id| color[id] == Some(wanted))
+            .filter_map(|id| self.vertex(id).cloned())
+            .collect();
+        
+        Some((side(false), side(true)))
+    }
+
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartition().is_some()
+    }
+
+    pub fn bipartite_matching(&self) -> Option<Vec<(V, V)>> {
+        let neighbors = self.undirected_ids();
+        let color = self.coloring(&neighbors)?;
+        let left: Vec<VertexId> = self.vertex_ids().filter(|&id| color[id] == Some(false)).collect();
+        
+        let n = self.capacity();
+        let mut pair = vec![None; n];
+        let mut distance = vec![usize::MAX; n];
+        
+        while Self::matching_layers(&left, &neighbors, &pair, &mut distance) {
+            for &vertex in &left {
+                if pair[vertex].is_none() {
+                    Self::augment(vertex, &neighbors, &mut pair, &mut distance);
+                }
+            }
+        }
+        
+        Some(left.iter()
+            .filter_map(|&u| Some((self.vertex(u)?.clone(), self.vertex(pair[u]?)?.clone())))
+            .collect())
+    }
+
+    fn matching_layers(left: &[VertexId], neighbors: &[Vec<VertexId>], pair: &[Option<VertexId>], distance: &mut [usize]) -> bool {
+        let mut queue = VecDeque::new();
+        let mut found = false;
+        
+        distance.fill(usize::MAX);
+        for &vertex in left {
+            if pair[vertex].is_none() {
+                distance[vertex] = 0;
+                queue.push_back(vertex);
+            }
+        }
+        
+        while let Some(vertex) = queue.pop_front() {
+            for &right in &neighbors[vertex] {
+                match pair[right] {
+                    None => found = true,
+                    Some(next) if distance[next] == usize::MAX => {
+                        distance[next] = distance[vertex] + 1;
+                        queue.push_back(next);
+                    },
+                    Some(_) => {},
+                }
+            }
+        }
+        
+        found
+    }
+
+    fn augment(vertex: VertexId, neighbors: &[Vec<VertexId>], pair: &mut [Option<VertexId>], distance: &mut [usize]) -> bool {
+        for &right in &neighbors[vertex] {
+            let free = match pair[right] {
+                None => true,
+                Some(next) => distance[next] == distance[vertex] + 1
+                    && Self::augment(next, neighbors, pair, distance),
+            };
+            
+            if free {
+                pair[vertex] = Some(right);
+                pair[right] = Some(vertex);
+                return true;
+            }
+        }
+        
+        distance[vertex] = usize::MAX;
+        false
+    }
+}
@@ -0,0 +1,272 @@
+pub mod weight;
+pub mod traversal;
+pub mod shortest_path;
+pub mod ordering;
+pub mod spanning;
+pub mod flow;
+pub mod matching;
+pub mod format;
+
+pub use weight::EdgeWeight;
+pub use shortest_path::{AStarPath, DistanceMatrix, NegativeCycle, ShortestPaths};
+pub use ordering::{Cycle, TopologicalSortError};
+pub use flow::{FlowAlgorithm, MaxFlow};
+pub use format::{AdjacencyEntry, AdjacencyList, Neighbor};
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub type VertexId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphKind {
+    Directed,
+    Undirected,
+}
+
+#[derive(Debug, Clone)]
+pub struct Graph<V = String, E = f64> {
+    kind: GraphKind,
+    vertices: Vec<Option<V>>,
+    ids: HashMap<V, VertexId>,
+    adjacency: Vec<Vec<(VertexId, E)>>,
+}
+
+impl<V: Clone + Eq + Hash, E> Default for Graph<V, E> {
+    fn default() -> Self {
+        Graph::new(GraphKind::Directed)
+    }
+}
+
+impl<V: Clone + Eq + Hash, E> Graph<V, E> {
+    pub fn new(kind: GraphKind) -> Self {
+        Graph {
+            kind,
+            vertices: Vec::new(),
+            ids: HashMap::new(),
+            adjacency: Vec::new(),
+        }
+    }
+
+    pub fn directed() -> Self {
+        Self::new(GraphKind::Directed)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(GraphKind::Undirected)
+    }
+
+    pub fn kind(&self) -> GraphKind {
+        self.kind
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.kind == GraphKind::Directed
+    }
+
+    pub fn add_vertex(&mut self, vertex: V) -> VertexId {
+        if let Some(&id) = self.ids.get(&vertex) {
+            return id;
+        }
+        
+        let id = self.vertices.len();
+        self.vertices.push(Some(vertex.clone()));
+        self.adjacency.push(Vec::new());
+        self.ids.insert(vertex, id);
+        id
+    }
+
+    pub fn remove_vertex(&mut self, vertex: &V) -> bool {
+        let Some(id) = self.ids.remove(vertex) else {
+            return false;
+        };
+        
+        self.vertices[id] = None;
+        self.adjacency[id].clear();
+        for edges in self.adjacency.iter_mut() {
+            edges.retain(|(to, _)| *to != id);
+        }
+        
+        true
+    }
+
+    pub fn id(&self, vertex: &V) -> Option<VertexId> {
+        self.ids.get(vertex).copied()
+    }
+
+    pub fn vertex(&self, id: VertexId) -> Option<&V> {
+        self.vertices.get(id).and_then(|v| v.as_ref())
+    }
+
+    pub fn contains_vertex(&self, vertex: &V) -> bool {
+        self.ids.contains_key(vertex)
+    }
+
+    pub fn vertices(&self) -> impl Iterator<Item = &V> {
+        self.vertices.iter().flatten()
+    }
+
+    pub fn vertex_ids(&self) -> impl Iterator<Item = VertexId> + '_ {
+        self.vertices.iter().enumerate().filter(|(_, v)| v.is_some()).map(|(id, _)| id)
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        let arcs: usize = self.adjacency.iter().map(|edges| edges.len()).sum();
+        
+        match self.kind {
+            GraphKind::Directed => arcs,
+            GraphKind::Undirected => {
+                let loops = self.adjacency.iter().enumerate()
+                    .map(|(id, edges)| edges.iter().filter(|(to, _)| *to == id).count())
+                    .sum::<usize>();
+                (arcs + loops) / 2
+            },
+        }
+    }
+
+    pub fn edge(&self, from: &V, to: &V) -> Option<&E> {
+        let (from, to) = (self.id(from)?, self.id(to)?);
+        self.adjacency[from].iter().find(|(dest, _)| *dest == to).map(|(_, e)| e)
+    }
+
+    pub fn contains_edge(&self, from: &V, to: &V) -> bool {
+        self.edge(from, to).is_some()
+    }
+
+    pub fn remove_edge(&mut self, from: &V, to: &V) -> Option<E> {
+        let (from, to) = (self.id(from)?, self.id(to)?);
+        let removed = Self::take_arc(&mut self.adjacency[from], to);
+        
+        if self.kind == GraphKind::Undirected && from != to {
+            Self::take_arc(&mut self.adjacency[to], from);
+        }
+        
+        removed
+    }
+
+    fn take_arc(edges: &mut Vec<(VertexId, E)>, to: VertexId) -> Option<E> {
+        let index = edges.iter().position(|(dest, _)| *dest == to)?;
+        Some(edges.remove(index).1)
+    }
+
+    pub fn neighbors<'a>(&'a self, vertex: &V) -> impl Iterator<Item = (&'a V, &'a E)> + 'a {
+        let edges = self.id(vertex).map(|id| self.adjacency[id].as_slice()).unwrap_or(&[]);
+        edges.iter().filter_map(move |(to, e)| Some((self.vertex(*to)?, e)))
+    }
+
+    pub fn out_edges(&self, id: VertexId) -> &[(VertexId, E)] {
+        self.adjacency.get(id).map(|edges| edges.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn edges(&self) -> Vec<(&V, &V, &E)> {
+        let mut result = Vec::new();
+        
+        for from in self.vertex_ids() {
+            for (to, e) in &self.adjacency[from] {
+                if self.kind == GraphKind::Undirected && *to < from {
+                    continue;
+                }
+                if let (Some(a), Some(b)) = (self.vertex(from), self.vertex(*to)) {
+                    result.push((a, b, e));
+                }
+            }
+        }
+        
+        result
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.vertices.len()
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone> Graph<V, E> {
+    pub fn add_edge(&mut self, from: V, to: V, edge: E) -> bool {
+        let from = self.add_vertex(from);
+        let to = self.add_vertex(to);
+        
+        if self.adjacency[from].iter().any(|(dest, _)| *dest == to) {
+            return false;
+        }
+        
+        if self.kind == GraphKind::Undirected && from != to {
+            self.adjacency[to].push((from, edge.clone()));
+        }
+        self.adjacency[from].push((to, edge));
+        
+        true
+    }
+
+    pub fn set_edge(&mut self, from: V, to: V, edge: E) {
+        self.remove_edge(&from, &to);
+        self.add_edge(from, to, edge);
+    }
+
+    pub fn reversed(&self) -> Graph<V, E> {
+        let mut result = Graph::new(self.kind);
+        
+        for vertex in self.vertices() {
+            result.add_vertex(vertex.clone());
+        }
+        for (from, to, e) in self.edges() {
+            result.add_edge(to.clone(), from.clone(), e.clone());
+        }
+        
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(kind: GraphKind, edges: &[(&str, &str, f64)]) -> Graph {
+        let mut graph = Graph::new(kind);
+        for &(from, to, weight) in edges {
+            graph.add_edge(from.to_string(), to.to_string(), weight);
+        }
+        graph
+    }
+
+    fn names(vertices: &[&str]) -> Vec<String> {
+        vertices.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn edges_follow_graph_kind() {
+        let directed = graph(GraphKind::Directed, &[("a", "b", 1.0)]);
+        assert!(directed.contains_edge(&"a".into(), &"b".into()));
+        assert!(!directed.contains_edge(&"b".into(), &"a".into()));
+        assert_eq!(directed.edge_count(), 1);
+
+        let mut undirected = graph(GraphKind::Undirected, &[("a", "b", 1.0), ("b", "c", 2.0)]);
+        assert_eq!(undirected.edge(&"c".into(), &"b".into()), Some(&2.0));
+        assert_eq!(undirected.edge_count(), 2);
+
+        assert!(undirected.remove_vertex(&"b".into()));
+        assert_eq!((undirected.vertex_count(), undirected.edge_count()), (2, 0));
+    }
+
+    #[test]
+    fn traversals() {
+        let graph = graph(GraphKind::Directed, &[("a", "b", 1.0), ("a", "c", 1.0), ("b", "d", 1.0), ("c", "d", 1.0)]);
+        assert_eq!(graph.bfs(&"a".into()), names(&["a", "b", "c", "d"]));
+        assert_eq!(graph.dfs(&"a".into()), names(&["a", "b", "d", "c"]));
+        assert!(graph.bfs(&"missing".into()).is_empty());
+    }
+
+    #[test]
+    fn shortest_path_prefers_lighter_route() {
+        let graph = graph(GraphKind::Undirected, &[("a", "b", 1.0), ("b", "c", 1.0), ("a", "c", 5.0), ("x", "y", 1.0)]);
+        let paths = graph.dijkstra(&"a".into(), None).unwrap();
+
+        assert_eq!(graph.shortest_path(&"a".into(), &"c".into()), Some(names(&["a", "b", "c"])));
+        assert_eq!(paths.distance(&"c".into()), Some(2.0));
+        assert_eq!(paths.distance(&"x".into()), None);
+        assert_eq!(graph.connected_components().len(), 2);
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::hash::Hash;
+
+use super::{Graph, VertexId};
+
+#[derive(Debug, Clone)]
+pub struct Cycle<V> {
+    pub cycle: Vec<V>,
+}
+
+impl<V: fmt::Debug> fmt::Display for Cycle<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Graph contains a cycle: {:?}", self.cycle)
+    }
+}
+
+impl<V: fmt::Debug> std::error::Error for Cycle<V> {}
+
+#[derive(Debug, Clone)]
+pub enum TopologicalSortError<V> {
+    Undirected,
+    Cycle(Cycle<V>),
+}
+
+impl<V: fmt::Debug> fmt::Display for TopologicalSortError<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TopologicalSortError::Undirected => write!(f, "Topological sort requires a directed graph"),
+            TopologicalSortError::Cycle(cycle) => cycle.fmt(f),
+        }
+    }
+}
+
+impl<V: fmt::Debug> std::error::Error for TopologicalSortError<V> {}
+
+impl<V: Clone + Eq + Hash, E> Graph<V, E> {
+    pub fn topological_sort(&self) -> Result<Vec<V>, TopologicalSortError<V>> {
+        if !self.is_directed() {
+            return Err(TopologicalSortError::Undirected);
+        }
+        
+        let n = self.capacity();
+        let mut in_degree = vec![0usize; n];
+        let mut predecessors = vec![Vec::new(); n];
+        
+        for from in self.vertex_ids() {
+            for (to, _) in self.out_edges(from) {
+                in_degree[*to] += 1;
+                predecessors[*to].push(from);
+            }
+        }
+        
+        let mut queue: VecDeque<VertexId> = self.vertex_ids().filter(|&id| in_degree[id] == 0).collect();
+        let mut order = Vec::with_capacity(self.vertex_count());
+        
+        while let Some(vertex) = queue.pop_front() {
+            order.push(vertex);
+            
+            for (to, _) in self.out_edges(vertex) {
+                in_degree[*to] -= 1;
+                if in_degree[*to] == 0 {
+                    queue.push_back(*to);
+                }
+            }
+        }
+        
+        if order.len() == self.vertex_count() {
+            return Ok(order.into_iter().filter_map(|id| self.vertex(id).cloned()).collect());
+        }
+        
+        let remaining: Vec<bool> = (0..n).map(|id| in_degree[id] > 0).collect();
+        let start = (0..n).find(|&id| remaining[id]).unwrap_or_default();
+        
+        let mut position = vec![None; n];
+        let mut walk = Vec::new();
+        let mut current = start;
+        
+        while position[current].is_none() {
+            position[current] = Some(walk.len());
+            walk.push(current);
+            current = predecessors[current].iter().copied().find(|&p| remaining[p]).unwrap_or(current);
+        }
+        
+        let mut cycle: Vec<VertexId> = walk[position[current].unwrap_or_default()..].to_vec();
+        cycle.reverse();
+        cycle.push(cycle[0]);
+        
+        Err(TopologicalSortError::Cycle(Cycle {
+            cycle: cycle.into_iter().filter_map(|id| self.vertex(id).cloned()).collect(),
+        }))
+    }
+
+    pub fn is_acyclic(&self) -> bool {
+        if self.is_directed() {
+            self.topological_sort().is_ok()
+        } else {
+            self.edge_count() + self.connected_components().len() == self.vertex_count()
+        }
+    }
+
+    pub fn strongly_connected_components(&self) -> Vec<Vec<V>> {
+        let n = self.capacity();
+        let mut index = vec![usize::MAX; n];
+        let mut low_link = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut counter = 0;
+        
+        for root in self.vertex_ids() {
+            if index[root] != usize::MAX {
+                continue;
+            }
+            
+            let mut call_stack = vec![(root, 0)];
+            index[root] = counter;
+            low_link[root] = counter;
+            counter += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            
+            while let Some(&mut (vertex, ref mut next)) = call_stack.last_mut() {
+                if let Some((neighbor, _)) = self.out_edges(vertex).get(*next) {
+                    *next += 1;
+                    let neighbor = *neighbor;
+                    
+                    if index[neighbor] == usize::MAX {
+                        index[neighbor] = counter;
+                        low_link[neighbor] = counter;
+                        counter += 1;
+                        stack.push(neighbor);
+                        on_stack[neighbor] = true;
+                        call_stack.push((neighbor, 0));
+                    } else if on_stack[neighbor] {
+                        low_link[vertex] = low_link[vertex].min(index[neighbor]);
+                    }
+                    continue;
+                }
+                
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    low_link[parent] = low_link[parent].min(low_link[vertex]);
+                }
+                
+                if low_link[vertex] == index[vertex] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.extend(self.vertex(member).cloned());
+                        if member == vertex {
+                            break;
+                        }
+                    }
+                    component.reverse();
+                    components.push(component);
+                }
+            }
+        }
+        
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::GraphKind;
+
+    fn graph(kind: GraphKind, edges: &[(u32, u32)]) -> Graph<u32, f64> {
+        let mut graph = Graph::new(kind);
+        for &(from, to) in edges {
+            graph.add_edge(from, to, 1.0);
+        }
+        graph
+    }
+
+    #[test]
+    fn orders_directed_acyclic_graph() {
+        let edges = [(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)];
+        let order = graph(GraphKind::Directed, &edges).topological_sort().unwrap();
+        let position = |v: u32| order.iter().position(|&x| x == v).unwrap();
+
+        assert_eq!(order.len(), 6);
+        assert!(edges.iter().all(|&(from, to)| position(from) < position(to)));
+    }
+
+    #[test]
+    fn reports_cycle() {
+        let directed = graph(GraphKind::Directed, &[(1, 2), (2, 3), (3, 1), (3, 4)]);
+        let Err(TopologicalSortError::Cycle(cycle)) = directed.topological_sort() else {
+            panic!("expected a cycle");
+        };
+
+        assert_eq!(cycle.cycle.first(), cycle.cycle.last());
+        assert_eq!(cycle.cycle.len(), 4);
+        assert!(!directed.is_acyclic());
+    }
+
+    #[test]
+    fn undirected_graph_is_rejected() {
+        let tree = graph(GraphKind::Undirected, &[(1, 2), (2, 3), (4, 5)]);
+        assert!(matches!(tree.topological_sort(), Err(TopologicalSortError::Undirected)));
+        assert!(tree.is_acyclic());
+
+        let cycle = graph(GraphKind::Undirected, &[(1, 2), (2, 3), (3, 1)]);
+        assert!(!cycle.is_acyclic());
+    }
+}
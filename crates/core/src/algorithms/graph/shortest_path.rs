@@ -0,0 +1,338 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+use std::hash::Hash;
+
+use super::{EdgeWeight, Graph, VertexId};
+
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<V> {
+    pub source: V,
+    pub distances: HashMap<V, f64>,
+    pub previous: HashMap<V, V>,
+}
+
+impl<V: Clone + Eq + Hash> ShortestPaths<V> {
+    pub fn distance(&self, target: &V) -> Option<f64> {
+        self.distances.get(target).copied().filter(|d| d.is_finite())
+    }
+
+    pub fn path_to(&self, target: &V) -> Option<Vec<V>> {
+        if *target != self.source && !self.previous.contains_key(target) {
+            return None;
+        }
+        
+        let mut path = vec![target.clone()];
+        let mut current = target;
+        
+        while let Some(prev) = self.previous.get(current) {
+            if path.len() > self.distances.len() {
+                return None;
+            }
+            path.push(prev.clone());
+            current = prev;
+        }
+        path.reverse();
+        
+        Some(path)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AStarPath<V> {
+    pub cost: f64,
+    pub path: Vec<V>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NegativeCycle<V> {
+    pub cycle: Vec<V>,
+}
+
+impl<V: fmt::Debug> fmt::Display for NegativeCycle<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Graph contains a negative cycle: {:?}", self.cycle)
+    }
+}
+
+impl<V: fmt::Debug> std::error::Error for NegativeCycle<V> {}
+
+#[derive(Debug, Clone)]
+pub struct DistanceMatrix<V> {
+    pub vertices: Vec<V>,
+    pub distances: Vec<Vec<f64>>,
+    next: Vec<Vec<Option<usize>>>,
+}
+
+impl<V: Clone + Eq> DistanceMatrix<V> {
+    fn index(&self, vertex: &V) -> Option<usize> {
+        self.vertices.iter().position(|v| v == vertex)
+    }
+
+    pub fn distance(&self, from: &V, to: &V) -> Option<f64> {
+        let (i, j) = (self.index(from)?, self.index(to)?);
+        Some(self.distances[i][j]).filter(|d| d.is_finite())
+    }
+
+    pub fn path(&self, from: &V, to: &V) -> Option<Vec<V>> {
+        let (mut i, j) = (self.index(from)?, self.index(to)?);
+        if self.has_negative_cycle() {
+            return None;
+        }
+        
+        self.next[i][j]?;
+        let mut path = vec![self.vertices[i].clone()];
+        
+        while i != j {
+            i = self.next[i][j]?;
+            path.push(self.vertices[i].clone());
+        }
+        
+        Some(path)
+    }
+
+    pub fn has_negative_cycle(&self) -> bool {
+        (0..self.vertices.len()).any(|i| self.distances[i][i] < 0.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Scored(f64, VertexId);
+
+impl Eq for Scored {}
+
+impl Ord for Scored {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.total_cmp(&self.0).then_with(|| other.1.cmp(&self.1))
+    }
+}
+
+impl PartialOrd for Scored {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: EdgeWeight> Graph<V, E> {
+    pub fn dijkstra(&self, start: &V, target: Option<&V>) -> Option<ShortestPaths<V>> {
+        let start = self.id(start)?;
+        let target = target.and_then(|t| self.id(t));
+        
+        let mut distances = vec![f64::INFINITY; self.capacity()];
+        let mut previous = vec![None; self.capacity()];
+        let mut heap = BinaryHeap::new();
+        
+        distances[start] = 0.0;
+        heap.push(Scored(0.0, start));
+        
+        while let Some(Scored(distance, vertex)) = heap.pop() {
+            if distance > distances[vertex] {
+                continue;
+            }
+            if Some(vertex) == target {
+                break;
+            }
+            
+            for (neighbor, edge) in self.out_edges(vertex) {
+                let candidate = distance + edge.weight();
+                if candidate < distances[*neighbor] {
+                    distances[*neighbor] = candidate;
+                    previous[*neighbor] = Some(vertex);
+                    heap.push(Scored(candidate, *neighbor));
+                }
+            }
+        }
+        
+        Some(self.shortest_paths(start, &distances, &previous))
+    }
+
+    pub fn shortest_path(&self, start: &V, end: &V) -> Option<Vec<V>> {
+        self.dijkstra(start, Some(end))?.path_to(end)
+    }
+
+    pub fn a_star<H: Fn(&V) -> f64>(&self, start: &V, goal: &V, heuristic: H) -> Option<AStarPath<V>> {
+        let start = self.id(start)?;
+        let goal = self.id(goal)?;
+        
+        let mut costs = vec![f64::INFINITY; self.capacity()];
+        let mut previous = vec![None; self.capacity()];
+        let mut closed = vec![false; self.capacity()];
+        let mut heap = BinaryHeap::new();
+        
+        costs[start] = 0.0;
+        heap.push(Scored(self.vertex(start).map_or(0.0, &heuristic), start));
+        
+        while let Some(Scored(_, vertex)) = heap.pop() {
+            if vertex == goal {
+                let mut path = vec![goal];
+                while let Some(prev) = previous[*path.last()?] {
+                    path.push(prev);
+                }
+                path.reverse();
+                
+                return Some(AStarPath {
+                    cost: costs[goal],
+                    path: path.into_iter().filter_map(|id| self.vertex(id).cloned()).collect(),
+                });
+            }
+            if closed[vertex] {
+                continue;
+            }
+            closed[vertex] = true;
+            
+            for (neighbor, edge) in self.out_edges(vertex) {
+                let candidate = costs[vertex] + edge.weight();
+                if candidate < costs[*neighbor] {
+                    costs[*neighbor] = candidate;
+                    previous[*neighbor] = Some(vertex);
+                    closed[*neighbor] = false;
+                    
+                    let estimate = self.vertex(*neighbor).map_or(0.0, &heuristic);
+                    heap.push(Scored(candidate + estimate, *neighbor));
+                }
+            }
+        }
+        
+        None
+    }
+
+    pub fn bellman_ford(&self, start: &V) -> Option<Result<ShortestPaths<V>, NegativeCycle<V>>> {
+        let start = self.id(start)?;
+        let n = self.capacity();
+        
+        let mut distances = vec![f64::INFINITY; n];
+        let mut previous = vec![None; n];
+        distances[start] = 0.0;
+        
+        for _ in 1..self.vertex_count().max(1) {
+            if !self.relax_all(&mut distances, &mut previous) {
+                break;
+            }
+        }
+        
+        for from in self.vertex_ids() {
+            if !distances[from].is_finite() {
+                continue;
+            }
+            
+            for (to, edge) in self.out_edges(from) {
+                if distances[from] + edge.weight() < distances[*to] {
+                    previous[*to] = Some(from);
+                    return Some(Err(self.negative_cycle(*to, &previous)));
+                }
+            }
+        }
+        
+        Some(Ok(self.shortest_paths(start, &distances, &previous)))
+    }
+
+    fn relax_all(&self, distances: &mut [f64], previous: &mut [Option<VertexId>]) -> bool {
+        let mut changed = false;
+        
+        for from in self.vertex_ids() {
+            if !distances[from].is_finite() {
+                continue;
+            }
+            
+            for (to, edge) in self.out_edges(from) {
+                let candidate = distances[from] + edge.weight();
+                if candidate < distances[*to] {
+                    distances[*to] = candidate;
+                    previous[*to] = Some(from);
+                    changed = true;
+                }
+            }
+        }
+        
+        changed
+    }
+
+    fn negative_cycle(&self, vertex: VertexId, previous: &[Option<VertexId>]) -> NegativeCycle<V> {
+        let mut current = vertex;
+        for _ in 0..self.capacity() {
+            current = previous[current].unwrap_or(current);
+        }
+        
+        let mut cycle = vec![current];
+        let mut next = previous[current].unwrap_or(current);
+        
+        while next != current {
+            cycle.push(next);
+            next = previous[next].unwrap_or(current);
+        }
+        cycle.push(current);
+        cycle.reverse();
+        
+        NegativeCycle {
+            cycle: cycle.into_iter().filter_map(|id| self.vertex(id).cloned()).collect(),
+        }
+    }
+
+    pub fn floyd_warshall(&self) -> DistanceMatrix<V> {
+        let ids: Vec<VertexId> = self.vertex_ids().collect();
+        let n = ids.len();
+        let mut position = vec![usize::MAX; self.capacity()];
+        
+        for (i, &id) in ids.iter().enumerate() {
+            position[id] = i;
+        }
+        
+        let mut distances = vec![vec![f64::INFINITY; n]; n];
+        let mut next = vec![vec![None; n]; n];
+        
+        for (i, &from) in ids.iter().enumerate() {
+            distances[i][i] = 0.0;
+            next[i][i] = Some(i);
+            
+            for (to, edge) in self.out_edges(from) {
+                let j = position[*to];
+                let weight = edge.weight();
+                if weight < distances[i][j] {
+                    distances[i][j] = weight;
+                    next[i][j] = Some(j);
+                }
+            }
+        }
+        
+        for k in 0..n {
+            for i in 0..n {
+                if !distances[i][k].is_finite() {
+                    continue;
+                }
+                
+                for j in 0..n {
+                    let candidate = distances[i][k] + distances[k][j];
+                    if candidate < distances[i][j] {
+                        distances[i][j] = candidate;
+                        next[i][j] = next[i][k];
+                    }
+                }
+            }
+        }
+        
+        DistanceMatrix {
+            vertices: ids.iter().filter_map(|&id| self.vertex(id).cloned()).collect(),
+            distances,
+            next,
+        }
+    }
+
+    fn shortest_paths(&self, start: VertexId, distances: &[f64], previous: &[Option<VertexId>]) -> ShortestPaths<V> {
+        let mut result = ShortestPaths {
+            source: self.vertex(start).cloned().expect("start vertex exists"),
+            distances: HashMap::new(),
+            previous: HashMap::new(),
+        };
+        
+        for id in self.vertex_ids() {
+            let Some(vertex) = self.vertex(id) else { continue };
+            result.distances.insert(vertex.clone(), distances[id]);
+            
+            if let Some(prev) = previous[id].and_then(|p| self.vertex(p)) {
+                result.previous.insert(vertex.clone(), prev.clone());
+            }
+        }
+        
+        result
+    }
+}
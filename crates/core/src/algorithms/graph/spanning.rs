@@ -0,0 +1,58 @@
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use super::{EdgeWeight, Graph};
+
+impl<V: Clone + Eq + Hash, E: EdgeWeight + Clone> Graph<V, E> {
+    pub fn kruskal_mst(&self) -> Vec<(V, V, E)> {
+        let mut edges: Vec<(usize, usize, &E)> = self.vertex_ids()
+            .flat_map(|from| self.out_edges(from).iter().map(move |(to, e)| (from, *to, e)))
+            .filter(|(from, to, _)| self.is_directed() || from < to)
+            .collect();
+        edges.sort_by(|a, b| a.2.weight().partial_cmp(&b.2.weight()).unwrap_or(Ordering::Equal));
+        
+        let mut parent: Vec<usize> = (0..self.capacity()).collect();
+        let mut rank = vec![0usize; self.capacity()];
+        let mut result = Vec::new();
+        
+        for (u, v, edge) in edges {
+            let root_u = Self::find(&mut parent, u);
+            let root_v = Self::find(&mut parent, v);
+            
+            if root_u == root_v {
+                continue;
+            }
+            
+            match rank[root_u].cmp(&rank[root_v]) {
+                Ordering::Less => parent[root_u] = root_v,
+                Ordering::Greater => parent[root_v] = root_u,
+                Ordering::Equal => {
+                    parent[root_v] = root_u;
+                    rank[root_u] += 1;
+                },
+            }
+            
+            if let (Some(a), Some(b)) = (self.vertex(u), self.vertex(v)) {
+                result.push((a.clone(), b.clone(), edge.clone()));
+            }
+        }
+        
+        result
+    }
+
+    fn find(parent: &mut [usize], vertex: usize) -> usize {
+        let mut root = vertex;
+        while parent[root] != root {
+            root = parent[root];
+        }
+        
+        let mut current = vertex;
+        while parent[current] != root {
+            let next = parent[current];
+            parent[current] = root;
+            current = next;
+        }
+        
+        root
+    }
+}
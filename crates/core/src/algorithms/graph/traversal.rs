@@ -0,0 +1,117 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use super::{Graph, VertexId};
+
+impl<V: Clone + Eq + Hash, E> Graph<V, E> {
+    pub fn bfs(&self, start: &V) -> Vec<V> {
+        let Some(start) = self.id(start) else {
+            return Vec::new();
+        };
+        
+        let mut visited = vec![false; self.capacity()];
+        let mut queue = VecDeque::new();
+        let mut result = Vec::new();
+        
+        queue.push_back(start);
+        visited[start] = true;
+        
+        while let Some(vertex) = queue.pop_front() {
+            for (neighbor, _) in self.out_edges(vertex) {
+                if !visited[*neighbor] {
+                    visited[*neighbor] = true;
+                    queue.push_back(*neighbor);
+                }
+            }
+            
+            result.extend(self.vertex(vertex).cloned());
+        }
+        
+        result
+    }
+
+    pub fn dfs(&self, start: &V) -> Vec<V> {
+        let Some(start) = self.id(start) else {
+            return Vec::new();
+        };
+        
+        let mut visited = vec![false; self.capacity()];
+        
+        self.dfs_order(start, &mut visited)
+            .into_iter()
+            .filter_map(|id| self.vertex(id).cloned())
+            .collect()
+    }
+
+    pub(crate) fn dfs_order(&self, start: VertexId, visited: &mut [bool]) -> Vec<VertexId> {
+        let mut result = Vec::new();
+        let mut stack = vec![(start, 0)];
+        visited[start] = true;
+        result.push(start);
+        
+        while let Some((vertex, next)) = stack.last_mut() {
+            let edges = self.out_edges(*vertex);
+            
+            match edges.get(*next) {
+                Some((neighbor, _)) => {
+                    *next += 1;
+                    if !visited[*neighbor] {
+                        visited[*neighbor] = true;
+                        result.push(*neighbor);
+                        stack.push((*neighbor, 0));
+                    }
+                },
+                None => {
+                    stack.pop();
+                },
+            }
+        }
+        
+        result
+    }
+
+    pub fn connected_components(&self) -> Vec<Vec<V>> {
+        let mut visited = vec![false; self.capacity()];
+        let mut components = Vec::new();
+        let undirected = self.undirected_ids();
+        
+        for start in self.vertex_ids() {
+            if visited[start] {
+                continue;
+            }
+            
+            let mut component = Vec::new();
+            let mut queue = VecDeque::from([start]);
+            visited[start] = true;
+            
+            while let Some(vertex) = queue.pop_front() {
+                component.extend(self.vertex(vertex).cloned());
+                for &neighbor in &undirected[vertex] {
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            
+            components.push(component);
+        }
+        
+        components
+    }
+
+    pub(crate) fn undirected_ids(&self) -> Vec<Vec<VertexId>> {
+        let mut result = vec![Vec::new(); self.capacity()];
+        
+        for from in self.vertex_ids() {
+            for (to, _) in self.out_edges(from) {
+                result[from].push(*to);
+                if self.is_directed() {
+                    result[*to].push(from);
+                }
+            }
+        }
+        
+        result
+    }
+}
@@ -0,0 +1,29 @@
+pub trait EdgeWeight {
+    fn weight(&self) -> f64;
+}
+
+macro_rules! impl_edge_weight {
+    ($($t:ty),*) => {
+        $(
+            impl EdgeWeight for $t {
+                fn weight(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_edge_weight!(f64, f32, i8, i16, i32, i64, u8, u16, u32, u64, usize, isize);
+
+impl EdgeWeight for () {
+    fn weight(&self) -> f64 {
+        1.0
+    }
+}
+
+impl<T: EdgeWeight> EdgeWeight for &T {
+    fn weight(&self) -> f64 {
+        (**self).weight()
+    }
+}
@@ -0,0 +1,7 @@
+pub mod sorting;
+pub mod search;
+pub mod graph;
+
+pub use sorting::*;
+pub use search::*;
+pub use graph::{EdgeWeight, Graph, GraphKind, ShortestPaths};
@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+
+pub fn binary_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    binary_search_by(arr, |item| item.cmp(target))
+}
+
+pub fn binary_search_by<T, F>(arr: &[T], compare: F) -> Option<usize>
+where F: Fn(&T) -> Ordering {
+    let mut low = 0;
+    let mut high = arr.len();
+    
+    while low < high {
+        let mid = low + (high - low) / 2;
+        
+        match compare(&arr[mid]) {
+            Ordering::Equal => return Some(mid),
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+        }
+    }
+    
+    None
+}
+
+pub fn linear_search<T: PartialEq>(arr: &[T], target: &T) -> Option<usize> {
+    find_index(arr, |item| item == target)
+}
+
+pub fn find_index<T, F>(arr: &[T], predicate: F) -> Option<usize>
+where F: Fn(&T) -> bool {
+    arr.iter().position(predicate)
+}
+
+pub fn find_all<T, F>(arr: &[T], predicate: F) -> Vec<&T>
+where F: Fn(&T) -> bool {
+    arr.iter().filter(|item| predicate(item)).collect()
+}
+
+pub fn kmp_search(haystack: &str, needle: &str) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    
+    let h_chars: Vec<char> = haystack.chars().collect();
+    let n_chars: Vec<char> = needle.chars().collect();
+    
+    let lps = compute_lps(&n_chars);
+    
+    let mut i = 0;
+    let mut j = 0;
+    
+    while i < h_chars.len() {
+        if h_chars[i] == n_chars[j] {
+            i += 1;
+            j += 1;
+        }
+        
+        if j == n_chars.len() {
+            return Some(i - j);
+        } else if i < h_chars.len() && h_chars[i] != n_chars[j] {
+            if j != 0 {
+                j = lps[j - 1];
+            } else {
+                i += 1;
+            }
+        }
+    }
+    
+    None
+}
+
+fn compute_lps(pattern: &[char]) -> Vec<usize> {
+    let mut lps = vec![0; pattern.len()];
+    let mut len = 0;
+    let mut i = 1;
+    
+    while i < pattern.len() {
+        if pattern[i] == pattern[len] {
+            len += 1;
+            lps[i] = len;
+            i += 1;
+        } else if len != 0 {
+            len = lps[len - 1];
+        } else {
+            i += 1;
+        }
+    }
+    
+    lps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_search_finds_every_element() {
+        let values: Vec<i32> = (0..100).map(|i| i * 3).collect();
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(binary_search(&values, value), Some(i));
+        }
+        assert_eq!(binary_search(&values, &1), None);
+        assert_eq!(binary_search(&values, &300), None);
+        assert_eq!(binary_search(&[], &1), None);
+    }
+
+    #[test]
+    fn linear_searches() {
+        let values = [4, 8, 15, 16, 23, 42];
+        assert_eq!(linear_search(&values, &16), Some(3));
+        assert_eq!(linear_search(&values, &5), None);
+        assert_eq!(find_index(&values, |&v| v > 20), Some(4));
+        assert_eq!(find_all(&values, |&v| v % 2 == 0), vec![&4, &8, &16, &42]);
+    }
+
+    #[test]
+    fn kmp_search_returns_char_index() {
+        assert_eq!(kmp_search("abxabcabcaby", "abcaby"), Some(6));
+        assert_eq!(kmp_search("aaaaab", "aab"), Some(3));
+        assert_eq!(kmp_search("héllo wörld", "wörld"), Some(6));
+        assert_eq!(kmp_search("abc", "abd"), None);
+        assert_eq!(kmp_search("abc", ""), Some(0));
+    }
+}
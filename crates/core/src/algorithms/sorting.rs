@@ -0,0 +1,188 @@
+use std::cmp::Ordering;
+
+pub fn numeric_order(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
+
+pub fn quick_sort<T: Ord>(arr: &mut [T]) {
+    quick_sort_by(arr, T::cmp);
+}
+
+pub fn quick_sort_by<T, F>(arr: &mut [T], compare: F)
+where F: Fn(&T, &T) -> Ordering {
+    if arr.len() > 1 {
+        quick_sort_internal(arr, 0, arr.len() - 1, &compare);
+    }
+}
+
+pub fn merge_sort<T: Ord + Clone>(arr: &mut [T]) {
+    merge_sort_by(arr, T::cmp);
+}
+
+pub fn merge_sort_by<T: Clone, F>(arr: &mut [T], compare: F)
+where F: Fn(&T, &T) -> Ordering {
+    if arr.len() > 1 {
+        merge_sort_internal(arr, 0, arr.len() - 1, &compare);
+    }
+}
+
+pub fn heap_sort<T: Ord>(arr: &mut [T]) {
+    heap_sort_by(arr, T::cmp);
+}
+
+pub fn heap_sort_by<T, F>(arr: &mut [T], compare: F)
+where F: Fn(&T, &T) -> Ordering {
+    let n = arr.len();
+    
+    for i in (0..n/2).rev() {
+        heapify(arr, n, i, &compare);
+    }
+    
+    for i in (1..n).rev() {
+        arr.swap(0, i);
+        heapify(arr, i, 0, &compare);
+    }
+}
+
+pub fn is_sorted<T: Ord>(arr: &[T]) -> bool {
+    is_sorted_by(arr, T::cmp)
+}
+
+pub fn is_sorted_by<T, F>(arr: &[T], compare: F) -> bool
+where F: Fn(&T, &T) -> Ordering {
+    arr.windows(2).all(|pair| compare(&pair[0], &pair[1]) != Ordering::Greater)
+}
+
+fn quick_sort_internal<T, F>(arr: &mut [T], low: usize, high: usize, compare: &F) 
+where F: Fn(&T, &T) -> Ordering {
+    if low < high {
+        let pivot = partition(arr, low, high, compare);
+        
+        if pivot > 0 {
+            quick_sort_internal(arr, low, pivot - 1, compare);
+        }
+        quick_sort_internal(arr, pivot + 1, high, compare);
+    }
+}
+
+fn partition<T, F>(arr: &mut [T], low: usize, high: usize, compare: &F) -> usize 
+where F: Fn(&T, &T) -> Ordering {
+    let mut i = low;
+    
+    for j in low..high {
+        if compare(&arr[j], &arr[high]) != Ordering::Greater {
+            arr.swap(i, j);
+            i += 1;
+        }
+    }
+    
+    arr.swap(i, high);
+    i
+}
+
+fn merge_sort_internal<T: Clone, F>(arr: &mut [T], left: usize, right: usize, compare: &F)
+where F: Fn(&T, &T) -> Ordering {
+    if left < right {
+        let mid = left + (right - left) / 2;
+        
+        merge_sort_internal(arr, left, mid, compare);
+        merge_sort_internal(arr, mid + 1, right, compare);
+        
+        merge(arr, left, mid, right, compare);
+    }
+}
+
+fn merge<T: Clone, F>(arr: &mut [T], left: usize, mid: usize, right: usize, compare: &F)
+where F: Fn(&T, &T) -> Ordering {
+    let left_arr = arr[left..=mid].to_vec();
+    let right_arr = arr[mid + 1..=right].to_vec();
+    
+    let mut i = 0;
+    let mut j = 0;
+    let mut k = left;
+    
+    while i < left_arr.len() && j < right_arr.len() {
+        if compare(&left_arr[i], &right_arr[j]) != Ordering::Greater {
+            arr[k] = left_arr[i].clone();
+            i += 1;
+        } else {
+            arr[k] = right_arr[j].clone();
+            j += 1;
+        }
+        k += 1;
+    }
+    
+    for item in left_arr[i..].iter().chain(&right_arr[j..]) {
+        arr[k] = item.clone();
+        k += 1;
+    }
+}
+
+fn heapify<T, F>(arr: &mut [T], n: usize, i: usize, compare: &F)
+where F: Fn(&T, &T) -> Ordering {
+    let mut largest = i;
+    let left = 2 * i + 1;
+    let right = 2 * i + 2;
+    
+    if left < n && compare(&arr[left], &arr[largest]) == Ordering::Greater {
+        largest = left;
+    }
+    
+    if right < n && compare(&arr[right], &arr[largest]) == Ordering::Greater {
+        largest = right;
+    }
+    
+    if largest != i {
+        arr.swap(i, largest);
+        heapify(arr, n, largest, compare);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples() -> Vec<Vec<i64>> {
+        let mut state = 0x2545_f491_u64;
+        let random = (0..200)
+            .map(|_| {
+                state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+                (state >> 33) as i64 % 100 - 50
+            })
+            .collect();
+        vec![vec![], vec![1], vec![2, 1], vec![3, 3, 3], (0..50).collect(), (0..50).rev().collect(), random]
+    }
+
+    #[test]
+    fn sorts_match_std() {
+        for sort in [quick_sort::<i64>, merge_sort::<i64>, heap_sort::<i64>] {
+            for mut values in samples() {
+                let mut expected = values.clone();
+                expected.sort();
+                sort(&mut values);
+                assert_eq!(values, expected);
+                assert!(is_sorted(&values));
+            }
+        }
+    }
+
+    #[test]
+    fn sort_by_uses_comparator() {
+        let mut values = vec![1.5, f64::NAN, -2.0, 0.0, 10.0];
+        merge_sort_by(&mut values, |a, b| numeric_order(*a, *b));
+        assert_eq!(&values[..4], &[-2.0, 0.0, 1.5, 10.0]);
+        assert!(values[4].is_nan());
+
+        let mut values = vec![3, 1, 2];
+        quick_sort_by(&mut values, |a, b| b.cmp(a));
+        assert_eq!(values, vec![3, 2, 1]);
+        heap_sort_by(&mut values, |a, b| a.cmp(b));
+        assert_eq!(values, vec![1, 2, 3]);
+        assert!(!is_sorted_by(&values, |a, b| b.cmp(a)));
+    }
+}
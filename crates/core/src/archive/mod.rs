@@ -0,0 +1,5 @@
+pub mod zip;
+pub mod tar;
+
+pub use zip::*;
+pub use tar::*;
@@ -0,0 +1,580 @@
+use anyhow::{anyhow, bail, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use std::io::{self, Read, Write};
+
+use crate::compression::{compression_level, detect_format, DetectedFormat};
+
+const BLOCK_SIZE: usize = 512;
+const MAX_METADATA_SIZE: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TarEntryKind {
+    File,
+    Directory,
+    Symlink,
+    HardLink,
+    Other(u8),
+}
+
+impl TarEntryKind {
+    fn from_flag(flag: u8) -> Self {
+        match flag {
+            b'0' | 0 | b'7' => TarEntryKind::File,
+            b'5' => TarEntryKind::Directory,
+            b'2' => TarEntryKind::Symlink,
+            b'1' => TarEntryKind::HardLink,
+            other => TarEntryKind::Other(other),
+        }
+    }
+
+    fn flag(&self) -> u8 {
+        match self {
+            TarEntryKind::File => b'0',
+            TarEntryKind::Directory => b'5',
+            TarEntryKind::Symlink => b'2',
+            TarEntryKind::HardLink => b'1',
+            TarEntryKind::Other(flag) => *flag,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TarEntryKind::File => "file",
+            TarEntryKind::Directory => "directory",
+            TarEntryKind::Symlink => "symlink",
+            TarEntryKind::HardLink => "hardlink",
+            TarEntryKind::Other(_) => "other",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TarEntry {
+    pub path: String,
+    pub kind: TarEntryKind,
+    pub size: u64,
+    pub mode: u32,
+    pub modified: u64,
+    pub link_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TarOptions {
+    pub mode: Option<u32>,
+    pub modified: Option<u64>,
+}
+
+fn parse_string(field: &[u8]) -> String {
+    let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+fn parse_number(field: &[u8]) -> Result<u64> {
+    if field.first().is_some_and(|&b| b & 0x80 != 0) {
+        let mut value = u64::from(field[0] & 0x7f);
+        for &byte in &field[1..] {
+            value = value.checked_shl(8).ok_or_else(|| anyhow!("TAR numeric field overflow"))? | u64::from(byte);
+        }
+        return Ok(value);
+    }
+    
+    let text = parse_string(field);
+    let text = text.trim_matches(|c: char| c == ' ' || c == '\0');
+    if text.is_empty() {
+        return Ok(0);
+    }
+    
+    u64::from_str_radix(text, 8).map_err(|_| anyhow!("Invalid TAR numeric field: '{}'", text))
+}
+
+fn checksum(header: &[u8]) -> u64 {
+    header.iter().enumerate()
+        .map(|(i, &b)| if (148..156).contains(&i) { u64::from(b' ') } else { u64::from(b) })
+        .sum()
+}
+
+fn padding(size: u64) -> u64 {
+    (BLOCK_SIZE as u64 - size % BLOCK_SIZE as u64) % BLOCK_SIZE as u64
+}
+
+fn parse_pax(data: &[u8]) -> Vec<(String, String)> {
+    let mut records = Vec::new();
+    let mut rest = data;
+    
+    while !rest.is_empty() {
+        let Some(space) = rest.iter().position(|&b| b == b' ') else { break };
+        let Some(len) = std::str::from_utf8(&rest[..space]).ok().and_then(|s| s.parse::<usize>().ok()) else { break };
+        if len <= space + 1 || len > rest.len() {
+            break;
+        }
+        
+        let record = &rest[space + 1..len];
+        let record = record.strip_suffix(b"\n").unwrap_or(record);
+        if let Some(eq) = record.iter().position(|&b| b == b'=') {
+            records.push((
+                String::from_utf8_lossy(&record[..eq]).into_owned(),
+                String::from_utf8_lossy(&record[eq + 1..]).into_owned(),
+            ));
+        }
+        rest = &rest[len..];
+    }
+    
+    records
+}
+
+#[derive(Default)]
+struct Overrides {
+    path: Option<String>,
+    link_name: Option<String>,
+    size: Option<u64>,
+    modified: Option<u64>,
+}
+
+impl Overrides {
+    fn apply_pax(&mut self, records: Vec<(String, String)>) {
+        for (key, value) in records {
+            match key.as_str() {
+                "path" => self.path = Some(value),
+                "linkpath" => self.link_name = Some(value),
+                "size" => self.size = value.parse().ok(),
+                "mtime" => self.modified = value.split('.').next().and_then(|s| s.parse().ok()),
+                _ => {},
+            }
+        }
+    }
+}
+
+pub struct TarReader<R: Read> {
+    reader: R,
+    remaining: u64,
+    padding: u64,
+    globals: Overrides,
+    finished: bool,
+}
+
+pub struct TarEntryData<'a, R: Read> {
+    archive: &'a mut TarReader<R>,
+}
+
+impl<R: Read> Read for TarEntryData<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.archive.remaining == 0 {
+            return Ok(0);
+        }
+        
+        let limit = buf.len().min(self.archive.remaining.min(usize::MAX as u64) as usize);
+        let read = self.archive.reader.read(&mut buf[..limit])?;
+        if read == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Truncated TAR entry"));
+        }
+        
+        self.archive.remaining -= read as u64;
+        Ok(read)
+    }
+}
+
+impl<R: Read> TarReader<R> {
+    pub fn new(reader: R) -> Self {
+        TarReader {
+            reader,
+            remaining: 0,
+            padding: 0,
+            globals: Overrides::default(),
+            finished: false,
+        }
+    }
+
+    fn skip(&mut self, mut amount: u64) -> Result<()> {
+        let mut buffer = [0u8; 4096];
+        
+        while amount > 0 {
+            let chunk = amount.min(buffer.len() as u64) as usize;
+            self.reader.read_exact(&mut buffer[..chunk]).map_err(|_| anyhow!("Truncated TAR archive"))?;
+            amount -= chunk as u64;
+        }
+        
+        Ok(())
+    }
+
+    fn read_block(&mut self) -> Result<Option<[u8; BLOCK_SIZE]>> {
+        let mut block = [0u8; BLOCK_SIZE];
+        let mut filled = 0;
+        
+        while filled < BLOCK_SIZE {
+            match self.reader.read(&mut block[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => bail!("Truncated TAR header"),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+                Err(e) => bail!("Failed to read TAR archive: {}", e),
+            }
+        }
+        
+        Ok(Some(block))
+    }
+
+    fn read_payload(&mut self, size: u64) -> Result<Vec<u8>> {
+        if size > MAX_METADATA_SIZE {
+            bail!("TAR metadata entry of {} bytes exceeds the limit of {} bytes", size, MAX_METADATA_SIZE);
+        }
+        
+        let mut data = vec![0u8; usize::try_from(size)?];
+        self.reader.read_exact(&mut data).map_err(|_| anyhow!("Truncated TAR archive"))?;
+        self.skip(padding(size))?;
+        Ok(data)
+    }
+
+    pub fn next_entry(&mut self) -> Result<Option<TarEntry>> {
+        if self.finished {
+            return Ok(None);
+        }
+        
+        let pending = self.remaining + self.padding;
+        self.remaining = 0;
+        self.padding = 0;
+        self.skip(pending)?;
+        
+        let mut local = Overrides::default();
+        
+        loop {
+            let Some(header) = self.read_block()? else {
+                self.finished = true;
+                return Ok(None);
+            };
+            
+            if header.iter().all(|&b| b == 0) {
+                self.finished = true;
+                return Ok(None);
+            }
+            
+            if parse_number(&header[148..156])? != checksum(&header) {
+                bail!("Invalid TAR header checksum");
+            }
+            
+            let flag = header[156];
+            let size = parse_number(&header[124..136])?;
+            
+            match flag {
+                b'x' => {
+                    let records = parse_pax(&self.read_payload(size)?);
+                    local.apply_pax(records);
+                    continue;
+                },
+                b'g' => {
+                    let records = parse_pax(&self.read_payload(size)?);
+                    self.globals.apply_pax(records);
+                    continue;
+                },
+                b'L' => {
+                    local.path = Some(parse_string(&self.read_payload(size)?));
+                    continue;
+                },
+                b'K' => {
+                    local.link_name = Some(parse_string(&self.read_payload(size)?));
+                    continue;
+                },
+                _ => {},
+            }
+            
+            let mut path = parse_string(&header[0..100]);
+            if &header[257..263] == b"ustar\0" {
+                let prefix = parse_string(&header[345..500]);
+                if !prefix.is_empty() {
+                    path = format!("{}/{}", prefix, path);
+                }
+            }
+            
+            let link_name = parse_string(&header[157..257]);
+            let size = local.size.or(self.globals.size).unwrap_or(size);
+            let kind = TarEntryKind::from_flag(flag);
+            
+            let entry = TarEntry {
+                path: local.path.or_else(|| self.globals.path.clone()).unwrap_or(path),
+                kind,
+                size,
+                mode: parse_number(&header[100..108])? as u32,
+                modified: local.modified.or(self.globals.modified).unwrap_or(parse_number(&header[136..148])?),
+                link_name: local.link_name.or_else(|| (!link_name.is_empty()).then_some(link_name)),
+            };
+            
+            let has_data = !matches!(kind, TarEntryKind::Directory | TarEntryKind::Symlink | TarEntryKind::HardLink);
+            self.remaining = if has_data { size } else { 0 };
+            self.padding = if has_data { padding(size) } else { 0 };
+            
+            return Ok(Some(entry));
+        }
+    }
+
+    pub fn data(&mut self) -> TarEntryData<'_, R> {
+        TarEntryData { archive: self }
+    }
+
+    pub fn read_data(&mut self) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(self.remaining.min(MAX_METADATA_SIZE) as usize);
+        self.data().read_to_end(&mut data).map_err(|e| anyhow!("Failed to read TAR entry: {}", e))?;
+        Ok(data)
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Iterator for TarReader<R> {
+    type Item = Result<(TarEntry, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_entry() {
+            Ok(Some(entry)) => Some(self.read_data().map(|data| (entry, data))),
+            Ok(None) => None,
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            },
+        }
+    }
+}
+
+pub fn open_tar<'a, D: AsRef<[u8]> + 'a>(data: D) -> TarReader<Box<dyn Read + 'a>> {
+    let gzip = detect_format(data.as_ref()) == Some(DetectedFormat::Gzip);
+    let cursor = io::Cursor::new(data);
+    
+    let reader: Box<dyn Read + 'a> = if gzip {
+        Box::new(GzDecoder::new(cursor))
+    } else {
+        Box::new(cursor)
+    };
+    
+    TarReader::new(reader)
+}
+
+pub struct TarWriter<W: Write> {
+    writer: W,
+}
+
+fn write_octal(field: &mut [u8], value: u64) -> Result<()> {
+    let digits = field.len() - 1;
+    let text = format!("{:0width$o}", value, width = digits);
+    
+    if text.len() > digits {
+        bail!("Value {} does not fit in TAR header field", value);
+    }
+    
+    field[..digits].copy_from_slice(text.as_bytes());
+    field[digits] = 0;
+    Ok(())
+}
+
+fn split_ustar_path(path: &str) -> Option<(&str, &str)> {
+    if path.len() <= 100 {
+        return Some(("", path));
+    }
+    
+    path.char_indices()
+        .filter(|&(i, c)| c == '/' && i <= 155 && path.len() - i - 1 <= 100 && i > 0)
+        .map(|(i, _)| (&path[..i], &path[i + 1..]))
+        .next()
+}
+
+fn pax_record(key: &str, value: &str) -> Vec<u8> {
+    let body = format!(" {}={}\n", key, value);
+    let mut len = body.len() + 1;
+    
+    while len.to_string().len() + body.len() != len {
+        len = len.to_string().len() + body.len();
+    }
+    
+    format!("{}{}", len, body).into_bytes()
+}
+
+impl<W: Write> TarWriter<W> {
+    pub fn new(writer: W) -> Self {
+        TarWriter { writer }
+    }
+
+    fn emit(&mut self, bytes: &[u8]) -> Result<()> {
+        self.writer.write_all(bytes).map_err(|e| anyhow!("Failed to write TAR archive: {}", e))
+    }
+
+    fn emit_padding(&mut self, size: u64) -> Result<()> {
+        let zeros = [0u8; BLOCK_SIZE];
+        self.emit(&zeros[..padding(size) as usize])
+    }
+
+    fn header(&mut self, path: &str, kind: TarEntryKind, size: u64, options: &TarOptions, link_name: Option<&str>) -> Result<()> {
+        if path.is_empty() {
+            bail!("TAR entry path cannot be empty");
+        }
+        
+        let split = split_ustar_path(path);
+        let mut pax = Vec::new();
+        
+        if split.is_none() {
+            pax.extend(pax_record("path", path));
+        }
+        if size >= 0o77_777_777_777 {
+            pax.extend(pax_record("size", &size.to_string()));
+        }
+        if let Some(link) = link_name.filter(|link| link.len() > 100) {
+            pax.extend(pax_record("linkpath", link));
+        }
+        
+        if !pax.is_empty() {
+            let pax_name = format!("PaxHeaders/{}", path.rsplit('/').next().unwrap_or(path));
+            let truncated: String = pax_name.chars().take(99).collect();
+            self.raw_header(&truncated, "", b'x', pax.len() as u64, &TarOptions::default(), "")?;
+            self.emit(&pax)?;
+            self.emit_padding(pax.len() as u64)?;
+        }
+        
+        let (prefix, name) = split.unwrap_or(("", ""));
+        let name = if split.is_some() { name.to_string() } else { path.chars().take(100).collect() };
+        let size_field = if size >= 0o77_777_777_777 { 0 } else { size };
+        let link = link_name.filter(|link| link.len() <= 100).unwrap_or("");
+        
+        self.raw_header(&name, prefix, kind.flag(), size_field, options, link)
+    }
+
+    fn raw_header(&mut self, name: &str, prefix: &str, flag: u8, size: u64, options: &TarOptions, link: &str) -> Result<()> {
+        let mut header = [0u8; BLOCK_SIZE];
+        let default_mode = match flag {
+            b'5' => 0o755,
+            b'2' => 0o777,
+            _ => 0o644,
+        };
+        
+        let name = &name.as_bytes()[..name.len().min(100)];
+        header[..name.len()].copy_from_slice(name);
+        write_octal(&mut header[100..108], u64::from(options.mode.unwrap_or(default_mode) & 0o7777))?;
+        write_octal(&mut header[108..116], 0)?;
+        write_octal(&mut header[116..124], 0)?;
+        write_octal(&mut header[124..136], size)?;
+        write_octal(&mut header[136..148], options.modified.unwrap_or(0))?;
+        header[156] = flag;
+        header[157..157 + link.len()].copy_from_slice(link.as_bytes());
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+        header[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+        
+        let sum = checksum(&header);
+        header[148..156].copy_from_slice(format!("{:06o}\0 ", sum).as_bytes());
+        
+        self.emit(&header)
+    }
+
+    pub fn append_file(&mut self, path: &str, data: &[u8], options: TarOptions) -> Result<()> {
+        self.header(path, TarEntryKind::File, data.len() as u64, &options, None)?;
+        self.emit(data)?;
+        self.emit_padding(data.len() as u64)
+    }
+
+    pub fn append_from_reader<R: Read>(&mut self, path: &str, size: u64, reader: R, options: TarOptions) -> Result<()> {
+        self.header(path, TarEntryKind::File, size, &options, None)?;
+        
+        let copied = io::copy(&mut reader.take(size), &mut self.writer)
+            .map_err(|e| anyhow!("Failed to write TAR archive: {}", e))?;
+        if copied != size {
+            bail!("TAR entry '{}' is shorter than its declared size", path);
+        }
+        
+        self.emit_padding(size)
+    }
+
+    pub fn append_directory(&mut self, path: &str, options: TarOptions) -> Result<()> {
+        let path = if path.ends_with('/') { path.to_string() } else { format!("{}/", path) };
+        self.header(&path, TarEntryKind::Directory, 0, &options, None)
+    }
+
+    pub fn append_symlink(&mut self, path: &str, target: &str, options: TarOptions) -> Result<()> {
+        self.header(path, TarEntryKind::Symlink, 0, &options, Some(target))
+    }
+
+    pub fn finish(mut self) -> Result<W> {
+        self.emit(&[0u8; BLOCK_SIZE * 2])?;
+        self.writer.flush().map_err(|e| anyhow!("Failed to write TAR archive: {}", e))?;
+        Ok(self.writer)
+    }
+}
+
+pub fn create_tar(files: &[(String, Vec<u8>)], options: TarOptions) -> Result<Vec<u8>> {
+    let mut writer = TarWriter::new(Vec::new());
+    
+    for (path, data) in files {
+        writer.append_file(path, data, options)?;
+    }
+    
+    writer.finish()
+}
+
+pub fn create_tar_gz(files: &[(String, Vec<u8>)], options: TarOptions, level: Option<u32>) -> Result<Vec<u8>> {
+    let encoder = GzEncoder::new(Vec::new(), compression_level(level)?);
+    let mut writer = TarWriter::new(encoder);
+    
+    for (path, data) in files {
+        writer.append_file(path, data, options)?;
+    }
+    
+    writer.finish()?.finish().map_err(|e| anyhow!("Compression finalization error: {}", e))
+}
+
+pub fn extract_tar(data: &[u8]) -> Result<Vec<(TarEntry, Vec<u8>)>> {
+    open_tar(data).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archive_with_header(flag: u8, size: u64) -> Vec<u8> {
+        let mut writer = TarWriter::new(Vec::new());
+        writer.raw_header("metadata", "", flag, size, &TarOptions::default(), "").unwrap();
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let long_path = format!("{}/file.txt", "nested-directory".repeat(20));
+        let long_target = "target/".repeat(20);
+        let options = TarOptions { mode: Some(0o600), modified: Some(1_700_000_000) };
+
+        let mut writer = TarWriter::new(Vec::new());
+        writer.append_directory("docs", options).unwrap();
+        writer.append_file("docs/readme.md", b"hello", options).unwrap();
+        writer.append_file(&long_path, &[7; 1500], options).unwrap();
+        writer.append_symlink("link", &long_target, options).unwrap();
+        let archive = writer.finish().unwrap();
+
+        let entries = extract_tar(&archive).unwrap();
+        let summary: Vec<(&str, TarEntryKind, usize)> = entries
+            .iter()
+            .map(|(entry, data)| (entry.path.as_str(), entry.kind, data.len()))
+            .collect();
+        assert_eq!(summary, vec![
+            ("docs/", TarEntryKind::Directory, 0),
+            ("docs/readme.md", TarEntryKind::File, 5),
+            (long_path.as_str(), TarEntryKind::File, 1500),
+            ("link", TarEntryKind::Symlink, 0),
+        ]);
+        assert_eq!(entries[1].0.mode, 0o600);
+        assert_eq!(entries[1].0.modified, 1_700_000_000);
+        assert_eq!(entries[3].0.link_name.as_deref(), Some(long_target.as_str()));
+
+        let gzipped = create_tar_gz(&[("a.txt".to_string(), b"abc".to_vec())], TarOptions::default(), None).unwrap();
+        assert_eq!(extract_tar(&gzipped).unwrap()[0].1, b"abc");
+    }
+
+    #[test]
+    fn rejects_oversized_metadata() {
+        for flag in [b'x', b'g', b'L', b'K'] {
+            let archive = archive_with_header(flag, 1 << 30);
+            assert!(open_tar(&archive).next_entry().is_err(), "{}", flag as char);
+        }
+    }
+
+    #[test]
+    fn rejects_truncated_entry() {
+        let archive = create_tar(&[("a.txt".to_string(), vec![1; 1000])], TarOptions::default()).unwrap();
+        assert!(extract_tar(&archive[..BLOCK_SIZE + 100]).is_err());
+    }
+}
@@ -0,0 +1,634 @@
+use anyhow::{anyhow, bail, Result};
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+use crate::compression::{compression_level, crc32};
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+const ZIP64_END_SIGNATURE: u32 = 0x0606_4b50;
+const ZIP64_LOCATOR_SIGNATURE: u32 = 0x0706_4b50;
+const DATA_DESCRIPTOR_SIGNATURE: u32 = 0x0807_4b50;
+
+const FLAG_DATA_DESCRIPTOR: u16 = 1 << 3;
+const FLAG_UTF8: u16 = 1 << 11;
+const VERSION_MADE_BY_UNIX: u16 = (3 << 8) | 20;
+const VERSION_NEEDED: u16 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZipMethod {
+    Stored,
+    #[default]
+    Deflate,
+}
+
+impl ZipMethod {
+    fn code(&self) -> u16 {
+        match self {
+            ZipMethod::Stored => 0,
+            ZipMethod::Deflate => 8,
+        }
+    }
+
+    fn from_code(code: u16) -> Result<Self> {
+        match code {
+            0 => Ok(ZipMethod::Stored),
+            8 => Ok(ZipMethod::Deflate),
+            _ => bail!("Unsupported ZIP compression method: {}", code),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ZipMethod::Stored => "stored",
+            ZipMethod::Deflate => "deflate",
+        }
+    }
+}
+
+impl FromStr for ZipMethod {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "stored" | "store" | "none" => Ok(ZipMethod::Stored),
+            "deflate" | "deflated" => Ok(ZipMethod::Deflate),
+            _ => bail!("Unsupported ZIP compression method: {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZipEntry {
+    pub name: String,
+    pub method: u16,
+    pub size: u64,
+    pub compressed_size: u64,
+    pub crc32: u32,
+    pub modified: u64,
+    pub mode: Option<u32>,
+    pub comment: String,
+    header_offset: u64,
+}
+
+impl ZipEntry {
+    pub fn is_dir(&self) -> bool {
+        self.name.ends_with('/')
+    }
+
+    pub fn compression(&self) -> Result<ZipMethod> {
+        ZipMethod::from_code(self.method)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZipOptions {
+    pub method: ZipMethod,
+    pub level: Option<u32>,
+    pub modified: Option<u64>,
+    pub mode: Option<u32>,
+}
+
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+pub fn dos_datetime(unix_seconds: u64) -> (u16, u16) {
+    let min = days_from_civil(1980, 1, 1) as u64 * 86_400;
+    let max = days_from_civil(2107, 12, 31) as u64 * 86_400 + 86_399;
+    let seconds = unix_seconds.clamp(min, max);
+    
+    let (year, month, day) = civil_from_days((seconds / 86_400) as i64);
+    let time_of_day = seconds % 86_400;
+    
+    let time = (((time_of_day / 3600) << 11) | ((time_of_day % 3600 / 60) << 5) | ((time_of_day % 60) / 2)) as u16;
+    let date = ((((year - 1980) as u32) << 9) | (month << 5) | day) as u16;
+    (time, date)
+}
+
+pub fn unix_from_dos(time: u16, date: u16) -> u64 {
+    let year = 1980 + i64::from(date >> 9);
+    let month = u32::from((date >> 5) & 0x0f).clamp(1, 12);
+    let day = u32::from(date & 0x1f).max(1);
+    
+    let days = days_from_civil(year, month, day).max(0) as u64;
+    let seconds = u64::from(time >> 11) * 3600 + u64::from((time >> 5) & 0x3f) * 60 + u64::from(time & 0x1f) * 2;
+    days * 86_400 + seconds
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn at(data: &'a [u8], position: usize) -> Self {
+        Cursor { data, position }
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self.position.checked_add(len).filter(|&end| end <= self.data.len())
+            .ok_or_else(|| anyhow!("Truncated ZIP archive"))?;
+        let slice = &self.data[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        let b = self.bytes(2)?;
+        Ok(u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let b = self.bytes(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        let b = self.bytes(8)?;
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(b);
+        Ok(u64::from_le_bytes(bytes))
+    }
+}
+
+struct CrcReader<R> {
+    inner: R,
+    crc: flate2::Crc,
+    read: u64,
+    expected_crc: u32,
+    expected_size: u64,
+}
+
+impl<R: Read> Read for CrcReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.crc.update(&buf[..read]);
+        self.read += read as u64;
+        
+        if self.read > self.expected_size || (read == 0 && !buf.is_empty() && self.read != self.expected_size) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "ZIP entry size mismatch"));
+        }
+        if read == 0 && !buf.is_empty() && self.crc.sum() != self.expected_crc {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "ZIP entry checksum mismatch"));
+        }
+        
+        Ok(read)
+    }
+}
+
+pub struct ZipReader<'a> {
+    data: &'a [u8],
+    entries: Vec<ZipEntry>,
+    comment: String,
+}
+
+impl<'a> ZipReader<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self> {
+        let end = Self::find_end_of_central_directory(data)?;
+        let mut cursor = Cursor::at(data, end + 10);
+        
+        let mut total_entries = u64::from(cursor.u16()?);
+        let mut directory_size = u64::from(cursor.u32()?);
+        let mut directory_offset = u64::from(cursor.u32()?);
+        let comment_len = cursor.u16()? as usize;
+        let comment = String::from_utf8_lossy(cursor.bytes(comment_len)?).into_owned();
+        
+        if end >= 20 {
+            let mut locator = Cursor::at(data, end - 20);
+            if locator.u32()? == ZIP64_LOCATOR_SIGNATURE {
+                locator.u32()?;
+                let zip64_end = usize::try_from(locator.u64()?)?;
+                
+                let mut record = Cursor::at(data, zip64_end);
+                if record.u32()? != ZIP64_END_SIGNATURE {
+                    bail!("Invalid ZIP64 end of central directory record");
+                }
+                record.bytes(20)?;
+                record.u64()?;
+                total_entries = record.u64()?;
+                directory_size = record.u64()?;
+                directory_offset = record.u64()?;
+            }
+        }
+        
+        let directory_end = directory_offset.checked_add(directory_size)
+            .filter(|&end| end <= data.len() as u64)
+            .ok_or_else(|| anyhow!("ZIP central directory is out of bounds"))?;
+        
+        let mut cursor = Cursor::at(&data[..directory_end as usize], directory_offset as usize);
+        let mut entries = Vec::with_capacity(total_entries.min(65_536) as usize);
+        
+        for _ in 0..total_entries {
+            entries.push(Self::read_central_header(&mut cursor)?);
+        }
+        
+        Ok(ZipReader { data, entries, comment })
+    }
+
+    fn find_end_of_central_directory(data: &[u8]) -> Result<usize> {
+        if data.len() < 22 {
+            bail!("Data is too short to be a ZIP archive");
+        }
+        
+        let lowest = data.len().saturating_sub(22 + 65_535);
+        (lowest..=data.len() - 22)
+            .rev()
+            .find(|&i| data[i..i + 4] == END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes())
+            .ok_or_else(|| anyhow!("End of central directory not found; not a ZIP archive"))
+    }
+
+    fn read_central_header(cursor: &mut Cursor<'_>) -> Result<ZipEntry> {
+        if cursor.u32()? != CENTRAL_HEADER_SIGNATURE {
+            bail!("Invalid ZIP central directory entry");
+        }
+        
+        let made_by = cursor.u16()?;
+        cursor.u16()?;
+        let _flags = cursor.u16()?;
+        let method = cursor.u16()?;
+        let time = cursor.u16()?;
+        let date = cursor.u16()?;
+        let crc32 = cursor.u32()?;
+        let mut compressed_size = u64::from(cursor.u32()?);
+        let mut size = u64::from(cursor.u32()?);
+        let name_len = cursor.u16()? as usize;
+        let extra_len = cursor.u16()? as usize;
+        let comment_len = cursor.u16()? as usize;
+        cursor.u16()?;
+        cursor.u16()?;
+        let external_attributes = cursor.u32()?;
+        let mut header_offset = u64::from(cursor.u32()?);
+        
+        let name = String::from_utf8_lossy(cursor.bytes(name_len)?).into_owned();
+        let mut extra = Cursor::at(cursor.bytes(extra_len)?, 0);
+        let comment = String::from_utf8_lossy(cursor.bytes(comment_len)?).into_owned();
+        
+        while extra.position + 4 <= extra.data.len() {
+            let id = extra.u16()?;
+            let len = extra.u16()? as usize;
+            let mut field = Cursor::at(extra.bytes(len)?, 0);
+            
+            if id == 0x0001 {
+                if size == 0xffff_ffff {
+                    size = field.u64()?;
+                }
+                if compressed_size == 0xffff_ffff {
+                    compressed_size = field.u64()?;
+                }
+                if header_offset == 0xffff_ffff {
+                    header_offset = field.u64()?;
+                }
+            }
+        }
+        
+        let mode = (made_by >> 8 == 3 && external_attributes >> 16 != 0).then_some(external_attributes >> 16);
+        
+        Ok(ZipEntry {
+            name,
+            method,
+            size,
+            compressed_size,
+            crc32,
+            modified: unix_from_dos(time, date),
+            mode,
+            comment,
+            header_offset,
+        })
+    }
+
+    pub fn entries(&self) -> &[ZipEntry] {
+        &self.entries
+    }
+
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    pub fn find(&self, name: &str) -> Option<&ZipEntry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    fn raw_data(&self, entry: &ZipEntry) -> Result<&'a [u8]> {
+        let mut cursor = Cursor::at(self.data, usize::try_from(entry.header_offset)?);
+        
+        if cursor.u32()? != LOCAL_HEADER_SIGNATURE {
+            bail!("Invalid ZIP local header for '{}'", entry.name);
+        }
+        cursor.bytes(22)?;
+        let name_len = cursor.u16()? as usize;
+        let extra_len = cursor.u16()? as usize;
+        cursor.bytes(name_len + extra_len)?;
+        
+        cursor.bytes(usize::try_from(entry.compressed_size)?)
+    }
+
+    pub fn entry_reader(&self, entry: &ZipEntry) -> Result<Box<dyn Read + 'a>> {
+        let raw = self.raw_data(entry)?;
+        let inner: Box<dyn Read + 'a> = match entry.compression()? {
+            ZipMethod::Stored => Box::new(raw),
+            ZipMethod::Deflate => Box::new(DeflateDecoder::new(raw)),
+        };
+        
+        Ok(Box::new(CrcReader {
+            inner,
+            crc: flate2::Crc::new(),
+            read: 0,
+            expected_crc: entry.crc32,
+            expected_size: entry.size,
+        }))
+    }
+
+    pub fn read(&self, entry: &ZipEntry) -> Result<Vec<u8>> {
+        let mut output = Vec::with_capacity(entry.size.min(1 << 30) as usize);
+        self.entry_reader(entry)?
+            .read_to_end(&mut output)
+            .map_err(|e| anyhow!("Failed to read '{}': {}", entry.name, e))?;
+        Ok(output)
+    }
+
+    pub fn read_file(&self, name: &str) -> Result<Vec<u8>> {
+        let entry = self.find(name).ok_or_else(|| anyhow!("No entry named '{}' in ZIP archive", name))?;
+        self.read(entry)
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = Result<(&ZipEntry, Vec<u8>)>> + '_ {
+        self.entries
+            .iter()
+            .filter(|entry| !entry.is_dir())
+            .map(move |entry| Ok((entry, self.read(entry)?)))
+    }
+
+    pub fn extract_all(&self) -> Result<Vec<(String, Vec<u8>)>> {
+        self.files().map(|file| file.map(|(entry, data)| (entry.name.clone(), data))).collect()
+    }
+}
+
+struct CentralRecord {
+    name: String,
+    method: u16,
+    flags: u16,
+    time: u16,
+    date: u16,
+    crc32: u32,
+    compressed_size: u64,
+    size: u64,
+    external_attributes: u32,
+    header_offset: u64,
+}
+
+pub struct ZipWriter<W: Write> {
+    writer: W,
+    records: Vec<CentralRecord>,
+    offset: u64,
+    comment: String,
+}
+
+fn to_u32(value: u64) -> Result<u32> {
+    u32::try_from(value).map_err(|_| anyhow!("ZIP64 archives are not supported for writing"))
+}
+
+impl<W: Write> ZipWriter<W> {
+    pub fn new(writer: W) -> Self {
+        ZipWriter {
+            writer,
+            records: Vec::new(),
+            offset: 0,
+            comment: String::new(),
+        }
+    }
+
+    pub fn set_comment(&mut self, comment: &str) {
+        self.comment = comment.to_string();
+    }
+
+    fn emit(&mut self, bytes: &[u8]) -> Result<()> {
+        self.writer.write_all(bytes).map_err(|e| anyhow!("Failed to write ZIP archive: {}", e))?;
+        self.offset += bytes.len() as u64;
+        Ok(())
+    }
+
+    fn validate_name(&self, name: &str) -> Result<()> {
+        if name.is_empty() || name.len() > u16::MAX as usize {
+            bail!("Invalid ZIP entry name: '{}'", name);
+        }
+        if self.records.iter().any(|record| record.name == name) {
+            bail!("Duplicate ZIP entry name: '{}'", name);
+        }
+        Ok(())
+    }
+
+    fn local_header(&mut self, record: &CentralRecord) -> Result<()> {
+        let mut header = Vec::with_capacity(30 + record.name.len());
+        header.extend_from_slice(&LOCAL_HEADER_SIGNATURE.to_le_bytes());
+        header.extend_from_slice(&VERSION_NEEDED.to_le_bytes());
+        header.extend_from_slice(&record.flags.to_le_bytes());
+        header.extend_from_slice(&record.method.to_le_bytes());
+        header.extend_from_slice(&record.time.to_le_bytes());
+        header.extend_from_slice(&record.date.to_le_bytes());
+        header.extend_from_slice(&record.crc32.to_le_bytes());
+        header.extend_from_slice(&to_u32(record.compressed_size)?.to_le_bytes());
+        header.extend_from_slice(&to_u32(record.size)?.to_le_bytes());
+        header.extend_from_slice(&(record.name.len() as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(record.name.as_bytes());
+        self.emit(&header)
+    }
+
+    fn record(&self, name: &str, options: &ZipOptions, directory: bool) -> CentralRecord {
+        let (time, date) = dos_datetime(options.modified.unwrap_or(0));
+        let default_mode = if directory { 0o040_755 } else { 0o100_644 };
+        let mode = options.mode.unwrap_or(default_mode);
+        
+        CentralRecord {
+            name: name.to_string(),
+            method: if directory { 0 } else { options.method.code() },
+            flags: if name.is_ascii() { 0 } else { FLAG_UTF8 },
+            time,
+            date,
+            crc32: 0,
+            compressed_size: 0,
+            size: 0,
+            external_attributes: (mode << 16) | (u32::from(directory) << 4),
+            header_offset: self.offset,
+        }
+    }
+
+    fn compress(data: &[u8], options: &ZipOptions) -> Result<Vec<u8>> {
+        match options.method {
+            ZipMethod::Stored => Ok(data.to_vec()),
+            ZipMethod::Deflate => {
+                let mut encoder = DeflateEncoder::new(Vec::new(), compression_level(options.level)?);
+                encoder.write_all(data).map_err(|e| anyhow!("Compression error: {}", e))?;
+                encoder.finish().map_err(|e| anyhow!("Compression finalization error: {}", e))
+            },
+        }
+    }
+
+    pub fn add_file(&mut self, name: &str, data: &[u8], options: ZipOptions) -> Result<()> {
+        self.validate_name(name)?;
+        
+        let compressed = Self::compress(data, &options)?;
+        let mut record = self.record(name, &options, false);
+        record.crc32 = crc32(data);
+        record.size = data.len() as u64;
+        record.compressed_size = compressed.len() as u64;
+        
+        self.local_header(&record)?;
+        self.emit(&compressed)?;
+        self.records.push(record);
+        Ok(())
+    }
+
+    pub fn add_file_from_reader<R: Read>(&mut self, name: &str, mut reader: R, options: ZipOptions) -> Result<()> {
+        self.validate_name(name)?;
+        
+        let mut record = self.record(name, &options, false);
+        record.flags |= FLAG_DATA_DESCRIPTOR;
+        self.local_header(&record)?;
+        
+        let mut crc = flate2::Crc::new();
+        let mut buffer = vec![0u8; 64 * 1024];
+        let mut size = 0u64;
+        let mut compressed_size = 0u64;
+        let mut encoder = match options.method {
+            ZipMethod::Deflate => Some(DeflateEncoder::new(Vec::new(), compression_level(options.level)?)),
+            ZipMethod::Stored => None,
+        };
+        
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => bail!("Failed to read '{}': {}", name, e),
+            };
+            
+            crc.update(&buffer[..read]);
+            size += read as u64;
+            
+            let chunk = match encoder.as_mut() {
+                Some(encoder) => {
+                    encoder.write_all(&buffer[..read]).map_err(|e| anyhow!("Compression error: {}", e))?;
+                    std::mem::take(encoder.get_mut())
+                },
+                None => buffer[..read].to_vec(),
+            };
+            compressed_size += chunk.len() as u64;
+            self.emit(&chunk)?;
+        }
+        
+        if let Some(encoder) = encoder {
+            let tail = encoder.finish().map_err(|e| anyhow!("Compression finalization error: {}", e))?;
+            compressed_size += tail.len() as u64;
+            self.emit(&tail)?;
+        }
+        
+        record.crc32 = crc.sum();
+        record.size = size;
+        record.compressed_size = compressed_size;
+        
+        let mut descriptor = Vec::with_capacity(16);
+        descriptor.extend_from_slice(&DATA_DESCRIPTOR_SIGNATURE.to_le_bytes());
+        descriptor.extend_from_slice(&record.crc32.to_le_bytes());
+        descriptor.extend_from_slice(&to_u32(compressed_size)?.to_le_bytes());
+        descriptor.extend_from_slice(&to_u32(size)?.to_le_bytes());
+        self.emit(&descriptor)?;
+        
+        self.records.push(record);
+        Ok(())
+    }
+
+    pub fn add_directory(&mut self, name: &str, options: ZipOptions) -> Result<()> {
+        let name = if name.ends_with('/') { name.to_string() } else { format!("{}/", name) };
+        self.validate_name(&name)?;
+        
+        let record = self.record(&name, &options, true);
+        self.local_header(&record)?;
+        self.records.push(record);
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W> {
+        if self.records.len() > u16::MAX as usize {
+            bail!("ZIP64 archives are not supported for writing");
+        }
+        
+        let directory_offset = self.offset;
+        let records = std::mem::take(&mut self.records);
+        
+        for record in &records {
+            let mut header = Vec::with_capacity(46 + record.name.len());
+            header.extend_from_slice(&CENTRAL_HEADER_SIGNATURE.to_le_bytes());
+            header.extend_from_slice(&VERSION_MADE_BY_UNIX.to_le_bytes());
+            header.extend_from_slice(&VERSION_NEEDED.to_le_bytes());
+            header.extend_from_slice(&record.flags.to_le_bytes());
+            header.extend_from_slice(&record.method.to_le_bytes());
+            header.extend_from_slice(&record.time.to_le_bytes());
+            header.extend_from_slice(&record.date.to_le_bytes());
+            header.extend_from_slice(&record.crc32.to_le_bytes());
+            header.extend_from_slice(&to_u32(record.compressed_size)?.to_le_bytes());
+            header.extend_from_slice(&to_u32(record.size)?.to_le_bytes());
+            header.extend_from_slice(&(record.name.len() as u16).to_le_bytes());
+            header.extend_from_slice(&0u16.to_le_bytes());
+            header.extend_from_slice(&0u16.to_le_bytes());
+            header.extend_from_slice(&0u16.to_le_bytes());
+            header.extend_from_slice(&0u16.to_le_bytes());
+            header.extend_from_slice(&record.external_attributes.to_le_bytes());
+            header.extend_from_slice(&to_u32(record.header_offset)?.to_le_bytes());
+            header.extend_from_slice(record.name.as_bytes());
+            self.emit(&header)?;
+        }
+        
+        let directory_size = self.offset - directory_offset;
+        let comment = std::mem::take(&mut self.comment);
+        let comment_len = u16::try_from(comment.len()).map_err(|_| anyhow!("ZIP comment is too long"))?;
+        
+        let mut end = Vec::with_capacity(22 + comment.len());
+        end.extend_from_slice(&END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes());
+        end.extend_from_slice(&(records.len() as u16).to_le_bytes());
+        end.extend_from_slice(&(records.len() as u16).to_le_bytes());
+        end.extend_from_slice(&to_u32(directory_size)?.to_le_bytes());
+        end.extend_from_slice(&to_u32(directory_offset)?.to_le_bytes());
+        end.extend_from_slice(&comment_len.to_le_bytes());
+        end.extend_from_slice(comment.as_bytes());
+        self.emit(&end)?;
+        
+        self.writer.flush().map_err(|e| anyhow!("Failed to write ZIP archive: {}", e))?;
+        Ok(self.writer)
+    }
+}
+
+pub fn create_zip(files: &[(String, Vec<u8>)], options: ZipOptions) -> Result<Vec<u8>> {
+    let mut writer = ZipWriter::new(Vec::new());
+    
+    for (name, data) in files {
+        writer.add_file(name, data, options)?;
+    }
+    
+    writer.finish()
+}
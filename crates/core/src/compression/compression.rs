@@ -0,0 +1,224 @@
+use anyhow::{anyhow, bail, Result};
+use flate2::write::{GzEncoder, ZlibEncoder, DeflateEncoder};
+use flate2::read::{GzDecoder, ZlibDecoder, DeflateDecoder};
+use flate2::Compression;
+use ruzstd::decoding::StreamingDecoder;
+use ruzstd::encoding::CompressionLevel;
+use std::io::{Read, Write};
+
+pub(crate) fn compression_level(level: Option<u32>) -> Result<Compression> {
+    match level {
+        Some(lvl) if lvl > 9 => bail!("Compression level must be between 0 and 9"),
+        Some(lvl) => Ok(Compression::new(lvl)),
+        None => Ok(Compression::default()),
+    }
+}
+
+fn encode<W: Write>(mut encoder: W, data: &[u8], finish: impl FnOnce(W) -> std::io::Result<Vec<u8>>) -> Result<Vec<u8>> {
+    encoder.write_all(data).map_err(|e| anyhow!("Compression error: {}", e))?;
+    finish(encoder).map_err(|e| anyhow!("Compression finalization error: {}", e))
+}
+
+fn decode<R: Read>(mut decoder: R) -> Result<Vec<u8>> {
+    let mut decompressed = Vec::new();
+    decoder.read_to_end(&mut decompressed).map_err(|e| anyhow!("Decompression error: {}", e))?;
+    Ok(decompressed)
+}
+
+pub fn compress_gzip(data: &[u8], level: Option<u32>) -> Result<Vec<u8>> {
+    let encoder = GzEncoder::new(Vec::new(), compression_level(level)?);
+    encode(encoder, data, GzEncoder::finish)
+}
+
+pub fn decompress_gzip(data: &[u8]) -> Result<Vec<u8>> {
+    decode(GzDecoder::new(data))
+}
+
+pub fn compress_zlib(data: &[u8], level: Option<u32>) -> Result<Vec<u8>> {
+    let encoder = ZlibEncoder::new(Vec::new(), compression_level(level)?);
+    encode(encoder, data, ZlibEncoder::finish)
+}
+
+pub fn decompress_zlib(data: &[u8]) -> Result<Vec<u8>> {
+    decode(ZlibDecoder::new(data))
+}
+
+pub fn compress_deflate(data: &[u8], level: Option<u32>) -> Result<Vec<u8>> {
+    let encoder = DeflateEncoder::new(Vec::new(), compression_level(level)?);
+    encode(encoder, data, DeflateEncoder::finish)
+}
+
+pub fn decompress_deflate(data: &[u8]) -> Result<Vec<u8>> {
+    decode(DeflateDecoder::new(data))
+}
+
+pub fn compress_lz4(data: &[u8], level: Option<u32>) -> Result<Vec<u8>> {
+    if let Some(level) = level.filter(|&level| level != 1) {
+        bail!("LZ4 only supports compression level 1, got {}", level);
+    }
+    
+    let encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+    encode(encoder, data, |encoder| encoder.finish().map_err(std::io::Error::other))
+}
+
+pub fn decompress_lz4(data: &[u8]) -> Result<Vec<u8>> {
+    decode(lz4_flex::frame::FrameDecoder::new(data))
+}
+
+pub fn compress_lz4_block(data: &[u8]) -> Vec<u8> {
+    lz4_flex::block::compress_prepend_size(data)
+}
+
+pub fn decompress_lz4_block(data: &[u8]) -> Result<Vec<u8>> {
+    lz4_flex::block::decompress_size_prepended(data).map_err(|e| anyhow!("Decompression error: {}", e))
+}
+
+pub fn compress_zstd(data: &[u8], level: Option<u32>) -> Result<Vec<u8>> {
+    let level = match level {
+        Some(0) => CompressionLevel::Uncompressed,
+        None | Some(1) => CompressionLevel::Fastest,
+        Some(level) => bail!("zstd only supports compression levels 0 (stored) and 1 (fastest), got {}", level),
+    };
+    
+    Ok(ruzstd::encoding::compress_to_vec(data, level))
+}
+
+pub fn decompress_zstd(data: &[u8]) -> Result<Vec<u8>> {
+    let mut source = data;
+    let mut decompressed = Vec::new();
+    
+    while !source.is_empty() {
+        let mut decoder = StreamingDecoder::new(&mut source).map_err(|e| anyhow!("Decompression error: {}", e))?;
+        decoder.read_to_end(&mut decompressed).map_err(|e| anyhow!("Decompression error: {}", e))?;
+    }
+    
+    Ok(decompressed)
+}
+
+pub fn decompress_brotli(data: &[u8]) -> Result<Vec<u8>> {
+    decode(brotli_decompressor::Decompressor::new(data, 4096))
+}
+
+pub fn compress_lz77(data: &[u8], window_size: Option<usize>) -> Vec<u8> {
+    let window = window_size.unwrap_or(4096).min(32768);
+    let min_match_length = 3;
+    let max_match_length = 255;
+    
+    let mut compressed = Vec::new();
+    let mut i = 0;
+    
+    while i < data.len() {
+        let (offset, length) = find_longest_match(data, i, window, min_match_length, max_match_length);
+        
+        if length >= min_match_length {
+            compressed.push(1);
+            compressed.push((offset & 0xFF) as u8);
+            compressed.push(((offset >> 8) & 0xFF) as u8);
+            compressed.push(length as u8);
+            
+            i += length;
+        } else {
+            compressed.push(0);
+            compressed.push(data[i]);
+            i += 1;
+        }
+    }
+    
+    compressed
+}
+
+pub fn decompress_lz77(data: &[u8]) -> Vec<u8> {
+    let mut decompressed = Vec::new();
+    let mut i = 0;
+    
+    while i < data.len() {
+        let flag = data[i];
+        i += 1;
+        
+        if flag == 0 {
+            if i < data.len() {
+                decompressed.push(data[i]);
+                i += 1;
+            }
+        } else if i + 2 < data.len() {
+            let offset = (data[i] as usize) | ((data[i + 1] as usize) << 8);
+            let length = data[i + 2] as usize;
+            i += 3;
+            
+            if offset > 0 && offset <= decompressed.len() {
+                let start = decompressed.len() - offset;
+                for j in 0..length {
+                    decompressed.push(decompressed[start + (j % offset)]);
+                }
+            }
+        }
+    }
+    
+    decompressed
+}
+
+fn find_longest_match(data: &[u8], current_pos: usize, window_size: usize, min_length: usize, max_length: usize) -> (usize, usize) {
+    let max_search_pos = current_pos.saturating_sub(window_size);
+    let search_end = (current_pos + max_length).min(data.len());
+    
+    let mut best_length = 0;
+    let mut best_offset = 0;
+    
+    for i in (max_search_pos..current_pos).rev() {
+        let mut length = 0;
+        
+        while current_pos + length < search_end && 
+              data[i + (length % (current_pos - i))] == data[current_pos + length] {
+            length += 1;
+        }
+        
+        if length >= min_length && length > best_length {
+            best_length = length;
+            best_offset = current_pos - i;
+            
+            if length >= max_length {
+                break;
+            }
+        }
+    }
+    
+    (best_offset, best_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples() -> Vec<Vec<u8>> {
+        let text = b"the quick brown fox jumps over the lazy dog. ".repeat(40);
+        let binary: Vec<u8> = (0..5000u32).map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8).collect();
+        vec![Vec::new(), b"a".to_vec(), vec![0; 1000], text, binary]
+    }
+
+    #[test]
+    fn codec_round_trips() {
+        for data in samples() {
+            assert_eq!(decompress_gzip(&compress_gzip(&data, None).unwrap()).unwrap(), data);
+            assert_eq!(decompress_zlib(&compress_zlib(&data, Some(9)).unwrap()).unwrap(), data);
+            assert_eq!(decompress_deflate(&compress_deflate(&data, Some(1)).unwrap()).unwrap(), data);
+            assert_eq!(decompress_lz4(&compress_lz4(&data, None).unwrap()).unwrap(), data);
+            assert_eq!(decompress_lz4_block(&compress_lz4_block(&data)).unwrap(), data);
+            assert_eq!(decompress_zstd(&compress_zstd(&data, None).unwrap()).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn lz77_round_trips() {
+        for data in samples() {
+            assert_eq!(decompress_lz77(&compress_lz77(&data, None)), data);
+            assert_eq!(decompress_lz77(&compress_lz77(&data, Some(64))), data);
+        }
+    }
+
+    #[test]
+    fn rejects_unsupported_levels() {
+        assert!(compress_gzip(b"data", Some(10)).is_err());
+        assert!(compress_lz4(b"data", Some(9)).is_err());
+        assert!(compress_zstd(b"data", Some(3)).is_err());
+    }
+}
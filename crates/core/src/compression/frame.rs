@@ -0,0 +1,355 @@
+use anyhow::{anyhow, bail, Result};
+use flate2::read::DeflateDecoder;
+use std::io::Read;
+use std::str::FromStr;
+
+use super::compression::{
+    compress_deflate, compress_gzip, compress_lz4, compress_lz77, compress_zlib, compress_zstd, decompress_brotli,
+    decompress_deflate, decompress_gzip, decompress_lz4, decompress_lz77, decompress_zlib, decompress_zstd,
+};
+use super::huffman::{compress_huffman, decompress_huffman_bounded};
+
+pub const FRAME_MAGIC: [u8; 4] = *b"MLSZ";
+pub const FRAME_VERSION: u8 = 1;
+pub const FRAME_HEADER_LEN: usize = 18;
+
+const DETECTION_LIMIT: u64 = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    Stored,
+    Gzip,
+    Zlib,
+    Deflate,
+    Lz77,
+    Huffman,
+    Lz4,
+    Zstd,
+}
+
+impl Codec {
+    pub fn id(&self) -> u8 {
+        match self {
+            Codec::Stored => 0,
+            Codec::Gzip => 1,
+            Codec::Zlib => 2,
+            Codec::Deflate => 3,
+            Codec::Lz77 => 4,
+            Codec::Huffman => 5,
+            Codec::Lz4 => 6,
+            Codec::Zstd => 7,
+        }
+    }
+
+    pub fn from_id(id: u8) -> Result<Self> {
+        match id {
+            0 => Ok(Codec::Stored),
+            1 => Ok(Codec::Gzip),
+            2 => Ok(Codec::Zlib),
+            3 => Ok(Codec::Deflate),
+            4 => Ok(Codec::Lz77),
+            5 => Ok(Codec::Huffman),
+            6 => Ok(Codec::Lz4),
+            7 => Ok(Codec::Zstd),
+            _ => bail!("Unknown codec id: {}", id),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Codec::Stored => "stored",
+            Codec::Gzip => "gzip",
+            Codec::Zlib => "zlib",
+            Codec::Deflate => "deflate",
+            Codec::Lz77 => "lz77",
+            Codec::Huffman => "huffman",
+            Codec::Lz4 => "lz4",
+            Codec::Zstd => "zstd",
+        }
+    }
+
+    pub(crate) fn encode(&self, data: &[u8], level: Option<u32>) -> Result<Vec<u8>> {
+        match self {
+            Codec::Stored => Ok(data.to_vec()),
+            Codec::Gzip => compress_gzip(data, level),
+            Codec::Zlib => compress_zlib(data, level),
+            Codec::Deflate => compress_deflate(data, level),
+            Codec::Lz77 => Ok(compress_lz77(data, None)),
+            Codec::Lz4 => compress_lz4(data, level),
+            Codec::Zstd => compress_zstd(data, level),
+            Codec::Huffman => {
+                let encoded = compress_huffman(data);
+                let tree_len = u16::try_from(encoded.tree.len())
+                    .map_err(|_| anyhow!("Huffman tree too large to frame"))?;
+                
+                let mut payload = Vec::with_capacity(2 + encoded.tree.len() + encoded.data.len());
+                payload.extend_from_slice(&tree_len.to_le_bytes());
+                payload.extend_from_slice(&encoded.tree);
+                payload.extend_from_slice(&encoded.data);
+                Ok(payload)
+            },
+        }
+    }
+
+    pub(crate) fn decode(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        self.decode_bounded(payload, usize::MAX)
+    }
+
+    fn decode_bounded(&self, payload: &[u8], max_length: usize) -> Result<Vec<u8>> {
+        match self {
+            Codec::Stored => Ok(payload.to_vec()),
+            Codec::Gzip => decompress_gzip(payload),
+            Codec::Zlib => decompress_zlib(payload),
+            Codec::Deflate => decompress_deflate(payload),
+            Codec::Lz77 => Ok(decompress_lz77(payload)),
+            Codec::Lz4 => decompress_lz4(payload),
+            Codec::Zstd => decompress_zstd(payload),
+            Codec::Huffman => {
+                if payload.len() < 2 {
+                    bail!("Truncated Huffman payload");
+                }
+                
+                let tree_len = u16::from_le_bytes([payload[0], payload[1]]) as usize;
+                if payload.len() < 2 + tree_len {
+                    bail!("Truncated Huffman tree");
+                }
+                
+                decompress_huffman_bounded(&payload[2 + tree_len..], &payload[2..2 + tree_len], max_length)
+            },
+        }
+    }
+}
+
+impl FromStr for Codec {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "stored" | "none" => Ok(Codec::Stored),
+            "gzip" | "gz" => Ok(Codec::Gzip),
+            "zlib" => Ok(Codec::Zlib),
+            "deflate" | "deflate-raw" => Ok(Codec::Deflate),
+            "lz77" => Ok(Codec::Lz77),
+            "huffman" => Ok(Codec::Huffman),
+            "lz4" => Ok(Codec::Lz4),
+            "zstd" | "zst" => Ok(Codec::Zstd),
+            _ => bail!("Unsupported codec: {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameHeader {
+    pub version: u8,
+    pub codec: Codec,
+    pub original_length: u64,
+    pub checksum: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectedFormat {
+    Framed(Codec),
+    Gzip,
+    Zlib,
+    Deflate,
+    Lz4,
+    Zstd,
+    Brotli,
+}
+
+impl DetectedFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DetectedFormat::Framed(codec) => codec.name(),
+            DetectedFormat::Gzip => "gzip",
+            DetectedFormat::Zlib => "zlib",
+            DetectedFormat::Deflate => "deflate",
+            DetectedFormat::Lz4 => "lz4",
+            DetectedFormat::Zstd => "zstd",
+            DetectedFormat::Brotli => "brotli",
+        }
+    }
+}
+
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = flate2::Crc::new();
+    crc.update(data);
+    crc.sum()
+}
+
+pub fn compress_framed(data: &[u8], codec: Codec, level: Option<u32>) -> Result<Vec<u8>> {
+    let payload = codec.encode(data, level)?;
+    
+    let mut framed = Vec::with_capacity(FRAME_HEADER_LEN + payload.len());
+    framed.extend_from_slice(&FRAME_MAGIC);
+    framed.push(FRAME_VERSION);
+    framed.push(codec.id());
+    framed.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    framed.extend_from_slice(&crc32(data).to_le_bytes());
+    framed.extend_from_slice(&payload);
+    
+    Ok(framed)
+}
+
+pub fn read_frame_header(data: &[u8]) -> Result<FrameHeader> {
+    if data.len() < FRAME_HEADER_LEN || data[..4] != FRAME_MAGIC {
+        bail!("Data is not a framed compression container");
+    }
+    
+    let version = data[4];
+    if version == 0 || version > FRAME_VERSION {
+        bail!("Unsupported frame version {} (expected at most {})", version, FRAME_VERSION);
+    }
+    
+    let mut length = [0u8; 8];
+    length.copy_from_slice(&data[6..14]);
+    let mut checksum = [0u8; 4];
+    checksum.copy_from_slice(&data[14..18]);
+    
+    Ok(FrameHeader {
+        version,
+        codec: Codec::from_id(data[5])?,
+        original_length: u64::from_le_bytes(length),
+        checksum: u32::from_le_bytes(checksum),
+    })
+}
+
+pub fn decompress_framed(data: &[u8]) -> Result<Vec<u8>> {
+    let header = read_frame_header(data)?;
+    let max_length = usize::try_from(header.original_length).unwrap_or(usize::MAX);
+    let decoded = header.codec.decode_bounded(&data[FRAME_HEADER_LEN..], max_length)?;
+    
+    if decoded.len() as u64 != header.original_length {
+        bail!(
+            "Frame length mismatch: expected {} bytes, decoded {}",
+            header.original_length,
+            decoded.len()
+        );
+    }
+    if crc32(&decoded) != header.checksum {
+        bail!("Frame checksum mismatch");
+    }
+    
+    Ok(decoded)
+}
+
+fn magic_format(data: &[u8]) -> Option<DetectedFormat> {
+    if let Ok(header) = read_frame_header(data) {
+        return Some(DetectedFormat::Framed(header.codec));
+    }
+    
+    match data {
+        [0x1f, 0x8b, ..] => Some(DetectedFormat::Gzip),
+        [0x04, 0x22, 0x4d, 0x18, ..] => Some(DetectedFormat::Lz4),
+        [0x28, 0xb5, 0x2f, 0xfd, ..] => Some(DetectedFormat::Zstd),
+        [cmf, flg, ..] if cmf & 0x0f == 8 && cmf >> 4 <= 7 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0 => {
+            Some(DetectedFormat::Zlib)
+        },
+        _ => None,
+    }
+}
+
+fn may_be_deflate(data: &[u8]) -> bool {
+    data.first().is_some_and(|&first| (first >> 1) & 0b11 != 0b11)
+}
+
+fn decodes_prefix(decoder: impl Read) -> bool {
+    decoder.take(DETECTION_LIMIT).read_to_end(&mut Vec::new()).is_ok()
+}
+
+pub fn detect_format(data: &[u8]) -> Option<DetectedFormat> {
+    if data.is_empty() {
+        return None;
+    }
+    
+    magic_format(data).or_else(|| {
+        if may_be_deflate(data) && decodes_prefix(DeflateDecoder::new(data)) {
+            return Some(DetectedFormat::Deflate);
+        }
+        
+        decodes_prefix(brotli_decompressor::Decompressor::new(data, 4096)).then_some(DetectedFormat::Brotli)
+    })
+}
+
+fn decode_as(format: DetectedFormat, data: &[u8]) -> Result<Vec<u8>> {
+    match format {
+        DetectedFormat::Framed(_) => decompress_framed(data),
+        DetectedFormat::Gzip => decompress_gzip(data),
+        DetectedFormat::Zlib => decompress_zlib(data),
+        DetectedFormat::Deflate => decompress_deflate(data),
+        DetectedFormat::Lz4 => decompress_lz4(data),
+        DetectedFormat::Zstd => decompress_zstd(data),
+        DetectedFormat::Brotli => decompress_brotli(data),
+    }
+}
+
+pub fn decompress_auto(data: &[u8]) -> Result<Vec<u8>> {
+    if let Some(format) = magic_format(data) {
+        return decode_as(format, data);
+    }
+    
+    if may_be_deflate(data) {
+        if let Ok(decompressed) = decompress_deflate(data) {
+            return Ok(decompressed);
+        }
+    }
+    
+    decompress_brotli(data).map_err(|_| anyhow!("Unrecognized compression format"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &[u8] = b"the quick brown fox jumps over the lazy dog, the quick brown fox jumps over the lazy dog";
+
+    fn frame(codec: Codec, original_length: u64, payload: &[u8]) -> Vec<u8> {
+        let mut framed = FRAME_MAGIC.to_vec();
+        framed.push(FRAME_VERSION);
+        framed.push(codec.id());
+        framed.extend_from_slice(&original_length.to_le_bytes());
+        framed.extend_from_slice(&0u32.to_le_bytes());
+        framed.extend_from_slice(payload);
+        framed
+    }
+
+    #[test]
+    fn framed_round_trip() {
+        for codec in [Codec::Stored, Codec::Gzip, Codec::Zlib, Codec::Deflate, Codec::Lz77, Codec::Huffman, Codec::Lz4, Codec::Zstd] {
+            let framed = compress_framed(DATA, codec, None).unwrap();
+            assert_eq!(read_frame_header(&framed).unwrap().codec, codec);
+            assert_eq!(decompress_auto(&framed).unwrap(), DATA, "{}", codec.name());
+        }
+    }
+
+    #[test]
+    fn detects_headerless_formats() {
+        assert_eq!(detect_format(&compress_gzip(DATA, None).unwrap()), Some(DetectedFormat::Gzip));
+        assert_eq!(detect_format(&compress_zlib(DATA, None).unwrap()), Some(DetectedFormat::Zlib));
+        assert_eq!(detect_format(&compress_deflate(DATA, None).unwrap()), Some(DetectedFormat::Deflate));
+        assert_eq!(detect_format(&compress_zstd(DATA, None).unwrap()), Some(DetectedFormat::Zstd));
+        assert_eq!(decompress_auto(&compress_deflate(DATA, None).unwrap()).unwrap(), DATA);
+    }
+
+    #[test]
+    fn rejects_deep_huffman_tree() {
+        let mut payload = 60_000u16.to_le_bytes().to_vec();
+        payload.extend_from_slice(&[1; 60_000]);
+        payload.extend_from_slice(&[8, 0, 0, 0, 0]);
+        assert!(decompress_auto(&frame(Codec::Huffman, 1, &payload)).is_err());
+    }
+
+    #[test]
+    fn huffman_output_is_bounded_by_original_length() {
+        let mut payload = 2u16.to_le_bytes().to_vec();
+        payload.extend_from_slice(&[0, b'a']);
+        payload.extend_from_slice(&64u32.to_le_bytes());
+        payload.extend_from_slice(&[0; 8]);
+        assert!(decompress_framed(&frame(Codec::Huffman, 4, &payload)).is_err());
+
+        let framed = compress_framed(DATA, Codec::Huffman, None).unwrap();
+        let mut truncated_length = framed.clone();
+        truncated_length[6..14].copy_from_slice(&10u64.to_le_bytes());
+        assert!(decompress_framed(&truncated_length).is_err());
+    }
+}
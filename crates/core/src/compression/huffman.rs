@@ -0,0 +1,335 @@
+use anyhow::{bail, Result};
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap}};
+
+const MAX_TREE_NODES: usize = 511;
+
+#[derive(Debug, Clone, Default)]
+pub struct HuffmanEncoded {
+    pub data: Vec<u8>,
+    pub tree: Vec<u8>,
+}
+
+#[derive(Clone)]
+enum HuffmanNode {
+    Leaf { byte: u8, freq: usize },
+    Internal { freq: usize, left: Box<HuffmanNode>, right: Box<HuffmanNode> },
+}
+
+impl HuffmanNode {
+    fn freq(&self) -> usize {
+        match self {
+            HuffmanNode::Leaf { freq, .. } => *freq,
+            HuffmanNode::Internal { freq, .. } => *freq,
+        }
+    }
+}
+
+impl PartialEq for HuffmanNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.freq() == other.freq()
+    }
+}
+
+impl Eq for HuffmanNode {}
+
+impl PartialOrd for HuffmanNode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HuffmanNode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.freq().cmp(&other.freq())
+    }
+}
+
+pub fn compress_huffman(data: &[u8]) -> HuffmanEncoded {
+    if data.is_empty() {
+        return HuffmanEncoded::default();
+    }
+    
+    let frequencies = calculate_frequencies(data);
+    let tree = build_huffman_tree(&frequencies);
+    let codes = generate_huffman_codes(&tree);
+    
+    HuffmanEncoded {
+        data: encode_data(data, &codes),
+        tree: serialize_huffman_tree(&tree),
+    }
+}
+
+pub fn decompress_huffman(compressed_data: &[u8], tree_data: &[u8]) -> Result<Vec<u8>> {
+    decompress_huffman_bounded(compressed_data, tree_data, usize::MAX)
+}
+
+pub fn decompress_huffman_bounded(compressed_data: &[u8], tree_data: &[u8], max_length: usize) -> Result<Vec<u8>> {
+    if compressed_data.is_empty() || tree_data.is_empty() {
+        return Ok(Vec::new());
+    }
+    
+    let tree = deserialize_huffman_tree(tree_data)?;
+    decode_data(compressed_data, &tree, max_length)
+}
+
+fn calculate_frequencies(data: &[u8]) -> HashMap<u8, usize> {
+    let mut frequencies = HashMap::new();
+    
+    for &byte in data {
+        *frequencies.entry(byte).or_insert(0) += 1;
+    }
+    
+    frequencies
+}
+
+fn build_huffman_tree(frequencies: &HashMap<u8, usize>) -> HuffmanNode {
+    if frequencies.len() == 1 {
+        let (&byte, &freq) = frequencies.iter().next().unwrap();
+        return HuffmanNode::Leaf { byte, freq };
+    }
+    
+    let mut heap = BinaryHeap::new();
+    
+    for (&byte, &freq) in frequencies {
+        heap.push(Reverse(HuffmanNode::Leaf { byte, freq }));
+    }
+    
+    while heap.len() > 1 {
+        let Reverse(left) = heap.pop().unwrap();
+        let Reverse(right) = heap.pop().unwrap();
+        
+        heap.push(Reverse(HuffmanNode::Internal {
+            freq: left.freq() + right.freq(),
+            left: Box::new(left),
+            right: Box::new(right),
+        }));
+    }
+    
+    if let Some(Reverse(root)) = heap.pop() {
+        root
+    } else {
+        HuffmanNode::Leaf { byte: 0, freq: 0 }
+    }
+}
+
+fn generate_huffman_codes(tree: &HuffmanNode) -> HashMap<u8, Vec<bool>> {
+    let mut codes = HashMap::new();
+    let mut prefix = Vec::new();
+    
+    if let HuffmanNode::Leaf { byte, .. } = tree {
+        codes.insert(*byte, vec![false]);
+        return codes;
+    }
+    
+    generate_codes_recursive(tree, &mut prefix, &mut codes);
+    
+    codes
+}
+
+fn generate_codes_recursive(node: &HuffmanNode, prefix: &mut Vec<bool>, codes: &mut HashMap<u8, Vec<bool>>) {
+    match node {
+        HuffmanNode::Leaf { byte, .. } => {
+            codes.insert(*byte, prefix.clone());
+        },
+        HuffmanNode::Internal { left, right, .. } => {
+            prefix.push(false);
+            generate_codes_recursive(left, prefix, codes);
+            prefix.pop();
+            
+            prefix.push(true);
+            generate_codes_recursive(right, prefix, codes);
+            prefix.pop();
+        },
+    }
+}
+
+fn serialize_huffman_tree(tree: &HuffmanNode) -> Vec<u8> {
+    let mut result = Vec::new();
+    serialize_node(tree, &mut result);
+    result
+}
+
+fn serialize_node(node: &HuffmanNode, output: &mut Vec<u8>) {
+    match node {
+        HuffmanNode::Leaf { byte, .. } => {
+            output.push(0);
+            output.push(*byte);
+        },
+        HuffmanNode::Internal { left, right, .. } => {
+            output.push(1);
+            serialize_node(left, output);
+            serialize_node(right, output);
+        },
+    }
+}
+
+fn encode_data(data: &[u8], codes: &HashMap<u8, Vec<bool>>) -> Vec<u8> {
+    let mut result = Vec::new();
+    let mut current_byte = 0u8;
+    let mut bit_position = 0;
+    let mut total_bits = 0usize;
+    
+    for &byte in data {
+        if let Some(code) = codes.get(&byte) {
+            total_bits += code.len();
+            
+            for &bit in code {
+                if bit {
+                    current_byte |= 1 << bit_position;
+                }
+                
+                bit_position += 1;
+                
+                if bit_position == 8 {
+                    result.push(current_byte);
+                    current_byte = 0;
+                    bit_position = 0;
+                }
+            }
+        }
+    }
+    
+    if bit_position > 0 {
+        result.push(current_byte);
+    }
+    
+    let mut header = Vec::with_capacity(4 + result.len());
+    header.extend_from_slice(&(total_bits as u32).to_le_bytes());
+    header.append(&mut result);
+    
+    header
+}
+
+fn decode_data(compressed: &[u8], tree: &HuffmanNode, max_length: usize) -> Result<Vec<u8>> {
+    if compressed.len() < 4 {
+        bail!("Invalid compressed data");
+    }
+    
+    let mut total_bits_bytes = [0u8; 4];
+    total_bits_bytes.copy_from_slice(&compressed[0..4]);
+    let total_bits = u32::from_le_bytes(total_bits_bytes) as usize;
+    let encoded_bits = (compressed.len() - 4).saturating_mul(8);
+    
+    if total_bits > encoded_bits {
+        bail!("Huffman bit count {} exceeds the {} encoded bits", total_bits, encoded_bits);
+    }
+    
+    if let HuffmanNode::Leaf { byte, .. } = *tree {
+        if total_bits > max_length {
+            bail!("Huffman data decodes to more than {} bytes", max_length);
+        }
+        return Ok(vec![byte; total_bits]);
+    }
+    
+    let mut result = Vec::new();
+    let mut current_node = tree;
+    let mut bit_count = 0;
+    
+    for &byte in &compressed[4..] {
+        for bit_pos in 0..8 {
+            if bit_count >= total_bits {
+                break;
+            }
+            
+            let bit = (byte >> bit_pos) & 1 == 1;
+            
+            current_node = match current_node {
+                HuffmanNode::Internal { left, right, .. } => {
+                    if bit {
+                        right
+                    } else {
+                        left
+                    }
+                },
+                _ => bail!("Invalid Huffman tree"),
+            };
+            
+            if let HuffmanNode::Leaf { byte, .. } = *current_node {
+                if result.len() == max_length {
+                    bail!("Huffman data decodes to more than {} bytes", max_length);
+                }
+                result.push(byte);
+                current_node = tree;
+            }
+            
+            bit_count += 1;
+        }
+    }
+    
+    Ok(result)
+}
+
+fn deserialize_huffman_tree(data: &[u8]) -> Result<HuffmanNode> {
+    let mut index = 0;
+    let mut nodes = 0;
+    deserialize_node(data, &mut index, &mut nodes)
+}
+
+fn deserialize_node(data: &[u8], index: &mut usize, nodes: &mut usize) -> Result<HuffmanNode> {
+    if *index >= data.len() {
+        bail!("Invalid Huffman tree data");
+    }
+    
+    *nodes += 1;
+    if *nodes > MAX_TREE_NODES {
+        bail!("Huffman tree has more than {} nodes", MAX_TREE_NODES);
+    }
+    
+    let node_type = data[*index];
+    *index += 1;
+    
+    if node_type == 0 {
+        if *index >= data.len() {
+            bail!("Invalid Huffman tree data");
+        }
+        
+        let byte = data[*index];
+        *index += 1;
+        
+        Ok(HuffmanNode::Leaf { byte, freq: 0 })
+    } else {
+        let left = deserialize_node(data, index, nodes)?;
+        let right = deserialize_node(data, index, nodes)?;
+        
+        Ok(HuffmanNode::Internal {
+            freq: 0,
+            left: Box::new(left),
+            right: Box::new(right),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for data in [&b"abracadabra, abracadabra"[..], b"aaaaaaaa", b"x", &(0..=255).collect::<Vec<u8>>()] {
+            let encoded = compress_huffman(data);
+            assert_eq!(decompress_huffman(&encoded.data, &encoded.tree).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn rejects_oversized_tree() {
+        let encoded = compress_huffman(b"abracadabra");
+        assert!(decompress_huffman(&encoded.data, &[1; 60_000]).is_err());
+    }
+
+    #[test]
+    fn rejects_bit_count_beyond_data() {
+        let mut compressed = u32::MAX.to_le_bytes().to_vec();
+        compressed.push(0);
+        assert!(decompress_huffman(&compressed, &[0, b'a']).is_err());
+    }
+
+    #[test]
+    fn bounded_decode_stops_at_max_length() {
+        for data in [&b"abracadabra"[..], b"aaaaaaaaaaa"] {
+            let encoded = compress_huffman(data);
+            assert_eq!(decompress_huffman_bounded(&encoded.data, &encoded.tree, data.len()).unwrap(), data);
+            assert!(decompress_huffman_bounded(&encoded.data, &encoded.tree, data.len() - 1).is_err());
+        }
+    }
+}
@@ -0,0 +1,10 @@
+#[allow(clippy::module_inception)]
+pub mod compression;
+pub mod huffman;
+pub mod stream;
+pub mod frame;
+
+pub use compression::*;
+pub use huffman::*;
+pub use stream::*;
+pub use frame::*;
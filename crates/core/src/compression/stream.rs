@@ -0,0 +1,463 @@
+use anyhow::{anyhow, bail, Result};
+use flate2::write::{DeflateDecoder, DeflateEncoder, GzDecoder, GzEncoder, ZlibDecoder, ZlibEncoder};
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+use super::compression::compression_level;
+
+pub const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionFormat {
+    Gzip,
+    Zlib,
+    Deflate,
+}
+
+impl CompressionFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CompressionFormat::Gzip => "gzip",
+            CompressionFormat::Zlib => "zlib",
+            CompressionFormat::Deflate => "deflate",
+        }
+    }
+}
+
+impl FromStr for CompressionFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "gzip" | "gz" => Ok(CompressionFormat::Gzip),
+            "zlib" => Ok(CompressionFormat::Zlib),
+            "deflate" | "deflate-raw" => Ok(CompressionFormat::Deflate),
+            _ => bail!("Unsupported compression format: {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Progress {
+    pub bytes_in: u64,
+    pub bytes_out: u64,
+}
+
+impl Progress {
+    pub fn ratio(&self) -> f64 {
+        if self.bytes_in == 0 {
+            0.0
+        } else {
+            self.bytes_out as f64 / self.bytes_in as f64
+        }
+    }
+}
+
+trait Sink: Write {
+    fn buffer(&mut self) -> &mut Vec<u8>;
+    fn finish_boxed(self: Box<Self>) -> io::Result<Vec<u8>>;
+}
+
+macro_rules! impl_sink {
+    ($($t:ident),*) => {
+        $(
+            impl Sink for $t<Vec<u8>> {
+                fn buffer(&mut self) -> &mut Vec<u8> {
+                    self.get_mut()
+                }
+
+                fn finish_boxed(self: Box<Self>) -> io::Result<Vec<u8>> {
+                    (*self).finish()
+                }
+            }
+        )*
+    };
+}
+
+impl_sink!(GzEncoder, ZlibEncoder, DeflateEncoder, GzDecoder, ZlibDecoder, DeflateDecoder);
+
+struct Pipeline {
+    sink: Option<Box<dyn Sink>>,
+    input: Vec<u8>,
+    output: Vec<u8>,
+    progress: Progress,
+    operation: &'static str,
+}
+
+impl Pipeline {
+    fn new(sink: Box<dyn Sink>, operation: &'static str) -> Self {
+        Pipeline {
+            sink: Some(sink),
+            input: Vec::new(),
+            output: Vec::new(),
+            progress: Progress::default(),
+            operation,
+        }
+    }
+
+    fn sink(&mut self) -> Result<&mut Box<dyn Sink>> {
+        self.sink.as_mut().ok_or_else(|| anyhow!("{} stream already finished", self.operation))
+    }
+
+    fn collect(&mut self, produced: Vec<u8>) {
+        self.progress.bytes_out += produced.len() as u64;
+        if self.output.is_empty() {
+            self.output = produced;
+        } else {
+            self.output.extend_from_slice(&produced);
+        }
+    }
+
+    fn write(&mut self, chunk: &[u8]) -> Result<()> {
+        self.sink()?;
+        self.input.extend_from_slice(chunk);
+        self.process(STREAM_CHUNK_SIZE)
+    }
+
+    fn process(&mut self, target: usize) -> Result<()> {
+        let operation = self.operation;
+        let mut consumed = 0;
+        
+        while consumed < self.input.len() && self.output.len() < target {
+            let sink = self.sink.as_mut().ok_or_else(|| anyhow!("{} stream already finished", operation))?;
+            let written = sink.write(&self.input[consumed..]).map_err(|e| anyhow!("{} error: {}", operation, e))?;
+            if written == 0 {
+                bail!("{} error: unexpected data after the end of the stream", operation);
+            }
+            
+            consumed += written;
+            let produced = std::mem::take(sink.buffer());
+            self.progress.bytes_in += written as u64;
+            self.collect(produced);
+        }
+        
+        self.input.drain(..consumed);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        let operation = self.operation;
+        self.process(usize::MAX)?;
+        let sink = self.sink()?;
+        
+        sink.flush().map_err(|e| anyhow!("{} error: {}", operation, e))?;
+        let produced = std::mem::take(sink.buffer());
+        
+        self.collect(produced);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        let operation = self.operation;
+        self.process(usize::MAX)?;
+        let sink = self.sink.take().ok_or_else(|| anyhow!("{} stream already finished", operation))?;
+        
+        let produced = sink.finish_boxed().map_err(|e| anyhow!("{} finalization error: {}", operation, e))?;
+        self.collect(produced);
+        Ok(())
+    }
+
+    fn take_output(&mut self, max: Option<usize>) -> Result<Vec<u8>> {
+        self.process(max.unwrap_or(usize::MAX))?;
+        
+        Ok(match max {
+            Some(limit) if limit < self.output.len() => self.output.drain(..limit).collect(),
+            _ => std::mem::take(&mut self.output),
+        })
+    }
+
+    fn drain_to<W: Write>(&mut self, writer: &mut W) -> Result<()> {
+        loop {
+            let output = self.take_output(Some(STREAM_CHUNK_SIZE))?;
+            if output.is_empty() {
+                return Ok(());
+            }
+            writer.write_all(&output).map_err(|e| anyhow!("{} write error: {}", self.operation, e))?;
+        }
+    }
+}
+
+pub trait ChunkedStream {
+    fn write_chunk(&mut self, chunk: &[u8]) -> Result<()>;
+    fn push(&mut self, chunk: &[u8]) -> Result<Vec<u8>>;
+    fn sync_flush(&mut self) -> Result<Vec<u8>>;
+    fn close(&mut self) -> Result<()>;
+    fn finish(&mut self) -> Result<Vec<u8>>;
+    fn take_output(&mut self, max: Option<usize>) -> Result<Vec<u8>>;
+    fn pending_output(&self) -> usize;
+    fn is_finished(&self) -> bool;
+    fn format(&self) -> CompressionFormat;
+    fn progress(&self) -> Progress;
+}
+
+macro_rules! stream_api {
+    ($name:ident) => {
+        impl ChunkedStream for $name {
+            fn write_chunk(&mut self, chunk: &[u8]) -> Result<()> {
+                self.pipeline.write(chunk)
+            }
+
+            fn push(&mut self, chunk: &[u8]) -> Result<Vec<u8>> {
+                self.pipeline.write(chunk)?;
+                self.pipeline.take_output(None)
+            }
+
+            fn sync_flush(&mut self) -> Result<Vec<u8>> {
+                self.pipeline.flush()?;
+                self.pipeline.take_output(None)
+            }
+
+            fn close(&mut self) -> Result<()> {
+                self.pipeline.finish()
+            }
+
+            fn finish(&mut self) -> Result<Vec<u8>> {
+                self.pipeline.finish()?;
+                self.pipeline.take_output(None)
+            }
+
+            fn take_output(&mut self, max: Option<usize>) -> Result<Vec<u8>> {
+                self.pipeline.take_output(max)
+            }
+
+            fn pending_output(&self) -> usize {
+                self.pipeline.output.len()
+            }
+
+            fn is_finished(&self) -> bool {
+                self.pipeline.sink.is_none()
+            }
+
+            fn format(&self) -> CompressionFormat {
+                self.format
+            }
+
+            fn progress(&self) -> Progress {
+                self.pipeline.progress
+            }
+        }
+
+        impl Write for $name {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.pipeline.write(buf).map_err(|e| io::Error::other(e.to_string()))?;
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                self.pipeline.flush().map_err(|e| io::Error::other(e.to_string()))
+            }
+        }
+
+        impl Read for $name {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if buf.is_empty() {
+                    return Ok(0);
+                }
+                
+                let chunk = self.pipeline.take_output(Some(buf.len())).map_err(|e| io::Error::other(e.to_string()))?;
+                if chunk.is_empty() && self.pipeline.sink.is_some() {
+                    return Err(io::Error::new(io::ErrorKind::WouldBlock, "stream output is not available until more input arrives or the stream is finished"));
+                }
+                
+                buf[..chunk.len()].copy_from_slice(&chunk);
+                Ok(chunk.len())
+            }
+        }
+    };
+}
+
+pub struct StreamEncoder {
+    format: CompressionFormat,
+    pipeline: Pipeline,
+}
+
+impl StreamEncoder {
+    pub fn new(format: CompressionFormat, level: Option<u32>) -> Result<Self> {
+        let level = compression_level(level)?;
+        let sink: Box<dyn Sink> = match format {
+            CompressionFormat::Gzip => Box::new(GzEncoder::new(Vec::new(), level)),
+            CompressionFormat::Zlib => Box::new(ZlibEncoder::new(Vec::new(), level)),
+            CompressionFormat::Deflate => Box::new(DeflateEncoder::new(Vec::new(), level)),
+        };
+        
+        Ok(StreamEncoder {
+            format,
+            pipeline: Pipeline::new(sink, "Compression"),
+        })
+    }
+}
+
+pub struct StreamDecoder {
+    format: CompressionFormat,
+    pipeline: Pipeline,
+}
+
+impl StreamDecoder {
+    pub fn new(format: CompressionFormat) -> Self {
+        let sink: Box<dyn Sink> = match format {
+            CompressionFormat::Gzip => Box::new(GzDecoder::new(Vec::new())),
+            CompressionFormat::Zlib => Box::new(ZlibDecoder::new(Vec::new())),
+            CompressionFormat::Deflate => Box::new(DeflateDecoder::new(Vec::new())),
+        };
+        
+        StreamDecoder {
+            format,
+            pipeline: Pipeline::new(sink, "Decompression"),
+        }
+    }
+}
+
+stream_api!(StreamEncoder);
+stream_api!(StreamDecoder);
+
+fn pump<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    pipeline: &mut Pipeline,
+    mut on_progress: impl FnMut(Progress),
+) -> Result<Progress> {
+    let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+    let operation = pipeline.operation;
+    
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => bail!("{} read error: {}", operation, e),
+        };
+        
+        pipeline.write(&buffer[..read])?;
+        pipeline.drain_to(&mut writer)?;
+        on_progress(pipeline.progress);
+    }
+    
+    pipeline.finish()?;
+    pipeline.drain_to(&mut writer)?;
+    writer.flush().map_err(|e| anyhow!("{} write error: {}", operation, e))?;
+    on_progress(pipeline.progress);
+    
+    Ok(pipeline.progress)
+}
+
+pub fn compress_stream<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    format: CompressionFormat,
+    level: Option<u32>,
+    on_progress: impl FnMut(Progress),
+) -> Result<Progress> {
+    let mut encoder = StreamEncoder::new(format, level)?;
+    pump(reader, writer, &mut encoder.pipeline, on_progress)
+}
+
+pub fn decompress_stream<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    format: CompressionFormat,
+    on_progress: impl FnMut(Progress),
+) -> Result<Progress> {
+    let mut decoder = StreamDecoder::new(format);
+    pump(reader, writer, &mut decoder.pipeline, on_progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORMATS: [CompressionFormat; 3] = [CompressionFormat::Gzip, CompressionFormat::Zlib, CompressionFormat::Deflate];
+
+    fn sample() -> Vec<u8> {
+        (0..200_000u32).map(|i| (i % 251) as u8 ^ (i / 1000) as u8).collect()
+    }
+
+    fn compress(data: &[u8], format: CompressionFormat) -> Vec<u8> {
+        let mut encoder = StreamEncoder::new(format, None).unwrap();
+        let mut compressed = Vec::new();
+        for chunk in data.chunks(7_000) {
+            compressed.extend(encoder.push(chunk).unwrap());
+        }
+        compressed.extend(encoder.finish().unwrap());
+        compressed
+    }
+
+    #[test]
+    fn chunked_round_trip() {
+        let data = sample();
+        for format in FORMATS {
+            let compressed = compress(&data, format);
+            let mut decoder = StreamDecoder::new(format);
+            let mut decompressed = Vec::new();
+            for chunk in compressed.chunks(1_000) {
+                decompressed.extend(decoder.push(chunk).unwrap());
+            }
+            decompressed.extend(decoder.finish().unwrap());
+
+            assert_eq!(decompressed, data, "{}", format.name());
+            assert_eq!(decoder.progress().bytes_in, compressed.len() as u64);
+            assert_eq!(decoder.progress().bytes_out, data.len() as u64);
+        }
+    }
+
+    #[test]
+    fn io_round_trip() {
+        let data = sample();
+        for format in FORMATS {
+            let mut compressed = Vec::new();
+            compress_stream(&data[..], &mut compressed, format, Some(6), |_| {}).unwrap();
+
+            let mut decompressed = Vec::new();
+            let progress = decompress_stream(&compressed[..], &mut decompressed, format, |_| {}).unwrap();
+            assert_eq!(decompressed, data);
+            assert_eq!(progress.bytes_out, data.len() as u64);
+        }
+    }
+
+    #[test]
+    fn decoding_is_bounded_by_reads() {
+        let data = vec![0u8; 8 * 1024 * 1024];
+        let compressed = compress(&data, CompressionFormat::Gzip);
+        let mut decoder = StreamDecoder::new(CompressionFormat::Gzip);
+
+        decoder.write_chunk(&compressed).unwrap();
+        assert!(decoder.pending_output() <= 2 * STREAM_CHUNK_SIZE, "{}", decoder.pending_output());
+
+        let mut total = 0;
+        loop {
+            let chunk = decoder.take_output(Some(4096)).unwrap();
+            if chunk.is_empty() {
+                break;
+            }
+            assert!(chunk.len() <= 4096);
+            assert!(decoder.pending_output() <= 2 * STREAM_CHUNK_SIZE);
+            total += chunk.len();
+        }
+        decoder.close().unwrap();
+        total += decoder.take_output(None).unwrap().len();
+        assert_eq!(total, data.len());
+    }
+
+    #[test]
+    fn read_blocks_until_finished() {
+        let data = sample();
+        let mut encoder = StreamEncoder::new(CompressionFormat::Zlib, None).unwrap();
+        encoder.write_all(&data).unwrap();
+
+        let mut partial = Vec::new();
+        let error = encoder.read_to_end(&mut partial).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WouldBlock);
+
+        encoder.close().unwrap();
+        encoder.read_to_end(&mut partial).unwrap();
+        assert_eq!(crate::compression::decompress_zlib(&partial).unwrap(), data);
+    }
+
+    #[test]
+    fn rejects_trailing_and_corrupt_data() {
+        let mut compressed = compress(b"payload", CompressionFormat::Deflate);
+        compressed.extend_from_slice(b"trailing");
+        assert!(StreamDecoder::new(CompressionFormat::Deflate).push(&compressed).is_err());
+
+        assert!(StreamDecoder::new(CompressionFormat::Zlib).push(&[0xff; 64]).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::{anyhow, bail, Result};
+use sha2::{Sha256, Sha512, Digest};
+use hmac::{Hmac, Mac};
+use aes_gcm::{
+    aead::{Aead, KeyInit, AeadCore, Nonce, OsRng, Payload},
+    Aes256Gcm
+};
+use chacha20poly1305::ChaCha20Poly1305;
+
+pub const AES_GCM_NONCE_LEN: usize = 12;
+pub const CHACHA20_POLY1305_NONCE_LEN: usize = 12;
+
+pub fn sha256(data: &[u8]) -> Vec<u8> {
+    Sha256::digest(data).to_vec()
+}
+
+pub fn sha512(data: &[u8]) -> Vec<u8> {
+    Sha512::digest(data).to_vec()
+}
+
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key)
+        .map_err(|_| anyhow!("Invalid key length"))?;
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+pub fn hmac_sha512(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    let mut mac = <Hmac<Sha512> as Mac>::new_from_slice(key)
+        .map_err(|_| anyhow!("Invalid key length"))?;
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+pub(crate) fn aes_gcm_cipher(key: &[u8]) -> Result<Aes256Gcm> {
+    if key.len() != 32 {
+        bail!("Key must be 32 bytes (256 bits)");
+    }
+    
+    Aes256Gcm::new_from_slice(key).map_err(|_| anyhow!("Failed to create cipher"))
+}
+
+pub(crate) fn chacha20_poly1305_cipher(key: &[u8]) -> Result<ChaCha20Poly1305> {
+    if key.len() != 32 {
+        bail!("Key must be 32 bytes (256 bits)");
+    }
+    
+    ChaCha20Poly1305::new_from_slice(key).map_err(|_| anyhow!("Failed to create cipher"))
+}
+
+fn seal<C: Aead + AeadCore>(cipher: &C, plaintext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>> {
+    let nonce = C::generate_nonce(&mut OsRng);
+    let ciphertext = cipher.encrypt(&nonce, Payload { msg: plaintext, aad: associated_data })
+        .map_err(|_| anyhow!("Encryption failed"))?;
+    
+    let mut result = Vec::with_capacity(nonce.len() + ciphertext.len());
+    result.extend_from_slice(&nonce);
+    result.extend_from_slice(&ciphertext);
+    
+    Ok(result)
+}
+
+fn open<C: Aead>(cipher: &C, nonce_len: usize, ciphertext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>> {
+    if ciphertext.len() < nonce_len {
+        bail!("Invalid ciphertext length");
+    }
+    
+    let (nonce, body) = ciphertext.split_at(nonce_len);
+    cipher.decrypt(Nonce::<C>::from_slice(nonce), Payload { msg: body, aad: associated_data })
+        .map_err(|_| anyhow!("Decryption failed"))
+}
+
+pub fn aes_gcm_encrypt(key: &[u8], plaintext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>> {
+    seal(&aes_gcm_cipher(key)?, plaintext, associated_data)
+}
+
+pub fn aes_gcm_decrypt(key: &[u8], ciphertext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>> {
+    open(&aes_gcm_cipher(key)?, AES_GCM_NONCE_LEN, ciphertext, associated_data)
+}
+
+pub fn chacha20_poly1305_encrypt(key: &[u8], plaintext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>> {
+    seal(&chacha20_poly1305_cipher(key)?, plaintext, associated_data)
+}
+
+pub fn chacha20_poly1305_decrypt(key: &[u8], ciphertext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>> {
+    open(&chacha20_poly1305_cipher(key)?, CHACHA20_POLY1305_NONCE_LEN, ciphertext, associated_data)
+}
+
+pub fn random_bytes(length: usize) -> Result<Vec<u8>> {
+    let mut bytes = vec![0u8; length];
+    getrandom::getrandom(&mut bytes).map_err(|e| anyhow!("Failed to generate random bytes: {}", e))?;
+    Ok(bytes)
+}
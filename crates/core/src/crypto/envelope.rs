@@ -0,0 +1,444 @@
+use anyhow::{anyhow, bail, Result};
+use std::str::FromStr;
+
+use crate::compression::Codec;
+use super::crypto::random_bytes;
+use super::hash::HashAlgorithm;
+use super::kdf::{argon2id, pbkdf2, Argon2Params};
+use super::stream::{AeadAlgorithm, SegmentCipher};
+
+// Envelope layout, integers big-endian:
+//
+//   magic "MLEV" (4) | version (1) | cipher id (1) | codec id (1) | kdf id (1)
+//   kdf params       | salt len (1) | salt | nonce (12) | ciphertext + tag (16)
+//
+// cipher ids follow AeadAlgorithm::id, codec ids follow compression::Codec::id.
+// kdf 0 is a raw 32-byte key with no params and an empty salt, kdf 1 is
+// PBKDF2-HMAC-SHA256 with params iterations (u32), kdf 2 is Argon2id with
+// params memory KiB, iterations and parallelism (u32 each). Every byte before
+// the ciphertext is authenticated as associated data.
+
+pub const ENVELOPE_MAGIC: &[u8; 4] = b"MLEV";
+pub const ENVELOPE_VERSION: u8 = 1;
+pub const ENVELOPE_NONCE_LEN: usize = 12;
+pub const ENVELOPE_TAG_LEN: usize = 16;
+pub const ENVELOPE_SALT_LEN: usize = 16;
+pub const ENVELOPE_KEY_LEN: usize = 32;
+pub const DEFAULT_PBKDF2_ITERATIONS: u32 = 600_000;
+pub const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+pub const MAX_ARGON2_MEMORY_KIB: u32 = 256 * 1024;
+pub const MAX_ARGON2_ITERATIONS: u32 = 1_000;
+pub const MAX_ARGON2_PARALLELISM: u32 = 255;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordKdf {
+    Pbkdf2 { iterations: u32 },
+    Argon2id(Argon2Params),
+}
+
+impl Default for PasswordKdf {
+    fn default() -> Self {
+        PasswordKdf::Argon2id(Argon2Params::default())
+    }
+}
+
+impl PasswordKdf {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PasswordKdf::Pbkdf2 { .. } => "pbkdf2",
+            PasswordKdf::Argon2id(_) => "argon2id",
+        }
+    }
+
+    fn id(&self) -> u8 {
+        match self {
+            PasswordKdf::Pbkdf2 { .. } => 1,
+            PasswordKdf::Argon2id(_) => 2,
+        }
+    }
+
+    fn check_limits(&self) -> Result<()> {
+        match *self {
+            PasswordKdf::Pbkdf2 { iterations } if iterations > MAX_PBKDF2_ITERATIONS => {
+                bail!("PBKDF2 iteration count {} exceeds the limit of {}", iterations, MAX_PBKDF2_ITERATIONS)
+            }
+            PasswordKdf::Argon2id(params) if params.memory_kib > MAX_ARGON2_MEMORY_KIB => {
+                bail!("Argon2 memory cost {} KiB exceeds the limit of {} KiB", params.memory_kib, MAX_ARGON2_MEMORY_KIB)
+            }
+            PasswordKdf::Argon2id(params) if params.iterations > MAX_ARGON2_ITERATIONS => {
+                bail!("Argon2 iteration count {} exceeds the limit of {}", params.iterations, MAX_ARGON2_ITERATIONS)
+            }
+            PasswordKdf::Argon2id(params) if params.parallelism > MAX_ARGON2_PARALLELISM => {
+                bail!("Argon2 parallelism {} exceeds the limit of {}", params.parallelism, MAX_ARGON2_PARALLELISM)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn derive(&self, password: &[u8], salt: &[u8]) -> Result<Vec<u8>> {
+        match *self {
+            PasswordKdf::Pbkdf2 { iterations } => pbkdf2(password, salt, iterations, ENVELOPE_KEY_LEN, HashAlgorithm::Sha256),
+            PasswordKdf::Argon2id(params) => argon2id(password, salt, params, ENVELOPE_KEY_LEN),
+        }
+    }
+}
+
+impl FromStr for PasswordKdf {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "pbkdf2" => Ok(PasswordKdf::Pbkdf2 { iterations: DEFAULT_PBKDF2_ITERATIONS }),
+            "argon2id" | "argon2" => Ok(PasswordKdf::default()),
+            _ => bail!("Unsupported password KDF: {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SealKey<'a> {
+    Key(&'a [u8]),
+    Password(&'a [u8]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SealOptions {
+    pub cipher: AeadAlgorithm,
+    pub compression: Codec,
+    pub level: Option<u32>,
+    pub kdf: PasswordKdf,
+}
+
+impl Default for SealOptions {
+    fn default() -> Self {
+        SealOptions {
+            cipher: AeadAlgorithm::Aes256Gcm,
+            compression: Codec::Stored,
+            level: None,
+            kdf: PasswordKdf::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvelopeHeader {
+    pub cipher: AeadAlgorithm,
+    pub compression: Codec,
+    pub kdf: Option<PasswordKdf>,
+    pub salt: Vec<u8>,
+    pub nonce: [u8; ENVELOPE_NONCE_LEN],
+}
+
+impl EnvelopeHeader {
+    pub fn is_password_protected(&self) -> bool {
+        self.kdf.is_some()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 + self.salt.len());
+        bytes.extend_from_slice(ENVELOPE_MAGIC);
+        bytes.push(ENVELOPE_VERSION);
+        bytes.push(self.cipher.id());
+        bytes.push(self.compression.id());
+        bytes.push(self.kdf.map_or(0, |kdf| kdf.id()));
+
+        match self.kdf {
+            None => {}
+            Some(PasswordKdf::Pbkdf2 { iterations }) => bytes.extend_from_slice(&iterations.to_be_bytes()),
+            Some(PasswordKdf::Argon2id(params)) => {
+                bytes.extend_from_slice(&params.memory_kib.to_be_bytes());
+                bytes.extend_from_slice(&params.iterations.to_be_bytes());
+                bytes.extend_from_slice(&params.parallelism.to_be_bytes());
+            }
+        }
+
+        bytes.push(self.salt.len() as u8);
+        bytes.extend_from_slice(&self.salt);
+        bytes.extend_from_slice(&self.nonce);
+        bytes
+    }
+
+    pub fn parse(data: &[u8]) -> Result<(EnvelopeHeader, usize)> {
+        let mut reader = HeaderReader { data, pos: 0 };
+
+        if reader.take(4)? != ENVELOPE_MAGIC {
+            bail!("Not a sealed envelope");
+        }
+        let version = reader.u8()?;
+        if version != ENVELOPE_VERSION {
+            bail!("Unsupported envelope version: {}", version);
+        }
+
+        let cipher = AeadAlgorithm::from_id(reader.u8()?)?;
+        let compression = Codec::from_id(reader.u8()?)?;
+        let kdf = match reader.u8()? {
+            0 => None,
+            1 => Some(PasswordKdf::Pbkdf2 { iterations: reader.u32()? }),
+            2 => Some(PasswordKdf::Argon2id(Argon2Params {
+                memory_kib: reader.u32()?,
+                iterations: reader.u32()?,
+                parallelism: reader.u32()?,
+            })),
+            id => bail!("Unknown KDF id: {}", id),
+        };
+        if let Some(kdf) = kdf {
+            kdf.check_limits()?;
+        }
+
+        let salt_len = reader.u8()? as usize;
+        let salt = reader.take(salt_len)?.to_vec();
+        let nonce = reader.take(ENVELOPE_NONCE_LEN)?.try_into().unwrap();
+
+        let header = EnvelopeHeader { cipher, compression, kdf, salt, nonce };
+        Ok((header, reader.pos))
+    }
+}
+
+struct HeaderReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> HeaderReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos + len).ok_or_else(|| anyhow!("Truncated envelope header"))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+}
+
+fn envelope_key(header: &EnvelopeHeader, key: SealKey) -> Result<Vec<u8>> {
+    match (key, header.kdf) {
+        (SealKey::Key(key), None) => {
+            if key.len() != ENVELOPE_KEY_LEN {
+                bail!("Key must be {} bytes", ENVELOPE_KEY_LEN);
+            }
+            Ok(key.to_vec())
+        }
+        (SealKey::Password(password), Some(kdf)) => kdf.derive(password, &header.salt),
+        (SealKey::Key(_), Some(_)) => bail!("Envelope is sealed with a password, not a key"),
+        (SealKey::Password(_), None) => bail!("Envelope is sealed with a key, not a password"),
+    }
+}
+
+pub fn seal(data: &[u8], key: SealKey, options: &SealOptions) -> Result<Vec<u8>> {
+    let (kdf, salt) = match key {
+        SealKey::Key(_) => (None, Vec::new()),
+        SealKey::Password(_) => {
+            options.kdf.check_limits()?;
+            (Some(options.kdf), random_bytes(ENVELOPE_SALT_LEN)?)
+        }
+    };
+
+    let header = EnvelopeHeader {
+        cipher: options.cipher,
+        compression: options.compression,
+        kdf,
+        salt,
+        nonce: random_bytes(ENVELOPE_NONCE_LEN)?.try_into().unwrap(),
+    };
+    let key = envelope_key(&header, key)?;
+    let payload = options.compression.encode(data, options.level)?;
+
+    let mut envelope = header.to_bytes();
+    let ciphertext = SegmentCipher::new(header.cipher, &key)?.seal(&header.nonce, &payload, &envelope)?;
+    envelope.extend_from_slice(&ciphertext);
+    Ok(envelope)
+}
+
+pub fn open(envelope: &[u8], key: SealKey) -> Result<Vec<u8>> {
+    let (header, header_len) = EnvelopeHeader::parse(envelope)?;
+    if envelope.len() < header_len + ENVELOPE_TAG_LEN {
+        bail!("Truncated envelope");
+    }
+
+    let key = envelope_key(&header, key)?;
+    let (aad, ciphertext) = envelope.split_at(header_len);
+    let payload = SegmentCipher::new(header.cipher, &key)?
+        .open(&header.nonce, ciphertext, aad)
+        .ok_or_else(|| anyhow!("Envelope authentication failed: wrong key or corrupted data"))?;
+    header.compression.decode(&payload)
+}
+
+pub fn read_envelope_header(envelope: &[u8]) -> Result<EnvelopeHeader> {
+    EnvelopeHeader::parse(envelope).map(|(header, _)| header)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; ENVELOPE_KEY_LEN] = [7; ENVELOPE_KEY_LEN];
+    const PLAINTEXT: &[u8] = b"sealed envelope payload, sealed envelope payload, sealed envelope payload";
+
+    fn fast_argon2() -> PasswordKdf {
+        PasswordKdf::Argon2id(Argon2Params { memory_kib: 64, iterations: 1, parallelism: 1 })
+    }
+
+    fn password_options(kdf: PasswordKdf) -> SealOptions {
+        SealOptions { kdf, ..SealOptions::default() }
+    }
+
+    fn header_len(envelope: &[u8]) -> usize {
+        EnvelopeHeader::parse(envelope).unwrap().1
+    }
+
+    #[test]
+    fn key_header_layout() {
+        let header = EnvelopeHeader {
+            cipher: AeadAlgorithm::ChaCha20Poly1305,
+            compression: Codec::Zstd,
+            kdf: None,
+            salt: Vec::new(),
+            nonce: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+        };
+        let expected = [
+            b'M', b'L', b'E', b'V', 1, 2, 7, 0,
+            0,
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11,
+        ];
+
+        assert_eq!(header.to_bytes(), expected);
+        assert_eq!(EnvelopeHeader::parse(&expected).unwrap(), (header, expected.len()));
+    }
+
+    #[test]
+    fn pbkdf2_header_layout() {
+        let header = EnvelopeHeader {
+            cipher: AeadAlgorithm::Aes256Gcm,
+            compression: Codec::Stored,
+            kdf: Some(PasswordKdf::Pbkdf2 { iterations: 600_000 }),
+            salt: vec![0xa0, 0xa1, 0xa2],
+            nonce: [0xee; ENVELOPE_NONCE_LEN],
+        };
+        let expected = [
+            b'M', b'L', b'E', b'V', 1, 1, 0, 1,
+            0x00, 0x09, 0x27, 0xc0,
+            3, 0xa0, 0xa1, 0xa2,
+            0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee,
+        ];
+
+        assert_eq!(header.to_bytes(), expected);
+        assert_eq!(EnvelopeHeader::parse(&expected).unwrap(), (header, expected.len()));
+    }
+
+    #[test]
+    fn argon2_header_layout() {
+        let header = EnvelopeHeader {
+            cipher: AeadAlgorithm::Aes256Gcm,
+            compression: Codec::Deflate,
+            kdf: Some(PasswordKdf::Argon2id(Argon2Params { memory_kib: 19 * 1024, iterations: 2, parallelism: 1 })),
+            salt: vec![1, 2, 3, 4],
+            nonce: [0x5a; ENVELOPE_NONCE_LEN],
+        };
+        let expected = [
+            b'M', b'L', b'E', b'V', 1, 1, 3, 2,
+            0x00, 0x00, 0x4c, 0x00,
+            0x00, 0x00, 0x00, 0x02,
+            0x00, 0x00, 0x00, 0x01,
+            4, 1, 2, 3, 4,
+            0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a,
+        ];
+
+        assert_eq!(header.to_bytes(), expected);
+        assert_eq!(EnvelopeHeader::parse(&expected).unwrap(), (header, expected.len()));
+    }
+
+    #[test]
+    fn parse_rejects_excessive_kdf_costs() {
+        let header = |kdf| EnvelopeHeader {
+            cipher: AeadAlgorithm::Aes256Gcm,
+            compression: Codec::Stored,
+            kdf: Some(kdf),
+            salt: vec![0; ENVELOPE_SALT_LEN],
+            nonce: [0; ENVELOPE_NONCE_LEN],
+        };
+        let argon2 = |memory_kib, iterations, parallelism| {
+            PasswordKdf::Argon2id(Argon2Params { memory_kib, iterations, parallelism })
+        };
+
+        for kdf in [
+            PasswordKdf::Pbkdf2 { iterations: MAX_PBKDF2_ITERATIONS + 1 },
+            argon2(MAX_ARGON2_MEMORY_KIB + 1, 2, 1),
+            argon2(64, u32::MAX, 1),
+            argon2(64, 2, u32::MAX),
+        ] {
+            assert!(EnvelopeHeader::parse(&header(kdf).to_bytes()).is_err(), "{:?}", kdf);
+            assert!(seal(PLAINTEXT, SealKey::Password(b"password"), &password_options(kdf)).is_err(), "{:?}", kdf);
+        }
+    }
+
+    #[test]
+    fn key_round_trip() {
+        for cipher in [AeadAlgorithm::Aes256Gcm, AeadAlgorithm::ChaCha20Poly1305] {
+            for compression in [Codec::Stored, Codec::Deflate, Codec::Zstd] {
+                let options = SealOptions { cipher, compression, ..SealOptions::default() };
+                let envelope = seal(PLAINTEXT, SealKey::Key(&KEY), &options).unwrap();
+                let header = read_envelope_header(&envelope).unwrap();
+
+                assert_eq!((header.cipher, header.compression, header.kdf), (cipher, compression, None));
+                assert_eq!(open(&envelope, SealKey::Key(&KEY)).unwrap(), PLAINTEXT);
+            }
+        }
+    }
+
+    #[test]
+    fn password_round_trip() {
+        for kdf in [PasswordKdf::Pbkdf2 { iterations: 1_000 }, fast_argon2()] {
+            let envelope = seal(PLAINTEXT, SealKey::Password(b"correct horse"), &password_options(kdf)).unwrap();
+            let header = read_envelope_header(&envelope).unwrap();
+
+            assert_eq!(header.kdf, Some(kdf));
+            assert_eq!(header.salt.len(), ENVELOPE_SALT_LEN);
+            assert_eq!(open(&envelope, SealKey::Password(b"correct horse")).unwrap(), PLAINTEXT);
+        }
+    }
+
+    #[test]
+    fn flipped_header_byte_fails() {
+        let envelope = seal(PLAINTEXT, SealKey::Key(&KEY), &SealOptions::default()).unwrap();
+
+        for index in 0..header_len(&envelope) {
+            let mut tampered = envelope.clone();
+            tampered[index] ^= 0x01;
+            assert!(open(&tampered, SealKey::Key(&KEY)).is_err(), "byte {} was not authenticated", index);
+        }
+
+        let envelope = seal(PLAINTEXT, SealKey::Password(b"password"), &password_options(fast_argon2())).unwrap();
+        let salt_index = header_len(&envelope) - ENVELOPE_NONCE_LEN - 1;
+        let mut tampered = envelope.clone();
+        tampered[salt_index] ^= 0x01;
+        assert!(open(&tampered, SealKey::Password(b"password")).is_err());
+    }
+
+    #[test]
+    fn wrong_key_or_password_fails() {
+        let envelope = seal(PLAINTEXT, SealKey::Key(&KEY), &SealOptions::default()).unwrap();
+        assert!(open(&envelope, SealKey::Key(&[8; ENVELOPE_KEY_LEN])).is_err());
+        assert!(open(&envelope, SealKey::Password(b"password")).is_err());
+
+        for kdf in [PasswordKdf::Pbkdf2 { iterations: 1_000 }, fast_argon2()] {
+            let envelope = seal(PLAINTEXT, SealKey::Password(b"password"), &password_options(kdf)).unwrap();
+            assert!(open(&envelope, SealKey::Password(b"Password")).is_err());
+            assert!(open(&envelope, SealKey::Key(&KEY)).is_err());
+        }
+    }
+
+    #[test]
+    fn truncated_envelope_fails() {
+        let envelope = seal(PLAINTEXT, SealKey::Key(&KEY), &SealOptions::default()).unwrap();
+        let header_len = header_len(&envelope);
+
+        assert!(open(&envelope[..header_len - 1], SealKey::Key(&KEY)).is_err());
+        assert!(open(&envelope[..header_len + ENVELOPE_TAG_LEN - 1], SealKey::Key(&KEY)).is_err());
+        assert!(open(&envelope[..envelope.len() - 1], SealKey::Key(&KEY)).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use anyhow::{bail, Result};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use sha3::{Sha3_256, Sha3_384, Sha3_512};
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+pub const HASH_CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha384 => "sha384",
+            HashAlgorithm::Sha512 => "sha512",
+            HashAlgorithm::Sha3_256 => "sha3-256",
+            HashAlgorithm::Sha3_384 => "sha3-384",
+            HashAlgorithm::Sha3_512 => "sha3-512",
+            HashAlgorithm::Blake3 => "blake3",
+        }
+    }
+
+    pub fn output_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Sha256 | HashAlgorithm::Sha3_256 | HashAlgorithm::Blake3 => 32,
+            HashAlgorithm::Sha384 | HashAlgorithm::Sha3_384 => 48,
+            HashAlgorithm::Sha512 | HashAlgorithm::Sha3_512 => 64,
+        }
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().replace(['-', '_'], "").as_str() {
+            "sha256" => Ok(HashAlgorithm::Sha256),
+            "sha384" => Ok(HashAlgorithm::Sha384),
+            "sha512" => Ok(HashAlgorithm::Sha512),
+            "sha3256" => Ok(HashAlgorithm::Sha3_256),
+            "sha3384" => Ok(HashAlgorithm::Sha3_384),
+            "sha3512" => Ok(HashAlgorithm::Sha3_512),
+            "blake3" => Ok(HashAlgorithm::Blake3),
+            _ => bail!("Unsupported hash algorithm: {}", name),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum HasherState {
+    Sha256(Sha256),
+    Sha384(Sha384),
+    Sha512(Sha512),
+    Sha3_256(Sha3_256),
+    Sha3_384(Sha3_384),
+    Sha3_512(Sha3_512),
+    Blake3(Box<blake3::Hasher>),
+}
+
+#[derive(Clone)]
+pub struct Hasher {
+    algorithm: HashAlgorithm,
+    state: HasherState,
+    bytes_processed: u64,
+}
+
+impl Hasher {
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        let state = match algorithm {
+            HashAlgorithm::Sha256 => HasherState::Sha256(Sha256::new()),
+            HashAlgorithm::Sha384 => HasherState::Sha384(Sha384::new()),
+            HashAlgorithm::Sha512 => HasherState::Sha512(Sha512::new()),
+            HashAlgorithm::Sha3_256 => HasherState::Sha3_256(Sha3_256::new()),
+            HashAlgorithm::Sha3_384 => HasherState::Sha3_384(Sha3_384::new()),
+            HashAlgorithm::Sha3_512 => HasherState::Sha3_512(Sha3_512::new()),
+            HashAlgorithm::Blake3 => HasherState::Blake3(Box::new(blake3::Hasher::new())),
+        };
+
+        Hasher {
+            algorithm,
+            state,
+            bytes_processed: 0,
+        }
+    }
+
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    pub fn bytes_processed(&self) -> u64 {
+        self.bytes_processed
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.state {
+            HasherState::Sha256(hasher) => hasher.update(data),
+            HasherState::Sha384(hasher) => hasher.update(data),
+            HasherState::Sha512(hasher) => hasher.update(data),
+            HasherState::Sha3_256(hasher) => hasher.update(data),
+            HasherState::Sha3_384(hasher) => hasher.update(data),
+            HasherState::Sha3_512(hasher) => hasher.update(data),
+            HasherState::Blake3(hasher) => {
+                hasher.update(data);
+            }
+        }
+        self.bytes_processed += data.len() as u64;
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        match self.state {
+            HasherState::Sha256(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha384(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha512(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha3_256(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha3_384(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha3_512(hasher) => hasher.finalize().to_vec(),
+            HasherState::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+        }
+    }
+
+    pub fn finalize_reset(&mut self) -> Vec<u8> {
+        std::mem::replace(self, Hasher::new(self.algorithm)).finalize()
+    }
+}
+
+impl Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn hash(algorithm: HashAlgorithm, data: &[u8]) -> Vec<u8> {
+    let mut hasher = Hasher::new(algorithm);
+    hasher.update(data);
+    hasher.finalize()
+}
+
+pub fn hash_reader<R: Read>(algorithm: HashAlgorithm, mut reader: R) -> Result<Vec<u8>> {
+    let mut hasher = Hasher::new(algorithm);
+    let mut buffer = vec![0u8; HASH_CHUNK_SIZE];
+
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => hasher.update(&buffer[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => bail!("Hash read error: {}", e),
+        }
+    }
+
+    Ok(hasher.finalize())
+}
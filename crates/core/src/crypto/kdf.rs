@@ -0,0 +1,87 @@
+use anyhow::{anyhow, bail, Result};
+use argon2::{Algorithm, Argon2, Params, Version};
+use hkdf::Hkdf;
+use sha2::{Sha256, Sha384, Sha512};
+
+use super::hash::HashAlgorithm;
+
+pub const MIN_SALT_LEN: usize = 8;
+pub const MAX_DERIVED_KEY_LEN: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Params {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Params {
+    fn default() -> Self {
+        Argon2Params {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+fn check_output_len(length: usize) -> Result<()> {
+    if length == 0 || length > MAX_DERIVED_KEY_LEN {
+        bail!("Derived key length must be between 1 and {} bytes", MAX_DERIVED_KEY_LEN);
+    }
+    Ok(())
+}
+
+fn check_salt(salt: &[u8]) -> Result<()> {
+    if salt.len() < MIN_SALT_LEN {
+        bail!("Salt must be at least {} bytes", MIN_SALT_LEN);
+    }
+    Ok(())
+}
+
+pub fn pbkdf2(password: &[u8], salt: &[u8], iterations: u32, length: usize, hash: HashAlgorithm) -> Result<Vec<u8>> {
+    check_output_len(length)?;
+    check_salt(salt)?;
+    if iterations == 0 {
+        bail!("PBKDF2 iterations must be greater than zero");
+    }
+
+    let mut key = vec![0u8; length];
+    match hash {
+        HashAlgorithm::Sha256 => pbkdf2::pbkdf2_hmac::<Sha256>(password, salt, iterations, &mut key),
+        HashAlgorithm::Sha384 => pbkdf2::pbkdf2_hmac::<Sha384>(password, salt, iterations, &mut key),
+        HashAlgorithm::Sha512 => pbkdf2::pbkdf2_hmac::<Sha512>(password, salt, iterations, &mut key),
+        _ => bail!("PBKDF2 supports only SHA-2 hashes, got {}", hash.name()),
+    }
+    Ok(key)
+}
+
+pub fn argon2id(password: &[u8], salt: &[u8], params: Argon2Params, length: usize) -> Result<Vec<u8>> {
+    check_output_len(length)?;
+    check_salt(salt)?;
+
+    let params = Params::new(params.memory_kib, params.iterations, params.parallelism, Some(length))
+        .map_err(|e| anyhow!("Invalid Argon2 parameters: {}", e))?;
+    let argon = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+    let mut key = vec![0u8; length];
+    argon.hash_password_into(password, salt, &mut key)
+        .map_err(|e| anyhow!("Argon2 key derivation failed: {}", e))?;
+    Ok(key)
+}
+
+pub fn hkdf(input_key: &[u8], salt: Option<&[u8]>, info: &[u8], length: usize, hash: HashAlgorithm) -> Result<Vec<u8>> {
+    if length == 0 || length > 255 * hash.output_len() {
+        bail!("HKDF output length must be between 1 and {} bytes", 255 * hash.output_len());
+    }
+
+    let mut key = vec![0u8; length];
+    let expanded = match hash {
+        HashAlgorithm::Sha256 => Hkdf::<Sha256>::new(salt, input_key).expand(info, &mut key),
+        HashAlgorithm::Sha384 => Hkdf::<Sha384>::new(salt, input_key).expand(info, &mut key),
+        HashAlgorithm::Sha512 => Hkdf::<Sha512>::new(salt, input_key).expand(info, &mut key),
+        _ => bail!("HKDF supports only SHA-2 hashes, got {}", hash.name()),
+    };
+    expanded.map_err(|_| anyhow!("HKDF expansion failed"))?;
+    Ok(key)
+}
@@ -0,0 +1,344 @@
+use anyhow::{anyhow, bail, Result};
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use super::keys::{key_bytes, KeyAlgorithm, KeyPair, KEY_LEN};
+
+const OID_ED25519: &[u8] = &[0x2b, 0x65, 0x70];
+const OID_X25519: &[u8] = &[0x2b, 0x65, 0x6e];
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_ATTRIBUTES: u8 = 0xa0;
+const TAG_PUBLIC_KEY: u8 = 0x81;
+
+const PEM_PUBLIC_LABEL: &str = "PUBLIC KEY";
+const PEM_PRIVATE_LABEL: &str = "PRIVATE KEY";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsymmetricKey {
+    pub algorithm: KeyAlgorithm,
+    pub public_key: Vec<u8>,
+    pub secret_key: Option<Vec<u8>>,
+}
+
+impl AsymmetricKey {
+    pub fn public(algorithm: KeyAlgorithm, public_key: &[u8]) -> Result<Self> {
+        key_bytes(public_key, "Public key")?;
+        Ok(AsymmetricKey {
+            algorithm,
+            public_key: public_key.to_vec(),
+            secret_key: None,
+        })
+    }
+
+    pub fn private(algorithm: KeyAlgorithm, secret_key: &[u8]) -> Result<Self> {
+        Ok(AsymmetricKey {
+            algorithm,
+            public_key: algorithm.public_key(secret_key)?,
+            secret_key: Some(secret_key.to_vec()),
+        })
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.secret_key.is_some()
+    }
+
+    pub fn to_public(&self) -> AsymmetricKey {
+        AsymmetricKey {
+            algorithm: self.algorithm,
+            public_key: self.public_key.clone(),
+            secret_key: None,
+        }
+    }
+}
+
+impl From<KeyPair> for AsymmetricKey {
+    fn from(pair: KeyPair) -> Self {
+        AsymmetricKey {
+            algorithm: pair.algorithm,
+            public_key: pair.public_key,
+            secret_key: Some(pair.secret_key),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub d: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+fn base64url_decode(value: &str, field: &str) -> Result<Vec<u8>> {
+    URL_SAFE_NO_PAD.decode(value.trim_end_matches('='))
+        .map_err(|_| anyhow!("JWK field '{}' is not valid base64url", field))
+}
+
+impl Jwk {
+    pub fn from_key(key: &AsymmetricKey) -> Jwk {
+        Jwk {
+            kty: "OKP".to_string(),
+            crv: Some(key.algorithm.name().to_string()),
+            x: Some(URL_SAFE_NO_PAD.encode(&key.public_key)),
+            d: key.secret_key.as_ref().map(|secret| URL_SAFE_NO_PAD.encode(secret)),
+            alg: match key.algorithm {
+                KeyAlgorithm::Ed25519 => Some("EdDSA".to_string()),
+                KeyAlgorithm::X25519 => None,
+            },
+            ..Jwk::default()
+        }
+    }
+
+    pub fn symmetric(key: &[u8], alg: Option<&str>) -> Jwk {
+        Jwk {
+            kty: "oct".to_string(),
+            k: Some(URL_SAFE_NO_PAD.encode(key)),
+            alg: alg.map(str::to_string),
+            ..Jwk::default()
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|e| anyhow!("Failed to serialize JWK: {}", e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Jwk> {
+        serde_json::from_str(json).map_err(|e| anyhow!("Invalid JWK: {}", e))
+    }
+
+    pub fn is_symmetric(&self) -> bool {
+        self.kty == "oct"
+    }
+
+    pub fn to_key(&self) -> Result<AsymmetricKey> {
+        if self.kty != "OKP" {
+            bail!("Unsupported JWK key type: {}", self.kty);
+        }
+
+        let algorithm: KeyAlgorithm = self.crv.as_deref()
+            .ok_or_else(|| anyhow!("JWK is missing 'crv'"))?
+            .parse()?;
+        let public_key = self.x.as_deref()
+            .map(|x| base64url_decode(x, "x"))
+            .transpose()?;
+
+        let key = match &self.d {
+            Some(d) => AsymmetricKey::private(algorithm, &base64url_decode(d, "d")?)?,
+            None => AsymmetricKey::public(algorithm, &public_key.clone().ok_or_else(|| anyhow!("JWK is missing 'x'"))?)?,
+        };
+
+        if public_key.is_some_and(|public_key| public_key != key.public_key) {
+            bail!("JWK public key does not match its private key");
+        }
+        Ok(key)
+    }
+
+    pub fn to_symmetric_key(&self) -> Result<Vec<u8>> {
+        if !self.is_symmetric() {
+            bail!("JWK is not a symmetric key");
+        }
+        base64url_decode(self.k.as_deref().ok_or_else(|| anyhow!("JWK is missing 'k'"))?, "k")
+    }
+}
+
+fn algorithm_oid(algorithm: KeyAlgorithm) -> &'static [u8] {
+    match algorithm {
+        KeyAlgorithm::Ed25519 => OID_ED25519,
+        KeyAlgorithm::X25519 => OID_X25519,
+    }
+}
+
+fn der(tag: u8, contents: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    if contents.len() < 0x80 {
+        out.push(contents.len() as u8);
+    } else {
+        let len = (contents.len() as u32).to_be_bytes();
+        let skip = len.iter().take_while(|&&b| b == 0).count();
+        out.push(0x80 | (4 - skip) as u8);
+        out.extend_from_slice(&len[skip..]);
+    }
+    out.extend_from_slice(contents);
+    out
+}
+
+fn algorithm_identifier(algorithm: KeyAlgorithm) -> Vec<u8> {
+    der(TAG_SEQUENCE, &der(TAG_OID, algorithm_oid(algorithm)))
+}
+
+struct DerReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DerReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        DerReader { data }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn peek_tag(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+
+    fn read_any(&mut self) -> Result<(u8, &'a [u8])> {
+        let truncated = || anyhow!("Truncated DER structure");
+        let (&tag, rest) = self.data.split_first().ok_or_else(truncated)?;
+        let (&first, mut rest) = rest.split_first().ok_or_else(truncated)?;
+
+        let len = if first < 0x80 {
+            first as usize
+        } else {
+            let count = (first & 0x7f) as usize;
+            if count == 0 || count > 4 || rest.len() < count {
+                bail!("Invalid DER length");
+            }
+            let len = rest[..count].iter().fold(0usize, |acc, &b| (acc << 8) | b as usize);
+            rest = &rest[count..];
+            len
+        };
+
+        if rest.len() < len {
+            return Err(truncated());
+        }
+        let (contents, rest) = rest.split_at(len);
+        self.data = rest;
+        Ok((tag, contents))
+    }
+
+    fn read(&mut self, expected: u8) -> Result<&'a [u8]> {
+        let (tag, contents) = self.read_any()?;
+        if tag != expected {
+            bail!("Unexpected DER tag 0x{:02x}, expected 0x{:02x}", tag, expected);
+        }
+        Ok(contents)
+    }
+}
+
+fn read_algorithm(reader: &mut DerReader) -> Result<KeyAlgorithm> {
+    let mut identifier = DerReader::new(reader.read(TAG_SEQUENCE)?);
+    let oid = identifier.read(TAG_OID)?;
+    if !identifier.is_empty() {
+        bail!("Unexpected parameters in key algorithm identifier");
+    }
+
+    match oid {
+        OID_ED25519 => Ok(KeyAlgorithm::Ed25519),
+        OID_X25519 => Ok(KeyAlgorithm::X25519),
+        _ => bail!("Unsupported key algorithm OID"),
+    }
+}
+
+fn read_bit_string_key(contents: &[u8]) -> Result<&[u8]> {
+    match contents.split_first() {
+        Some((0, key)) if key.len() == KEY_LEN => Ok(key),
+        _ => bail!("Invalid public key bit string"),
+    }
+}
+
+pub fn encode_public_key_der(key: &AsymmetricKey) -> Vec<u8> {
+    let mut bit_string = vec![0u8];
+    bit_string.extend_from_slice(&key.public_key);
+
+    let mut contents = algorithm_identifier(key.algorithm);
+    contents.extend(der(TAG_BIT_STRING, &bit_string));
+    der(TAG_SEQUENCE, &contents)
+}
+
+pub fn encode_private_key_der(key: &AsymmetricKey) -> Result<Vec<u8>> {
+    let secret_key = key.secret_key.as_ref().ok_or_else(|| anyhow!("Key has no private part"))?;
+
+    let mut contents = der(TAG_INTEGER, &[0]);
+    contents.extend(algorithm_identifier(key.algorithm));
+    contents.extend(der(TAG_OCTET_STRING, &der(TAG_OCTET_STRING, secret_key)));
+    Ok(der(TAG_SEQUENCE, &contents))
+}
+
+pub fn decode_public_key_der(data: &[u8]) -> Result<AsymmetricKey> {
+    let mut outer = DerReader::new(data);
+    let mut info = DerReader::new(outer.read(TAG_SEQUENCE)?);
+    let algorithm = read_algorithm(&mut info)?;
+    let public_key = read_bit_string_key(info.read(TAG_BIT_STRING)?)?;
+
+    AsymmetricKey::public(algorithm, public_key)
+}
+
+pub fn decode_private_key_der(data: &[u8]) -> Result<AsymmetricKey> {
+    let mut outer = DerReader::new(data);
+    let mut info = DerReader::new(outer.read(TAG_SEQUENCE)?);
+
+    match info.read(TAG_INTEGER)? {
+        [0] | [1] => {}
+        _ => bail!("Unsupported PKCS#8 version"),
+    }
+    let algorithm = read_algorithm(&mut info)?;
+    let secret_key = DerReader::new(info.read(TAG_OCTET_STRING)?).read(TAG_OCTET_STRING)?;
+    let key = AsymmetricKey::private(algorithm, secret_key)?;
+
+    if info.peek_tag() == Some(TAG_ATTRIBUTES) {
+        info.read_any()?;
+    }
+    if info.peek_tag() == Some(TAG_PUBLIC_KEY) && read_bit_string_key(info.read(TAG_PUBLIC_KEY)?)? != key.public_key {
+        bail!("PKCS#8 public key does not match its private key");
+    }
+    Ok(key)
+}
+
+fn pem_encode(label: &str, der: &[u8]) -> String {
+    let encoded = STANDARD.encode(der);
+    let mut pem = format!("-----BEGIN {}-----\n", label);
+    for line in encoded.as_bytes().chunks(64) {
+        pem.push_str(std::str::from_utf8(line).unwrap());
+        pem.push('\n');
+    }
+    pem.push_str(&format!("-----END {}-----\n", label));
+    pem
+}
+
+pub fn encode_public_key_pem(key: &AsymmetricKey) -> String {
+    pem_encode(PEM_PUBLIC_LABEL, &encode_public_key_der(key))
+}
+
+pub fn encode_private_key_pem(key: &AsymmetricKey) -> Result<String> {
+    Ok(pem_encode(PEM_PRIVATE_LABEL, &encode_private_key_der(key)?))
+}
+
+pub fn decode_pem(pem: &str) -> Result<AsymmetricKey> {
+    let pem = pem.trim();
+    let label = pem.strip_prefix("-----BEGIN ")
+        .and_then(|rest| rest.split_once("-----"))
+        .map(|(label, _)| label)
+        .ok_or_else(|| anyhow!("Missing PEM header"))?;
+
+    let footer = format!("-----END {}-----", label);
+    let body = pem.strip_suffix(&footer).ok_or_else(|| anyhow!("Missing PEM footer for {}", label))?;
+    let body: String = body.lines()
+        .skip(1)
+        .flat_map(|line| line.chars())
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let der = STANDARD.decode(body).map_err(|_| anyhow!("Invalid base64 in PEM body"))?;
+
+    match label {
+        PEM_PUBLIC_LABEL => decode_public_key_der(&der),
+        PEM_PRIVATE_LABEL => decode_private_key_der(&der),
+        _ => bail!("Unsupported PEM label: {}", label),
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::{anyhow, bail, Result};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use std::str::FromStr;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use super::crypto::random_bytes;
+
+pub const KEY_LEN: usize = 32;
+pub const SIGNATURE_LEN: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAlgorithm {
+    Ed25519,
+    X25519,
+}
+
+impl KeyAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyAlgorithm::Ed25519 => "Ed25519",
+            KeyAlgorithm::X25519 => "X25519",
+        }
+    }
+
+    pub fn public_key(&self, secret_key: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            KeyAlgorithm::Ed25519 => ed25519_public_key(secret_key),
+            KeyAlgorithm::X25519 => x25519_public_key(secret_key),
+        }
+    }
+
+    pub fn generate_key_pair(&self) -> Result<KeyPair> {
+        match self {
+            KeyAlgorithm::Ed25519 => ed25519_generate_key_pair(),
+            KeyAlgorithm::X25519 => x25519_generate_key_pair(),
+        }
+    }
+}
+
+impl FromStr for KeyAlgorithm {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "ed25519" => Ok(KeyAlgorithm::Ed25519),
+            "x25519" => Ok(KeyAlgorithm::X25519),
+            _ => bail!("Unsupported key algorithm: {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPair {
+    pub algorithm: KeyAlgorithm,
+    pub public_key: Vec<u8>,
+    pub secret_key: Vec<u8>,
+}
+
+pub(crate) fn key_bytes(key: &[u8], kind: &str) -> Result<[u8; KEY_LEN]> {
+    key.try_into().map_err(|_| anyhow!("{} must be {} bytes", kind, KEY_LEN))
+}
+
+fn random_key() -> Result<[u8; KEY_LEN]> {
+    key_bytes(&random_bytes(KEY_LEN)?, "Secret key")
+}
+
+pub fn ed25519_generate_key_pair() -> Result<KeyPair> {
+    let signing_key = SigningKey::from_bytes(&random_key()?);
+    Ok(KeyPair {
+        algorithm: KeyAlgorithm::Ed25519,
+        public_key: signing_key.verifying_key().to_bytes().to_vec(),
+        secret_key: signing_key.to_bytes().to_vec(),
+    })
+}
+
+pub fn ed25519_public_key(secret_key: &[u8]) -> Result<Vec<u8>> {
+    let signing_key = SigningKey::from_bytes(&key_bytes(secret_key, "Secret key")?);
+    Ok(signing_key.verifying_key().to_bytes().to_vec())
+}
+
+pub fn ed25519_sign(secret_key: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+    let signing_key = SigningKey::from_bytes(&key_bytes(secret_key, "Secret key")?);
+    Ok(signing_key.sign(message).to_bytes().to_vec())
+}
+
+pub fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool> {
+    let verifying_key = VerifyingKey::from_bytes(&key_bytes(public_key, "Public key")?)
+        .map_err(|_| anyhow!("Invalid Ed25519 public key"))?;
+    let signature = Signature::from_slice(signature)
+        .map_err(|_| anyhow!("Signature must be {} bytes", SIGNATURE_LEN))?;
+
+    Ok(verifying_key.verify_strict(message, &signature).is_ok())
+}
+
+pub fn x25519_generate_key_pair() -> Result<KeyPair> {
+    let secret = StaticSecret::from(random_key()?);
+    Ok(KeyPair {
+        algorithm: KeyAlgorithm::X25519,
+        public_key: PublicKey::from(&secret).to_bytes().to_vec(),
+        secret_key: secret.to_bytes().to_vec(),
+    })
+}
+
+pub fn x25519_public_key(secret_key: &[u8]) -> Result<Vec<u8>> {
+    let secret = StaticSecret::from(key_bytes(secret_key, "Secret key")?);
+    Ok(PublicKey::from(&secret).to_bytes().to_vec())
+}
+
+pub fn x25519_shared_secret(secret_key: &[u8], public_key: &[u8]) -> Result<Vec<u8>> {
+    let secret = StaticSecret::from(key_bytes(secret_key, "Secret key")?);
+    let public = PublicKey::from(key_bytes(public_key, "Public key")?);
+
+    let shared = secret.diffie_hellman(&public);
+    if !shared.was_contributory() {
+        bail!("Public key produced a low-order shared secret");
+    }
+    Ok(shared.to_bytes().to_vec())
+}
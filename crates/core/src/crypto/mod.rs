@@ -0,0 +1,16 @@
+#[allow(clippy::module_inception)]
+pub mod crypto;
+pub mod envelope;
+pub mod hash;
+pub mod kdf;
+pub mod keys;
+pub mod key_format;
+pub mod stream;
+
+pub use crypto::*;
+pub use envelope::*;
+pub use hash::*;
+pub use kdf::*;
+pub use keys::*;
+pub use key_format::*;
+pub use stream::*;
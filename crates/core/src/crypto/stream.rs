@@ -0,0 +1,386 @@
+use anyhow::{anyhow, bail, Result};
+use aes_gcm::aead::{Aead, Nonce, Payload};
+use aes_gcm::Aes256Gcm;
+use chacha20poly1305::ChaCha20Poly1305;
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+use super::crypto::{aes_gcm_cipher, chacha20_poly1305_cipher, random_bytes};
+
+pub const STREAM_MAGIC: &[u8; 4] = b"MLSE";
+pub const STREAM_VERSION: u8 = 1;
+pub const STREAM_HEADER_LEN: usize = 17;
+pub const STREAM_NONCE_PREFIX_LEN: usize = 7;
+pub const STREAM_TAG_LEN: usize = 16;
+pub const DEFAULT_SEGMENT_SIZE: usize = 64 * 1024;
+pub const MAX_SEGMENT_SIZE: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AeadAlgorithm {
+    Aes256Gcm,
+    ChaCha20Poly1305,
+}
+
+impl AeadAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AeadAlgorithm::Aes256Gcm => "aes-256-gcm",
+            AeadAlgorithm::ChaCha20Poly1305 => "chacha20-poly1305",
+        }
+    }
+
+    pub fn id(&self) -> u8 {
+        match self {
+            AeadAlgorithm::Aes256Gcm => 1,
+            AeadAlgorithm::ChaCha20Poly1305 => 2,
+        }
+    }
+
+    pub fn from_id(id: u8) -> Result<Self> {
+        match id {
+            1 => Ok(AeadAlgorithm::Aes256Gcm),
+            2 => Ok(AeadAlgorithm::ChaCha20Poly1305),
+            _ => bail!("Unknown AEAD algorithm id: {}", id),
+        }
+    }
+}
+
+impl FromStr for AeadAlgorithm {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().replace(['-', '_'], "").as_str() {
+            "aes256gcm" | "aesgcm" => Ok(AeadAlgorithm::Aes256Gcm),
+            "chacha20poly1305" | "chacha20" => Ok(AeadAlgorithm::ChaCha20Poly1305),
+            _ => bail!("Unsupported AEAD algorithm: {}", name),
+        }
+    }
+}
+
+pub(crate) enum SegmentCipher {
+    Aes(Box<Aes256Gcm>),
+    ChaCha(Box<ChaCha20Poly1305>),
+}
+
+impl SegmentCipher {
+    pub(crate) fn new(algorithm: AeadAlgorithm, key: &[u8]) -> Result<Self> {
+        match algorithm {
+            AeadAlgorithm::Aes256Gcm => Ok(SegmentCipher::Aes(Box::new(aes_gcm_cipher(key)?))),
+            AeadAlgorithm::ChaCha20Poly1305 => Ok(SegmentCipher::ChaCha(Box::new(chacha20_poly1305_cipher(key)?))),
+        }
+    }
+
+    pub(crate) fn seal(&self, nonce: &[u8], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let payload = Payload { msg: plaintext, aad };
+        let sealed = match self {
+            SegmentCipher::Aes(cipher) => cipher.encrypt(Nonce::<Aes256Gcm>::from_slice(nonce), payload),
+            SegmentCipher::ChaCha(cipher) => cipher.encrypt(Nonce::<ChaCha20Poly1305>::from_slice(nonce), payload),
+        };
+        sealed.map_err(|_| anyhow!("Encryption failed"))
+    }
+
+    pub(crate) fn open(&self, nonce: &[u8], ciphertext: &[u8], aad: &[u8]) -> Option<Vec<u8>> {
+        let payload = Payload { msg: ciphertext, aad };
+        match self {
+            SegmentCipher::Aes(cipher) => cipher.decrypt(Nonce::<Aes256Gcm>::from_slice(nonce), payload).ok(),
+            SegmentCipher::ChaCha(cipher) => cipher.decrypt(Nonce::<ChaCha20Poly1305>::from_slice(nonce), payload).ok(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamHeader {
+    pub algorithm: AeadAlgorithm,
+    pub segment_size: usize,
+    pub nonce_prefix: [u8; STREAM_NONCE_PREFIX_LEN],
+}
+
+impl StreamHeader {
+    pub fn to_bytes(&self) -> [u8; STREAM_HEADER_LEN] {
+        let mut bytes = [0u8; STREAM_HEADER_LEN];
+        bytes[..4].copy_from_slice(STREAM_MAGIC);
+        bytes[4] = STREAM_VERSION;
+        bytes[5] = self.algorithm.id();
+        bytes[6..10].copy_from_slice(&(self.segment_size as u32).to_be_bytes());
+        bytes[10..].copy_from_slice(&self.nonce_prefix);
+        bytes
+    }
+
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < STREAM_HEADER_LEN {
+            bail!("Truncated stream header");
+        }
+        if &bytes[..4] != STREAM_MAGIC {
+            bail!("Not an encrypted stream");
+        }
+        if bytes[4] != STREAM_VERSION {
+            bail!("Unsupported encrypted stream version: {}", bytes[4]);
+        }
+
+        let segment_size = u32::from_be_bytes(bytes[6..10].try_into().unwrap()) as usize;
+        check_segment_size(segment_size)?;
+
+        Ok(StreamHeader {
+            algorithm: AeadAlgorithm::from_id(bytes[5])?,
+            segment_size,
+            nonce_prefix: bytes[10..STREAM_HEADER_LEN].try_into().unwrap(),
+        })
+    }
+
+    fn nonce(&self, counter: u32, last: bool) -> [u8; 12] {
+        let mut nonce = [0u8; 12];
+        nonce[..STREAM_NONCE_PREFIX_LEN].copy_from_slice(&self.nonce_prefix);
+        nonce[STREAM_NONCE_PREFIX_LEN..11].copy_from_slice(&counter.to_be_bytes());
+        nonce[11] = last as u8;
+        nonce
+    }
+}
+
+fn check_segment_size(segment_size: usize) -> Result<()> {
+    if segment_size == 0 || segment_size > MAX_SEGMENT_SIZE {
+        bail!("Segment size must be between 1 and {} bytes", MAX_SEGMENT_SIZE);
+    }
+    Ok(())
+}
+
+fn next_counter(counter: &mut u32) -> Result<u32> {
+    let current = *counter;
+    *counter = counter.checked_add(1).ok_or_else(|| anyhow!("Encrypted stream has too many segments"))?;
+    Ok(current)
+}
+
+pub struct StreamEncryptor {
+    cipher: SegmentCipher,
+    header: StreamHeader,
+    aad: [u8; STREAM_HEADER_LEN],
+    counter: u32,
+    buffer: Vec<u8>,
+    output: Vec<u8>,
+    finished: bool,
+}
+
+impl StreamEncryptor {
+    pub fn new(algorithm: AeadAlgorithm, key: &[u8], segment_size: Option<usize>) -> Result<Self> {
+        let segment_size = segment_size.unwrap_or(DEFAULT_SEGMENT_SIZE);
+        check_segment_size(segment_size)?;
+
+        let header = StreamHeader {
+            algorithm,
+            segment_size,
+            nonce_prefix: random_bytes(STREAM_NONCE_PREFIX_LEN)?.try_into().unwrap(),
+        };
+        let aad = header.to_bytes();
+
+        Ok(StreamEncryptor {
+            cipher: SegmentCipher::new(algorithm, key)?,
+            header,
+            aad,
+            counter: 0,
+            buffer: Vec::new(),
+            output: aad.to_vec(),
+            finished: false,
+        })
+    }
+
+    pub fn header(&self) -> StreamHeader {
+        self.header
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn seal_segment(&mut self, start: usize, end: usize, last: bool) -> Result<()> {
+        let counter = next_counter(&mut self.counter)?;
+        let nonce = self.header.nonce(counter, last);
+        let sealed = self.cipher.seal(&nonce, &self.buffer[start..end], &self.aad)?;
+        self.output.extend_from_slice(&sealed);
+        Ok(())
+    }
+
+    pub fn push(&mut self, data: &[u8]) -> Result<Vec<u8>> {
+        if self.finished {
+            bail!("Encrypted stream already finished");
+        }
+
+        self.buffer.extend_from_slice(data);
+        let segment_size = self.header.segment_size;
+        let mut start = 0;
+        while self.buffer.len() - start > segment_size {
+            self.seal_segment(start, start + segment_size, false)?;
+            start += segment_size;
+        }
+        self.buffer.drain(..start);
+        Ok(std::mem::take(&mut self.output))
+    }
+
+    pub fn finish(&mut self) -> Result<Vec<u8>> {
+        if self.finished {
+            bail!("Encrypted stream already finished");
+        }
+
+        self.seal_segment(0, self.buffer.len(), true)?;
+        self.buffer.clear();
+        self.finished = true;
+        Ok(std::mem::take(&mut self.output))
+    }
+}
+
+pub struct StreamDecryptor {
+    key: Vec<u8>,
+    state: Option<(StreamHeader, SegmentCipher)>,
+    counter: u32,
+    buffer: Vec<u8>,
+    finished: bool,
+}
+
+impl StreamDecryptor {
+    pub fn new(key: &[u8]) -> Result<Self> {
+        if key.len() != 32 {
+            bail!("Key must be 32 bytes (256 bits)");
+        }
+
+        Ok(StreamDecryptor {
+            key: key.to_vec(),
+            state: None,
+            counter: 0,
+            buffer: Vec::new(),
+            finished: false,
+        })
+    }
+
+    pub fn header(&self) -> Option<StreamHeader> {
+        self.state.as_ref().map(|(header, _)| *header)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn read_header(&mut self) -> Result<bool> {
+        if self.state.is_some() {
+            return Ok(true);
+        }
+        if self.buffer.len() < STREAM_HEADER_LEN {
+            return Ok(false);
+        }
+
+        let header = StreamHeader::parse(&self.buffer)?;
+        let cipher = SegmentCipher::new(header.algorithm, &self.key)?;
+        self.buffer.drain(..STREAM_HEADER_LEN);
+        self.state = Some((header, cipher));
+        Ok(true)
+    }
+
+    fn open_segment(&mut self, start: usize, end: usize, last: bool, plaintext: &mut Vec<u8>) -> Result<()> {
+        let counter = next_counter(&mut self.counter)?;
+        let (header, cipher) = self.state.as_ref().unwrap();
+        let nonce = header.nonce(counter, last);
+
+        let Some(opened) = cipher.open(&nonce, &self.buffer[start..end], &header.to_bytes()) else {
+            self.finished = true;
+            bail!("Encrypted stream segment {} failed authentication", counter);
+        };
+        plaintext.extend_from_slice(&opened);
+        Ok(())
+    }
+
+    pub fn push(&mut self, data: &[u8]) -> Result<Vec<u8>> {
+        if self.finished {
+            bail!("Encrypted stream already finished");
+        }
+
+        self.buffer.extend_from_slice(data);
+        let mut plaintext = Vec::new();
+        if !self.read_header()? {
+            return Ok(plaintext);
+        }
+
+        let sealed_len = self.header().unwrap().segment_size + STREAM_TAG_LEN;
+        let mut start = 0;
+        while self.buffer.len() - start > sealed_len {
+            self.open_segment(start, start + sealed_len, false, &mut plaintext)?;
+            start += sealed_len;
+        }
+        self.buffer.drain(..start);
+        Ok(plaintext)
+    }
+
+    pub fn finish(&mut self) -> Result<Vec<u8>> {
+        if self.finished {
+            bail!("Encrypted stream already finished");
+        }
+        if !self.read_header()? {
+            bail!("Truncated stream header");
+        }
+        if self.buffer.len() < STREAM_TAG_LEN {
+            bail!("Encrypted stream is truncated");
+        }
+
+        let mut plaintext = Vec::new();
+        self.open_segment(0, self.buffer.len(), true, &mut plaintext)?;
+        self.buffer.clear();
+        self.finished = true;
+        Ok(plaintext)
+    }
+}
+
+trait SegmentStream {
+    fn push(&mut self, data: &[u8]) -> Result<Vec<u8>>;
+    fn finish(&mut self) -> Result<Vec<u8>>;
+}
+
+impl SegmentStream for StreamEncryptor {
+    fn push(&mut self, data: &[u8]) -> Result<Vec<u8>> {
+        StreamEncryptor::push(self, data)
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>> {
+        StreamEncryptor::finish(self)
+    }
+}
+
+impl SegmentStream for StreamDecryptor {
+    fn push(&mut self, data: &[u8]) -> Result<Vec<u8>> {
+        StreamDecryptor::push(self, data)
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>> {
+        StreamDecryptor::finish(self)
+    }
+}
+
+fn pump<R: Read, W: Write>(mut reader: R, mut writer: W, stream: &mut impl SegmentStream) -> Result<u64> {
+    let mut buffer = vec![0u8; DEFAULT_SEGMENT_SIZE];
+    let mut written = 0u64;
+
+    loop {
+        let output = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => stream.push(&buffer[..n])?,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => bail!("Stream read error: {}", e),
+        };
+        writer.write_all(&output).map_err(|e| anyhow!("Stream write error: {}", e))?;
+        written += output.len() as u64;
+    }
+
+    let output = stream.finish()?;
+    writer.write_all(&output).map_err(|e| anyhow!("Stream write error: {}", e))?;
+    writer.flush().map_err(|e| anyhow!("Stream write error: {}", e))?;
+    Ok(written + output.len() as u64)
+}
+
+pub fn encrypt_stream<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    algorithm: AeadAlgorithm,
+    key: &[u8],
+    segment_size: Option<usize>,
+) -> Result<u64> {
+    pump(reader, writer, &mut StreamEncryptor::new(algorithm, key, segment_size)?)
+}
+
+pub fn decrypt_stream<R: Read, W: Write>(reader: R, writer: W, key: &[u8]) -> Result<u64> {
+    pump(reader, writer, &mut StreamDecryptor::new(key)?)
+}
@@ -0,0 +1,373 @@
+use anyhow::{anyhow, bail, Result};
+use std::f64::consts::PI;
+use std::str::FromStr;
+
+use crate::machine_learning::KMeans;
+use crate::utils::rng_from_seed;
+use super::resampling::{resample, ResizeFilter};
+
+fn check_dimensions(data: &[u8], width: u32, height: u32) -> Result<()> {
+    if width == 0 || height == 0 || data.len() != width as usize * height as usize * 4 {
+        bail!("RGBA data does not match {}x{}", width, height);
+    }
+    Ok(())
+}
+
+fn luma(pixel: &[u8]) -> f64 {
+    0.299 * pixel[0] as f64 + 0.587 * pixel[1] as f64 + 0.114 * pixel[2] as f64
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    pub red: [u32; 256],
+    pub green: [u32; 256],
+    pub blue: [u32; 256],
+    pub alpha: [u32; 256],
+    pub luminance: [u32; 256],
+}
+
+impl Histogram {
+    pub fn compute(data: &[u8]) -> Histogram {
+        let mut histogram = Histogram {
+            red: [0; 256],
+            green: [0; 256],
+            blue: [0; 256],
+            alpha: [0; 256],
+            luminance: [0; 256],
+        };
+
+        for pixel in data.chunks_exact(4) {
+            histogram.red[pixel[0] as usize] += 1;
+            histogram.green[pixel[1] as usize] += 1;
+            histogram.blue[pixel[2] as usize] += 1;
+            histogram.alpha[pixel[3] as usize] += 1;
+            histogram.luminance[luma(pixel).round() as usize] += 1;
+        }
+
+        histogram
+    }
+
+    pub fn total(&self) -> u64 {
+        self.alpha.iter().map(|&count| count as u64).sum()
+    }
+
+    pub fn channel(&self, name: &str) -> Result<&[u32; 256]> {
+        match name.to_ascii_lowercase().as_str() {
+            "red" | "r" => Ok(&self.red),
+            "green" | "g" => Ok(&self.green),
+            "blue" | "b" => Ok(&self.blue),
+            "alpha" | "a" => Ok(&self.alpha),
+            "luminance" | "luma" | "l" => Ok(&self.luminance),
+            _ => bail!("Unknown histogram channel: {}", name),
+        }
+    }
+}
+
+pub fn histogram(data: &[u8]) -> Histogram {
+    Histogram::compute(data)
+}
+
+pub fn average_color(data: &[u8]) -> Option<[u8; 4]> {
+    let mut sums = [0.0f64; 4];
+    let mut count = 0usize;
+
+    for pixel in data.chunks_exact(4) {
+        let alpha = pixel[3] as f64;
+        for channel in 0..3 {
+            sums[channel] += pixel[channel] as f64 * alpha;
+        }
+        sums[3] += alpha;
+        count += 1;
+    }
+
+    if count == 0 {
+        return None;
+    }
+    if sums[3] == 0.0 {
+        return Some([0, 0, 0, 0]);
+    }
+
+    let channel = |sum: f64| (sum / sums[3]).round() as u8;
+    Some([channel(sums[0]), channel(sums[1]), channel(sums[2]), (sums[3] / count as f64).round() as u8])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaletteColor {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub proportion: f64,
+}
+
+impl PaletteColor {
+    pub fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantColorOptions {
+    pub max_samples: usize,
+    pub max_iterations: usize,
+    pub min_alpha: u8,
+    pub seed: u64,
+}
+
+impl Default for DominantColorOptions {
+    fn default() -> Self {
+        DominantColorOptions {
+            max_samples: 10_000,
+            max_iterations: 50,
+            min_alpha: 128,
+            seed: 0,
+        }
+    }
+}
+
+pub fn dominant_colors(data: &[u8], count: usize, options: DominantColorOptions) -> Result<Vec<PaletteColor>> {
+    if count == 0 {
+        bail!("Number of colors must be greater than zero");
+    }
+
+    let pixels = data.len() / 4;
+    let stride = pixels.div_ceil(options.max_samples.max(1)).max(1);
+    let samples: Vec<Vec<f64>> = data
+        .chunks_exact(4)
+        .step_by(stride)
+        .filter(|pixel| pixel[3] >= options.min_alpha)
+        .map(|pixel| vec![pixel[0] as f64, pixel[1] as f64, pixel[2] as f64])
+        .collect();
+
+    if samples.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut rng = rng_from_seed(Some(options.seed));
+    let (model, assignments) = KMeans::fit(&samples, count.min(samples.len()), options.max_iterations, &mut rng)?;
+
+    let mut sizes = vec![0usize; model.n_clusters()];
+    for &cluster in &assignments {
+        sizes[cluster] += 1;
+    }
+
+    let mut colors: Vec<PaletteColor> = model.centroids
+        .iter()
+        .zip(sizes)
+        .filter(|(_, size)| *size > 0)
+        .map(|(centroid, size)| PaletteColor {
+            red: centroid[0].round().clamp(0.0, 255.0) as u8,
+            green: centroid[1].round().clamp(0.0, 255.0) as u8,
+            blue: centroid[2].round().clamp(0.0, 255.0) as u8,
+            proportion: size as f64 / samples.len() as f64,
+        })
+        .collect();
+
+    colors.sort_by(|a, b| b.proportion.total_cmp(&a.proportion));
+    Ok(colors)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageHashAlgorithm {
+    Average,
+    Difference,
+    #[default]
+    Perceptual,
+}
+
+impl ImageHashAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ImageHashAlgorithm::Average => "ahash",
+            ImageHashAlgorithm::Difference => "dhash",
+            ImageHashAlgorithm::Perceptual => "phash",
+        }
+    }
+}
+
+impl FromStr for ImageHashAlgorithm {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "ahash" | "average" => Ok(ImageHashAlgorithm::Average),
+            "dhash" | "difference" => Ok(ImageHashAlgorithm::Difference),
+            "phash" | "perceptual" | "dct" => Ok(ImageHashAlgorithm::Perceptual),
+            _ => bail!("Unsupported image hash algorithm: {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImageHash(pub u64);
+
+impl ImageHash {
+    pub fn distance(&self, other: &ImageHash) -> u32 {
+        (self.0 ^ other.0).count_ones()
+    }
+
+    pub fn similarity(&self, other: &ImageHash) -> f64 {
+        1.0 - self.distance(other) as f64 / 64.0
+    }
+
+    pub fn to_hex(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+
+    pub fn from_hex(hex: &str) -> Result<ImageHash> {
+        if hex.len() != 16 {
+            bail!("Image hash must be 16 hex digits");
+        }
+        u64::from_str_radix(hex, 16)
+            .map(ImageHash)
+            .map_err(|_| anyhow!("Invalid image hash: {}", hex))
+    }
+}
+
+fn luma_thumbnail(data: &[u8], width: u32, height: u32, target_width: u32, target_height: u32) -> Result<Vec<f64>> {
+    let flattened: Vec<u8> = data
+        .chunks_exact(4)
+        .flat_map(|pixel| {
+            let alpha = pixel[3] as u16;
+            let over_white = |channel: u8| ((channel as u16 * alpha + 255 * (255 - alpha)) / 255) as u8;
+            [over_white(pixel[0]), over_white(pixel[1]), over_white(pixel[2]), 255]
+        })
+        .collect();
+    let thumbnail = resample(&flattened, width, height, target_width, target_height, ResizeFilter::Bilinear)?;
+    Ok(thumbnail.chunks_exact(4).map(luma).collect())
+}
+
+fn bits_from(flags: impl Iterator<Item = bool>) -> ImageHash {
+    ImageHash(flags.take(64).fold(0u64, |hash, bit| (hash << 1) | bit as u64))
+}
+
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let middle = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[middle - 1] + sorted[middle]) / 2.0
+    } else {
+        sorted[middle]
+    }
+}
+
+fn dct_2d(values: &[f64], size: usize, keep: usize) -> Vec<f64> {
+    let cosines: Vec<f64> = (0..keep * size)
+        .map(|index| {
+            let (frequency, position) = (index / size, index % size);
+            ((2 * position + 1) as f64 * frequency as f64 * PI / (2 * size) as f64).cos()
+        })
+        .collect();
+
+    let mut rows = vec![0.0; size * keep];
+    for y in 0..size {
+        for u in 0..keep {
+            rows[y * keep + u] = (0..size).map(|x| values[y * size + x] * cosines[u * size + x]).sum();
+        }
+    }
+
+    let mut coefficients = vec![0.0; keep * keep];
+    for v in 0..keep {
+        for u in 0..keep {
+            coefficients[v * keep + u] = (0..size).map(|y| rows[y * keep + u] * cosines[v * size + y]).sum();
+        }
+    }
+    coefficients
+}
+
+pub fn image_hash(data: &[u8], width: u32, height: u32, algorithm: ImageHashAlgorithm) -> Result<ImageHash> {
+    check_dimensions(data, width, height)?;
+
+    match algorithm {
+        ImageHashAlgorithm::Average => {
+            let pixels = luma_thumbnail(data, width, height, 8, 8)?;
+            let mean = pixels.iter().sum::<f64>() / pixels.len() as f64;
+            Ok(bits_from(pixels.iter().map(|&value| value > mean)))
+        }
+        ImageHashAlgorithm::Difference => {
+            let pixels = luma_thumbnail(data, width, height, 9, 8)?;
+            Ok(bits_from((0..8).flat_map(|y| {
+                let row = &pixels[y * 9..y * 9 + 9];
+                (0..8).map(move |x| row[x + 1] > row[x])
+            })))
+        }
+        ImageHashAlgorithm::Perceptual => {
+            let pixels = luma_thumbnail(data, width, height, 32, 32)?;
+            let coefficients = dct_2d(&pixels, 32, 8);
+            let threshold = median(&coefficients[1..]);
+            Ok(bits_from(coefficients.iter().map(|&value| value > threshold)))
+        }
+    }
+}
+
+pub fn hamming_distance(a: &ImageHash, b: &ImageHash) -> u32 {
+    a.distance(b)
+}
+
+fn gaussian_window(values: &[f64], width: usize, height: usize, weights: &[f64]) -> Vec<f64> {
+    let radius = (weights.len() / 2) as i64;
+    let mut horizontal = vec![0.0; values.len()];
+    for y in 0..height {
+        for x in 0..width {
+            horizontal[y * width + x] = weights
+                .iter()
+                .enumerate()
+                .map(|(tap, weight)| {
+                    let sx = (x as i64 + tap as i64 - radius).clamp(0, width as i64 - 1) as usize;
+                    weight * values[y * width + sx]
+                })
+                .sum();
+        }
+    }
+
+    let mut output = vec![0.0; values.len()];
+    for y in 0..height {
+        for x in 0..width {
+            output[y * width + x] = weights
+                .iter()
+                .enumerate()
+                .map(|(tap, weight)| {
+                    let sy = (y as i64 + tap as i64 - radius).clamp(0, height as i64 - 1) as usize;
+                    weight * horizontal[sy * width + x]
+                })
+                .sum();
+        }
+    }
+    output
+}
+
+pub fn ssim(a: &[u8], b: &[u8], width: u32, height: u32) -> Result<f64> {
+    check_dimensions(a, width, height)?;
+    check_dimensions(b, width, height)?;
+
+    const C1: f64 = (0.01 * 255.0) * (0.01 * 255.0);
+    const C2: f64 = (0.03 * 255.0) * (0.03 * 255.0);
+    let (width, height) = (width as usize, height as usize);
+
+    let weights: Vec<f64> = (-5i32..=5).map(|offset| (-(offset * offset) as f64 / (2.0 * 1.5 * 1.5)).exp()).collect();
+    let sum: f64 = weights.iter().sum();
+    let weights: Vec<f64> = weights.iter().map(|weight| weight / sum).collect();
+
+    let x: Vec<f64> = a.chunks_exact(4).map(luma).collect();
+    let y: Vec<f64> = b.chunks_exact(4).map(luma).collect();
+    let products = |p: &[f64], q: &[f64]| p.iter().zip(q).map(|(p, q)| p * q).collect::<Vec<f64>>();
+
+    let mu_x = gaussian_window(&x, width, height, &weights);
+    let mu_y = gaussian_window(&y, width, height, &weights);
+    let xx = gaussian_window(&products(&x, &x), width, height, &weights);
+    let yy = gaussian_window(&products(&y, &y), width, height, &weights);
+    let xy = gaussian_window(&products(&x, &y), width, height, &weights);
+
+    let total: f64 = (0..x.len())
+        .map(|i| {
+            let (mx, my) = (mu_x[i], mu_y[i]);
+            let variance_x = xx[i] - mx * mx;
+            let variance_y = yy[i] - my * my;
+            let covariance = xy[i] - mx * my;
+            ((2.0 * mx * my + C1) * (2.0 * covariance + C2))
+                / ((mx * mx + my * my + C1) * (variance_x + variance_y + C2))
+        })
+        .sum();
+
+    Ok(total / x.len() as f64)
+}
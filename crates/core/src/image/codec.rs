@@ -0,0 +1,300 @@
+use anyhow::{anyhow, bail, Result};
+use ::image::codecs::jpeg::JpegEncoder;
+use ::image::codecs::png::PngEncoder;
+use ::image::codecs::webp::WebPEncoder;
+use ::image::imageops;
+use ::image::metadata::Orientation;
+use ::image::{DynamicImage, ExtendedColorType, ImageDecoder, ImageEncoder, ImageReader, RgbaImage};
+use std::io::Cursor;
+use std::str::FromStr;
+
+use super::filters::{apply_filters, Filter};
+use super::image_processing;
+use super::resampling::{resample, rotate_with, warp, ResizeFilter, Transform, WarpOptions};
+
+pub const DEFAULT_JPEG_QUALITY: u8 = 85;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Webp,
+    Gif,
+    Bmp,
+}
+
+impl ImageFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpeg",
+            ImageFormat::Webp => "webp",
+            ImageFormat::Gif => "gif",
+            ImageFormat::Bmp => "bmp",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "image/png",
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Webp => "image/webp",
+            ImageFormat::Gif => "image/gif",
+            ImageFormat::Bmp => "image/bmp",
+        }
+    }
+
+    fn from_library(format: ::image::ImageFormat) -> Option<Self> {
+        match format {
+            ::image::ImageFormat::Png => Some(ImageFormat::Png),
+            ::image::ImageFormat::Jpeg => Some(ImageFormat::Jpeg),
+            ::image::ImageFormat::WebP => Some(ImageFormat::Webp),
+            ::image::ImageFormat::Gif => Some(ImageFormat::Gif),
+            ::image::ImageFormat::Bmp => Some(ImageFormat::Bmp),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().trim_start_matches("image/") {
+            "png" => Ok(ImageFormat::Png),
+            "jpeg" | "jpg" => Ok(ImageFormat::Jpeg),
+            "webp" => Ok(ImageFormat::Webp),
+            "gif" => Ok(ImageFormat::Gif),
+            "bmp" => Ok(ImageFormat::Bmp),
+            _ => bail!("Unsupported image format: {}", name),
+        }
+    }
+}
+
+pub fn detect_image_format(data: &[u8]) -> Option<ImageFormat> {
+    ::image::guess_format(data).ok().and_then(ImageFormat::from_library)
+}
+
+#[derive(Debug, Clone)]
+pub struct Image {
+    pixels: RgbaImage,
+    source_format: Option<ImageFormat>,
+    orientation: u8,
+}
+
+impl Image {
+    pub fn decode(data: &[u8]) -> Result<Image> {
+        Self::decode_with(data, true)
+    }
+
+    pub fn decode_with(data: &[u8], auto_orient: bool) -> Result<Image> {
+        let reader = ImageReader::new(Cursor::new(data))
+            .with_guessed_format()
+            .map_err(|e| anyhow!("Failed to read image: {}", e))?;
+        let source_format = reader.format().and_then(ImageFormat::from_library);
+
+        let mut decoder = reader.into_decoder().map_err(|e| anyhow!("Unsupported image data: {}", e))?;
+        let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+        let mut image = DynamicImage::from_decoder(decoder).map_err(|e| anyhow!("Failed to decode image: {}", e))?;
+
+        if auto_orient {
+            image.apply_orientation(orientation);
+        }
+
+        Ok(Image {
+            pixels: image.into_rgba8(),
+            source_format,
+            orientation: orientation.to_exif(),
+        })
+    }
+
+    pub fn from_rgba(width: u32, height: u32, data: Vec<u8>) -> Result<Image> {
+        let pixels = RgbaImage::from_raw(width, height, data)
+            .ok_or_else(|| anyhow!("RGBA data does not match {}x{}", width, height))?;
+
+        Ok(Image {
+            pixels,
+            source_format: None,
+            orientation: 1,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.pixels.height()
+    }
+
+    pub fn source_format(&self) -> Option<ImageFormat> {
+        self.source_format
+    }
+
+    pub fn orientation(&self) -> u8 {
+        self.orientation
+    }
+
+    pub fn rgba(&self) -> &[u8] {
+        self.pixels.as_raw()
+    }
+
+    pub fn into_rgba(self) -> Vec<u8> {
+        self.pixels.into_raw()
+    }
+
+    fn with_pixels(self, pixels: RgbaImage) -> Image {
+        Image { pixels, ..self }
+    }
+
+    fn map_raw(self, f: impl FnOnce(&[u8], u32, u32) -> Vec<u8>) -> Image {
+        let (width, height) = (self.width(), self.height());
+        let data = f(self.pixels.as_raw(), width, height);
+        let pixels = RgbaImage::from_raw(width, height, data).expect("filter preserves dimensions");
+        self.with_pixels(pixels)
+    }
+
+    pub fn resize(self, width: u32, height: u32, filter: ResizeFilter) -> Result<Image> {
+        if width == 0 || height == 0 {
+            bail!("Target dimensions must be non-zero");
+        }
+        let data = resample(self.pixels.as_raw(), self.width(), self.height(), width, height, filter)?;
+        let pixels = RgbaImage::from_raw(width, height, data).expect("resample output matches dimensions");
+        Ok(self.with_pixels(pixels))
+    }
+
+    pub fn resize_to_fit(self, max_width: u32, max_height: u32, filter: ResizeFilter) -> Result<Image> {
+        if max_width == 0 || max_height == 0 {
+            bail!("Target dimensions must be non-zero");
+        }
+
+        let scale = (max_width as f64 / self.width() as f64).min(max_height as f64 / self.height() as f64);
+        if scale >= 1.0 {
+            return Ok(self);
+        }
+
+        let width = ((self.width() as f64 * scale).round() as u32).max(1);
+        let height = ((self.height() as f64 * scale).round() as u32).max(1);
+        self.resize(width, height, filter)
+    }
+
+    pub fn crop(self, x: u32, y: u32, width: u32, height: u32) -> Result<Image> {
+        if width == 0 || height == 0 || x.saturating_add(width) > self.width() || y.saturating_add(height) > self.height() {
+            bail!("Crop rectangle {}x{}+{}+{} is outside the {}x{} image", width, height, x, y, self.width(), self.height());
+        }
+        let pixels = imageops::crop_imm(&self.pixels, x, y, width, height).to_image();
+        Ok(self.with_pixels(pixels))
+    }
+
+    pub fn rotate90(self) -> Image {
+        let pixels = imageops::rotate90(&self.pixels);
+        self.with_pixels(pixels)
+    }
+
+    pub fn rotate180(self) -> Image {
+        let pixels = imageops::rotate180(&self.pixels);
+        self.with_pixels(pixels)
+    }
+
+    pub fn rotate270(self) -> Image {
+        let pixels = imageops::rotate270(&self.pixels);
+        self.with_pixels(pixels)
+    }
+
+    pub fn rotate(self, angle_degrees: f64, options: WarpOptions) -> Result<Image> {
+        let normalized = angle_degrees.rem_euclid(360.0);
+        if normalized == 0.0 {
+            return Ok(self);
+        } else if normalized == 90.0 {
+            return Ok(self.rotate90());
+        } else if normalized == 180.0 {
+            return Ok(self.rotate180());
+        } else if normalized == 270.0 {
+            return Ok(self.rotate270());
+        }
+
+        let (data, width, height) = rotate_with(self.pixels.as_raw(), self.width(), self.height(), angle_degrees, options)?;
+        let pixels = RgbaImage::from_raw(width, height, data).expect("rotation output matches dimensions");
+        Ok(self.with_pixels(pixels))
+    }
+
+    pub fn warp(self, transform: &Transform, width: u32, height: u32, options: WarpOptions) -> Result<Image> {
+        let data = warp(self.pixels.as_raw(), self.width(), self.height(), transform, width, height, options)?;
+        let pixels = RgbaImage::from_raw(width, height, data).expect("warp output matches dimensions");
+        Ok(self.with_pixels(pixels))
+    }
+
+    pub fn flip_horizontal(self) -> Image {
+        let pixels = imageops::flip_horizontal(&self.pixels);
+        self.with_pixels(pixels)
+    }
+
+    pub fn flip_vertical(self) -> Image {
+        let pixels = imageops::flip_vertical(&self.pixels);
+        self.with_pixels(pixels)
+    }
+
+    pub fn grayscale(self) -> Image {
+        self.map_raw(|data, _, _| image_processing::grayscale(data))
+    }
+
+    pub fn invert(self) -> Image {
+        self.map_raw(|data, _, _| image_processing::invert(data))
+    }
+
+    pub fn brightness(self, factor: f64) -> Image {
+        self.map_raw(|data, _, _| image_processing::brightness(data, factor))
+    }
+
+    pub fn blur(self, radius: u32) -> Image {
+        self.map_raw(|data, width, height| image_processing::blur(data, width, height, radius))
+    }
+
+    pub fn edge_detection(self) -> Image {
+        self.map_raw(image_processing::edge_detection)
+    }
+
+    pub fn filter(self, filter: &Filter) -> Result<Image> {
+        let data = filter.apply(self.pixels.as_raw(), self.width(), self.height())?;
+        let pixels = RgbaImage::from_raw(self.width(), self.height(), data).expect("filter preserves dimensions");
+        Ok(self.with_pixels(pixels))
+    }
+
+    pub fn filters(self, filters: &[Filter]) -> Result<Image> {
+        let data = apply_filters(self.pixels.as_raw(), self.width(), self.height(), filters)?;
+        let pixels = RgbaImage::from_raw(self.width(), self.height(), data).expect("filters preserve dimensions");
+        Ok(self.with_pixels(pixels))
+    }
+
+    pub fn encode(&self, format: ImageFormat, quality: Option<u8>) -> Result<Vec<u8>> {
+        let (width, height) = (self.width(), self.height());
+        if quality.is_some() && format != ImageFormat::Jpeg {
+            bail!("{} encoding is lossless and does not accept a quality setting", format.name());
+        }
+        let mut output = Vec::new();
+
+        let encoded = match format {
+            ImageFormat::Jpeg => {
+                let quality = quality.unwrap_or(DEFAULT_JPEG_QUALITY);
+                if !(1..=100).contains(&quality) {
+                    bail!("JPEG quality must be between 1 and 100");
+                }
+                let rgb = DynamicImage::ImageRgba8(self.pixels.clone()).into_rgb8();
+                JpegEncoder::new_with_quality(&mut output, quality)
+                    .write_image(rgb.as_raw(), width, height, ExtendedColorType::Rgb8)
+            }
+            ImageFormat::Png => PngEncoder::new(&mut output)
+                .write_image(self.pixels.as_raw(), width, height, ExtendedColorType::Rgba8),
+            ImageFormat::Webp => WebPEncoder::new_lossless(&mut output)
+                .write_image(self.pixels.as_raw(), width, height, ExtendedColorType::Rgba8),
+            ImageFormat::Gif | ImageFormat::Bmp => self.pixels
+                .write_to(&mut Cursor::new(&mut output), match format {
+                    ImageFormat::Gif => ::image::ImageFormat::Gif,
+                    _ => ::image::ImageFormat::Bmp,
+                }),
+        };
+
+        encoded.map_err(|e| anyhow!("Failed to encode {}: {}", format.name(), e))?;
+        Ok(output)
+    }
+}
@@ -0,0 +1,848 @@
+use anyhow::{anyhow, bail, Result};
+
+use super::image_processing;
+use super::resampling::{premultiply, unpremultiply, EdgeMode, Pixel};
+
+pub const MAX_KERNEL_SIZE: usize = 63;
+pub const MAX_BLUR_SIGMA: f64 = 256.0;
+pub const MAX_COLOR_LUT_SIZE: usize = 256;
+
+fn check_dimensions(data: &[u8], width: u32, height: u32) -> Result<()> {
+    if data.len() != width as usize * height as usize * 4 {
+        bail!("RGBA data does not match {}x{}", width, height);
+    }
+    Ok(())
+}
+
+fn map_rgb(data: &[u8], mut f: impl FnMut(&mut [u8])) -> Vec<u8> {
+    let mut result = data.to_vec();
+    for pixel in result.chunks_exact_mut(4) {
+        f(&mut pixel[..3]);
+    }
+    result
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kernel {
+    width: usize,
+    height: usize,
+    weights: Vec<f32>,
+}
+
+impl Kernel {
+    pub fn new(width: usize, height: usize, weights: Vec<f32>) -> Result<Kernel> {
+        if width.is_multiple_of(2) || height.is_multiple_of(2) {
+            bail!("Kernel dimensions must be odd, got {}x{}", width, height);
+        }
+        if width > MAX_KERNEL_SIZE || height > MAX_KERNEL_SIZE {
+            bail!("Kernel dimensions must not exceed {}x{}", MAX_KERNEL_SIZE, MAX_KERNEL_SIZE);
+        }
+        if weights.len() != width * height {
+            bail!("A {}x{} kernel needs {} weights, got {}", width, height, width * height, weights.len());
+        }
+        if weights.iter().any(|weight| !weight.is_finite()) {
+            bail!("Kernel weights must be finite");
+        }
+        Ok(Kernel { width, height, weights })
+    }
+
+    pub fn from_slice(weights: &[f64]) -> Result<Kernel> {
+        let size = (weights.len() as f64).sqrt().round() as usize;
+        if size * size != weights.len() {
+            bail!("Expected a square kernel, got {} weights", weights.len());
+        }
+        Kernel::new(size, size, weights.iter().map(|&weight| weight as f32).collect())
+    }
+
+    pub fn identity() -> Kernel {
+        Kernel { width: 1, height: 1, weights: vec![1.0] }
+    }
+
+    pub fn box_blur(radius: usize) -> Result<Kernel> {
+        let size = radius * 2 + 1;
+        Kernel::new(size, size, vec![1.0; size * size]).map(Kernel::normalized)
+    }
+
+    pub fn sharpen(amount: f64) -> Kernel {
+        let a = amount as f32;
+        Kernel {
+            width: 3,
+            height: 3,
+            weights: vec![0.0, -a, 0.0, -a, 1.0 + 4.0 * a, -a, 0.0, -a, 0.0],
+        }
+    }
+
+    pub fn emboss() -> Kernel {
+        Kernel {
+            width: 3,
+            height: 3,
+            weights: vec![-2.0, -1.0, 0.0, -1.0, 1.0, 1.0, 0.0, 1.0, 2.0],
+        }
+    }
+
+    pub fn outline() -> Kernel {
+        Kernel {
+            width: 3,
+            height: 3,
+            weights: vec![-1.0, -1.0, -1.0, -1.0, 8.0, -1.0, -1.0, -1.0, -1.0],
+        }
+    }
+
+    pub fn normalized(self) -> Kernel {
+        let sum: f32 = self.weights.iter().sum();
+        if sum.abs() <= f32::EPSILON {
+            return self;
+        }
+        Kernel { weights: self.weights.iter().map(|weight| weight / sum).collect(), ..self }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+}
+
+pub fn convolve(data: &[u8], width: u32, height: u32, kernel: &Kernel, edge: EdgeMode) -> Result<Vec<u8>> {
+    check_dimensions(data, width, height)?;
+    let (width, height) = (width as usize, height as usize);
+    let source = premultiply(data);
+    let (half_w, half_h) = ((kernel.width / 2) as i64, (kernel.height / 2) as i64);
+    let mut output = vec![[0.0f32; 4]; source.len()];
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = [0.0f32; 4];
+            for ky in 0..kernel.height {
+                let Some(sy) = edge.resolve(y as i64 + ky as i64 - half_h, height) else {
+                    continue;
+                };
+                for kx in 0..kernel.width {
+                    let Some(sx) = edge.resolve(x as i64 + kx as i64 - half_w, width) else {
+                        continue;
+                    };
+                    let weight = kernel.weights[ky * kernel.width + kx];
+                    let pixel = source[sy * width + sx];
+                    for (total, channel) in sum.iter_mut().zip(pixel) {
+                        *total += weight * channel;
+                    }
+                }
+            }
+            let alpha = sum[3].clamp(0.0, 1.0);
+            output[y * width + x] = [sum[0].clamp(0.0, alpha), sum[1].clamp(0.0, alpha), sum[2].clamp(0.0, alpha), alpha];
+        }
+    }
+
+    Ok(unpremultiply(&output))
+}
+
+fn gaussian_weights(sigma: f64) -> Vec<f32> {
+    let radius = (sigma * 3.0).ceil() as i64;
+    let weights: Vec<f64> = (-radius..=radius)
+        .map(|offset| (-(offset * offset) as f64 / (2.0 * sigma * sigma)).exp())
+        .collect();
+    let sum: f64 = weights.iter().sum();
+    weights.iter().map(|weight| (weight / sum) as f32).collect()
+}
+
+fn blur_pass(source: &[Pixel], width: usize, height: usize, weights: &[f32], horizontal: bool) -> Vec<Pixel> {
+    let radius = (weights.len() / 2) as i64;
+    let (len, lines) = if horizontal { (width, height) } else { (height, width) };
+    let index = |line: usize, position: usize| if horizontal { line * width + position } else { position * width + line };
+    let mut output = vec![[0.0f32; 4]; source.len()];
+
+    for line in 0..lines {
+        for position in 0..len {
+            let mut sum = [0.0f32; 4];
+            for (tap, weight) in weights.iter().enumerate() {
+                let sample = (position as i64 + tap as i64 - radius).clamp(0, len as i64 - 1) as usize;
+                let pixel = source[index(line, sample)];
+                for (total, channel) in sum.iter_mut().zip(pixel) {
+                    *total += weight * channel;
+                }
+            }
+            output[index(line, position)] = sum;
+        }
+    }
+
+    output
+}
+
+pub fn gaussian_blur(data: &[u8], width: u32, height: u32, sigma: f64) -> Result<Vec<u8>> {
+    check_dimensions(data, width, height)?;
+    if !(0.0..=MAX_BLUR_SIGMA).contains(&sigma) {
+        bail!("Blur sigma must be between 0 and {}", MAX_BLUR_SIGMA);
+    }
+    if sigma < 0.01 || data.is_empty() {
+        return Ok(data.to_vec());
+    }
+
+    let (width, height) = (width as usize, height as usize);
+    let weights = gaussian_weights(sigma);
+    let horizontal = blur_pass(&premultiply(data), width, height, &weights, true);
+    Ok(unpremultiply(&blur_pass(&horizontal, width, height, &weights, false)))
+}
+
+pub fn sharpen(data: &[u8], width: u32, height: u32, amount: f64) -> Result<Vec<u8>> {
+    convolve(data, width, height, &Kernel::sharpen(amount), EdgeMode::Clamp)
+}
+
+pub fn unsharp_mask(data: &[u8], width: u32, height: u32, sigma: f64, amount: f64, threshold: u8) -> Result<Vec<u8>> {
+    let blurred = gaussian_blur(data, width, height, sigma)?;
+    let mut result = data.to_vec();
+
+    for (pixel, soft) in result.chunks_exact_mut(4).zip(blurred.chunks_exact(4)) {
+        for (channel, &blurred) in pixel[..3].iter_mut().zip(&soft[..3]) {
+            let difference = *channel as f64 - blurred as f64;
+            if difference.abs() >= threshold as f64 {
+                *channel = (*channel as f64 + amount * difference).round().clamp(0.0, 255.0) as u8;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorMatrix {
+    values: [f32; 20],
+}
+
+impl Default for ColorMatrix {
+    fn default() -> Self {
+        ColorMatrix::identity()
+    }
+}
+
+impl ColorMatrix {
+    pub fn new(values: [f32; 20]) -> ColorMatrix {
+        ColorMatrix { values }
+    }
+
+    pub fn from_slice(values: &[f64]) -> Result<ColorMatrix> {
+        let values: [f64; 20] = values
+            .try_into()
+            .map_err(|_| anyhow!("A color matrix needs 20 values, got {}", values.len()))?;
+        Ok(ColorMatrix { values: values.map(|value| value as f32) })
+    }
+
+    pub fn identity() -> ColorMatrix {
+        ColorMatrix::rgb([1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0], [0.0; 3])
+    }
+
+    fn rgb(m: [f32; 9], offset: [f32; 3]) -> ColorMatrix {
+        ColorMatrix {
+            values: [
+                m[0], m[1], m[2], 0.0, offset[0],
+                m[3], m[4], m[5], 0.0, offset[1],
+                m[6], m[7], m[8], 0.0, offset[2],
+                0.0, 0.0, 0.0, 1.0, 0.0,
+            ],
+        }
+    }
+
+    pub fn grayscale(amount: f64) -> ColorMatrix {
+        let a = 1.0 - amount.clamp(0.0, 1.0) as f32;
+        ColorMatrix::rgb([
+            0.2126 + 0.7874 * a, 0.7152 - 0.7152 * a, 0.0722 - 0.0722 * a,
+            0.2126 - 0.2126 * a, 0.7152 + 0.2848 * a, 0.0722 - 0.0722 * a,
+            0.2126 - 0.2126 * a, 0.7152 - 0.7152 * a, 0.0722 + 0.9278 * a,
+        ], [0.0; 3])
+    }
+
+    pub fn sepia(amount: f64) -> ColorMatrix {
+        let a = 1.0 - amount.clamp(0.0, 1.0) as f32;
+        ColorMatrix::rgb([
+            0.393 + 0.607 * a, 0.769 - 0.769 * a, 0.189 - 0.189 * a,
+            0.349 - 0.349 * a, 0.686 + 0.314 * a, 0.168 - 0.168 * a,
+            0.272 - 0.272 * a, 0.534 - 0.534 * a, 0.131 + 0.869 * a,
+        ], [0.0; 3])
+    }
+
+    pub fn saturate(amount: f64) -> ColorMatrix {
+        let s = amount.max(0.0) as f32;
+        ColorMatrix::rgb([
+            0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s,
+            0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s,
+            0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s,
+        ], [0.0; 3])
+    }
+
+    pub fn hue_rotate(angle_degrees: f64) -> ColorMatrix {
+        let (sin, cos) = angle_degrees.to_radians().sin_cos();
+        let (s, c) = (sin as f32, cos as f32);
+        ColorMatrix::rgb([
+            0.213 + c * 0.787 - s * 0.213, 0.715 - c * 0.715 - s * 0.715, 0.072 - c * 0.072 + s * 0.928,
+            0.213 - c * 0.213 + s * 0.143, 0.715 + c * 0.285 + s * 0.140, 0.072 - c * 0.072 - s * 0.283,
+            0.213 - c * 0.213 - s * 0.787, 0.715 - c * 0.715 + s * 0.715, 0.072 + c * 0.928 + s * 0.072,
+        ], [0.0; 3])
+    }
+
+    pub fn brightness(amount: f64) -> ColorMatrix {
+        let b = amount.max(0.0) as f32;
+        ColorMatrix::rgb([b, 0.0, 0.0, 0.0, b, 0.0, 0.0, 0.0, b], [0.0; 3])
+    }
+
+    pub fn contrast(amount: f64) -> ColorMatrix {
+        let c = amount.max(0.0) as f32;
+        let offset = 0.5 - 0.5 * c;
+        ColorMatrix::rgb([c, 0.0, 0.0, 0.0, c, 0.0, 0.0, 0.0, c], [offset; 3])
+    }
+
+    pub fn invert(amount: f64) -> ColorMatrix {
+        let a = amount.clamp(0.0, 1.0) as f32;
+        let scale = 1.0 - 2.0 * a;
+        ColorMatrix::rgb([scale, 0.0, 0.0, 0.0, scale, 0.0, 0.0, 0.0, scale], [a; 3])
+    }
+
+    pub fn opacity(amount: f64) -> ColorMatrix {
+        let mut matrix = ColorMatrix::identity();
+        matrix.values[18] = amount.clamp(0.0, 1.0) as f32;
+        matrix
+    }
+
+    pub fn values(&self) -> &[f32; 20] {
+        &self.values
+    }
+
+    pub fn then(&self, next: &ColorMatrix) -> ColorMatrix {
+        let (a, b) = (&self.values, &next.values);
+        let mut values = [0.0f32; 20];
+        for row in 0..4 {
+            for column in 0..5 {
+                let mut sum = if column == 4 { b[row * 5 + 4] } else { 0.0 };
+                for k in 0..4 {
+                    sum += b[row * 5 + k] * a[k * 5 + column];
+                }
+                values[row * 5 + column] = sum;
+            }
+        }
+        ColorMatrix { values }
+    }
+
+    pub fn apply(&self, data: &[u8]) -> Vec<u8> {
+        let m = &self.values;
+        let mut result = Vec::with_capacity(data.len());
+        for pixel in data.chunks_exact(4) {
+            let input = [pixel[0], pixel[1], pixel[2], pixel[3]].map(|channel| channel as f32 / 255.0);
+            for row in 0..4 {
+                let value = m[row * 5] * input[0] + m[row * 5 + 1] * input[1] + m[row * 5 + 2] * input[2]
+                    + m[row * 5 + 3] * input[3] + m[row * 5 + 4];
+                result.push((value.clamp(0.0, 1.0) * 255.0).round() as u8);
+            }
+        }
+        result
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Levels {
+    pub input_black: f64,
+    pub input_white: f64,
+    pub gamma: f64,
+    pub output_black: f64,
+    pub output_white: f64,
+}
+
+impl Default for Levels {
+    fn default() -> Self {
+        Levels {
+            input_black: 0.0,
+            input_white: 1.0,
+            gamma: 1.0,
+            output_black: 0.0,
+            output_white: 1.0,
+        }
+    }
+}
+
+impl Levels {
+    pub fn validate(&self) -> Result<()> {
+        let in_range = |value: f64| (0.0..=1.0).contains(&value);
+        if ![self.input_black, self.input_white, self.output_black, self.output_white].into_iter().all(in_range) {
+            bail!("Levels must be between 0 and 1");
+        }
+        if self.input_white <= self.input_black {
+            bail!("Input white point must be above the black point");
+        }
+        if !(self.gamma > 0.0 && self.gamma.is_finite()) {
+            bail!("Levels gamma must be positive");
+        }
+        Ok(())
+    }
+
+    pub fn map(&self, value: f64) -> f64 {
+        let normalized = ((value - self.input_black) / (self.input_white - self.input_black)).clamp(0.0, 1.0);
+        self.output_black + normalized.powf(1.0 / self.gamma) * (self.output_white - self.output_black)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Curve {
+    points: Vec<(f64, f64)>,
+    tangents: Vec<f64>,
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Curve::identity()
+    }
+}
+
+impl Curve {
+    pub fn new(points: &[(f64, f64)]) -> Result<Curve> {
+        if points.len() < 2 {
+            bail!("A curve needs at least two points");
+        }
+        if points.iter().any(|&(x, y)| !(0.0..=1.0).contains(&x) || !(0.0..=1.0).contains(&y)) {
+            bail!("Curve points must be between 0 and 1");
+        }
+
+        let mut points = points.to_vec();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if points.windows(2).any(|pair| pair[1].0 - pair[0].0 <= f64::EPSILON) {
+            bail!("Curve points must have distinct x coordinates");
+        }
+
+        let slopes: Vec<f64> = points.windows(2).map(|pair| (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0)).collect();
+        let mut tangents = Vec::with_capacity(points.len());
+        tangents.push(slopes[0]);
+        for pair in slopes.windows(2) {
+            tangents.push(if pair[0] * pair[1] <= 0.0 { 0.0 } else { (pair[0] + pair[1]) / 2.0 });
+        }
+        tangents.push(slopes[slopes.len() - 1]);
+
+        for (segment, &slope) in slopes.iter().enumerate() {
+            if slope == 0.0 {
+                tangents[segment] = 0.0;
+                tangents[segment + 1] = 0.0;
+                continue;
+            }
+            let (alpha, beta) = (tangents[segment] / slope, tangents[segment + 1] / slope);
+            let magnitude = alpha.hypot(beta);
+            if magnitude > 3.0 {
+                let scale = 3.0 / magnitude;
+                tangents[segment] = scale * alpha * slope;
+                tangents[segment + 1] = scale * beta * slope;
+            }
+        }
+
+        Ok(Curve { points, tangents })
+    }
+
+    pub fn from_slice(values: &[f64]) -> Result<Curve> {
+        if !values.len().is_multiple_of(2) {
+            bail!("Curve points must be given as x, y pairs");
+        }
+        let points: Vec<(f64, f64)> = values.chunks_exact(2).map(|pair| (pair[0], pair[1])).collect();
+        Curve::new(&points)
+    }
+
+    pub fn identity() -> Curve {
+        Curve { points: vec![(0.0, 0.0), (1.0, 1.0)], tangents: vec![1.0, 1.0] }
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+        if x <= first.0 {
+            return first.1;
+        }
+        if x >= last.0 {
+            return last.1;
+        }
+
+        let segment = self.points.partition_point(|point| point.0 <= x) - 1;
+        let ((x0, y0), (x1, y1)) = (self.points[segment], self.points[segment + 1]);
+        let h = x1 - x0;
+        let t = (x - x0) / h;
+        let (t2, t3) = (t * t, t * t * t);
+
+        let value = (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+            + (t3 - 2.0 * t2 + t) * h * self.tangents[segment]
+            + (-2.0 * t3 + 3.0 * t2) * y1
+            + (t3 - t2) * h * self.tangents[segment + 1];
+        value.clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Curves {
+    pub rgb: Curve,
+    pub red: Curve,
+    pub green: Curve,
+    pub blue: Curve,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToneLut {
+    tables: [[u8; 256]; 3],
+}
+
+impl Default for ToneLut {
+    fn default() -> Self {
+        ToneLut::identity()
+    }
+}
+
+impl ToneLut {
+    pub fn identity() -> ToneLut {
+        ToneLut::from_fn(|_, value| value)
+    }
+
+    pub fn from_fn(f: impl Fn(usize, f64) -> f64) -> ToneLut {
+        let mut tables = [[0u8; 256]; 3];
+        for (channel, table) in tables.iter_mut().enumerate() {
+            for (index, entry) in table.iter_mut().enumerate() {
+                *entry = (f(channel, index as f64 / 255.0).clamp(0.0, 1.0) * 255.0).round() as u8;
+            }
+        }
+        ToneLut { tables }
+    }
+
+    pub fn from_tables(red: [u8; 256], green: [u8; 256], blue: [u8; 256]) -> ToneLut {
+        ToneLut { tables: [red, green, blue] }
+    }
+
+    pub fn gamma(gamma: f64) -> Result<ToneLut> {
+        if !(gamma > 0.0 && gamma.is_finite()) {
+            bail!("Gamma must be positive");
+        }
+        Ok(ToneLut::from_fn(|_, value| value.powf(1.0 / gamma)))
+    }
+
+    pub fn levels(levels: &Levels) -> Result<ToneLut> {
+        levels.validate()?;
+        Ok(ToneLut::from_fn(|_, value| levels.map(value)))
+    }
+
+    pub fn curves(curves: &Curves) -> ToneLut {
+        let channels = [&curves.red, &curves.green, &curves.blue];
+        ToneLut::from_fn(|channel, value| channels[channel].evaluate(curves.rgb.evaluate(value)))
+    }
+
+    pub fn then(&self, next: &ToneLut) -> ToneLut {
+        let mut tables = [[0u8; 256]; 3];
+        for (channel, table) in tables.iter_mut().enumerate() {
+            for (index, entry) in table.iter_mut().enumerate() {
+                *entry = next.tables[channel][self.tables[channel][index] as usize];
+            }
+        }
+        ToneLut { tables }
+    }
+
+    pub fn apply(&self, data: &[u8]) -> Vec<u8> {
+        map_rgb(data, |rgb| {
+            for (channel, value) in rgb.iter_mut().enumerate() {
+                *value = self.tables[channel][*value as usize];
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorLut {
+    size: usize,
+    entries: Vec<[f32; 3]>,
+}
+
+impl ColorLut {
+    pub fn new(size: usize, entries: Vec<[f32; 3]>) -> Result<ColorLut> {
+        if !(2..=MAX_COLOR_LUT_SIZE).contains(&size) {
+            bail!("3D LUT size must be between 2 and {}", MAX_COLOR_LUT_SIZE);
+        }
+        if entries.len() != size * size * size {
+            bail!("A 3D LUT of size {} needs {} entries, got {}", size, size * size * size, entries.len());
+        }
+        Ok(ColorLut { size, entries })
+    }
+
+    pub fn identity(size: usize) -> Result<ColorLut> {
+        let scale = (size.max(2) - 1) as f32;
+        let entries = (0..size * size * size)
+            .map(|index| [(index % size) as f32 / scale, (index / size % size) as f32 / scale, (index / (size * size)) as f32 / scale])
+            .collect();
+        ColorLut::new(size, entries)
+    }
+
+    pub fn parse_cube(text: &str) -> Result<ColorLut> {
+        let mut size = None;
+        let mut domain_min = [0.0f32; 3];
+        let mut domain_max = [1.0f32; 3];
+        let mut entries = Vec::new();
+
+        let triple = |fields: &[&str], line: usize| -> Result<[f32; 3]> {
+            if fields.len() != 3 {
+                bail!("Line {}: expected three values", line);
+            }
+            let mut values = [0.0f32; 3];
+            for (value, field) in values.iter_mut().zip(fields) {
+                *value = field.parse().map_err(|_| anyhow!("Line {}: invalid number '{}'", line, field))?;
+            }
+            Ok(values)
+        };
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[0] {
+                "TITLE" => {}
+                "LUT_3D_SIZE" => {
+                    let value = fields.get(1).and_then(|field| field.parse().ok());
+                    size = Some(value.ok_or_else(|| anyhow!("Line {}: invalid LUT_3D_SIZE", number + 1))?);
+                }
+                "LUT_1D_SIZE" => bail!("1D .cube LUTs are not supported"),
+                "DOMAIN_MIN" => domain_min = triple(&fields[1..], number + 1)?,
+                "DOMAIN_MAX" => domain_max = triple(&fields[1..], number + 1)?,
+                keyword if keyword.starts_with(|c: char| c.is_ascii_alphabetic()) => {}
+                _ => entries.push(triple(&fields, number + 1)?),
+            }
+        }
+
+        let size = size.ok_or_else(|| anyhow!("Missing LUT_3D_SIZE"))?;
+        if (0..3).any(|channel| domain_max[channel] <= domain_min[channel]) {
+            bail!("DOMAIN_MAX must be above DOMAIN_MIN");
+        }
+        for entry in entries.iter_mut() {
+            for channel in 0..3 {
+                entry[channel] = (entry[channel] - domain_min[channel]) / (domain_max[channel] - domain_min[channel]);
+            }
+        }
+        ColorLut::new(size, entries)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    fn entry(&self, r: usize, g: usize, b: usize) -> [f32; 3] {
+        self.entries[(b * self.size + g) * self.size + r]
+    }
+
+    pub fn lookup(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let scale = (self.size - 1) as f32;
+        let mut base = [0usize; 3];
+        let mut fraction = [0.0f32; 3];
+        for channel in 0..3 {
+            let position = rgb[channel].clamp(0.0, 1.0) * scale;
+            base[channel] = (position.floor() as usize).min(self.size - 2);
+            fraction[channel] = position - base[channel] as f32;
+        }
+
+        let mut result = [0.0f32; 3];
+        for corner in 0..8 {
+            let offset = [corner & 1, (corner >> 1) & 1, (corner >> 2) & 1];
+            let mut weight = 1.0;
+            for channel in 0..3 {
+                weight *= if offset[channel] == 1 { fraction[channel] } else { 1.0 - fraction[channel] };
+            }
+            if weight == 0.0 {
+                continue;
+            }
+            let entry = self.entry(base[0] + offset[0], base[1] + offset[1], base[2] + offset[2]);
+            for channel in 0..3 {
+                result[channel] += weight * entry[channel];
+            }
+        }
+        result
+    }
+
+    pub fn apply(&self, data: &[u8], intensity: f64) -> Vec<u8> {
+        let intensity = intensity.clamp(0.0, 1.0) as f32;
+        map_rgb(data, |rgb| {
+            let input = [rgb[0] as f32 / 255.0, rgb[1] as f32 / 255.0, rgb[2] as f32 / 255.0];
+            let graded = self.lookup(input);
+            for channel in 0..3 {
+                let value = input[channel] + (graded[channel] - input[channel]) * intensity;
+                rgb[channel] = (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    GaussianBlur(f64),
+    BoxBlur(u32),
+    Sharpen(f64),
+    UnsharpMask { sigma: f64, amount: f64, threshold: u8 },
+    Convolve(Kernel, EdgeMode),
+    ColorMatrix(ColorMatrix),
+    Grayscale(f64),
+    Sepia(f64),
+    Saturate(f64),
+    HueRotate(f64),
+    Brightness(f64),
+    Contrast(f64),
+    Invert(f64),
+    Opacity(f64),
+    Gamma(f64),
+    Levels(Levels),
+    Curves(Curves),
+    ColorLut(ColorLut, f64),
+}
+
+impl Filter {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Filter::GaussianBlur(_) => "blur",
+            Filter::BoxBlur(_) => "box-blur",
+            Filter::Sharpen(_) => "sharpen",
+            Filter::UnsharpMask { .. } => "unsharp-mask",
+            Filter::Convolve(..) => "convolve",
+            Filter::ColorMatrix(_) => "color-matrix",
+            Filter::Grayscale(_) => "grayscale",
+            Filter::Sepia(_) => "sepia",
+            Filter::Saturate(_) => "saturate",
+            Filter::HueRotate(_) => "hue-rotate",
+            Filter::Brightness(_) => "brightness",
+            Filter::Contrast(_) => "contrast",
+            Filter::Invert(_) => "invert",
+            Filter::Opacity(_) => "opacity",
+            Filter::Gamma(_) => "gamma",
+            Filter::Levels(_) => "levels",
+            Filter::Curves(_) => "curves",
+            Filter::ColorLut(..) => "lut",
+        }
+    }
+
+    pub fn color_matrix(&self) -> Option<ColorMatrix> {
+        match *self {
+            Filter::ColorMatrix(matrix) => Some(matrix),
+            Filter::Grayscale(amount) => Some(ColorMatrix::grayscale(amount)),
+            Filter::Sepia(amount) => Some(ColorMatrix::sepia(amount)),
+            Filter::Saturate(amount) => Some(ColorMatrix::saturate(amount)),
+            Filter::HueRotate(angle) => Some(ColorMatrix::hue_rotate(angle)),
+            Filter::Brightness(amount) => Some(ColorMatrix::brightness(amount)),
+            Filter::Contrast(amount) => Some(ColorMatrix::contrast(amount)),
+            Filter::Invert(amount) => Some(ColorMatrix::invert(amount)),
+            Filter::Opacity(amount) => Some(ColorMatrix::opacity(amount)),
+            _ => None,
+        }
+    }
+
+    pub fn to_css(&self) -> Option<String> {
+        let percent = |value: f64| format!("{}%", value * 100.0);
+        Some(match self {
+            Filter::GaussianBlur(sigma) => format!("blur({}px)", sigma),
+            Filter::Grayscale(amount) => format!("grayscale({})", percent(*amount)),
+            Filter::Sepia(amount) => format!("sepia({})", percent(*amount)),
+            Filter::Saturate(amount) => format!("saturate({})", percent(*amount)),
+            Filter::HueRotate(angle) => format!("hue-rotate({}deg)", angle),
+            Filter::Brightness(amount) => format!("brightness({})", percent(*amount)),
+            Filter::Contrast(amount) => format!("contrast({})", percent(*amount)),
+            Filter::Invert(amount) => format!("invert({})", percent(*amount)),
+            Filter::Opacity(amount) => format!("opacity({})", percent(*amount)),
+            _ => return None,
+        })
+    }
+
+    pub fn apply(&self, data: &[u8], width: u32, height: u32) -> Result<Vec<u8>> {
+        check_dimensions(data, width, height)?;
+        if let Some(matrix) = self.color_matrix() {
+            return Ok(matrix.apply(data));
+        }
+
+        match self {
+            Filter::GaussianBlur(sigma) => gaussian_blur(data, width, height, *sigma),
+            Filter::BoxBlur(radius) => Ok(image_processing::blur(data, width, height, *radius)),
+            Filter::Sharpen(amount) => sharpen(data, width, height, *amount),
+            Filter::UnsharpMask { sigma, amount, threshold } => unsharp_mask(data, width, height, *sigma, *amount, *threshold),
+            Filter::Convolve(kernel, edge) => convolve(data, width, height, kernel, *edge),
+            Filter::Gamma(gamma) => Ok(ToneLut::gamma(*gamma)?.apply(data)),
+            Filter::Levels(levels) => Ok(ToneLut::levels(levels)?.apply(data)),
+            Filter::Curves(curves) => Ok(ToneLut::curves(curves).apply(data)),
+            Filter::ColorLut(lut, intensity) => Ok(lut.apply(data, *intensity)),
+            _ => unreachable!("color matrix filters are handled above"),
+        }
+    }
+}
+
+pub fn apply_filters(data: &[u8], width: u32, height: u32, filters: &[Filter]) -> Result<Vec<u8>> {
+    check_dimensions(data, width, height)?;
+    filters.iter().try_fold(data.to_vec(), |result, filter| filter.apply(&result, width, height))
+}
+
+fn css_amount(argument: &str, default: f64) -> Result<f64> {
+    if argument.is_empty() {
+        return Ok(default);
+    }
+    let value = match argument.strip_suffix('%') {
+        Some(percent) => percent.trim().parse::<f64>().map(|value| value / 100.0),
+        None => argument.parse::<f64>(),
+    };
+    value.ok().filter(|value| *value >= 0.0).ok_or_else(|| anyhow!("Invalid filter amount: {}", argument))
+}
+
+fn css_angle(argument: &str) -> Result<f64> {
+    if argument.is_empty() {
+        return Ok(0.0);
+    }
+    let units = [("deg", 1.0), ("grad", 0.9), ("rad", 180.0 / std::f64::consts::PI), ("turn", 360.0)];
+    let (number, scale) = units
+        .iter()
+        .find_map(|(unit, scale)| argument.strip_suffix(unit).map(|number| (number, *scale)))
+        .unwrap_or((argument, 1.0));
+    if scale == 1.0 && number == argument && argument != "0" {
+        bail!("Angle needs a unit: {}", argument);
+    }
+    number.trim().parse::<f64>().map(|value| value * scale).map_err(|_| anyhow!("Invalid angle: {}", argument))
+}
+
+fn css_length(argument: &str) -> Result<f64> {
+    if argument.is_empty() {
+        return Ok(0.0);
+    }
+    let number = argument.strip_suffix("px").unwrap_or(argument);
+    if number == argument && argument != "0" {
+        bail!("Blur radius needs a px unit: {}", argument);
+    }
+    number.trim().parse::<f64>().ok().filter(|value| *value >= 0.0).ok_or_else(|| anyhow!("Invalid blur radius: {}", argument))
+}
+
+pub fn parse_css_filters(css: &str) -> Result<Vec<Filter>> {
+    let css = css.trim();
+    if css.is_empty() || css.eq_ignore_ascii_case("none") {
+        return Ok(Vec::new());
+    }
+
+    let mut filters = Vec::new();
+    let mut rest = css;
+    while !rest.is_empty() {
+        let open = rest.find('(').ok_or_else(|| anyhow!("Expected '(' in filter: {}", rest))?;
+        let close = rest.find(')').ok_or_else(|| anyhow!("Unterminated filter: {}", rest))?;
+        if close < open {
+            bail!("Malformed filter: {}", rest);
+        }
+
+        let name = rest[..open].trim().to_ascii_lowercase();
+        let argument = rest[open + 1..close].trim().to_ascii_lowercase();
+        filters.push(match name.as_str() {
+            "blur" => Filter::GaussianBlur(css_length(&argument)?),
+            "grayscale" => Filter::Grayscale(css_amount(&argument, 1.0)?.min(1.0)),
+            "sepia" => Filter::Sepia(css_amount(&argument, 1.0)?.min(1.0)),
+            "saturate" => Filter::Saturate(css_amount(&argument, 1.0)?),
+            "hue-rotate" => Filter::HueRotate(css_angle(&argument)?),
+            "brightness" => Filter::Brightness(css_amount(&argument, 1.0)?),
+            "contrast" => Filter::Contrast(css_amount(&argument, 1.0)?),
+            "invert" => Filter::Invert(css_amount(&argument, 1.0)?.min(1.0)),
+            "opacity" => Filter::Opacity(css_amount(&argument, 1.0)?.min(1.0)),
+            _ => bail!("Unsupported CSS filter: {}", name),
+        });
+        rest = rest[close + 1..].trim_start();
+    }
+
+    Ok(filters)
+}
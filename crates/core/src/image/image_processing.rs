@@ -0,0 +1,130 @@
+use super::resampling::{resample, rotate_with, ResizeFilter, WarpOptions};
+
+#[derive(Debug, Clone)]
+pub struct RotatedImage {
+    pub data: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+fn map_pixels<F>(image_data: &[u8], f: F) -> Vec<u8>
+where F: Fn(u8, u8, u8) -> (u8, u8, u8) {
+    if !image_data.len().is_multiple_of(4) {
+        return Vec::new();
+    }
+    
+    let mut result = Vec::with_capacity(image_data.len());
+    
+    for pixel in image_data.chunks_exact(4) {
+        let (r, g, b) = f(pixel[0], pixel[1], pixel[2]);
+        result.extend_from_slice(&[r, g, b, pixel[3]]);
+    }
+    
+    result
+}
+
+fn matches_dimensions(image_data: &[u8], width: u32, height: u32) -> bool {
+    image_data.len() == width as usize * height as usize * 4
+}
+
+pub fn grayscale(image_data: &[u8]) -> Vec<u8> {
+    map_pixels(image_data, |r, g, b| {
+        let gray = (0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64) as u8;
+        (gray, gray, gray)
+    })
+}
+
+pub fn invert(image_data: &[u8]) -> Vec<u8> {
+    map_pixels(image_data, |r, g, b| (255 - r, 255 - g, 255 - b))
+}
+
+pub fn brightness(image_data: &[u8], factor: f64) -> Vec<u8> {
+    let scale = |c: u8| (c as f64 * factor).clamp(0.0, 255.0) as u8;
+    map_pixels(image_data, |r, g, b| (scale(r), scale(g), scale(b)))
+}
+
+pub fn blur(image_data: &[u8], width: u32, height: u32, radius: u32) -> Vec<u8> {
+    if !matches_dimensions(image_data, width, height) {
+        return Vec::new();
+    }
+    
+    let mut result = vec![0u8; image_data.len()];
+    let r = radius as i32;
+    let (w, h) = (width as i32, height as i32);
+    
+    for y in 0..h {
+        for x in 0..w {
+            let mut sums = [0.0; 3];
+            let mut count = 0.0;
+            
+            for ny in (y - r).max(0)..=(y + r).min(h - 1) {
+                for nx in (x - r).max(0)..=(x + r).min(w - 1) {
+                    let idx = ((ny * w + nx) * 4) as usize;
+                    
+                    for (c, sum) in sums.iter_mut().enumerate() {
+                        *sum += image_data[idx + c] as f64;
+                    }
+                    count += 1.0;
+                }
+            }
+            
+            let idx = ((y * w + x) * 4) as usize;
+            
+            for (c, sum) in sums.iter().enumerate() {
+                result[idx + c] = (sum / count) as u8;
+            }
+            result[idx + 3] = image_data[idx + 3];
+        }
+    }
+    
+    result
+}
+
+pub fn edge_detection(image_data: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let gray = grayscale(image_data);
+    let mut result = vec![0u8; gray.len()];
+    
+    if !matches_dimensions(&gray, width, height) || width < 3 || height < 3 {
+        return result;
+    }
+    
+    let at = |x: u32, y: u32| gray[((y * width + x) * 4) as usize] as i32;
+    
+    for y in 1..height-1 {
+        for x in 1..width-1 {
+            let top_left = at(x - 1, y - 1);
+            let top = at(x, y - 1);
+            let top_right = at(x + 1, y - 1);
+            
+            let left = at(x - 1, y);
+            let right = at(x + 1, y);
+            
+            let bottom_left = at(x - 1, y + 1);
+            let bottom = at(x, y + 1);
+            let bottom_right = at(x + 1, y + 1);
+            
+            let h_gradient = -top_left - 2 * left - bottom_left + top_right + 2 * right + bottom_right;
+            let v_gradient = -top_left - 2 * top - top_right + bottom_left + 2 * bottom + bottom_right;
+            
+            let gradient = ((h_gradient.pow(2) + v_gradient.pow(2)) as f64).sqrt().min(255.0) as u8;
+            
+            let idx = ((y * width + x) * 4) as usize;
+            result[idx] = gradient;
+            result[idx + 1] = gradient;
+            result[idx + 2] = gradient;
+            result[idx + 3] = gray[idx + 3];
+        }
+    }
+    
+    result
+}
+
+pub fn resize(image_data: &[u8], source_width: u32, source_height: u32, 
+              target_width: u32, target_height: u32, filter: ResizeFilter) -> Vec<u8> {
+    resample(image_data, source_width, source_height, target_width, target_height, filter).unwrap_or_default()
+}
+
+pub fn rotate(image_data: &[u8], width: u32, height: u32, angle_degrees: f64, options: WarpOptions) -> Option<RotatedImage> {
+    let (data, width, height) = rotate_with(image_data, width, height, angle_degrees, options).ok()?;
+    Some(RotatedImage { data, width, height })
+}
@@ -0,0 +1,11 @@
+pub mod image_processing;
+pub mod codec;
+pub mod resampling;
+pub mod filters;
+pub mod analysis;
+
+pub use image_processing::*;
+pub use codec::*;
+pub use resampling::*;
+pub use filters::*;
+pub use analysis::*;
@@ -0,0 +1,487 @@
+use anyhow::{anyhow, bail, Result};
+use std::f64::consts::PI;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizeFilter {
+    Nearest,
+    Bilinear,
+    Bicubic,
+    Gaussian,
+    #[default]
+    Lanczos3,
+}
+
+impl ResizeFilter {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResizeFilter::Nearest => "nearest",
+            ResizeFilter::Bilinear => "bilinear",
+            ResizeFilter::Bicubic => "bicubic",
+            ResizeFilter::Gaussian => "gaussian",
+            ResizeFilter::Lanczos3 => "lanczos3",
+        }
+    }
+
+    pub fn support(&self) -> f64 {
+        match self {
+            ResizeFilter::Nearest => 0.5,
+            ResizeFilter::Bilinear => 1.0,
+            ResizeFilter::Bicubic => 2.0,
+            ResizeFilter::Gaussian | ResizeFilter::Lanczos3 => 3.0,
+        }
+    }
+
+    pub fn kernel(&self, x: f64) -> f64 {
+        let x = x.abs();
+        match self {
+            ResizeFilter::Nearest => if x <= 0.5 { 1.0 } else { 0.0 },
+            ResizeFilter::Bilinear => (1.0 - x).max(0.0),
+            ResizeFilter::Bicubic => {
+                let a = -0.5;
+                if x < 1.0 {
+                    ((a + 2.0) * x - (a + 3.0)) * x * x + 1.0
+                } else if x < 2.0 {
+                    ((a * x - 5.0 * a) * x + 8.0 * a) * x - 4.0 * a
+                } else {
+                    0.0
+                }
+            }
+            ResizeFilter::Gaussian => {
+                if x < 3.0 {
+                    (-2.0 * x * x).exp() * (2.0 / PI).sqrt()
+                } else {
+                    0.0
+                }
+            }
+            ResizeFilter::Lanczos3 => {
+                if x < 1e-8 {
+                    1.0
+                } else if x < 3.0 {
+                    let px = PI * x;
+                    3.0 * px.sin() * (px / 3.0).sin() / (px * px)
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for ResizeFilter {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "nearest" => Ok(ResizeFilter::Nearest),
+            "bilinear" | "triangle" | "linear" => Ok(ResizeFilter::Bilinear),
+            "bicubic" | "catmullrom" | "cubic" => Ok(ResizeFilter::Bicubic),
+            "gaussian" => Ok(ResizeFilter::Gaussian),
+            "lanczos3" | "lanczos" => Ok(ResizeFilter::Lanczos3),
+            _ => bail!("Unsupported resize filter: {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeMode {
+    Clamp,
+    Wrap,
+    Mirror,
+    #[default]
+    Transparent,
+}
+
+impl EdgeMode {
+    pub(super) fn resolve(&self, index: i64, len: usize) -> Option<usize> {
+        let len = len as i64;
+        if (0..len).contains(&index) {
+            return Some(index as usize);
+        }
+
+        match self {
+            EdgeMode::Clamp => Some(index.clamp(0, len - 1) as usize),
+            EdgeMode::Wrap => Some(index.rem_euclid(len) as usize),
+            EdgeMode::Mirror => {
+                let period = 2 * len;
+                let folded = index.rem_euclid(period);
+                Some(if folded < len { folded } else { period - 1 - folded } as usize)
+            }
+            EdgeMode::Transparent => None,
+        }
+    }
+}
+
+impl FromStr for EdgeMode {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "clamp" | "extend" => Ok(EdgeMode::Clamp),
+            "wrap" | "repeat" => Ok(EdgeMode::Wrap),
+            "mirror" | "reflect" => Ok(EdgeMode::Mirror),
+            "transparent" | "none" => Ok(EdgeMode::Transparent),
+            _ => bail!("Unsupported edge mode: {}", name),
+        }
+    }
+}
+
+pub(super) type Pixel = [f32; 4];
+
+pub(super) fn premultiply(data: &[u8]) -> Vec<Pixel> {
+    data.chunks_exact(4)
+        .map(|p| {
+            let alpha = p[3] as f32 / 255.0;
+            [p[0] as f32 / 255.0 * alpha, p[1] as f32 / 255.0 * alpha, p[2] as f32 / 255.0 * alpha, alpha]
+        })
+        .collect()
+}
+
+pub(super) fn unpremultiply(pixels: &[Pixel]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(pixels.len() * 4);
+    for pixel in pixels {
+        let alpha = pixel[3].clamp(0.0, 1.0);
+        if alpha <= f32::EPSILON {
+            data.extend_from_slice(&[0, 0, 0, 0]);
+            continue;
+        }
+        for channel in &pixel[..3] {
+            data.push(((channel / alpha).clamp(0.0, 1.0) * 255.0).round() as u8);
+        }
+        data.push((alpha * 255.0).round() as u8);
+    }
+    data
+}
+
+struct Contribution {
+    start: usize,
+    weights: Vec<f32>,
+}
+
+fn contributions(source_len: usize, target_len: usize, filter: ResizeFilter) -> Vec<Contribution> {
+    let scale = source_len as f64 / target_len as f64;
+    let filter_scale = scale.max(1.0);
+    let support = filter.support() * filter_scale;
+
+    (0..target_len)
+        .map(|i| {
+            let center = (i as f64 + 0.5) * scale;
+            if filter == ResizeFilter::Nearest {
+                let index = (center.floor() as usize).min(source_len - 1);
+                return Contribution { start: index, weights: vec![1.0] };
+            }
+
+            let start = ((center - support).floor().max(0.0)) as usize;
+            let end = ((center + support).ceil() as usize).min(source_len);
+            let mut weights: Vec<f64> = (start..end)
+                .map(|j| filter.kernel((j as f64 + 0.5 - center) / filter_scale))
+                .collect();
+
+            let sum: f64 = weights.iter().sum();
+            if sum.abs() > f64::EPSILON {
+                weights.iter_mut().for_each(|w| *w /= sum);
+            }
+            Contribution { start, weights: weights.into_iter().map(|w| w as f32).collect() }
+        })
+        .collect()
+}
+
+fn resample_pass(
+    source: &[Pixel],
+    lines: usize,
+    stride: (usize, usize),
+    contributions: &[Contribution],
+    target: &mut [Pixel],
+    target_stride: (usize, usize),
+) {
+    let (line_stride, step) = stride;
+    let (target_line_stride, target_step) = target_stride;
+
+    for line in 0..lines {
+        for (i, contribution) in contributions.iter().enumerate() {
+            let mut sum = [0.0f32; 4];
+            for (k, weight) in contribution.weights.iter().enumerate() {
+                let pixel = source[line * line_stride + (contribution.start + k) * step];
+                for c in 0..4 {
+                    sum[c] += pixel[c] * weight;
+                }
+            }
+            target[line * target_line_stride + i * target_step] = sum;
+        }
+    }
+}
+
+pub fn resample(
+    image_data: &[u8],
+    source_width: u32,
+    source_height: u32,
+    target_width: u32,
+    target_height: u32,
+    filter: ResizeFilter,
+) -> Result<Vec<u8>> {
+    let (sw, sh) = (source_width as usize, source_height as usize);
+    let (tw, th) = (target_width as usize, target_height as usize);
+    if image_data.len() != sw * sh * 4 {
+        bail!("Image data does not match {}x{}", source_width, source_height);
+    }
+    if sw == 0 || sh == 0 || tw == 0 || th == 0 {
+        bail!("Image dimensions must be non-zero");
+    }
+
+    let source = premultiply(image_data);
+
+    let mut horizontal = vec![[0.0f32; 4]; tw * sh];
+    resample_pass(&source, sh, (sw, 1), &contributions(sw, tw, filter), &mut horizontal, (tw, 1));
+
+    let mut vertical = vec![[0.0f32; 4]; tw * th];
+    resample_pass(&horizontal, tw, (1, tw), &contributions(sh, th, filter), &mut vertical, (1, tw));
+
+    Ok(unpremultiply(&vertical))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub matrix: [f64; 9],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+impl Transform {
+    pub fn identity() -> Self {
+        Transform { matrix: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0] }
+    }
+
+    pub fn affine(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self {
+        Transform { matrix: [a, b, c, d, e, f, 0.0, 0.0, 1.0] }
+    }
+
+    pub fn perspective(matrix: [f64; 9]) -> Self {
+        Transform { matrix }
+    }
+
+    pub fn from_slice(values: &[f64]) -> Result<Self> {
+        match values.len() {
+            6 => Ok(Transform::affine(values[0], values[1], values[2], values[3], values[4], values[5])),
+            9 => Ok(Transform::perspective(values.try_into().unwrap())),
+            n => bail!("Transform needs 6 (affine) or 9 (perspective) values, got {}", n),
+        }
+    }
+
+    pub fn translate(tx: f64, ty: f64) -> Self {
+        Transform::affine(1.0, 0.0, tx, 0.0, 1.0, ty)
+    }
+
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Transform::affine(sx, 0.0, 0.0, 0.0, sy, 0.0)
+    }
+
+    pub fn rotate(angle_degrees: f64) -> Self {
+        let (sin, cos) = angle_degrees.to_radians().sin_cos();
+        Transform::affine(cos, -sin, 0.0, sin, cos, 0.0)
+    }
+
+    pub fn rotate_about(angle_degrees: f64, cx: f64, cy: f64) -> Self {
+        Transform::translate(-cx, -cy)
+            .then(&Transform::rotate(angle_degrees))
+            .then(&Transform::translate(cx, cy))
+    }
+
+    pub fn shear(kx: f64, ky: f64) -> Self {
+        Transform::affine(1.0, kx, 0.0, ky, 1.0, 0.0)
+    }
+
+    pub fn from_quad(source: [(f64, f64); 4], target: [(f64, f64); 4]) -> Result<Self> {
+        let mut system = [[0.0f64; 9]; 8];
+        for (i, (&(x, y), &(u, v))) in source.iter().zip(target.iter()).enumerate() {
+            system[2 * i] = [x, y, 1.0, 0.0, 0.0, 0.0, -u * x, -u * y, u];
+            system[2 * i + 1] = [0.0, 0.0, 0.0, x, y, 1.0, -v * x, -v * y, v];
+        }
+
+        for col in 0..8 {
+            let pivot = (col..8)
+                .max_by(|&a, &b| system[a][col].abs().total_cmp(&system[b][col].abs()))
+                .unwrap();
+            if system[pivot][col].abs() < 1e-12 {
+                bail!("Quadrilateral points are degenerate");
+            }
+            system.swap(col, pivot);
+
+            let pivot_row = system[col];
+            for (row, equation) in system.iter_mut().enumerate() {
+                if row != col {
+                    let factor = equation[col] / pivot_row[col];
+                    for k in col..9 {
+                        equation[k] -= factor * pivot_row[k];
+                    }
+                }
+            }
+        }
+
+        let mut matrix = [1.0; 9];
+        for (i, equation) in system.iter().enumerate() {
+            matrix[i] = equation[8] / equation[i];
+        }
+        Ok(Transform { matrix })
+    }
+
+    pub fn then(&self, next: &Transform) -> Transform {
+        let (a, b) = (&next.matrix, &self.matrix);
+        let mut matrix = [0.0; 9];
+        for row in 0..3 {
+            for col in 0..3 {
+                matrix[row * 3 + col] = (0..3).map(|k| a[row * 3 + k] * b[k * 3 + col]).sum();
+            }
+        }
+        Transform { matrix }
+    }
+
+    pub fn is_affine(&self) -> bool {
+        self.matrix[6] == 0.0 && self.matrix[7] == 0.0 && self.matrix[8] == 1.0
+    }
+
+    pub fn inverse(&self) -> Option<Transform> {
+        let m = &self.matrix;
+        let cofactors = [
+            m[4] * m[8] - m[5] * m[7],
+            m[2] * m[7] - m[1] * m[8],
+            m[1] * m[5] - m[2] * m[4],
+            m[5] * m[6] - m[3] * m[8],
+            m[0] * m[8] - m[2] * m[6],
+            m[2] * m[3] - m[0] * m[5],
+            m[3] * m[7] - m[4] * m[6],
+            m[1] * m[6] - m[0] * m[7],
+            m[0] * m[4] - m[1] * m[3],
+        ];
+        let determinant = m[0] * cofactors[0] + m[1] * cofactors[3] + m[2] * cofactors[6];
+        if determinant.abs() < 1e-12 {
+            return None;
+        }
+        Some(Transform { matrix: cofactors.map(|c| c / determinant) })
+    }
+
+    pub fn apply(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let m = &self.matrix;
+        let w = m[6] * x + m[7] * y + m[8];
+        if w.abs() < 1e-12 {
+            return None;
+        }
+        Some(((m[0] * x + m[1] * y + m[2]) / w, (m[3] * x + m[4] * y + m[5]) / w))
+    }
+
+    pub fn bounds(&self, width: u32, height: u32) -> Option<(f64, f64, f64, f64)> {
+        let corners = [(0.0, 0.0), (width as f64, 0.0), (0.0, height as f64), (width as f64, height as f64)];
+        let mut bounds = (f64::MAX, f64::MAX, f64::MIN, f64::MIN);
+        for (x, y) in corners {
+            let (tx, ty) = self.apply(x, y)?;
+            bounds = (bounds.0.min(tx), bounds.1.min(ty), bounds.2.max(tx), bounds.3.max(ty));
+        }
+        Some(bounds)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WarpOptions {
+    pub filter: ResizeFilter,
+    pub edge: EdgeMode,
+}
+
+fn sample(source: &[Pixel], width: usize, height: usize, x: f64, y: f64, options: WarpOptions) -> Pixel {
+    let fx = x - 0.5;
+    let fy = y - 0.5;
+
+    if options.filter == ResizeFilter::Nearest {
+        let sx = options.edge.resolve(fx.round() as i64, width);
+        let sy = options.edge.resolve(fy.round() as i64, height);
+        return match (sx, sy) {
+            (Some(sx), Some(sy)) => source[sy * width + sx],
+            _ => [0.0; 4],
+        };
+    }
+
+    let support = options.filter.support();
+    let (x0, x1) = ((fx - support).ceil() as i64, (fx + support).floor() as i64);
+    let (y0, y1) = ((fy - support).ceil() as i64, (fy + support).floor() as i64);
+
+    let mut sum = [0.0f32; 4];
+    let mut total = 0.0f64;
+    for ty in y0..=y1 {
+        let wy = options.filter.kernel(ty as f64 - fy);
+        if wy == 0.0 {
+            continue;
+        }
+        let sy = options.edge.resolve(ty, height);
+        for tx in x0..=x1 {
+            let weight = wy * options.filter.kernel(tx as f64 - fx);
+            if weight == 0.0 {
+                continue;
+            }
+            total += weight;
+            if let (Some(sx), Some(sy)) = (options.edge.resolve(tx, width), sy) {
+                let pixel = source[sy * width + sx];
+                for c in 0..4 {
+                    sum[c] += pixel[c] * weight as f32;
+                }
+            }
+        }
+    }
+
+    if total.abs() > f64::EPSILON {
+        sum.iter_mut().for_each(|c| *c /= total as f32);
+    }
+    sum
+}
+
+pub fn warp(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    transform: &Transform,
+    target_width: u32,
+    target_height: u32,
+    options: WarpOptions,
+) -> Result<Vec<u8>> {
+    let (w, h) = (width as usize, height as usize);
+    if image_data.len() != w * h * 4 {
+        bail!("Image data does not match {}x{}", width, height);
+    }
+    if w == 0 || h == 0 {
+        bail!("Image dimensions must be non-zero");
+    }
+
+    let inverse = transform.inverse().ok_or_else(|| anyhow!("Transform is not invertible"))?;
+    let source = premultiply(image_data);
+    let mut target = vec![[0.0f32; 4]; target_width as usize * target_height as usize];
+
+    for y in 0..target_height as usize {
+        for x in 0..target_width as usize {
+            if let Some((sx, sy)) = inverse.apply(x as f64 + 0.5, y as f64 + 0.5) {
+                target[y * target_width as usize + x] = sample(&source, w, h, sx, sy, options);
+            }
+        }
+    }
+
+    Ok(unpremultiply(&target))
+}
+
+pub fn rotate_with(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    angle_degrees: f64,
+    options: WarpOptions,
+) -> Result<(Vec<u8>, u32, u32)> {
+    let rotation = Transform::rotate_about(angle_degrees, width as f64 / 2.0, height as f64 / 2.0);
+    let (min_x, min_y, max_x, max_y) = rotation.bounds(width, height)
+        .ok_or_else(|| anyhow!("Rotation produced an invalid transform"))?;
+
+    let target_width = ((max_x - min_x - 1e-9).ceil().max(1.0)) as u32;
+    let target_height = ((max_y - min_y - 1e-9).ceil().max(1.0)) as u32;
+    let transform = rotation.then(&Transform::translate(-min_x, -min_y));
+
+    let data = warp(image_data, width, height, &transform, target_width, target_height, options)?;
+    Ok((data, target_width, target_height))
+}
@@ -1,16 +1,39 @@
+pub mod algorithms;
+pub mod archive;
+pub mod compression;
+pub mod crypto;
+pub mod image;
+pub mod machine_learning;
+pub mod math;
+pub mod text;
 
 pub mod utils {
+    use rand::{rngs::StdRng, SeedableRng};
+
     pub fn placeholder() {
     }
+
+    pub fn rng_from_seed(seed: Option<u64>) -> StdRng {
+        match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        }
+    }
 }
 
 pub mod config {
     pub struct Config {
     }
     
+    impl Default for Config {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
     impl Config {
         pub fn new() -> Self {
             Config {}
         }
     }
-}
\ No newline at end of file
+}
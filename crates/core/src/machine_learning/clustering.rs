@@ -0,0 +1,101 @@
+use anyhow::{bail, Result};
+use rand::{seq::index, Rng};
+use serde::{Deserialize, Serialize};
+use super::data::{check_features, check_length, euclidean_distance};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KMeans {
+    pub n_features: usize,
+    pub centroids: Vec<Vec<f64>>,
+}
+
+impl KMeans {
+    pub fn fit<R: Rng>(points: &[Vec<f64>], n_clusters: usize, max_iterations: usize, rng: &mut R) -> Result<(KMeans, Vec<usize>)> {
+        if n_clusters == 0 {
+            bail!("Number of clusters must be greater than zero");
+        }
+        
+        if points.len() < n_clusters {
+            bail!("Number of samples must be greater than number of clusters");
+        }
+        
+        let n_features = points[0].len();
+        check_features(points, n_features)?;
+        
+        let mut model = KMeans {
+            n_features,
+            centroids: index::sample(rng, points.len(), n_clusters)
+                .into_iter()
+                .map(|i| points[i].clone())
+                .collect(),
+        };
+        let mut assignments = model.predict(points);
+        
+        for _ in 0..max_iterations {
+            model.update_centroids(points, &assignments);
+            
+            let next = model.predict(points);
+            if next == assignments {
+                break;
+            }
+            assignments = next;
+        }
+        
+        Ok((model, assignments))
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        if self.centroids.is_empty() {
+            bail!("K-means model must contain at least one centroid");
+        }
+        
+        for centroid in &self.centroids {
+            check_length("centroid values", centroid.len(), self.n_features)?;
+        }
+        
+        Ok(())
+    }
+    
+    pub fn n_clusters(&self) -> usize {
+        self.centroids.len()
+    }
+    
+    pub fn predict_one(&self, point: &[f64]) -> usize {
+        self.centroids
+            .iter()
+            .map(|centroid| euclidean_distance(point, centroid))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+    
+    pub fn predict(&self, points: &[Vec<f64>]) -> Vec<usize> {
+        points.iter().map(|point| self.predict_one(point)).collect()
+    }
+    
+    pub fn inertia(&self, points: &[Vec<f64>]) -> f64 {
+        points
+            .iter()
+            .map(|point| euclidean_distance(point, &self.centroids[self.predict_one(point)]).powi(2))
+            .sum()
+    }
+    
+    fn update_centroids(&mut self, points: &[Vec<f64>], assignments: &[usize]) {
+        let mut sums = vec![vec![0.0; self.n_features]; self.centroids.len()];
+        let mut counts = vec![0usize; self.centroids.len()];
+        
+        for (point, &cluster) in points.iter().zip(assignments) {
+            counts[cluster] += 1;
+            for (sum, value) in sums[cluster].iter_mut().zip(point) {
+                *sum += value;
+            }
+        }
+        
+        for ((centroid, sum), count) in self.centroids.iter_mut().zip(sums).zip(counts) {
+            if count > 0 {
+                *centroid = sum.into_iter().map(|value| value / count as f64).collect();
+            }
+        }
+    }
+}
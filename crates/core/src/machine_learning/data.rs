@@ -0,0 +1,85 @@
+use anyhow::{bail, Result};
+use std::collections::HashMap;
+
+pub fn to_rows(data: &[f64], n_features: usize) -> Result<Vec<Vec<f64>>> {
+    if n_features == 0 {
+        bail!("Number of features must be greater than zero");
+    }
+    
+    if !data.len().is_multiple_of(n_features) {
+        bail!("Data length {} is not a multiple of the number of features {}", data.len(), n_features);
+    }
+    
+    Ok(data.chunks(n_features).map(|row| row.to_vec()).collect())
+}
+
+pub fn flatten(rows: &[Vec<f64>]) -> Vec<f64> {
+    rows.iter().flatten().copied().collect()
+}
+
+pub fn check_samples<T>(x: &[Vec<f64>], y: &[T]) -> Result<usize> {
+    if x.is_empty() {
+        bail!("Training data must not be empty");
+    }
+    
+    if x.len() != y.len() {
+        bail!("Length of labels ({}) must match number of samples ({})", y.len(), x.len());
+    }
+    
+    let n_features = x[0].len();
+    check_features(x, n_features)?;
+    
+    Ok(n_features)
+}
+
+pub fn check_features(x: &[Vec<f64>], n_features: usize) -> Result<()> {
+    if let Some(row) = x.iter().find(|row| row.len() != n_features) {
+        bail!("Expected {} features, got {}", n_features, row.len());
+    }
+    
+    Ok(())
+}
+
+pub fn check_length(name: &str, len: usize, expected: usize) -> Result<()> {
+    if len != expected {
+        bail!("Expected {} {}, got {}", expected, name, len);
+    }
+    
+    Ok(())
+}
+
+pub fn to_labels(y: &[f64]) -> Result<Vec<u32>> {
+    y.iter()
+        .map(|&value| {
+            if value < 0.0 || value.fract() != 0.0 || value > u32::MAX as f64 {
+                bail!("Class labels must be non-negative integers, got {}", value);
+            }
+            Ok(value as u32)
+        })
+        .collect()
+}
+
+pub fn unique_labels(y: &[u32]) -> Vec<u32> {
+    let mut labels = y.to_vec();
+    labels.sort_unstable();
+    labels.dedup();
+    labels
+}
+
+pub fn euclidean_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt()
+}
+
+pub fn majority_vote(y: &[u32]) -> u32 {
+    let mut counts: HashMap<u32, usize> = HashMap::new();
+    
+    for &label in y {
+        *counts.entry(label).or_insert(0) += 1;
+    }
+    
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+        .map(|(label, _)| label)
+        .unwrap_or(0)
+}
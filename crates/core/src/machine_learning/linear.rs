@@ -0,0 +1,183 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use crate::math::{Matrix, Vector};
+use super::data::{check_features, check_length, check_samples, unique_labels};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinearRegression {
+    pub n_features: usize,
+    pub intercept: f64,
+    pub coefficients: Vec<f64>,
+}
+
+impl LinearRegression {
+    pub fn fit(x: &[Vec<f64>], y: &[f64]) -> Result<LinearRegression> {
+        let n_features = check_samples(x, y)?;
+        let design = Matrix::from_fn(x.len(), n_features + 1, |i, j| if j == 0 { 1.0 } else { x[i][j - 1] });
+        let solution = design.least_squares(&Vector::from_slice(y))?.into_vec();
+        
+        Ok(LinearRegression {
+            n_features,
+            intercept: solution[0],
+            coefficients: solution[1..].to_vec(),
+        })
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        check_length("coefficients", self.coefficients.len(), self.n_features)
+    }
+    
+    pub fn predict_one(&self, features: &[f64]) -> f64 {
+        self.intercept + self.coefficients.iter().zip(features).map(|(w, x)| w * x).sum::<f64>()
+    }
+    
+    pub fn predict(&self, x: &[Vec<f64>]) -> Result<Vec<f64>> {
+        check_features(x, self.n_features)?;
+        Ok(x.iter().map(|row| self.predict_one(row)).collect())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogisticParams {
+    pub learning_rate: f64,
+    pub epochs: usize,
+    pub l2: f64,
+}
+
+impl Default for LogisticParams {
+    fn default() -> Self {
+        LogisticParams {
+            learning_rate: 0.1,
+            epochs: 1000,
+            l2: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogisticRegression {
+    pub n_features: usize,
+    pub classes: Vec<u32>,
+    pub weights: Vec<Vec<f64>>,
+    pub biases: Vec<f64>,
+}
+
+fn sigmoid(z: f64) -> f64 {
+    1.0 / (1.0 + (-z).exp())
+}
+
+impl LogisticRegression {
+    pub fn fit(x: &[Vec<f64>], y: &[u32], params: LogisticParams) -> Result<LogisticRegression> {
+        let n_features = check_samples(x, y)?;
+        let classes = unique_labels(y);
+        
+        if classes.len() < 2 {
+            bail!("Logistic regression needs at least two classes");
+        }
+        
+        let positives: Vec<u32> = if classes.len() == 2 {
+            vec![classes[1]]
+        } else {
+            classes.clone()
+        };
+        
+        let mut weights = Vec::with_capacity(positives.len());
+        let mut biases = Vec::with_capacity(positives.len());
+        
+        for positive in positives {
+            let targets: Vec<f64> = y.iter().map(|&label| if label == positive { 1.0 } else { 0.0 }).collect();
+            let (w, b) = Self::fit_binary(x, &targets, n_features, &params);
+            weights.push(w);
+            biases.push(b);
+        }
+        
+        Ok(LogisticRegression { n_features, classes, weights, biases })
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        if self.classes.len() < 2 {
+            bail!("Logistic regression needs at least two classes");
+        }
+        
+        let n_models = if self.classes.len() == 2 { 1 } else { self.classes.len() };
+        check_length("weight vectors", self.weights.len(), n_models)?;
+        check_length("biases", self.biases.len(), n_models)?;
+        
+        for w in &self.weights {
+            check_length("weights", w.len(), self.n_features)?;
+        }
+        
+        Ok(())
+    }
+    
+    fn fit_binary(x: &[Vec<f64>], targets: &[f64], n_features: usize, params: &LogisticParams) -> (Vec<f64>, f64) {
+        let n_samples = x.len() as f64;
+        let mut w = vec![0.0; n_features];
+        let mut b = 0.0;
+        
+        for _ in 0..params.epochs {
+            let mut grad_w = vec![0.0; n_features];
+            let mut grad_b = 0.0;
+            
+            for (row, target) in x.iter().zip(targets) {
+                let error = sigmoid(Self::score(&w, b, row)) - target;
+                for (g, value) in grad_w.iter_mut().zip(row) {
+                    *g += error * value;
+                }
+                grad_b += error;
+            }
+            
+            for (weight, g) in w.iter_mut().zip(&grad_w) {
+                *weight -= params.learning_rate * (g / n_samples + params.l2 * *weight);
+            }
+            b -= params.learning_rate * grad_b / n_samples;
+        }
+        
+        (w, b)
+    }
+    
+    fn score(w: &[f64], b: f64, row: &[f64]) -> f64 {
+        b + w.iter().zip(row).map(|(w, x)| w * x).sum::<f64>()
+    }
+    
+    pub fn predict_proba_one(&self, features: &[f64]) -> Vec<f64> {
+        let scores: Vec<f64> = self.weights
+            .iter()
+            .zip(&self.biases)
+            .map(|(w, &b)| sigmoid(Self::score(w, b, features)))
+            .collect();
+        
+        if self.classes.len() == 2 {
+            return vec![1.0 - scores[0], scores[0]];
+        }
+        
+        let total: f64 = scores.iter().sum();
+        if total > 0.0 {
+            scores.iter().map(|score| score / total).collect()
+        } else {
+            vec![1.0 / scores.len() as f64; scores.len()]
+        }
+    }
+    
+    pub fn predict_proba(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        check_features(x, self.n_features)?;
+        Ok(x.iter().map(|row| self.predict_proba_one(row)).collect())
+    }
+    
+    pub fn predict_one(&self, features: &[f64]) -> u32 {
+        let probabilities = self.predict_proba_one(features);
+        
+        probabilities
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(i, _)| self.classes[i])
+            .unwrap_or(self.classes[0])
+    }
+    
+    pub fn predict(&self, x: &[Vec<f64>]) -> Result<Vec<u32>> {
+        check_features(x, self.n_features)?;
+        Ok(x.iter().map(|row| self.predict_one(row)).collect())
+    }
+}
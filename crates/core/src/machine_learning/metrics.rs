@@ -0,0 +1,138 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use super::data::unique_labels;
+
+fn check_lengths<T>(y_true: &[T], y_pred: &[T]) -> Result<()> {
+    if y_true.is_empty() {
+        bail!("Metrics need at least one sample");
+    }
+    
+    if y_true.len() != y_pred.len() {
+        bail!("Length of predictions ({}) must match length of targets ({})", y_pred.len(), y_true.len());
+    }
+    
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfusionMatrix {
+    pub labels: Vec<u32>,
+    pub matrix: Vec<Vec<usize>>,
+}
+
+impl ConfusionMatrix {
+    fn index(&self, label: u32) -> Option<usize> {
+        self.labels.iter().position(|&l| l == label)
+    }
+    
+    pub fn true_positives(&self, label: u32) -> usize {
+        self.index(label).map_or(0, |i| self.matrix[i][i])
+    }
+    
+    pub fn predicted(&self, label: u32) -> usize {
+        self.index(label).map_or(0, |i| self.matrix.iter().map(|row| row[i]).sum())
+    }
+    
+    pub fn actual(&self, label: u32) -> usize {
+        self.index(label).map_or(0, |i| self.matrix[i].iter().sum())
+    }
+    
+    pub fn total(&self) -> usize {
+        self.matrix.iter().flatten().sum()
+    }
+    
+    pub fn accuracy(&self) -> f64 {
+        let correct: usize = (0..self.labels.len()).map(|i| self.matrix[i][i]).sum();
+        ratio(correct, self.total())
+    }
+    
+    pub fn precision(&self, label: Option<u32>) -> f64 {
+        self.average(label, |label| ratio(self.true_positives(label), self.predicted(label)))
+    }
+    
+    pub fn recall(&self, label: Option<u32>) -> f64 {
+        self.average(label, |label| ratio(self.true_positives(label), self.actual(label)))
+    }
+    
+    pub fn f1_score(&self, label: Option<u32>) -> f64 {
+        self.average(label, |label| {
+            let precision = self.precision(Some(label));
+            let recall = self.recall(Some(label));
+            if precision + recall > 0.0 {
+                2.0 * precision * recall / (precision + recall)
+            } else {
+                0.0
+            }
+        })
+    }
+    
+    fn average<F: Fn(u32) -> f64>(&self, label: Option<u32>, metric: F) -> f64 {
+        match label {
+            Some(label) => metric(label),
+            None => self.labels.iter().map(|&label| metric(label)).sum::<f64>() / self.labels.len() as f64,
+        }
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+pub fn confusion_matrix(y_true: &[u32], y_pred: &[u32]) -> Result<ConfusionMatrix> {
+    check_lengths(y_true, y_pred)?;
+    
+    let labels = unique_labels(&[y_true, y_pred].concat());
+    let mut matrix = vec![vec![0; labels.len()]; labels.len()];
+    
+    for (actual, predicted) in y_true.iter().zip(y_pred) {
+        let i = labels.binary_search(actual).unwrap_or(0);
+        let j = labels.binary_search(predicted).unwrap_or(0);
+        matrix[i][j] += 1;
+    }
+    
+    Ok(ConfusionMatrix { labels, matrix })
+}
+
+pub fn accuracy(y_true: &[u32], y_pred: &[u32]) -> Result<f64> {
+    Ok(confusion_matrix(y_true, y_pred)?.accuracy())
+}
+
+pub fn precision(y_true: &[u32], y_pred: &[u32], label: Option<u32>) -> Result<f64> {
+    Ok(confusion_matrix(y_true, y_pred)?.precision(label))
+}
+
+pub fn recall(y_true: &[u32], y_pred: &[u32], label: Option<u32>) -> Result<f64> {
+    Ok(confusion_matrix(y_true, y_pred)?.recall(label))
+}
+
+pub fn f1_score(y_true: &[u32], y_pred: &[u32], label: Option<u32>) -> Result<f64> {
+    Ok(confusion_matrix(y_true, y_pred)?.f1_score(label))
+}
+
+pub fn mean_squared_error(y_true: &[f64], y_pred: &[f64]) -> Result<f64> {
+    check_lengths(y_true, y_pred)?;
+    Ok(y_true.iter().zip(y_pred).map(|(t, p)| (t - p).powi(2)).sum::<f64>() / y_true.len() as f64)
+}
+
+pub fn mean_absolute_error(y_true: &[f64], y_pred: &[f64]) -> Result<f64> {
+    check_lengths(y_true, y_pred)?;
+    Ok(y_true.iter().zip(y_pred).map(|(t, p)| (t - p).abs()).sum::<f64>() / y_true.len() as f64)
+}
+
+pub fn r2_score(y_true: &[f64], y_pred: &[f64]) -> Result<f64> {
+    check_lengths(y_true, y_pred)?;
+    
+    let mean = y_true.iter().sum::<f64>() / y_true.len() as f64;
+    let total: f64 = y_true.iter().map(|t| (t - mean).powi(2)).sum();
+    let residual: f64 = y_true.iter().zip(y_pred).map(|(t, p)| (t - p).powi(2)).sum();
+    
+    if total == 0.0 {
+        return Ok(if residual == 0.0 { 1.0 } else { 0.0 });
+    }
+    
+    Ok(1.0 - residual / total)
+}
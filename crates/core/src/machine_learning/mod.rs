@@ -0,0 +1,21 @@
+pub mod data;
+pub mod clustering;
+pub mod tree;
+pub mod linear;
+pub mod neighbors;
+pub mod naive_bayes;
+pub mod preprocessing;
+pub mod metrics;
+pub mod model_selection;
+pub mod model;
+
+pub use data::*;
+pub use clustering::*;
+pub use tree::*;
+pub use linear::*;
+pub use neighbors::*;
+pub use naive_bayes::*;
+pub use preprocessing::*;
+pub use metrics::*;
+pub use model_selection::*;
+pub use model::*;
@@ -0,0 +1,355 @@
+use anyhow::{anyhow, bail, Result};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use super::clustering::KMeans;
+use super::data::to_labels;
+use super::linear::{LinearRegression, LogisticParams, LogisticRegression};
+use super::naive_bayes::GaussianNaiveBayes;
+use super::neighbors::KNearestNeighbors;
+use super::preprocessing::{MinMaxScaler, StandardScaler};
+use super::tree::{DecisionTree, ForestParams, RandomForest, TreeParams};
+
+pub const MODEL_FORMAT: &str = "milost-model";
+pub const MODEL_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Model {
+    KMeans(KMeans),
+    DecisionTree(DecisionTree),
+    RandomForest(RandomForest),
+    LinearRegression(LinearRegression),
+    LogisticRegression(LogisticRegression),
+    KNearestNeighbors(KNearestNeighbors),
+    GaussianNaiveBayes(GaussianNaiveBayes),
+    StandardScaler(StandardScaler),
+    MinMaxScaler(MinMaxScaler),
+}
+
+#[derive(Serialize, Deserialize)]
+struct ModelDocument<M> {
+    format: String,
+    version: u32,
+    model: M,
+}
+
+fn labels_to_f64(labels: Vec<u32>) -> Vec<f64> {
+    labels.into_iter().map(f64::from).collect()
+}
+
+impl Model {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Model::KMeans(_) => "k_means",
+            Model::DecisionTree(_) => "decision_tree",
+            Model::RandomForest(_) => "random_forest",
+            Model::LinearRegression(_) => "linear_regression",
+            Model::LogisticRegression(_) => "logistic_regression",
+            Model::KNearestNeighbors(_) => "k_nearest_neighbors",
+            Model::GaussianNaiveBayes(_) => "gaussian_naive_bayes",
+            Model::StandardScaler(_) => "standard_scaler",
+            Model::MinMaxScaler(_) => "min_max_scaler",
+        }
+    }
+    
+    pub fn n_features(&self) -> usize {
+        match self {
+            Model::KMeans(m) => m.n_features,
+            Model::DecisionTree(m) => m.n_features,
+            Model::RandomForest(m) => m.n_features,
+            Model::LinearRegression(m) => m.n_features,
+            Model::LogisticRegression(m) => m.n_features,
+            Model::KNearestNeighbors(m) => m.n_features,
+            Model::GaussianNaiveBayes(m) => m.n_features,
+            Model::StandardScaler(m) => m.n_features,
+            Model::MinMaxScaler(m) => m.n_features,
+        }
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Model::KMeans(m) => m.validate(),
+            Model::DecisionTree(m) => m.validate(),
+            Model::RandomForest(m) => m.validate(),
+            Model::LinearRegression(m) => m.validate(),
+            Model::LogisticRegression(m) => m.validate(),
+            Model::KNearestNeighbors(m) => m.validate(),
+            Model::GaussianNaiveBayes(m) => m.validate(),
+            Model::StandardScaler(m) => m.validate(),
+            Model::MinMaxScaler(m) => m.validate(),
+        }
+    }
+    
+    pub fn classes(&self) -> Option<&[u32]> {
+        match self {
+            Model::RandomForest(m) => Some(&m.classes),
+            Model::LogisticRegression(m) => Some(&m.classes),
+            Model::KNearestNeighbors(m) => Some(&m.classes),
+            Model::GaussianNaiveBayes(m) => Some(&m.classes),
+            _ => None,
+        }
+    }
+    
+    pub fn predict(&self, x: &[Vec<f64>]) -> Result<Vec<f64>> {
+        match self {
+            Model::KMeans(m) => {
+                super::data::check_features(x, m.n_features)?;
+                Ok(m.predict(x).into_iter().map(|cluster| cluster as f64).collect())
+            },
+            Model::DecisionTree(m) => m.predict(x).map(labels_to_f64),
+            Model::RandomForest(m) => m.predict(x).map(labels_to_f64),
+            Model::LinearRegression(m) => m.predict(x),
+            Model::LogisticRegression(m) => m.predict(x).map(labels_to_f64),
+            Model::KNearestNeighbors(m) => m.predict(x).map(labels_to_f64),
+            Model::GaussianNaiveBayes(m) => m.predict(x).map(labels_to_f64),
+            Model::StandardScaler(_) | Model::MinMaxScaler(_) => {
+                bail!("{} does not support prediction", self.kind())
+            },
+        }
+    }
+    
+    pub fn predict_proba(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        match self {
+            Model::RandomForest(m) => m.predict_proba(x),
+            Model::LogisticRegression(m) => m.predict_proba(x),
+            Model::KNearestNeighbors(m) => m.predict_proba(x),
+            Model::GaussianNaiveBayes(m) => m.predict_proba(x),
+            _ => bail!("{} does not support probability estimates", self.kind()),
+        }
+    }
+    
+    pub fn transform(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        match self {
+            Model::StandardScaler(m) => m.transform(x),
+            Model::MinMaxScaler(m) => m.transform(x),
+            _ => bail!("{} does not support transform", self.kind()),
+        }
+    }
+    
+    pub fn inverse_transform(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        match self {
+            Model::StandardScaler(m) => m.inverse_transform(x),
+            Model::MinMaxScaler(m) => m.inverse_transform(x),
+            _ => bail!("{} does not support inverse transform", self.kind()),
+        }
+    }
+    
+    pub fn to_json(&self) -> Result<String> {
+        let document = ModelDocument {
+            format: MODEL_FORMAT.to_string(),
+            version: MODEL_FORMAT_VERSION,
+            model: self,
+        };
+        
+        serde_json::to_string(&document).map_err(|e| anyhow!("Failed to serialize model: {}", e))
+    }
+    
+    pub fn from_json(json: &str) -> Result<Model> {
+        let document: ModelDocument<serde_json::Value> = serde_json::from_str(json)
+            .map_err(|e| anyhow!("Invalid model document: {}", e))?;
+        
+        if document.format != MODEL_FORMAT {
+            bail!("Unknown model format '{}'", document.format);
+        }
+        
+        if document.version == 0 || document.version > MODEL_FORMAT_VERSION {
+            bail!("Unsupported model format version {} (expected at most {})", document.version, MODEL_FORMAT_VERSION);
+        }
+        
+        let model: Model = serde_json::from_value(document.model).map_err(|e| anyhow!("Invalid model document: {}", e))?;
+        model.validate().map_err(|e| anyhow!("Invalid {} model: {}", model.kind(), e))?;
+        
+        Ok(model)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KMeansParams {
+    pub n_clusters: usize,
+    pub max_iterations: usize,
+}
+
+impl Default for KMeansParams {
+    fn default() -> Self {
+        KMeansParams {
+            n_clusters: 2,
+            max_iterations: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NeighborsParams {
+    pub k: usize,
+}
+
+impl Default for NeighborsParams {
+    fn default() -> Self {
+        NeighborsParams { k: 5 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Estimator {
+    KMeans(KMeansParams),
+    DecisionTree(TreeParams),
+    RandomForest(ForestParams),
+    LinearRegression,
+    LogisticRegression(LogisticParams),
+    KNearestNeighbors(NeighborsParams),
+    GaussianNaiveBayes,
+    StandardScaler,
+    MinMaxScaler,
+}
+
+impl Estimator {
+    pub fn is_supervised(&self) -> bool {
+        !matches!(self, Estimator::KMeans(_) | Estimator::StandardScaler | Estimator::MinMaxScaler)
+    }
+    
+    pub fn is_classifier(&self) -> bool {
+        self.is_supervised() && !matches!(self, Estimator::LinearRegression)
+    }
+    
+    pub fn fit<R: Rng>(&self, x: &[Vec<f64>], y: &[f64], rng: &mut R) -> Result<Model> {
+        let labels = || to_labels(y);
+        
+        Ok(match self {
+            Estimator::KMeans(params) => Model::KMeans(KMeans::fit(x, params.n_clusters, params.max_iterations, rng)?.0),
+            Estimator::DecisionTree(params) => Model::DecisionTree(DecisionTree::fit_with_rng(x, &labels()?, *params, rng)?),
+            Estimator::RandomForest(params) => Model::RandomForest(RandomForest::fit(x, &labels()?, *params, rng)?),
+            Estimator::LinearRegression => Model::LinearRegression(LinearRegression::fit(x, y)?),
+            Estimator::LogisticRegression(params) => Model::LogisticRegression(LogisticRegression::fit(x, &labels()?, *params)?),
+            Estimator::KNearestNeighbors(params) => Model::KNearestNeighbors(KNearestNeighbors::fit(x, &labels()?, params.k)?),
+            Estimator::GaussianNaiveBayes => Model::GaussianNaiveBayes(GaussianNaiveBayes::fit(x, &labels()?)?),
+            Estimator::StandardScaler => Model::StandardScaler(StandardScaler::fit(x)?),
+            Estimator::MinMaxScaler => Model::MinMaxScaler(MinMaxScaler::fit(x)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use crate::utils::rng_from_seed;
+
+    fn samples() -> (Vec<Vec<f64>>, Vec<f64>) {
+        let x = vec![vec![0.0, 0.1], vec![0.2, 0.0], vec![0.1, 0.3], vec![1.0, 0.9], vec![0.8, 1.1], vec![0.9, 1.0]];
+        let y = vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        (x, y)
+    }
+
+    fn document(model: Value) -> String {
+        json!({ "format": MODEL_FORMAT, "version": MODEL_FORMAT_VERSION, "model": model }).to_string()
+    }
+
+    fn split(feature_index: usize) -> Value {
+        json!({
+            "node": "split",
+            "feature_index": feature_index,
+            "threshold": 0.5,
+            "left": { "node": "leaf", "prediction": 0 },
+            "right": { "node": "leaf", "prediction": 1 },
+        })
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let (x, y) = samples();
+        let estimators = [
+            Estimator::KMeans(KMeansParams::default()),
+            Estimator::DecisionTree(TreeParams::default()),
+            Estimator::RandomForest(ForestParams { n_trees: 3, ..ForestParams::default() }),
+            Estimator::LinearRegression,
+            Estimator::LogisticRegression(LogisticParams::default()),
+            Estimator::KNearestNeighbors(NeighborsParams { k: 3 }),
+            Estimator::GaussianNaiveBayes,
+            Estimator::StandardScaler,
+            Estimator::MinMaxScaler,
+        ];
+
+        for estimator in estimators {
+            let model = estimator.fit(&x, &y, &mut rng_from_seed(Some(7))).unwrap();
+            let loaded = Model::from_json(&model.to_json().unwrap()).unwrap();
+
+            assert_eq!(loaded.kind(), model.kind());
+            assert_eq!(loaded.n_features(), model.n_features());
+            match estimator {
+                Estimator::StandardScaler | Estimator::MinMaxScaler => assert!(loaded.transform(&x).is_ok()),
+                _ => assert_eq!(loaded.predict(&x).unwrap(), model.predict(&x).unwrap()),
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_feature_index() {
+        let tree = json!({ "type": "decision_tree", "n_features": 1, "root": split(99) });
+        assert!(Model::from_json(&document(tree)).is_err());
+
+        let forest = json!({
+            "type": "random_forest",
+            "n_features": 1,
+            "classes": [0, 1],
+            "trees": [{ "n_features": 1, "root": split(0) }, { "n_features": 1, "root": split(1) }],
+        });
+        assert!(Model::from_json(&document(forest)).is_err());
+
+        let tree = json!({ "type": "decision_tree", "n_features": 1, "root": split(0) });
+        let model = Model::from_json(&document(tree)).unwrap();
+        assert_eq!(model.predict(&[vec![0.2], vec![0.7]]).unwrap(), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn rejects_empty_forest() {
+        let forest = json!({ "type": "random_forest", "n_features": 1, "classes": [0, 1], "trees": [] });
+        assert!(Model::from_json(&document(forest)).is_err());
+    }
+
+    #[test]
+    fn rejects_mismatched_lengths() {
+        let models = [
+            json!({ "type": "k_means", "n_features": 2, "centroids": [[0.0, 0.0], [1.0]] }),
+            json!({ "type": "linear_regression", "n_features": 2, "intercept": 0.0, "coefficients": [1.0] }),
+            json!({
+                "type": "logistic_regression",
+                "n_features": 2,
+                "classes": [0, 1],
+                "weights": [[1.0, 2.0]],
+                "biases": [],
+            }),
+            json!({
+                "type": "logistic_regression",
+                "n_features": 2,
+                "classes": [0, 1, 2],
+                "weights": [[1.0, 2.0], [1.0, 2.0], [1.0]],
+                "biases": [0.0, 0.0, 0.0],
+            }),
+            json!({
+                "type": "k_nearest_neighbors",
+                "n_features": 1,
+                "k": 1,
+                "classes": [0],
+                "points": [[0.0], [1.0]],
+                "labels": [0],
+            }),
+            json!({
+                "type": "gaussian_naive_bayes",
+                "n_features": 1,
+                "classes": [0, 1],
+                "priors": [0.5, 0.5],
+                "means": [[0.0]],
+                "variances": [[1.0], [1.0]],
+            }),
+            json!({ "type": "standard_scaler", "n_features": 2, "mean": [0.0, 0.0], "scale": [1.0] }),
+            json!({ "type": "min_max_scaler", "n_features": 2, "min": [0.0], "max": [1.0, 1.0] }),
+        ];
+
+        for model in models {
+            assert!(Model::from_json(&document(model.clone())).is_err(), "{}", model);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use anyhow::{bail, Result};
+use rand::{seq::SliceRandom, Rng};
+use super::data::to_labels;
+use super::metrics::{accuracy, r2_score};
+use super::model::Estimator;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Split {
+    pub train: Vec<usize>,
+    pub test: Vec<usize>,
+}
+
+fn shuffled_indices<R: Rng>(n_samples: usize, rng: &mut R) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..n_samples).collect();
+    indices.shuffle(rng);
+    indices
+}
+
+pub fn select<T: Clone>(items: &[T], indices: &[usize]) -> Vec<T> {
+    indices.iter().map(|&i| items[i].clone()).collect()
+}
+
+pub fn train_test_split<R: Rng>(n_samples: usize, test_ratio: f64, rng: &mut R) -> Result<Split> {
+    if test_ratio <= 0.0 || test_ratio >= 1.0 {
+        bail!("Test ratio must be between 0 and 1");
+    }
+    
+    if n_samples < 2 {
+        bail!("Need at least two samples to split");
+    }
+    
+    let n_test = ((n_samples as f64 * test_ratio).round() as usize).clamp(1, n_samples - 1);
+    
+    let mut indices = shuffled_indices(n_samples, rng);
+    let train = indices.split_off(n_test);
+    
+    Ok(Split { train, test: indices })
+}
+
+pub fn k_fold<R: Rng>(n_samples: usize, k: usize, rng: &mut R) -> Result<Vec<Split>> {
+    if k < 2 || k > n_samples {
+        bail!("Number of folds must be between 2 and the number of samples");
+    }
+    
+    let indices = shuffled_indices(n_samples, rng);
+    let mut folds = Vec::with_capacity(k);
+    let mut start = 0;
+    
+    for fold in 0..k {
+        let size = n_samples / k + usize::from(fold < n_samples % k);
+        let test = indices[start..start + size].to_vec();
+        let train = indices[..start].iter().chain(&indices[start + size..]).copied().collect();
+        
+        folds.push(Split { train, test });
+        start += size;
+    }
+    
+    Ok(folds)
+}
+
+pub fn cross_validate<R: Rng>(estimator: &Estimator, x: &[Vec<f64>], y: &[f64], k: usize, rng: &mut R) -> Result<Vec<f64>> {
+    if !estimator.is_supervised() {
+        bail!("Cross-validation needs a supervised estimator");
+    }
+    
+    if x.len() != y.len() {
+        bail!("Length of labels ({}) must match number of samples ({})", y.len(), x.len());
+    }
+    
+    let mut scores = Vec::with_capacity(k);
+    
+    for split in k_fold(x.len(), k, rng)? {
+        let model = estimator.fit(&select(x, &split.train), &select(y, &split.train), rng)?;
+        let x_test = select(x, &split.test);
+        let y_test = select(y, &split.test);
+        let predictions = model.predict(&x_test)?;
+        
+        let score = if estimator.is_classifier() {
+            accuracy(&to_labels(&y_test)?, &to_labels(&predictions)?)?
+        } else {
+            r2_score(&y_test, &predictions)?
+        };
+        scores.push(score);
+    }
+    
+    Ok(scores)
+}
@@ -0,0 +1,108 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use super::data::{check_features, check_length, check_samples, unique_labels};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GaussianNaiveBayes {
+    pub n_features: usize,
+    pub classes: Vec<u32>,
+    pub priors: Vec<f64>,
+    pub means: Vec<Vec<f64>>,
+    pub variances: Vec<Vec<f64>>,
+}
+
+impl GaussianNaiveBayes {
+    pub fn fit(x: &[Vec<f64>], y: &[u32]) -> Result<GaussianNaiveBayes> {
+        let n_features = check_samples(x, y)?;
+        let classes = unique_labels(y);
+        
+        let max_variance = (0..n_features)
+            .map(|j| {
+                let mean = x.iter().map(|row| row[j]).sum::<f64>() / x.len() as f64;
+                x.iter().map(|row| (row[j] - mean).powi(2)).sum::<f64>() / x.len() as f64
+            })
+            .fold(0.0, f64::max);
+        let smoothing = 1e-9 * max_variance.max(1.0);
+        
+        let mut priors = Vec::with_capacity(classes.len());
+        let mut means = Vec::with_capacity(classes.len());
+        let mut variances = Vec::with_capacity(classes.len());
+        
+        for &class in &classes {
+            let rows: Vec<&Vec<f64>> = x.iter().zip(y).filter(|(_, &label)| label == class).map(|(row, _)| row).collect();
+            let count = rows.len() as f64;
+            
+            let mean: Vec<f64> = (0..n_features)
+                .map(|j| rows.iter().map(|row| row[j]).sum::<f64>() / count)
+                .collect();
+            let variance: Vec<f64> = (0..n_features)
+                .map(|j| rows.iter().map(|row| (row[j] - mean[j]).powi(2)).sum::<f64>() / count + smoothing)
+                .collect();
+            
+            priors.push(count / x.len() as f64);
+            means.push(mean);
+            variances.push(variance);
+        }
+        
+        Ok(GaussianNaiveBayes { n_features, classes, priors, means, variances })
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        if self.classes.is_empty() {
+            bail!("Naive Bayes model must contain at least one class");
+        }
+        
+        check_length("priors", self.priors.len(), self.classes.len())?;
+        check_length("mean vectors", self.means.len(), self.classes.len())?;
+        check_length("variance vectors", self.variances.len(), self.classes.len())?;
+        
+        for (mean, variance) in self.means.iter().zip(&self.variances) {
+            check_length("means", mean.len(), self.n_features)?;
+            check_length("variances", variance.len(), self.n_features)?;
+        }
+        
+        Ok(())
+    }
+    
+    fn log_likelihoods(&self, features: &[f64]) -> Vec<f64> {
+        (0..self.classes.len())
+            .map(|c| {
+                self.priors[c].ln() + features
+                    .iter()
+                    .zip(&self.means[c])
+                    .zip(&self.variances[c])
+                    .map(|((x, mean), variance)| -0.5 * (2.0 * PI * variance).ln() - (x - mean).powi(2) / (2.0 * variance))
+                    .sum::<f64>()
+            })
+            .collect()
+    }
+    
+    pub fn predict_proba_one(&self, features: &[f64]) -> Vec<f64> {
+        let log_likelihoods = self.log_likelihoods(features);
+        let max = log_likelihoods.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let exp: Vec<f64> = log_likelihoods.iter().map(|value| (value - max).exp()).collect();
+        let total: f64 = exp.iter().sum();
+        
+        exp.iter().map(|value| value / total).collect()
+    }
+    
+    pub fn predict_one(&self, features: &[f64]) -> u32 {
+        self.log_likelihoods(features)
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(i, _)| self.classes[i])
+            .unwrap_or(0)
+    }
+    
+    pub fn predict_proba(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        check_features(x, self.n_features)?;
+        Ok(x.iter().map(|row| self.predict_proba_one(row)).collect())
+    }
+    
+    pub fn predict(&self, x: &[Vec<f64>]) -> Result<Vec<u32>> {
+        check_features(x, self.n_features)?;
+        Ok(x.iter().map(|row| self.predict_one(row)).collect())
+    }
+}
@@ -0,0 +1,103 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use super::data::{check_features, check_length, check_samples, euclidean_distance, unique_labels};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KNearestNeighbors {
+    pub n_features: usize,
+    pub k: usize,
+    pub classes: Vec<u32>,
+    pub points: Vec<Vec<f64>>,
+    pub labels: Vec<u32>,
+}
+
+impl KNearestNeighbors {
+    pub fn fit(x: &[Vec<f64>], y: &[u32], k: usize) -> Result<KNearestNeighbors> {
+        let n_features = check_samples(x, y)?;
+        
+        if k == 0 {
+            bail!("k must be greater than zero");
+        }
+        
+        Ok(KNearestNeighbors {
+            n_features,
+            k: k.min(x.len()),
+            classes: unique_labels(y),
+            points: x.to_vec(),
+            labels: y.to_vec(),
+        })
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        if self.points.is_empty() {
+            bail!("Nearest neighbors model must contain at least one point");
+        }
+        
+        if self.k == 0 {
+            bail!("k must be greater than zero");
+        }
+        
+        check_length("labels", self.labels.len(), self.points.len())?;
+        for point in &self.points {
+            check_length("point values", point.len(), self.n_features)?;
+        }
+        
+        Ok(())
+    }
+    
+    fn neighbors(&self, features: &[f64]) -> Vec<(f64, u32)> {
+        let mut distances: Vec<(f64, u32)> = self.points
+            .iter()
+            .zip(&self.labels)
+            .map(|(point, &label)| (euclidean_distance(point, features), label))
+            .collect();
+        
+        distances.sort_by(|a, b| a.0.total_cmp(&b.0));
+        distances.truncate(self.k);
+        distances
+    }
+    
+    pub fn predict_proba_one(&self, features: &[f64]) -> Vec<f64> {
+        let neighbors = self.neighbors(features);
+        let mut votes = vec![0.0; self.classes.len()];
+        
+        for (_, label) in &neighbors {
+            if let Some(i) = self.classes.iter().position(|class| class == label) {
+                votes[i] += 1.0;
+            }
+        }
+        
+        votes.iter().map(|count| count / neighbors.len() as f64).collect()
+    }
+    
+    pub fn predict_one(&self, features: &[f64]) -> u32 {
+        let neighbors = self.neighbors(features);
+        let mut votes: Vec<(u32, usize, f64)> = Vec::new();
+        
+        for (distance, label) in neighbors {
+            match votes.iter_mut().find(|(class, _, _)| *class == label) {
+                Some(vote) => {
+                    vote.1 += 1;
+                    vote.2 += distance;
+                },
+                None => votes.push((label, 1, distance)),
+            }
+        }
+        
+        votes
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.2.total_cmp(&a.2)))
+            .map(|(label, _, _)| label)
+            .unwrap_or(0)
+    }
+    
+    pub fn predict_proba(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        check_features(x, self.n_features)?;
+        Ok(x.iter().map(|row| self.predict_proba_one(row)).collect())
+    }
+    
+    pub fn predict(&self, x: &[Vec<f64>]) -> Result<Vec<u32>> {
+        check_features(x, self.n_features)?;
+        Ok(x.iter().map(|row| self.predict_one(row)).collect())
+    }
+}
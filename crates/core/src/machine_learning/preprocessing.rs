@@ -0,0 +1,102 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use super::data::{check_features, check_length};
+
+fn check_training_data(x: &[Vec<f64>]) -> Result<usize> {
+    if x.is_empty() {
+        bail!("Training data must not be empty");
+    }
+    
+    let n_features = x[0].len();
+    check_features(x, n_features)?;
+    
+    Ok(n_features)
+}
+
+fn map_columns<F>(x: &[Vec<f64>], n_features: usize, f: F) -> Result<Vec<Vec<f64>>>
+where F: Fn(usize, f64) -> f64 {
+    check_features(x, n_features)?;
+    
+    Ok(x.iter()
+        .map(|row| row.iter().enumerate().map(|(j, &value)| f(j, value)).collect())
+        .collect())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandardScaler {
+    pub n_features: usize,
+    pub mean: Vec<f64>,
+    pub scale: Vec<f64>,
+}
+
+impl StandardScaler {
+    pub fn fit(x: &[Vec<f64>]) -> Result<StandardScaler> {
+        let n_features = check_training_data(x)?;
+        let n = x.len() as f64;
+        
+        let mean: Vec<f64> = (0..n_features)
+            .map(|j| x.iter().map(|row| row[j]).sum::<f64>() / n)
+            .collect();
+        let scale = (0..n_features)
+            .map(|j| {
+                let std = (x.iter().map(|row| (row[j] - mean[j]).powi(2)).sum::<f64>() / n).sqrt();
+                if std > 0.0 { std } else { 1.0 }
+            })
+            .collect();
+        
+        Ok(StandardScaler { n_features, mean, scale })
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        check_length("means", self.mean.len(), self.n_features)?;
+        check_length("scales", self.scale.len(), self.n_features)
+    }
+    
+    pub fn transform(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        map_columns(x, self.n_features, |j, value| (value - self.mean[j]) / self.scale[j])
+    }
+    
+    pub fn inverse_transform(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        map_columns(x, self.n_features, |j, value| value * self.scale[j] + self.mean[j])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinMaxScaler {
+    pub n_features: usize,
+    pub min: Vec<f64>,
+    pub max: Vec<f64>,
+}
+
+impl MinMaxScaler {
+    pub fn fit(x: &[Vec<f64>]) -> Result<MinMaxScaler> {
+        let n_features = check_training_data(x)?;
+        
+        let min = (0..n_features)
+            .map(|j| x.iter().map(|row| row[j]).fold(f64::INFINITY, f64::min))
+            .collect();
+        let max = (0..n_features)
+            .map(|j| x.iter().map(|row| row[j]).fold(f64::NEG_INFINITY, f64::max))
+            .collect();
+        
+        Ok(MinMaxScaler { n_features, min, max })
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        check_length("minimums", self.min.len(), self.n_features)?;
+        check_length("maximums", self.max.len(), self.n_features)
+    }
+    
+    fn range(&self, j: usize) -> f64 {
+        let range = self.max[j] - self.min[j];
+        if range > 0.0 { range } else { 1.0 }
+    }
+    
+    pub fn transform(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        map_columns(x, self.n_features, |j, value| (value - self.min[j]) / self.range(j))
+    }
+    
+    pub fn inverse_transform(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        map_columns(x, self.n_features, |j, value| value * self.range(j) + self.min[j])
+    }
+}
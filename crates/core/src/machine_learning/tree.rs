@@ -0,0 +1,287 @@
+use anyhow::{bail, Result};
+use rand::{seq::index, Rng};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use super::data::{check_features, check_length, check_samples, majority_vote, unique_labels};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreeParams {
+    pub max_depth: usize,
+    pub min_samples_split: usize,
+    pub max_features: Option<usize>,
+}
+
+impl Default for TreeParams {
+    fn default() -> Self {
+        TreeParams {
+            max_depth: 10,
+            min_samples_split: 2,
+            max_features: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForestParams {
+    pub n_trees: usize,
+    pub max_depth: usize,
+    pub min_samples_split: usize,
+    pub max_features: Option<usize>,
+}
+
+impl Default for ForestParams {
+    fn default() -> Self {
+        ForestParams {
+            n_trees: 10,
+            max_depth: 10,
+            min_samples_split: 2,
+            max_features: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "node", rename_all = "snake_case")]
+pub enum TreeNode {
+    Split {
+        feature_index: usize,
+        threshold: f64,
+        left: Box<TreeNode>,
+        right: Box<TreeNode>,
+    },
+    Leaf {
+        prediction: u32,
+    },
+}
+
+impl TreeNode {
+    pub fn predict(&self, features: &[f64]) -> u32 {
+        match self {
+            TreeNode::Leaf { prediction } => *prediction,
+            TreeNode::Split { feature_index, threshold, left, right } => {
+                if features[*feature_index] <= *threshold {
+                    left.predict(features)
+                } else {
+                    right.predict(features)
+                }
+            },
+        }
+    }
+    
+    pub fn validate(&self, n_features: usize) -> Result<()> {
+        let mut stack = vec![self];
+        
+        while let Some(node) = stack.pop() {
+            if let TreeNode::Split { feature_index, left, right, .. } = node {
+                if *feature_index >= n_features {
+                    bail!("Split feature index {} is out of range for {} features", feature_index, n_features);
+                }
+                stack.push(left);
+                stack.push(right);
+            }
+        }
+        
+        Ok(())
+    }
+    
+    pub fn depth(&self) -> usize {
+        match self {
+            TreeNode::Leaf { .. } => 0,
+            TreeNode::Split { left, right, .. } => 1 + left.depth().max(right.depth()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionTree {
+    pub n_features: usize,
+    pub root: TreeNode,
+}
+
+struct Split {
+    feature_index: usize,
+    threshold: f64,
+    left: Vec<usize>,
+    right: Vec<usize>,
+}
+
+impl DecisionTree {
+    pub fn fit(x: &[Vec<f64>], y: &[u32], params: TreeParams) -> Result<DecisionTree> {
+        Self::fit_with_rng(x, y, params, &mut rand::thread_rng())
+    }
+    
+    pub fn fit_with_rng<R: Rng>(x: &[Vec<f64>], y: &[u32], params: TreeParams, rng: &mut R) -> Result<DecisionTree> {
+        let n_features = check_samples(x, y)?;
+        let indices: Vec<usize> = (0..x.len()).collect();
+        
+        Ok(DecisionTree {
+            n_features,
+            root: Self::build(x, y, &indices, 0, &params, rng),
+        })
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        self.root.validate(self.n_features)
+    }
+    
+    pub fn predict_one(&self, features: &[f64]) -> u32 {
+        self.root.predict(features)
+    }
+    
+    pub fn predict(&self, x: &[Vec<f64>]) -> Result<Vec<u32>> {
+        check_features(x, self.n_features)?;
+        Ok(x.iter().map(|row| self.predict_one(row)).collect())
+    }
+    
+    fn build<R: Rng>(x: &[Vec<f64>], y: &[u32], indices: &[usize], depth: usize, params: &TreeParams, rng: &mut R) -> TreeNode {
+        let labels: Vec<u32> = indices.iter().map(|&i| y[i]).collect();
+        
+        if depth >= params.max_depth || indices.len() < params.min_samples_split || unique_labels(&labels).len() <= 1 {
+            return TreeNode::Leaf { prediction: majority_vote(&labels) };
+        }
+        
+        let Some(split) = Self::best_split(x, y, indices, params, rng) else {
+            return TreeNode::Leaf { prediction: majority_vote(&labels) };
+        };
+        
+        TreeNode::Split {
+            feature_index: split.feature_index,
+            threshold: split.threshold,
+            left: Box::new(Self::build(x, y, &split.left, depth + 1, params, rng)),
+            right: Box::new(Self::build(x, y, &split.right, depth + 1, params, rng)),
+        }
+    }
+    
+    fn best_split<R: Rng>(x: &[Vec<f64>], y: &[u32], indices: &[usize], params: &TreeParams, rng: &mut R) -> Option<Split> {
+        let n_features = x[0].len();
+        let features: Vec<usize> = match params.max_features {
+            Some(max) if max > 0 && max < n_features => index::sample(rng, n_features, max).into_vec(),
+            _ => (0..n_features).collect(),
+        };
+        
+        let mut best: Option<(f64, Split)> = None;
+        
+        for feature_index in features {
+            let mut values: Vec<f64> = indices.iter().map(|&i| x[i][feature_index]).collect();
+            values.sort_by(f64::total_cmp);
+            values.dedup();
+            
+            for pair in values.windows(2) {
+                let threshold = (pair[0] + pair[1]) / 2.0;
+                let (left, right): (Vec<usize>, Vec<usize>) = indices
+                    .iter()
+                    .partition(|&&i| x[i][feature_index] <= threshold);
+                
+                let impurity = Self::weighted_gini(y, &left, &right);
+                
+                if best.as_ref().is_none_or(|(best_impurity, _)| impurity < *best_impurity) {
+                    best = Some((impurity, Split { feature_index, threshold, left, right }));
+                }
+            }
+        }
+        
+        best.map(|(_, split)| split)
+    }
+    
+    fn weighted_gini(y: &[u32], left: &[usize], right: &[usize]) -> f64 {
+        let total = (left.len() + right.len()) as f64;
+        
+        (left.len() as f64 / total) * Self::gini(y, left)
+            + (right.len() as f64 / total) * Self::gini(y, right)
+    }
+    
+    fn gini(y: &[u32], indices: &[usize]) -> f64 {
+        if indices.is_empty() {
+            return 0.0;
+        }
+        
+        let mut counts: HashMap<u32, usize> = HashMap::new();
+        for &i in indices {
+            *counts.entry(y[i]).or_insert(0) += 1;
+        }
+        
+        let total = indices.len() as f64;
+        1.0 - counts.values().map(|&count| (count as f64 / total).powi(2)).sum::<f64>()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RandomForest {
+    pub n_features: usize,
+    pub classes: Vec<u32>,
+    pub trees: Vec<DecisionTree>,
+}
+
+impl RandomForest {
+    pub fn fit<R: Rng>(x: &[Vec<f64>], y: &[u32], params: ForestParams, rng: &mut R) -> Result<RandomForest> {
+        let n_features = check_samples(x, y)?;
+        
+        if params.n_trees == 0 {
+            bail!("Number of trees must be greater than zero");
+        }
+        
+        let tree_params = TreeParams {
+            max_depth: params.max_depth,
+            min_samples_split: params.min_samples_split,
+            max_features: Some(params.max_features.unwrap_or_else(|| (n_features as f64).sqrt().ceil() as usize)),
+        };
+        
+        let mut trees = Vec::with_capacity(params.n_trees);
+        
+        for _ in 0..params.n_trees {
+            let sample: Vec<usize> = (0..x.len()).map(|_| rng.gen_range(0..x.len())).collect();
+            let x_sample: Vec<Vec<f64>> = sample.iter().map(|&i| x[i].clone()).collect();
+            let y_sample: Vec<u32> = sample.iter().map(|&i| y[i]).collect();
+            
+            trees.push(DecisionTree::fit_with_rng(&x_sample, &y_sample, tree_params, rng)?);
+        }
+        
+        Ok(RandomForest {
+            n_features,
+            classes: unique_labels(y),
+            trees,
+        })
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        if self.trees.is_empty() {
+            bail!("Random forest must contain at least one tree");
+        }
+        
+        for tree in &self.trees {
+            check_length("tree features", tree.n_features, self.n_features)?;
+            tree.validate()?;
+        }
+        
+        Ok(())
+    }
+    
+    pub fn predict_one(&self, features: &[f64]) -> u32 {
+        let votes: Vec<u32> = self.trees.iter().map(|tree| tree.predict_one(features)).collect();
+        majority_vote(&votes)
+    }
+    
+    pub fn predict(&self, x: &[Vec<f64>]) -> Result<Vec<u32>> {
+        check_features(x, self.n_features)?;
+        Ok(x.iter().map(|row| self.predict_one(row)).collect())
+    }
+    
+    pub fn predict_proba(&self, x: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        check_features(x, self.n_features)?;
+        
+        Ok(x.iter()
+            .map(|row| {
+                let mut votes = vec![0.0; self.classes.len()];
+                for tree in &self.trees {
+                    let label = tree.predict_one(row);
+                    if let Some(i) = self.classes.iter().position(|&class| class == label) {
+                        votes[i] += 1.0;
+                    }
+                }
+                votes.iter().map(|count| count / self.trees.len() as f64).collect()
+            })
+            .collect())
+    }
+}
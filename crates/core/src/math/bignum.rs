@@ -0,0 +1,221 @@
+use anyhow::{anyhow, bail, Result};
+use num_bigint::{BigInt, Sign};
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{FromPrimitive, One, Signed, Zero};
+use rand::RngCore;
+use crate::utils::rng_from_seed;
+
+const WITNESSES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+pub fn parse_bigint(text: &str, radix: u32) -> Result<BigInt> {
+    if !(2..=36).contains(&radix) {
+        bail!("Radix must be between 2 and 36, got {}", radix);
+    }
+    BigInt::parse_bytes(text.trim().as_bytes(), radix)
+        .ok_or_else(|| anyhow!("Invalid base-{} integer: {:?}", radix, text))
+}
+
+pub fn bigint_from_f64(value: f64) -> Result<BigInt> {
+    if !value.is_finite() || value.fract() != 0.0 {
+        bail!("{} is not a finite integer", value);
+    }
+    BigInt::from_f64(value).ok_or_else(|| anyhow!("Cannot convert {} to an integer", value))
+}
+
+pub fn extended_gcd(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    let result = a.extended_gcd(b);
+    (result.gcd, result.x, result.y)
+}
+
+pub fn mod_inverse(a: &BigInt, modulus: &BigInt) -> Result<BigInt> {
+    if modulus.is_zero() {
+        bail!("Modulus must be non-zero");
+    }
+    let modulus = modulus.abs();
+    let (gcd, x, _) = extended_gcd(&a.mod_floor(&modulus), &modulus);
+    if !gcd.is_one() {
+        bail!("{} has no inverse modulo {}", a, modulus);
+    }
+    Ok(x.mod_floor(&modulus))
+}
+
+pub fn mod_pow(base: &BigInt, exponent: &BigInt, modulus: &BigInt) -> Result<BigInt> {
+    if modulus.is_zero() {
+        bail!("Modulus must be non-zero");
+    }
+    let modulus = modulus.abs();
+    if exponent.is_negative() {
+        return Ok(mod_inverse(base, &modulus)?.modpow(&-exponent, &modulus));
+    }
+    Ok(base.mod_floor(&modulus).modpow(exponent, &modulus))
+}
+
+fn random_below(limit: &BigInt, rng: &mut impl RngCore) -> BigInt {
+    let mut bytes = vec![0u8; (limit.bits() / 8 + 8) as usize];
+    rng.fill_bytes(&mut bytes);
+    BigInt::from_bytes_le(Sign::Plus, &bytes) % limit
+}
+
+pub fn is_probable_prime(n: &BigInt, rounds: usize) -> bool {
+    if *n < BigInt::from(2) {
+        return false;
+    }
+    for &p in &WITNESSES {
+        let p = BigInt::from(p);
+        if *n == p {
+            return true;
+        }
+        if (n % &p).is_zero() {
+            return false;
+        }
+    }
+
+    let one = BigInt::one();
+    let n_minus_one = n - &one;
+    let shift = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> shift;
+    let is_witness = |a: &BigInt| {
+        let mut x = a.modpow(&d, n);
+        if x == one || x == n_minus_one {
+            return false;
+        }
+        for _ in 1..shift {
+            x = x.modpow(&BigInt::from(2), n);
+            if x == n_minus_one {
+                return false;
+            }
+        }
+        true
+    };
+
+    if WITNESSES.iter().any(|&a| is_witness(&BigInt::from(a))) {
+        return false;
+    }
+    let mut rng = rng_from_seed(None);
+    let range = n - BigInt::from(3);
+    (0..rounds).all(|_| !is_witness(&(random_below(&range, &mut rng) + 2)))
+}
+
+pub fn next_probable_prime(n: &BigInt, rounds: usize) -> BigInt {
+    let two = BigInt::from(2);
+    if *n < two {
+        return two;
+    }
+    let mut candidate = n + BigInt::one();
+    if candidate.is_even() {
+        candidate += 1;
+    }
+    while !is_probable_prime(&candidate, rounds) {
+        candidate += 2;
+    }
+    candidate
+}
+
+fn product(low: u64, high: u64) -> BigInt {
+    match high - low {
+        0 => BigInt::from(low),
+        1 => BigInt::from(low) * high,
+        _ => {
+            let middle = low + (high - low) / 2;
+            product(low, middle) * product(middle + 1, high)
+        }
+    }
+}
+
+pub fn factorial_exact(n: u32) -> BigInt {
+    if n < 2 { BigInt::one() } else { product(2, n as u64) }
+}
+
+pub fn fibonacci_exact(n: u32) -> BigInt {
+    let (mut a, mut b) = (BigInt::zero(), BigInt::one());
+    for bit in (0..u32::BITS - n.leading_zeros()).rev() {
+        let c = &a * (&b * 2 - &a);
+        let d = &a * &a + &b * &b;
+        (a, b) = if (n >> bit) & 1 == 1 { (d.clone(), c + d) } else { (c, d) };
+    }
+    a
+}
+
+pub fn binomial_exact(n: u32, k: u32) -> BigInt {
+    if k > n {
+        return BigInt::zero();
+    }
+    let k = k.min(n - k);
+    (0..k).fold(BigInt::one(), |acc, i| acc * (n - i) / (i + 1))
+}
+
+pub fn parse_rational(text: &str) -> Result<BigRational> {
+    let text = text.trim();
+    if let Some((numerator, denominator)) = text.split_once('/') {
+        let numerator = parse_bigint(numerator, 10)?;
+        let denominator = parse_bigint(denominator, 10)?;
+        if denominator.is_zero() {
+            bail!("Denominator must be non-zero");
+        }
+        return Ok(BigRational::new(numerator, denominator));
+    }
+
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(index) => (&text[..index], text[index + 1..].parse::<i32>().map_err(|_| anyhow!("Invalid exponent in {:?}", text))?),
+        None => (text, 0),
+    };
+    let (negative, unsigned) = match mantissa.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if whole.len() + fraction.len() == 0 || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        bail!("Invalid rational number: {:?}", text);
+    }
+    let digits = parse_bigint(&format!("{}{}", whole, fraction), 10)?;
+    let digits = if negative { -digits } else { digits };
+    let scale = exponent - fraction.len() as i32;
+    let power = BigInt::from(10).pow(scale.unsigned_abs());
+    Ok(if scale >= 0 {
+        BigRational::from_integer(digits * power)
+    } else {
+        BigRational::new(digits, power)
+    })
+}
+
+pub fn rational_from_f64(value: f64) -> Result<BigRational> {
+    BigRational::from_float(value).ok_or_else(|| anyhow!("{} cannot be represented as a rational", value))
+}
+
+pub fn limit_denominator(value: &BigRational, max_denominator: &BigInt) -> Result<BigRational> {
+    if *max_denominator < BigInt::one() {
+        bail!("Maximum denominator must be at least 1");
+    }
+    if value.denom() <= max_denominator {
+        return Ok(value.clone());
+    }
+
+    let (mut p0, mut q0, mut p1, mut q1) = (BigInt::zero(), BigInt::one(), BigInt::one(), BigInt::zero());
+    let (mut n, mut d) = (value.numer().clone(), value.denom().clone());
+    loop {
+        let a = n.div_floor(&d);
+        let q2 = &q0 + &a * &q1;
+        if q2 > *max_denominator {
+            break;
+        }
+        (p0, q0, p1, q1) = (p1.clone(), q1, p0 + &a * &p1, q2);
+        (n, d) = (d.clone(), n - &a * &d);
+    }
+
+    let k = (max_denominator - &q0).div_floor(&q1);
+    let lower = BigRational::new(&p0 + &k * &p1, &q0 + &k * &q1);
+    let upper = BigRational::new(p1, q1);
+    Ok(if (&upper - value).abs() <= (&lower - value).abs() { upper } else { lower })
+}
+
+pub fn rational_to_decimal(value: &BigRational, places: u32) -> String {
+    let scale = BigInt::from(10).pow(places);
+    let scaled = (value.abs() * BigRational::from_integer(scale.clone())).round().to_integer();
+    let (whole, fraction) = scaled.div_rem(&scale);
+    let sign = if value.is_negative() && !scaled.is_zero() { "-" } else { "" };
+    if places == 0 {
+        return format!("{}{}", sign, whole);
+    }
+    format!("{}{}.{:0>width$}", sign, whole, fraction, width = places as usize)
+}
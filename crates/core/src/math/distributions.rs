@@ -0,0 +1,635 @@
+use anyhow::{bail, Result};
+use rand::{rngs::StdRng, Rng};
+use std::f64::consts::{LN_2, PI, SQRT_2};
+use crate::utils::rng_from_seed;
+
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+const TINY: f64 = 1e-300;
+const MAX_ITERATIONS: usize = 500;
+
+pub fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        return (PI / (PI * x).sin().abs()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + LANCZOS_G + 0.5;
+    let series = LANCZOS[1..].iter().enumerate().fold(LANCZOS[0], |acc, (i, c)| acc + c / (x + i as f64 + 1.0));
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+pub fn gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        return PI / ((PI * x).sin() * gamma(1.0 - x));
+    }
+    ln_gamma(x).exp()
+}
+
+pub fn ln_beta(a: f64, b: f64) -> f64 {
+    ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b)
+}
+
+fn gamma_series(a: f64, x: f64) -> f64 {
+    let (mut term, mut sum, mut denominator) = (1.0 / a, 1.0 / a, a);
+    for _ in 0..MAX_ITERATIONS {
+        denominator += 1.0;
+        term *= x / denominator;
+        sum += term;
+        if term.abs() < sum.abs() * f64::EPSILON {
+            break;
+        }
+    }
+    sum * (-x + a * x.ln() - ln_gamma(a)).exp()
+}
+
+fn gamma_continued_fraction(a: f64, x: f64) -> f64 {
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..MAX_ITERATIONS {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < f64::EPSILON {
+            break;
+        }
+    }
+    (-x + a * x.ln() - ln_gamma(a)).exp() * h
+}
+
+pub fn gamma_p(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        0.0
+    } else if x < a + 1.0 {
+        gamma_series(a, x)
+    } else {
+        1.0 - gamma_continued_fraction(a, x)
+    }
+}
+
+pub fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        1.0
+    } else if x < a + 1.0 {
+        1.0 - gamma_series(a, x)
+    } else {
+        gamma_continued_fraction(a, x)
+    }
+}
+
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let clamp_tiny = |value: f64| if value.abs() < TINY { TINY } else { value };
+    let (qab, qap, qam) = (a + b, a + 1.0, a - 1.0);
+    let mut c = 1.0;
+    let mut d = 1.0 / clamp_tiny(1.0 - qab * x / qap);
+    let mut h = d;
+    for m in 1..MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 / clamp_tiny(1.0 + aa * d);
+        c = clamp_tiny(1.0 + aa / c);
+        h *= d * c;
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 / clamp_tiny(1.0 + aa * d);
+        c = clamp_tiny(1.0 + aa / c);
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < f64::EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+pub fn beta_inc(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front = (a * x.ln() + b * (1.0 - x).ln() - ln_beta(a, b)).exp();
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+pub fn erf(x: f64) -> f64 {
+    gamma_p(0.5, x * x).copysign(x)
+}
+
+pub fn erfc(x: f64) -> f64 {
+    if x >= 0.0 {
+        gamma_q(0.5, x * x)
+    } else {
+        1.0 + gamma_p(0.5, x * x)
+    }
+}
+
+fn check_probability(p: f64) -> Result<()> {
+    if !(0.0..=1.0).contains(&p) {
+        bail!("Probability must be between 0 and 1, got {}", p);
+    }
+    Ok(())
+}
+
+fn invert_continuous(cdf: impl Fn(f64) -> f64, p: f64, mut lower: f64, mut upper: f64, bounded_below: bool) -> f64 {
+    while cdf(upper) < p {
+        upper = upper * 2.0 + 1.0;
+    }
+    while !bounded_below && cdf(lower) > p {
+        lower = lower * 2.0 - 1.0;
+    }
+    for _ in 0..MAX_ITERATIONS {
+        let middle = 0.5 * (lower + upper);
+        if middle <= lower || middle >= upper {
+            break;
+        }
+        if cdf(middle) < p {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+    0.5 * (lower + upper)
+}
+
+fn invert_discrete(cdf: impl Fn(u64) -> f64, p: f64, start: u64, max: u64) -> u64 {
+    let mut k = start.min(max);
+    while k > 0 && cdf(k - 1) >= p {
+        k -= 1;
+    }
+    while k < max && cdf(k) < p {
+        k += 1;
+    }
+    k
+}
+
+fn standard_normal_sample(rng: &mut StdRng) -> f64 {
+    let u1: f64 = 1.0 - rng.gen::<f64>();
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+fn gamma_sample(shape: f64, rng: &mut StdRng) -> f64 {
+    if shape < 1.0 {
+        let u: f64 = 1.0 - rng.gen::<f64>();
+        return gamma_sample(shape + 1.0, rng) * u.powf(1.0 / shape);
+    }
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+    loop {
+        let x = standard_normal_sample(rng);
+        let v = (1.0 + c * x).powi(3);
+        if v <= 0.0 {
+            continue;
+        }
+        let u: f64 = 1.0 - rng.gen::<f64>();
+        if u.ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+            return d * v;
+        }
+    }
+}
+
+pub trait Distribution {
+    fn name(&self) -> &'static str;
+    fn pdf(&self, x: f64) -> f64;
+    fn cdf(&self, x: f64) -> f64;
+    fn quantile(&self, p: f64) -> Result<f64>;
+    fn mean(&self) -> f64;
+    fn variance(&self) -> f64;
+    fn sample(&self, rng: &mut StdRng) -> f64;
+
+    fn is_discrete(&self) -> bool {
+        false
+    }
+
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        1.0 - self.cdf(x)
+    }
+
+    fn sample_n(&self, count: usize, seed: Option<u64>) -> Vec<f64> {
+        let mut rng = rng_from_seed(seed);
+        (0..count).map(|_| self.sample(&mut rng)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    pub fn new(mean: f64, std_dev: f64) -> Result<Normal> {
+        if !(mean.is_finite() && std_dev > 0.0 && std_dev.is_finite()) {
+            bail!("Normal distribution needs a finite mean and positive standard deviation");
+        }
+        Ok(Normal { mean, std_dev })
+    }
+
+    pub fn standard() -> Normal {
+        Normal { mean: 0.0, std_dev: 1.0 }
+    }
+
+    fn standard_quantile(p: f64) -> f64 {
+        const A: [f64; 6] = [-39.696_830_286_653_76, 220.946_098_424_520_5, -275.928_510_446_968_7,
+                             138.357_751_867_269, -30.664_798_066_147_16, 2.506_628_277_459_239];
+        const B: [f64; 5] = [-54.476_098_798_224_06, 161.585_836_858_040_9, -155.698_979_859_886_6,
+                             66.801_311_887_719_72, -13.280_681_552_885_72];
+        const C: [f64; 6] = [-0.007_784_894_002_430_293, -0.322_396_458_041_136_5, -2.400_758_277_161_838,
+                             -2.549_732_539_343_734, 4.374_664_141_464_968, 2.938_163_982_698_783];
+        const D: [f64; 4] = [0.007_784_695_709_041_462, 0.322_467_129_070_039_8, 2.445_134_137_142_996,
+                             3.754_408_661_907_416];
+        const LOW: f64 = 0.024_25;
+
+        let tail = |q: f64| {
+            let q = (-2.0 * q.ln()).sqrt();
+            (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+                / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+        };
+        let x = if p < LOW {
+            tail(p)
+        } else if p > 1.0 - LOW {
+            -tail(1.0 - p)
+        } else {
+            let q = p - 0.5;
+            let r = q * q;
+            (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+                / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+        };
+
+        let error = 0.5 * erfc(-x / SQRT_2) - p;
+        let u = error * (2.0 * PI).sqrt() * (0.5 * x * x).exp();
+        x - u / (1.0 + 0.5 * x * u)
+    }
+}
+
+impl Distribution for Normal {
+    fn name(&self) -> &'static str {
+        "normal"
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean) / self.std_dev;
+        (-0.5 * z * z).exp() / (self.std_dev * (2.0 * PI).sqrt())
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        0.5 * erfc(-(x - self.mean) / (self.std_dev * SQRT_2))
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        0.5 * erfc((x - self.mean) / (self.std_dev * SQRT_2))
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64> {
+        check_probability(p)?;
+        Ok(match p {
+            0.0 => f64::NEG_INFINITY,
+            1.0 => f64::INFINITY,
+            _ => self.mean + self.std_dev * Normal::standard_quantile(p),
+        })
+    }
+
+    fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    fn variance(&self) -> f64 {
+        self.std_dev * self.std_dev
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> f64 {
+        self.mean + self.std_dev * standard_normal_sample(rng)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT {
+    degrees_of_freedom: f64,
+}
+
+impl StudentT {
+    pub fn new(degrees_of_freedom: f64) -> Result<StudentT> {
+        if degrees_of_freedom.is_nan() || degrees_of_freedom <= 0.0 {
+            bail!("Student's t distribution needs positive degrees of freedom");
+        }
+        Ok(StudentT { degrees_of_freedom })
+    }
+
+    pub fn degrees_of_freedom(&self) -> f64 {
+        self.degrees_of_freedom
+    }
+}
+
+impl Distribution for StudentT {
+    fn name(&self) -> &'static str {
+        "t"
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        let v = self.degrees_of_freedom;
+        let log_norm = ln_gamma((v + 1.0) / 2.0) - ln_gamma(v / 2.0) - 0.5 * (v * PI).ln();
+        (log_norm - (v + 1.0) / 2.0 * (x * x / v).ln_1p()).exp()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        let v = self.degrees_of_freedom;
+        let tail = 0.5 * beta_inc(v / 2.0, 0.5, v / (v + x * x));
+        if x > 0.0 { 1.0 - tail } else { tail }
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        self.cdf(-x)
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64> {
+        check_probability(p)?;
+        Ok(match p {
+            0.0 => f64::NEG_INFINITY,
+            1.0 => f64::INFINITY,
+            0.5 => 0.0,
+            _ => invert_continuous(|x| self.cdf(x), p, -1.0, 1.0, false),
+        })
+    }
+
+    fn mean(&self) -> f64 {
+        if self.degrees_of_freedom > 1.0 { 0.0 } else { f64::NAN }
+    }
+
+    fn variance(&self) -> f64 {
+        let v = self.degrees_of_freedom;
+        if v > 2.0 {
+            v / (v - 2.0)
+        } else if v > 1.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> f64 {
+        let chi_squared = 2.0 * gamma_sample(self.degrees_of_freedom / 2.0, rng);
+        standard_normal_sample(rng) / (chi_squared / self.degrees_of_freedom).sqrt()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquared {
+    degrees_of_freedom: f64,
+}
+
+impl ChiSquared {
+    pub fn new(degrees_of_freedom: f64) -> Result<ChiSquared> {
+        if degrees_of_freedom.is_nan() || degrees_of_freedom <= 0.0 {
+            bail!("Chi-squared distribution needs positive degrees of freedom");
+        }
+        Ok(ChiSquared { degrees_of_freedom })
+    }
+
+    pub fn degrees_of_freedom(&self) -> f64 {
+        self.degrees_of_freedom
+    }
+}
+
+impl Distribution for ChiSquared {
+    fn name(&self) -> &'static str {
+        "chi-squared"
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        let half = self.degrees_of_freedom / 2.0;
+        if x < 0.0 {
+            return 0.0;
+        }
+        if x == 0.0 {
+            return if half < 1.0 {
+                f64::INFINITY
+            } else if half == 1.0 {
+                0.5
+            } else {
+                0.0
+            };
+        }
+        ((half - 1.0) * x.ln() - x / 2.0 - half * LN_2 - ln_gamma(half)).exp()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        gamma_p(self.degrees_of_freedom / 2.0, x.max(0.0) / 2.0)
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        gamma_q(self.degrees_of_freedom / 2.0, x.max(0.0) / 2.0)
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64> {
+        check_probability(p)?;
+        Ok(match p {
+            0.0 => 0.0,
+            1.0 => f64::INFINITY,
+            _ => invert_continuous(|x| self.cdf(x), p, 0.0, self.degrees_of_freedom.max(1.0), true),
+        })
+    }
+
+    fn mean(&self) -> f64 {
+        self.degrees_of_freedom
+    }
+
+    fn variance(&self) -> f64 {
+        2.0 * self.degrees_of_freedom
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> f64 {
+        2.0 * gamma_sample(self.degrees_of_freedom / 2.0, rng)
+    }
+}
+
+fn discrete_point(x: f64) -> Option<u64> {
+    (x >= 0.0 && x.fract() == 0.0 && x <= u64::MAX as f64).then_some(x as u64)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+    trials: u64,
+    probability: f64,
+}
+
+impl Binomial {
+    pub fn new(trials: u64, probability: f64) -> Result<Binomial> {
+        check_probability(probability)?;
+        Ok(Binomial { trials, probability })
+    }
+
+    pub fn pmf(&self, k: u64) -> f64 {
+        let (n, p) = (self.trials, self.probability);
+        if k > n {
+            return 0.0;
+        }
+        match p {
+            0.0 => return if k == 0 { 1.0 } else { 0.0 },
+            1.0 => return if k == n { 1.0 } else { 0.0 },
+            _ => {}
+        }
+        let (n, k) = (n as f64, k as f64);
+        (ln_gamma(n + 1.0) - ln_gamma(k + 1.0) - ln_gamma(n - k + 1.0) + k * p.ln() + (n - k) * (-p).ln_1p()).exp()
+    }
+
+    pub fn cdf_at(&self, k: u64) -> f64 {
+        if k >= self.trials {
+            return 1.0;
+        }
+        beta_inc((self.trials - k) as f64, k as f64 + 1.0, 1.0 - self.probability)
+    }
+}
+
+impl Distribution for Binomial {
+    fn name(&self) -> &'static str {
+        "binomial"
+    }
+
+    fn is_discrete(&self) -> bool {
+        true
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        discrete_point(x).map_or(0.0, |k| self.pmf(k))
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0.0 { 0.0 } else { self.cdf_at(x.floor() as u64) }
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64> {
+        check_probability(p)?;
+        Ok(invert_discrete(|k| self.cdf_at(k), p, self.mean() as u64, self.trials) as f64)
+    }
+
+    fn mean(&self) -> f64 {
+        self.trials as f64 * self.probability
+    }
+
+    fn variance(&self) -> f64 {
+        self.mean() * (1.0 - self.probability)
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> f64 {
+        if self.trials <= 32 {
+            return (0..self.trials).filter(|_| rng.gen::<f64>() < self.probability).count() as f64;
+        }
+        let p = rng.gen::<f64>();
+        invert_discrete(|k| self.cdf_at(k), p, self.mean() as u64, self.trials) as f64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    pub fn new(lambda: f64) -> Result<Poisson> {
+        if !(lambda > 0.0 && lambda.is_finite()) {
+            bail!("Poisson distribution needs a positive finite rate");
+        }
+        Ok(Poisson { lambda })
+    }
+
+    pub fn pmf(&self, k: u64) -> f64 {
+        let k = k as f64;
+        (k * self.lambda.ln() - self.lambda - ln_gamma(k + 1.0)).exp()
+    }
+
+    pub fn cdf_at(&self, k: u64) -> f64 {
+        gamma_q(k as f64 + 1.0, self.lambda)
+    }
+}
+
+impl Distribution for Poisson {
+    fn name(&self) -> &'static str {
+        "poisson"
+    }
+
+    fn is_discrete(&self) -> bool {
+        true
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        discrete_point(x).map_or(0.0, |k| self.pmf(k))
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0.0 { 0.0 } else { self.cdf_at(x.floor() as u64) }
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64> {
+        check_probability(p)?;
+        if p == 1.0 {
+            return Ok(f64::INFINITY);
+        }
+        Ok(invert_discrete(|k| self.cdf_at(k), p, self.lambda as u64, u64::MAX) as f64)
+    }
+
+    fn mean(&self) -> f64 {
+        self.lambda
+    }
+
+    fn variance(&self) -> f64 {
+        self.lambda
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> f64 {
+        let p = rng.gen::<f64>();
+        invert_discrete(|k| self.cdf_at(k), p, self.lambda as u64, u64::MAX) as f64
+    }
+}
+
+pub fn distribution(name: &str, parameters: &[f64]) -> Result<Box<dyn Distribution>> {
+    let parameter = |index: usize, default: Option<f64>| {
+        parameters.get(index).copied().or(default)
+            .ok_or_else(|| anyhow::anyhow!("Distribution '{}' needs at least {} parameter(s)", name, index + 1))
+    };
+    let count = |value: f64| {
+        if value < 0.0 || value.fract() != 0.0 {
+            bail!("Number of trials must be a non-negative integer, got {}", value);
+        }
+        Ok(value as u64)
+    };
+
+    Ok(match name.to_ascii_lowercase().as_str() {
+        "normal" | "gaussian" => Box::new(Normal::new(parameter(0, Some(0.0))?, parameter(1, Some(1.0))?)?),
+        "t" | "student" | "student-t" | "studentt" => Box::new(StudentT::new(parameter(0, None)?)?),
+        "chi-squared" | "chisquared" | "chi2" | "chi-square" => Box::new(ChiSquared::new(parameter(0, None)?)?),
+        "binomial" => Box::new(Binomial::new(count(parameter(0, None)?)?, parameter(1, None)?)?),
+        "poisson" => Box::new(Poisson::new(parameter(0, None)?)?),
+        _ => bail!("Unsupported distribution: {}", name),
+    })
+}
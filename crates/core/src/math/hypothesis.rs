@@ -0,0 +1,209 @@
+use anyhow::{bail, Result};
+use std::str::FromStr;
+use super::distributions::{ChiSquared, Distribution, Normal, StudentT};
+use super::statistics::{mean, rank, variance, VarianceMode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alternative {
+    #[default]
+    TwoSided,
+    Less,
+    Greater,
+}
+
+impl Alternative {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Alternative::TwoSided => "two-sided",
+            Alternative::Less => "less",
+            Alternative::Greater => "greater",
+        }
+    }
+
+    fn p_value(&self, distribution: &dyn Distribution, statistic: f64) -> f64 {
+        match self {
+            Alternative::Less => distribution.cdf(statistic),
+            Alternative::Greater => distribution.survival(statistic),
+            Alternative::TwoSided => {
+                let tail = distribution.cdf(statistic).min(distribution.survival(statistic));
+                (2.0 * tail).min(1.0)
+            }
+        }
+    }
+}
+
+impl FromStr for Alternative {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "two-sided" | "two_sided" | "two" => Ok(Alternative::TwoSided),
+            "less" => Ok(Alternative::Less),
+            "greater" => Ok(Alternative::Greater),
+            _ => bail!("Unsupported alternative hypothesis: {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TestResult {
+    pub statistic: f64,
+    pub p_value: f64,
+    pub degrees_of_freedom: Option<f64>,
+}
+
+impl TestResult {
+    pub fn is_significant(&self, alpha: f64) -> bool {
+        self.p_value < alpha
+    }
+}
+
+fn sample_moments(data: &[f64], label: &str) -> Result<(f64, f64, f64)> {
+    if data.len() < 2 {
+        bail!("{} needs at least two observations", label);
+    }
+    let mean = mean(data).unwrap_or(0.0);
+    let variance = variance(data, VarianceMode::Sample).unwrap_or(0.0);
+    Ok((data.len() as f64, mean, variance))
+}
+
+fn t_result(statistic: f64, degrees_of_freedom: f64, alternative: Alternative) -> Result<TestResult> {
+    if !statistic.is_finite() {
+        bail!("Test statistic is undefined because the samples have zero variance");
+    }
+    let distribution = StudentT::new(degrees_of_freedom)?;
+    Ok(TestResult {
+        statistic,
+        p_value: alternative.p_value(&distribution, statistic),
+        degrees_of_freedom: Some(degrees_of_freedom),
+    })
+}
+
+pub fn one_sample_t_test(data: &[f64], population_mean: f64, alternative: Alternative) -> Result<TestResult> {
+    let (n, mean, variance) = sample_moments(data, "Sample")?;
+    t_result((mean - population_mean) / (variance / n).sqrt(), n - 1.0, alternative)
+}
+
+pub fn paired_t_test(a: &[f64], b: &[f64], alternative: Alternative) -> Result<TestResult> {
+    if a.len() != b.len() {
+        bail!("Paired samples must have the same length, got {} and {}", a.len(), b.len());
+    }
+    let differences: Vec<f64> = a.iter().zip(b).map(|(x, y)| x - y).collect();
+    one_sample_t_test(&differences, 0.0, alternative)
+}
+
+pub fn two_sample_t_test(a: &[f64], b: &[f64], equal_variance: bool, alternative: Alternative) -> Result<TestResult> {
+    let (n1, mean1, var1) = sample_moments(a, "First sample")?;
+    let (n2, mean2, var2) = sample_moments(b, "Second sample")?;
+
+    let (standard_error, degrees_of_freedom) = if equal_variance {
+        let pooled = ((n1 - 1.0) * var1 + (n2 - 1.0) * var2) / (n1 + n2 - 2.0);
+        ((pooled * (1.0 / n1 + 1.0 / n2)).sqrt(), n1 + n2 - 2.0)
+    } else {
+        let (v1, v2) = (var1 / n1, var2 / n2);
+        let df = (v1 + v2).powi(2) / (v1 * v1 / (n1 - 1.0) + v2 * v2 / (n2 - 1.0));
+        ((v1 + v2).sqrt(), df)
+    };
+
+    t_result((mean1 - mean2) / standard_error, degrees_of_freedom, alternative)
+}
+
+fn chi_square_result(statistic: f64, degrees_of_freedom: f64) -> Result<TestResult> {
+    let distribution = ChiSquared::new(degrees_of_freedom)?;
+    Ok(TestResult {
+        statistic,
+        p_value: distribution.survival(statistic),
+        degrees_of_freedom: Some(degrees_of_freedom),
+    })
+}
+
+pub fn chi_square_goodness_of_fit(observed: &[f64], expected: Option<&[f64]>) -> Result<TestResult> {
+    if observed.len() < 2 {
+        bail!("Goodness-of-fit test needs at least two categories");
+    }
+    if observed.iter().any(|&count| count < 0.0) {
+        bail!("Observed counts must not be negative");
+    }
+
+    let total: f64 = observed.iter().sum();
+    let expected: Vec<f64> = match expected {
+        Some(expected) => {
+            if expected.len() != observed.len() {
+                bail!("Expected frequencies must have {} entries, got {}", observed.len(), expected.len());
+            }
+            let expected_total: f64 = expected.iter().sum();
+            expected.iter().map(|&e| e * total / expected_total).collect()
+        }
+        None => vec![total / observed.len() as f64; observed.len()],
+    };
+    if expected.iter().any(|&e| e.is_nan() || e <= 0.0) {
+        bail!("Expected frequencies must be positive");
+    }
+
+    let statistic = observed.iter().zip(&expected).map(|(o, e)| (o - e).powi(2) / e).sum();
+    chi_square_result(statistic, (observed.len() - 1) as f64)
+}
+
+pub fn chi_square_independence(table: &[Vec<f64>]) -> Result<TestResult> {
+    let cols = table.first().map_or(0, Vec::len);
+    if table.len() < 2 || cols < 2 {
+        bail!("Contingency table must be at least 2x2");
+    }
+    if table.iter().any(|row| row.len() != cols) {
+        bail!("All contingency table rows must have {} columns", cols);
+    }
+
+    let row_totals: Vec<f64> = table.iter().map(|row| row.iter().sum()).collect();
+    let col_totals: Vec<f64> = (0..cols).map(|j| table.iter().map(|row| row[j]).sum()).collect();
+    let total: f64 = row_totals.iter().sum();
+    if row_totals.iter().chain(&col_totals).any(|&t| t <= 0.0) {
+        bail!("Every row and column of the contingency table needs a positive total");
+    }
+
+    let mut statistic = 0.0;
+    for (row, row_total) in table.iter().zip(&row_totals) {
+        for (observed, col_total) in row.iter().zip(&col_totals) {
+            let expected = row_total * col_total / total;
+            statistic += (observed - expected).powi(2) / expected;
+        }
+    }
+
+    chi_square_result(statistic, ((table.len() - 1) * (cols - 1)) as f64)
+}
+
+pub fn mann_whitney_u(a: &[f64], b: &[f64], alternative: Alternative) -> Result<TestResult> {
+    if a.is_empty() || b.is_empty() {
+        bail!("Mann-Whitney U test needs two non-empty samples");
+    }
+
+    let (n1, n2) = (a.len() as f64, b.len() as f64);
+    let n = n1 + n2;
+    let combined: Vec<f64> = a.iter().chain(b).copied().collect();
+    let ranks = rank(&combined);
+    let rank_sum: f64 = ranks[..a.len()].iter().sum();
+    let u1 = rank_sum - n1 * (n1 + 1.0) / 2.0;
+    let u2 = n1 * n2 - u1;
+
+    let mut sorted_ranks = ranks.clone();
+    sorted_ranks.sort_by(f64::total_cmp);
+    let tie_term: f64 = sorted_ranks
+        .chunk_by(|x, y| x == y)
+        .map(|group| (group.len() as f64).powi(3) - group.len() as f64)
+        .sum();
+
+    let mean_u = n1 * n2 / 2.0;
+    let sigma = (n1 * n2 / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)))).sqrt();
+    if sigma.is_nan() || sigma <= 0.0 {
+        bail!("Mann-Whitney U test is undefined when all observations are tied");
+    }
+
+    let normal = Normal::standard();
+    let z = |u: f64| (u - mean_u - 0.5) / sigma;
+    let p_value = match alternative {
+        Alternative::Greater => normal.survival(z(u1)),
+        Alternative::Less => normal.survival(z(u2)),
+        Alternative::TwoSided => (2.0 * normal.survival(z(u1.max(u2)))).min(1.0),
+    };
+
+    Ok(TestResult { statistic: u1, p_value, degrees_of_freedom: None })
+}
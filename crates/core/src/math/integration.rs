@@ -0,0 +1,173 @@
+use anyhow::{bail, Result};
+
+const KRONROD_NODES: [f64; 8] = [
+    0.991_455_371_120_812_6,
+    0.949_107_912_342_758_5,
+    0.864_864_423_359_769_1,
+    0.741_531_185_599_394_4,
+    0.586_087_235_467_691_1,
+    0.405_845_151_377_397_2,
+    0.207_784_955_007_898_5,
+    0.0,
+];
+const KRONROD_WEIGHTS: [f64; 8] = [
+    0.022_935_322_010_529_22,
+    0.063_092_092_629_978_55,
+    0.104_790_010_322_250_2,
+    0.140_653_259_715_525_9,
+    0.169_004_726_639_267_9,
+    0.190_350_578_064_785_4,
+    0.204_432_940_075_298_9,
+    0.209_482_141_084_727_8,
+];
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.129_484_966_168_869_7,
+    0.279_705_391_489_276_7,
+    0.381_830_050_505_118_9,
+    0.417_959_183_673_469_4,
+];
+const POINTS_PER_INTERVAL: usize = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntegrationOptions {
+    pub abs_tolerance: f64,
+    pub rel_tolerance: f64,
+    pub max_intervals: usize,
+}
+
+impl Default for IntegrationOptions {
+    fn default() -> Self {
+        IntegrationOptions {
+            abs_tolerance: 1e-10,
+            rel_tolerance: 1e-10,
+            max_intervals: 500,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Integral {
+    pub value: f64,
+    pub error_estimate: f64,
+    pub evaluations: usize,
+    pub intervals: usize,
+    pub converged: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Interval {
+    a: f64,
+    b: f64,
+    value: f64,
+    error: f64,
+}
+
+fn kronrod_points(a: f64, b: f64) -> impl Iterator<Item = f64> {
+    let (center, half) = (0.5 * (a + b), 0.5 * (b - a));
+    KRONROD_NODES[..7]
+        .iter()
+        .flat_map(move |&node| [center - half * node, center + half * node])
+        .chain(std::iter::once(center))
+}
+
+fn kronrod_rule(a: f64, b: f64, values: &[f64]) -> Interval {
+    let half = 0.5 * (b - a);
+    let center = values[POINTS_PER_INTERVAL - 1];
+    let mut kronrod = center * KRONROD_WEIGHTS[7];
+    let mut gauss = center * GAUSS_WEIGHTS[3];
+    for (i, pair) in values[..POINTS_PER_INTERVAL - 1].chunks(2).enumerate() {
+        let sum = pair[0] + pair[1];
+        kronrod += KRONROD_WEIGHTS[i] * sum;
+        if i % 2 == 1 {
+            gauss += GAUSS_WEIGHTS[i / 2] * sum;
+        }
+    }
+    Interval { a, b, value: kronrod * half, error: ((kronrod - gauss) * half).abs() }
+}
+
+fn evaluate_intervals<F>(f: &mut F, bounds: &[(f64, f64)], evaluations: &mut usize) -> Result<Vec<Interval>>
+where F: FnMut(&[f64]) -> Vec<f64> {
+    let points: Vec<f64> = bounds.iter().flat_map(|&(a, b)| kronrod_points(a, b)).collect();
+    let values = f(&points);
+    *evaluations += points.len();
+    if values.len() != points.len() {
+        bail!("Integrand returned {} values for {} points", values.len(), points.len());
+    }
+    if values.iter().any(|value| !value.is_finite()) {
+        bail!("Integrand returned a non-finite value");
+    }
+    Ok(bounds
+        .iter()
+        .zip(values.chunks(POINTS_PER_INTERVAL))
+        .map(|(&(a, b), values)| kronrod_rule(a, b, values))
+        .collect())
+}
+
+fn integrate_finite<F>(mut f: F, a: f64, b: f64, options: IntegrationOptions) -> Result<Integral>
+where F: FnMut(&[f64]) -> Vec<f64> {
+    let mut evaluations = 0;
+    let mut intervals = evaluate_intervals(&mut f, &[(a, b)], &mut evaluations)?;
+
+    loop {
+        let value: f64 = intervals.iter().map(|interval| interval.value).sum();
+        let error: f64 = intervals.iter().map(|interval| interval.error).sum();
+        let tolerance = options.abs_tolerance.max(options.rel_tolerance * value.abs());
+        let converged = error <= tolerance;
+        if converged || intervals.len() >= options.max_intervals.max(1) {
+            return Ok(Integral { value, error_estimate: error, evaluations, intervals: intervals.len(), converged });
+        }
+
+        let worst = intervals
+            .iter()
+            .enumerate()
+            .max_by(|x, y| x.1.error.total_cmp(&y.1.error))
+            .map_or(0, |(index, _)| index);
+        let Interval { a, b, .. } = intervals.swap_remove(worst);
+        let middle = 0.5 * (a + b);
+        if middle <= a || middle >= b {
+            return Ok(Integral { value, error_estimate: error, evaluations, intervals: intervals.len() + 1, converged: false });
+        }
+        intervals.extend(evaluate_intervals(&mut f, &[(a, middle), (middle, b)], &mut evaluations)?);
+    }
+}
+
+pub fn integrate_batched<F>(mut f: F, a: f64, b: f64, options: IntegrationOptions) -> Result<Integral>
+where F: FnMut(&[f64]) -> Vec<f64> {
+    if a.is_nan() || b.is_nan() {
+        bail!("Integration bounds must not be NaN");
+    }
+    if a == b {
+        return Ok(Integral { value: 0.0, error_estimate: 0.0, evaluations: 0, intervals: 0, converged: true });
+    }
+    if a > b {
+        let result = integrate_batched(f, b, a, options)?;
+        return Ok(Integral { value: -result.value, ..result });
+    }
+
+    match (a.is_finite(), b.is_finite()) {
+        (true, true) => integrate_finite(f, a, b, options),
+        (true, false) => integrate_finite(|ts| substitute(&mut f, ts, |t| (a + t / (1.0 - t), (1.0 - t).powi(-2))), 0.0, 1.0, options),
+        (false, true) => integrate_finite(|ts| substitute(&mut f, ts, |t| (b - t / (1.0 - t), (1.0 - t).powi(-2))), 0.0, 1.0, options),
+        (false, false) => integrate_finite(
+            |ts| substitute(&mut f, ts, |t| (t / (1.0 - t * t), (1.0 + t * t) / (1.0 - t * t).powi(2))),
+            -1.0,
+            1.0,
+            options,
+        ),
+    }
+}
+
+fn substitute<F>(f: &mut F, ts: &[f64], map: impl Fn(f64) -> (f64, f64)) -> Vec<f64>
+where F: FnMut(&[f64]) -> Vec<f64> {
+    let (xs, jacobians): (Vec<f64>, Vec<f64>) = ts.iter().map(|&t| map(t)).unzip();
+    let values = f(&xs);
+    if values.len() != xs.len() {
+        return values;
+    }
+    values.iter().zip(&jacobians).map(|(value, jacobian)| value * jacobian).collect()
+}
+
+pub fn integrate<F>(f: F, a: f64, b: f64, options: IntegrationOptions) -> Result<Integral>
+where F: Fn(f64) -> f64 {
+    integrate_batched(|xs| xs.iter().map(|&x| f(x)).collect(), a, b, options)
+}
@@ -0,0 +1,942 @@
+use anyhow::{bail, Result};
+use num_complex::Complex64;
+use std::ops::{Index, IndexMut};
+
+const MAX_SWEEPS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Vector {
+    data: Vec<f64>,
+}
+
+impl Vector {
+    pub fn new(data: Vec<f64>) -> Vector {
+        Vector { data }
+    }
+
+    pub fn zeros(len: usize) -> Vector {
+        Vector { data: vec![0.0; len] }
+    }
+
+    pub fn from_slice(data: &[f64]) -> Vector {
+        Vector { data: data.to_vec() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    pub fn into_vec(self) -> Vec<f64> {
+        self.data
+    }
+
+    fn check_len(&self, other: &Vector) -> Result<()> {
+        if self.len() != other.len() {
+            bail!("Vector lengths differ: {} and {}", self.len(), other.len());
+        }
+        Ok(())
+    }
+
+    pub fn dot(&self, other: &Vector) -> Result<f64> {
+        self.check_len(other)?;
+        Ok(self.data.iter().zip(&other.data).map(|(a, b)| a * b).sum())
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.data.iter().fold(0.0, |acc: f64, value| acc.hypot(*value))
+    }
+
+    pub fn normalized(&self) -> Option<Vector> {
+        let norm = self.norm();
+        (norm > 0.0).then(|| self.scaled(1.0 / norm))
+    }
+
+    pub fn scaled(&self, factor: f64) -> Vector {
+        Vector { data: self.data.iter().map(|value| value * factor).collect() }
+    }
+
+    pub fn checked_add(&self, other: &Vector) -> Result<Vector> {
+        self.check_len(other)?;
+        Ok(Vector { data: self.data.iter().zip(&other.data).map(|(a, b)| a + b).collect() })
+    }
+
+    pub fn checked_sub(&self, other: &Vector) -> Result<Vector> {
+        self.check_len(other)?;
+        Ok(Vector { data: self.data.iter().zip(&other.data).map(|(a, b)| a - b).collect() })
+    }
+}
+
+impl From<Vec<f64>> for Vector {
+    fn from(data: Vec<f64>) -> Self {
+        Vector { data }
+    }
+}
+
+impl Index<usize> for Vector {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &f64 {
+        &self.data[index]
+    }
+}
+
+impl IndexMut<usize> for Vector {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        &mut self.data[index]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &f64 {
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl Matrix {
+    pub fn new(rows: usize, cols: usize, data: Vec<f64>) -> Result<Matrix> {
+        if data.len() != rows * cols {
+            bail!("A {}x{} matrix needs {} values, got {}", rows, cols, rows * cols, data.len());
+        }
+        Ok(Matrix { rows, cols, data })
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Matrix {
+        Matrix { rows, cols, data: vec![0.0; rows * cols] }
+    }
+
+    pub fn identity(n: usize) -> Matrix {
+        Matrix::from_fn(n, n, |i, j| if i == j { 1.0 } else { 0.0 })
+    }
+
+    pub fn from_fn(rows: usize, cols: usize, f: impl Fn(usize, usize) -> f64) -> Matrix {
+        let data = (0..rows * cols).map(|index| f(index / cols, index % cols)).collect();
+        Matrix { rows, cols, data }
+    }
+
+    pub fn from_rows(rows: &[Vec<f64>]) -> Result<Matrix> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != cols) {
+            bail!("All rows must have {} columns", cols);
+        }
+        Ok(Matrix { rows: rows.len(), cols, data: rows.concat() })
+    }
+
+    pub fn from_diagonal(values: &[f64]) -> Matrix {
+        Matrix::from_fn(values.len(), values.len(), |i, j| if i == j { values[i] } else { 0.0 })
+    }
+
+    pub fn column_vector(vector: &Vector) -> Matrix {
+        Matrix { rows: vector.len(), cols: 1, data: vector.as_slice().to_vec() }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    pub fn into_vec(self) -> Vec<f64> {
+        self.data
+    }
+
+    pub fn row(&self, row: usize) -> &[f64] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> Vector {
+        Vector::new((0..self.rows).map(|row| self[(row, col)]).collect())
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<f64>> {
+        (0..self.rows).map(|row| self.row(row).to_vec()).collect()
+    }
+
+    pub fn diagonal(&self) -> Vector {
+        Vector::new((0..self.rows.min(self.cols)).map(|i| self[(i, i)]).collect())
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        Matrix::from_fn(self.cols, self.rows, |i, j| self[(j, i)])
+    }
+
+    pub fn scaled(&self, factor: f64) -> Matrix {
+        Matrix { data: self.data.iter().map(|value| value * factor).collect(), ..*self }
+    }
+
+    fn check_same_shape(&self, other: &Matrix) -> Result<()> {
+        if self.shape() != other.shape() {
+            bail!("Matrix shapes differ: {}x{} and {}x{}", self.rows, self.cols, other.rows, other.cols);
+        }
+        Ok(())
+    }
+
+    fn require_square(&self) -> Result<usize> {
+        if !self.is_square() {
+            bail!("Expected a square matrix, got {}x{}", self.rows, self.cols);
+        }
+        Ok(self.rows)
+    }
+
+    pub fn checked_add(&self, other: &Matrix) -> Result<Matrix> {
+        self.check_same_shape(other)?;
+        Ok(Matrix { data: self.data.iter().zip(&other.data).map(|(a, b)| a + b).collect(), ..*self })
+    }
+
+    pub fn checked_sub(&self, other: &Matrix) -> Result<Matrix> {
+        self.check_same_shape(other)?;
+        Ok(Matrix { data: self.data.iter().zip(&other.data).map(|(a, b)| a - b).collect(), ..*self })
+    }
+
+    pub fn matmul(&self, other: &Matrix) -> Result<Matrix> {
+        if self.cols != other.rows {
+            bail!("Cannot multiply {}x{} by {}x{}", self.rows, self.cols, other.rows, other.cols);
+        }
+        let mut result = Matrix::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                if a == 0.0 {
+                    continue;
+                }
+                for j in 0..other.cols {
+                    result[(i, j)] += a * other[(k, j)];
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn mul_vector(&self, vector: &Vector) -> Result<Vector> {
+        if self.cols != vector.len() {
+            bail!("Cannot multiply {}x{} matrix by vector of length {}", self.rows, self.cols, vector.len());
+        }
+        Ok(Vector::new((0..self.rows).map(|i| self.row(i).iter().zip(vector.as_slice()).map(|(a, b)| a * b).sum()).collect()))
+    }
+
+    pub fn trace(&self) -> Result<f64> {
+        self.require_square()?;
+        Ok(self.diagonal().as_slice().iter().sum())
+    }
+
+    pub fn frobenius_norm(&self) -> f64 {
+        self.data.iter().fold(0.0, |acc: f64, value| acc.hypot(*value))
+    }
+
+    pub fn max_abs(&self) -> f64 {
+        self.data.iter().fold(0.0, |acc: f64, value| acc.max(value.abs()))
+    }
+
+    pub fn is_symmetric(&self, tolerance: f64) -> bool {
+        self.is_square() && (0..self.rows).all(|i| (0..i).all(|j| (self[(i, j)] - self[(j, i)]).abs() <= tolerance))
+    }
+
+    fn default_tolerance(&self) -> f64 {
+        f64::EPSILON * self.rows.max(self.cols) as f64 * self.max_abs()
+    }
+
+    pub fn lu(&self) -> Result<Lu> {
+        let n = self.require_square()?;
+        let tolerance = self.default_tolerance();
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+        let mut singular = false;
+
+        for k in 0..n {
+            let pivot_row = (k..n).max_by(|&a, &b| lu[(a, k)].abs().total_cmp(&lu[(b, k)].abs())).unwrap_or(k);
+            if pivot_row != k {
+                for j in 0..n {
+                    lu.data.swap(k * n + j, pivot_row * n + j);
+                }
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = lu[(k, k)];
+            if pivot.abs() <= tolerance {
+                singular = true;
+                continue;
+            }
+            for i in k + 1..n {
+                lu[(i, k)] /= pivot;
+                let factor = lu[(i, k)];
+                for j in k + 1..n {
+                    lu[(i, j)] -= factor * lu[(k, j)];
+                }
+            }
+        }
+
+        Ok(Lu { lu, permutation, sign, singular })
+    }
+
+    pub fn qr(&self) -> Result<Qr> {
+        if self.rows < self.cols {
+            bail!("QR decomposition needs at least as many rows as columns, got {}x{}", self.rows, self.cols);
+        }
+        let (m, n) = self.shape();
+        let mut qr = self.clone();
+        let mut r_diagonal = vec![0.0; n];
+
+        for k in 0..n {
+            let mut norm = (k..m).fold(0.0, |acc: f64, i| acc.hypot(qr[(i, k)]));
+            if norm != 0.0 {
+                if qr[(k, k)] < 0.0 {
+                    norm = -norm;
+                }
+                for i in k..m {
+                    qr[(i, k)] /= norm;
+                }
+                qr[(k, k)] += 1.0;
+                for j in k + 1..n {
+                    let s = -(k..m).map(|i| qr[(i, k)] * qr[(i, j)]).sum::<f64>() / qr[(k, k)];
+                    for i in k..m {
+                        let v = qr[(i, k)];
+                        qr[(i, j)] += s * v;
+                    }
+                }
+            }
+            r_diagonal[k] = -norm;
+        }
+
+        let tolerance = self.default_tolerance();
+        Ok(Qr { qr, r_diagonal, tolerance })
+    }
+
+    pub fn cholesky(&self) -> Result<Cholesky> {
+        let n = self.require_square()?;
+        if !self.is_symmetric(self.default_tolerance() * 16.0) {
+            bail!("Cholesky decomposition needs a symmetric matrix");
+        }
+
+        let mut l = Matrix::zeros(n, n);
+        for j in 0..n {
+            let diagonal = self[(j, j)] - (0..j).map(|k| l[(j, k)] * l[(j, k)]).sum::<f64>();
+            if diagonal <= 0.0 {
+                bail!("Matrix is not positive definite");
+            }
+            l[(j, j)] = diagonal.sqrt();
+            for i in j + 1..n {
+                let sum: f64 = (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum();
+                l[(i, j)] = (self[(i, j)] - sum) / l[(j, j)];
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+
+    pub fn svd(&self) -> Result<Svd> {
+        if self.rows < self.cols {
+            let transposed = self.transpose().svd()?;
+            return Ok(Svd { u: transposed.v, singular_values: transposed.singular_values, v: transposed.u });
+        }
+
+        let (m, n) = self.shape();
+        let mut u = self.clone();
+        let mut v = Matrix::identity(n);
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                    for i in 0..m {
+                        alpha += u[(i, p)] * u[(i, p)];
+                        beta += u[(i, q)] * u[(i, q)];
+                        gamma += u[(i, p)] * u[(i, q)];
+                    }
+                    if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() || gamma == 0.0 {
+                        continue;
+                    }
+
+                    rotated = true;
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c = 1.0 / (1.0 + t * t).sqrt();
+                    let s = c * t;
+                    rotate_columns(&mut u, p, q, c, s);
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order: Vec<(f64, usize)> = (0..n).map(|j| (u.column(j).norm(), j)).collect();
+        order.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        let mut left = Matrix::zeros(m, n);
+        let mut right = Matrix::zeros(n, n);
+        for (target, &(sigma, source)) in order.iter().enumerate() {
+            for i in 0..m {
+                left[(i, target)] = if sigma > 0.0 { u[(i, source)] / sigma } else { 0.0 };
+            }
+            for i in 0..n {
+                right[(i, target)] = v[(i, source)];
+            }
+        }
+
+        let singular_values = Vector::new(order.iter().map(|&(sigma, _)| sigma).collect());
+        Ok(Svd { u: left, singular_values, v: right })
+    }
+
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen> {
+        let n = self.require_square()?;
+        if !self.is_symmetric(self.default_tolerance() * 16.0) {
+            bail!("Symmetric eigendecomposition needs a symmetric matrix");
+        }
+
+        let mut a = self.clone();
+        let mut v = Matrix::identity(n);
+        let scale = self.frobenius_norm();
+
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal = (0..n).flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .fold(0.0, |acc: f64, (i, j)| acc.hypot(a[(i, j)]));
+            if off_diagonal <= f64::EPSILON * scale {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = a[(p, q)];
+                    if apq.abs() <= f64::MIN_POSITIVE {
+                        continue;
+                    }
+                    let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * apq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    rotate_columns(&mut a, p, q, c, s);
+                    for k in 0..n {
+                        let (akp, akq) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * akp - s * akq;
+                        a[(q, k)] = s * akp + c * akq;
+                    }
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[(i, i)].total_cmp(&a[(j, j)]));
+
+        let eigenvalues = Vector::new(order.iter().map(|&i| a[(i, i)]).collect());
+        let eigenvectors = Matrix::from_fn(n, n, |row, col| v[(row, order[col])]);
+        Ok(SymmetricEigen { eigenvalues, eigenvectors })
+    }
+
+    pub fn eigenvalues(&self) -> Result<Vec<Complex64>> {
+        self.require_square()?;
+        if self.is_symmetric(0.0) {
+            return Ok(self.symmetric_eigen()?.eigenvalues.as_slice().iter().map(|&value| Complex64::new(value, 0.0)).collect());
+        }
+        let mut hessenberg = self.clone();
+        reduce_to_hessenberg(&mut hessenberg);
+        hessenberg_eigenvalues(hessenberg)
+    }
+
+    pub fn determinant(&self) -> Result<f64> {
+        Ok(self.lu()?.determinant())
+    }
+
+    pub fn inverse(&self) -> Result<Matrix> {
+        self.lu()?.inverse()
+    }
+
+    pub fn solve(&self, b: &Vector) -> Result<Vector> {
+        self.lu()?.solve(b)
+    }
+
+    pub fn rank(&self) -> Result<usize> {
+        Ok(self.svd()?.rank(None))
+    }
+
+    pub fn pseudo_inverse(&self) -> Result<Matrix> {
+        self.svd()?.pseudo_inverse(None)
+    }
+
+    pub fn least_squares(&self, b: &Vector) -> Result<Vector> {
+        if self.rows != b.len() {
+            bail!("Right-hand side has length {}, expected {}", b.len(), self.rows);
+        }
+        if self.rows >= self.cols {
+            let qr = self.qr()?;
+            if qr.is_full_rank() {
+                return qr.solve(b);
+            }
+        }
+        self.svd()?.solve(b, None)
+    }
+}
+
+fn rotate_columns(matrix: &mut Matrix, p: usize, q: usize, c: f64, s: f64) {
+    for i in 0..matrix.rows {
+        let (x, y) = (matrix[(i, p)], matrix[(i, q)]);
+        matrix[(i, p)] = c * x - s * y;
+        matrix[(i, q)] = s * x + c * y;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lu {
+    lu: Matrix,
+    permutation: Vec<usize>,
+    sign: f64,
+    singular: bool,
+}
+
+impl Lu {
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    pub fn l(&self) -> Matrix {
+        let n = self.lu.rows;
+        Matrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => self.lu[(i, j)],
+            std::cmp::Ordering::Equal => 1.0,
+            std::cmp::Ordering::Less => 0.0,
+        })
+    }
+
+    pub fn u(&self) -> Matrix {
+        let n = self.lu.rows;
+        Matrix::from_fn(n, n, |i, j| if i <= j { self.lu[(i, j)] } else { 0.0 })
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn determinant(&self) -> f64 {
+        if self.singular {
+            return 0.0;
+        }
+        self.sign * self.lu.diagonal().as_slice().iter().product::<f64>()
+    }
+
+    pub fn solve(&self, b: &Vector) -> Result<Vector> {
+        let n = self.lu.rows;
+        if b.len() != n {
+            bail!("Right-hand side has length {}, expected {}", b.len(), n);
+        }
+        if self.singular {
+            bail!("Matrix is singular");
+        }
+
+        let mut x: Vec<f64> = self.permutation.iter().map(|&row| b[row]).collect();
+        for i in 0..n {
+            let sum: f64 = (0..i).map(|j| self.lu[(i, j)] * x[j]).sum();
+            x[i] -= sum;
+        }
+        for i in (0..n).rev() {
+            let sum: f64 = (i + 1..n).map(|j| self.lu[(i, j)] * x[j]).sum();
+            x[i] = (x[i] - sum) / self.lu[(i, i)];
+        }
+        Ok(Vector::new(x))
+    }
+
+    pub fn inverse(&self) -> Result<Matrix> {
+        let n = self.lu.rows;
+        let mut inverse = Matrix::zeros(n, n);
+        for col in 0..n {
+            let unit = Vector::new((0..n).map(|i| if i == col { 1.0 } else { 0.0 }).collect());
+            let solution = self.solve(&unit)?;
+            for row in 0..n {
+                inverse[(row, col)] = solution[row];
+            }
+        }
+        Ok(inverse)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Qr {
+    qr: Matrix,
+    r_diagonal: Vec<f64>,
+    tolerance: f64,
+}
+
+impl Qr {
+    pub fn is_full_rank(&self) -> bool {
+        self.r_diagonal.iter().all(|value| value.abs() > self.tolerance)
+    }
+
+    pub fn r(&self) -> Matrix {
+        let n = self.qr.cols;
+        Matrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Less => self.qr[(i, j)],
+            std::cmp::Ordering::Equal => self.r_diagonal[i],
+            std::cmp::Ordering::Greater => 0.0,
+        })
+    }
+
+    pub fn q(&self) -> Matrix {
+        let (m, n) = self.qr.shape();
+        let mut q = Matrix::zeros(m, n);
+        for k in (0..n).rev() {
+            q[(k, k)] = 1.0;
+            for j in k..n {
+                if self.qr[(k, k)] != 0.0 {
+                    let s = -(k..m).map(|i| self.qr[(i, k)] * q[(i, j)]).sum::<f64>() / self.qr[(k, k)];
+                    for i in k..m {
+                        q[(i, j)] += s * self.qr[(i, k)];
+                    }
+                }
+            }
+        }
+        q
+    }
+
+    pub fn solve(&self, b: &Vector) -> Result<Vector> {
+        let (m, n) = self.qr.shape();
+        if b.len() != m {
+            bail!("Right-hand side has length {}, expected {}", b.len(), m);
+        }
+        if !self.is_full_rank() {
+            bail!("Matrix is rank deficient");
+        }
+
+        let mut y = b.as_slice().to_vec();
+        for k in 0..n {
+            let s = -(k..m).map(|i| self.qr[(i, k)] * y[i]).sum::<f64>() / self.qr[(k, k)];
+            for (i, value) in y.iter_mut().enumerate().skip(k) {
+                *value += s * self.qr[(i, k)];
+            }
+        }
+
+        let mut x = vec![0.0; n];
+        for k in (0..n).rev() {
+            let sum: f64 = (k + 1..n).map(|j| self.qr[(k, j)] * x[j]).sum();
+            x[k] = (y[k] - sum) / self.r_diagonal[k];
+        }
+        Ok(Vector::new(x))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cholesky {
+    l: Matrix,
+}
+
+impl Cholesky {
+    pub fn l(&self) -> &Matrix {
+        &self.l
+    }
+
+    pub fn determinant(&self) -> f64 {
+        self.l.diagonal().as_slice().iter().map(|value| value * value).product()
+    }
+
+    pub fn solve(&self, b: &Vector) -> Result<Vector> {
+        let n = self.l.rows;
+        if b.len() != n {
+            bail!("Right-hand side has length {}, expected {}", b.len(), n);
+        }
+
+        let mut x = b.as_slice().to_vec();
+        for i in 0..n {
+            let sum: f64 = (0..i).map(|k| self.l[(i, k)] * x[k]).sum();
+            x[i] = (x[i] - sum) / self.l[(i, i)];
+        }
+        for i in (0..n).rev() {
+            let sum: f64 = (i + 1..n).map(|k| self.l[(k, i)] * x[k]).sum();
+            x[i] = (x[i] - sum) / self.l[(i, i)];
+        }
+        Ok(Vector::new(x))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Svd {
+    pub u: Matrix,
+    pub singular_values: Vector,
+    pub v: Matrix,
+}
+
+impl Svd {
+    fn threshold(&self, tolerance: Option<f64>) -> f64 {
+        tolerance.unwrap_or_else(|| {
+            let largest = self.singular_values.as_slice().first().copied().unwrap_or(0.0);
+            f64::EPSILON * self.u.rows.max(self.v.rows) as f64 * largest
+        })
+    }
+
+    pub fn rank(&self, tolerance: Option<f64>) -> usize {
+        let threshold = self.threshold(tolerance);
+        self.singular_values.as_slice().iter().filter(|&&sigma| sigma > threshold).count()
+    }
+
+    pub fn condition_number(&self) -> f64 {
+        let values = self.singular_values.as_slice();
+        match (values.first(), values.last()) {
+            (Some(&largest), Some(&smallest)) if smallest > 0.0 => largest / smallest,
+            _ => f64::INFINITY,
+        }
+    }
+
+    pub fn pseudo_inverse(&self, tolerance: Option<f64>) -> Result<Matrix> {
+        let threshold = self.threshold(tolerance);
+        let (m, n) = (self.u.rows, self.v.rows);
+        let mut result = Matrix::zeros(n, m);
+        for (k, &sigma) in self.singular_values.as_slice().iter().enumerate() {
+            if sigma <= threshold {
+                continue;
+            }
+            for i in 0..n {
+                let factor = self.v[(i, k)] / sigma;
+                for j in 0..m {
+                    result[(i, j)] += factor * self.u[(j, k)];
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn solve(&self, b: &Vector, tolerance: Option<f64>) -> Result<Vector> {
+        self.pseudo_inverse(tolerance)?.mul_vector(b)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen {
+    pub eigenvalues: Vector,
+    pub eigenvectors: Matrix,
+}
+
+fn reduce_to_hessenberg(a: &mut Matrix) {
+    let n = a.rows;
+    for m in 1..n.saturating_sub(1) {
+        let mut pivot = 0.0;
+        let mut pivot_row = m;
+        for j in m..n {
+            if a[(j, m - 1)].abs() > f64::abs(pivot) {
+                pivot = a[(j, m - 1)];
+                pivot_row = j;
+            }
+        }
+        if pivot_row != m {
+            for j in m - 1..n {
+                a.data.swap(pivot_row * n + j, m * n + j);
+            }
+            for j in 0..n {
+                a.data.swap(j * n + pivot_row, j * n + m);
+            }
+        }
+        if pivot != 0.0 {
+            for i in m + 1..n {
+                let y = a[(i, m - 1)] / pivot;
+                if y == 0.0 {
+                    continue;
+                }
+                for j in m..n {
+                    a[(i, j)] -= y * a[(m, j)];
+                }
+                for j in 0..n {
+                    a[(j, m)] += y * a[(j, i)];
+                }
+            }
+        }
+    }
+    for i in 2..n {
+        for j in 0..i - 1 {
+            a[(i, j)] = 0.0;
+        }
+    }
+}
+
+fn hessenberg_eigenvalues(mut a: Matrix) -> Result<Vec<Complex64>> {
+    let n = a.rows;
+    let mut eigenvalues = vec![Complex64::new(0.0, 0.0); n];
+    if n == 0 {
+        return Ok(eigenvalues);
+    }
+
+    let norm: f64 = (0..n).map(|i| (i.saturating_sub(1)..n).map(|j| a[(i, j)].abs()).sum::<f64>()).sum();
+    let mut nn = n as isize - 1;
+    let mut shift = 0.0;
+    let at = |a: &Matrix, i: isize, j: isize| a[(i as usize, j as usize)];
+
+    while nn >= 0 {
+        let mut iterations = 0;
+        loop {
+            let mut l = nn;
+            while l > 0 {
+                let mut s = at(&a, l - 1, l - 1).abs() + at(&a, l, l).abs();
+                if s == 0.0 {
+                    s = norm;
+                }
+                if at(&a, l, l - 1).abs() <= f64::EPSILON * s {
+                    a[(l as usize, l as usize - 1)] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = at(&a, nn, nn);
+            if l == nn {
+                eigenvalues[nn as usize] = Complex64::new(x + shift, 0.0);
+                nn -= 1;
+                break;
+            }
+
+            let mut y = at(&a, nn - 1, nn - 1);
+            let mut w = at(&a, nn, nn - 1) * at(&a, nn - 1, nn);
+            if l == nn - 1 {
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += shift;
+                if q >= 0.0 {
+                    let z = p + z.copysign(p);
+                    eigenvalues[nn as usize - 1] = Complex64::new(x + z, 0.0);
+                    eigenvalues[nn as usize] = Complex64::new(if z != 0.0 { x - w / z } else { x + z }, 0.0);
+                } else {
+                    eigenvalues[nn as usize] = Complex64::new(x + p, -z);
+                    eigenvalues[nn as usize - 1] = Complex64::new(x + p, z);
+                }
+                nn -= 2;
+                break;
+            }
+
+            if iterations == 30 * n {
+                bail!("Eigenvalue iteration did not converge");
+            }
+            if iterations > 0 && iterations % 10 == 0 {
+                shift += x;
+                for i in 0..=nn as usize {
+                    a[(i, i)] -= x;
+                }
+                let s = at(&a, nn, nn - 1).abs() + at(&a, nn - 1, nn - 2).abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            iterations += 1;
+
+            let mut m = nn - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = at(&a, m, m);
+                let r0 = x - z;
+                let s0 = y - z;
+                p = (r0 * s0 - w) / at(&a, m + 1, m) + at(&a, m, m + 1);
+                q = at(&a, m + 1, m + 1) - z - r0 - s0;
+                r = at(&a, m + 2, m + 1);
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let u = at(&a, m, m - 1).abs() * (q.abs() + r.abs());
+                let v = p.abs() * (at(&a, m - 1, m - 1).abs() + z.abs() + at(&a, m + 1, m + 1).abs());
+                if u <= f64::EPSILON * v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m..nn - 1 {
+                a[(i as usize + 2, i as usize)] = 0.0;
+                if i != m {
+                    a[(i as usize + 2, i as usize - 1)] = 0.0;
+                }
+            }
+
+            for k in m..nn {
+                if k != m {
+                    p = at(&a, k, k - 1);
+                    q = at(&a, k + 1, k - 1);
+                    r = if k + 1 != nn { at(&a, k + 2, k - 1) } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0.0 {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                if s == 0.0 {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[(k as usize, k as usize - 1)] = -at(&a, k, k - 1);
+                    }
+                } else {
+                    a[(k as usize, k as usize - 1)] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+
+                for j in k..=nn {
+                    let mut p = at(&a, k, j) + q * at(&a, k + 1, j);
+                    if k + 1 != nn {
+                        p += r * at(&a, k + 2, j);
+                        a[(k as usize + 2, j as usize)] -= p * z;
+                    }
+                    a[(k as usize + 1, j as usize)] -= p * y;
+                    a[(k as usize, j as usize)] -= p * x;
+                }
+
+                let last = nn.min(k + 3);
+                for i in l..=last {
+                    let mut p = x * at(&a, i, k) + y * at(&a, i, k + 1);
+                    if k + 1 != nn {
+                        p += z * at(&a, i, k + 2);
+                        a[(i as usize, k as usize + 2)] -= p * r;
+                    }
+                    a[(i as usize, k as usize + 1)] -= p * q;
+                    a[(i as usize, k as usize)] -= p;
+                }
+            }
+        }
+    }
+
+    Ok(eigenvalues)
+}
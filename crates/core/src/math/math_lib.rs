@@ -0,0 +1,292 @@
+use anyhow::{bail, Result};
+use num_complex::Complex64;
+use super::integration::{integrate, Integral, IntegrationOptions};
+use super::linalg::{Matrix, Vector};
+use super::signal::{fft_complex, ifft_complex};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hyperbolic {
+    pub sinh: f64,
+    pub cosh: f64,
+    pub tanh: f64,
+    pub coth: f64,
+    pub sech: f64,
+    pub csch: f64,
+}
+
+pub fn is_prime(n: u32) -> bool {
+    if n <= 1 {
+        return false;
+    }
+    if n <= 3 {
+        return true;
+    }
+    if n.is_multiple_of(2) || n.is_multiple_of(3) {
+        return false;
+    }
+    
+    let n = n as u64;
+    let mut i = 5;
+    while i * i <= n {
+        if n.is_multiple_of(i) || n.is_multiple_of(i + 2) {
+            return false;
+        }
+        i += 6;
+    }
+    
+    true
+}
+
+pub fn next_prime(n: u32) -> u32 {
+    let mut next = n.saturating_add(1);
+    
+    while !is_prime(next) && next < u32::MAX {
+        next += 1;
+    }
+    
+    next
+}
+
+pub fn sieve_of_eratosthenes(limit: u32) -> Vec<u32> {
+    if limit <= 1 {
+        return Vec::new();
+    }
+    
+    let limit = limit as usize;
+    let mut is_prime = vec![true; limit + 1];
+    is_prime[0] = false;
+    is_prime[1] = false;
+    
+    let mut p = 2;
+    while p * p <= limit {
+        if is_prime[p] {
+            for i in (p * p..=limit).step_by(p) {
+                is_prime[i] = false;
+            }
+        }
+        p += 1;
+    }
+    
+    (2..=limit)
+        .filter(|&i| is_prime[i])
+        .map(|i| i as u32)
+        .collect()
+}
+
+pub fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub fn lcm(a: i32, b: i32) -> i32 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        (a.abs() / gcd(a, b)) * b.abs()
+    }
+}
+
+pub fn factorial(n: u32) -> f64 {
+    (2..=n).fold(1.0, |acc, i| acc * i as f64)
+}
+
+pub fn fibonacci(n: u32) -> f64 {
+    if n <= 1 {
+        return n as f64;
+    }
+    
+    let mut a = 0.0;
+    let mut b = 1.0;
+    
+    for _ in 2..=n {
+        let temp = a + b;
+        a = b;
+        b = temp;
+    }
+    
+    b
+}
+
+pub fn binomial_coefficient(n: u32, k: u32) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    
+    let k = k.min(n - k);
+    let mut c = 1.0;
+    for i in 0..k {
+        c = c * (n - i) as f64 / (i + 1) as f64;
+    }
+    
+    c
+}
+
+fn square_matrix(data: &[f64], n: usize) -> Result<Matrix> {
+    Matrix::new(n, n, data.to_vec())
+}
+
+pub fn linear_solve(a: &[f64], b: &[f64], n: usize) -> Result<Vec<f64>> {
+    if b.len() != n {
+        bail!("Invalid matrix dimensions");
+    }
+    Ok(square_matrix(a, n)?.solve(&Vector::from_slice(b))?.into_vec())
+}
+
+pub fn matrix_multiply(a: &[f64], b: &[f64], rows_a: usize, cols_a: usize, cols_b: usize) -> Result<Vec<f64>> {
+    let a = Matrix::new(rows_a, cols_a, a.to_vec())?;
+    let b = Matrix::new(cols_a, cols_b, b.to_vec())?;
+    Ok(a.matmul(&b)?.into_vec())
+}
+
+pub fn matrix_inverse(a: &[f64], n: usize) -> Result<Vec<f64>> {
+    Ok(square_matrix(a, n)?.inverse()?.into_vec())
+}
+
+pub fn eigenvalues(matrix: &[f64], n: usize) -> Result<Vec<Complex64>> {
+    square_matrix(matrix, n)?.eigenvalues()
+}
+
+fn fft_input(real: &[f64], imag: &[f64]) -> Result<Vec<Complex64>> {
+    if real.is_empty() {
+        bail!("Input must not be empty");
+    }
+    
+    if imag.len() != real.len() {
+        bail!("Real and imaginary arrays must have the same length");
+    }
+    
+    Ok(real.iter().zip(imag).map(|(&re, &im)| Complex64::new(re, im)).collect())
+}
+
+pub fn fft(real: &[f64], imag: &[f64]) -> Result<(Vec<f64>, Vec<f64>)> {
+    let data = fft_complex(&fft_input(real, imag)?);
+    Ok(data.iter().map(|c| (c.re, c.im)).unzip())
+}
+
+pub fn ifft(real: &[f64], imag: &[f64]) -> Result<(Vec<f64>, Vec<f64>)> {
+    let data = ifft_complex(&fft_input(real, imag)?);
+    Ok(data.iter().map(|c| (c.re, c.im)).unzip())
+}
+
+pub fn integrate_simpson<F>(f: F, a: f64, b: f64, n: u32) -> Result<f64>
+where F: Fn(f64) -> f64 {
+    if !n.is_multiple_of(2) {
+        bail!("Number of intervals must be even");
+    }
+    
+    let h = (b - a) / n as f64;
+    let mut sum = f(a) + f(b);
+    
+    for i in 1..n {
+        let coefficient = if i % 2 == 0 { 2.0 } else { 4.0 };
+        sum += coefficient * f(a + i as f64 * h);
+    }
+    
+    Ok(sum * h / 3.0)
+}
+
+pub fn laplace_transform<F>(f: F, s: f64, options: IntegrationOptions) -> Result<Integral>
+where F: Fn(f64) -> f64 {
+    integrate(|t| f(t) * (-s * t).exp(), 0.0, f64::INFINITY, options)
+}
+
+pub fn newton_raphson<F, D>(f: F, df: D, x0: f64, epsilon: f64, max_iterations: u32) -> Result<f64>
+where F: Fn(f64) -> f64, D: Fn(f64) -> f64 {
+    let mut x = x0;
+    
+    for _ in 0..max_iterations {
+        let f_x = f(x);
+        
+        if f_x.abs() < epsilon {
+            return Ok(x);
+        }
+        
+        let df_x = df(x);
+        
+        if df_x.abs() < epsilon {
+            bail!("Derivative is too close to zero");
+        }
+        
+        let x_new = x - f_x / df_x;
+        
+        if (x_new - x).abs() < epsilon {
+            return Ok(x_new);
+        }
+        
+        x = x_new;
+    }
+    
+    bail!("Method did not converge within the maximum number of iterations")
+}
+
+pub fn bessel_j(n: i32, x: f64) -> f64 {
+    if n < 0 {
+        let value = bessel_j(-n, x);
+        return if n % 2 != 0 { -value } else { value };
+    }
+    
+    if x == 0.0 {
+        return if n == 0 { 1.0 } else { 0.0 };
+    }
+    
+    let m = (n as usize).max(x.abs() as usize) + 20 + (40.0 * x.abs()).sqrt() as usize;
+    let mut bs = vec![0.0; m + 1];
+    bs[m - 1] = 1.0;
+    
+    for i in (1..m).rev() {
+        bs[i-1] = (2.0 * (i as f64)) / x * bs[i] - bs[i+1];
+    }
+    
+    let norm = bs[0] + 2.0 * bs.iter().skip(2).step_by(2).sum::<f64>();
+    bs[n as usize] / norm
+}
+
+pub fn legendre_polynomial(n: u32, x: f64) -> f64 {
+    if n == 0 {
+        return 1.0;
+    }
+    
+    let mut p0 = 1.0;
+    let mut p1 = x;
+    
+    for k in 2..=n {
+        let p = ((2.0 * k as f64 - 1.0) * x * p1 - (k as f64 - 1.0) * p0) / k as f64;
+        p0 = p1;
+        p1 = p;
+    }
+    
+    p1
+}
+
+pub fn hyperbolic_functions(x: f64) -> Hyperbolic {
+    let sinh = x.sinh();
+    let cosh = x.cosh();
+    
+    Hyperbolic {
+        sinh,
+        cosh,
+        tanh: x.tanh(),
+        coth: if sinh != 0.0 { cosh / sinh } else { f64::INFINITY },
+        sech: if cosh != 0.0 { 1.0 / cosh } else { 0.0 },
+        csch: if sinh != 0.0 { 1.0 / sinh } else { f64::INFINITY },
+    }
+}
+
+pub fn complex_operation(z1: Complex64, z2: Complex64, operation: &str) -> Complex64 {
+    match operation {
+        "add" => z1 + z2,
+        "subtract" => z1 - z2,
+        "multiply" => z1 * z2,
+        "divide" => z1 / z2,
+        "pow" => z1.powc(z2),
+        "ln" => z1.ln(),
+        "exp" => z1.exp(),
+        "sin" => z1.sin(),
+        "cos" => z1.cos(),
+        "tan" => z1.tan(),
+        _ => Complex64::new(0.0, 0.0)
+    }
+}
@@ -0,0 +1,23 @@
+pub mod linalg;
+pub mod math_lib;
+pub mod statistics;
+pub mod distributions;
+pub mod hypothesis;
+pub mod integration;
+pub mod roots;
+pub mod optimize;
+pub mod ode;
+pub mod signal;
+pub mod bignum;
+
+pub use linalg::*;
+pub use math_lib::*;
+pub use statistics::*;
+pub use distributions::*;
+pub use hypothesis::*;
+pub use integration::*;
+pub use roots::*;
+pub use optimize::*;
+pub use ode::*;
+pub use signal::*;
+pub use bignum::*;
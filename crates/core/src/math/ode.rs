@@ -0,0 +1,256 @@
+use anyhow::{bail, Result};
+
+const C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+const A: [[f64; 6]; 7] = [
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [19372.0 / 6561.0, -25360.0 / 2187.0, 64448.0 / 6561.0, -212.0 / 729.0, 0.0, 0.0],
+    [9017.0 / 3168.0, -355.0 / 33.0, 46732.0 / 5247.0, 49.0 / 176.0, -5103.0 / 18656.0, 0.0],
+    [35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0],
+];
+const ERROR: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OdeOptions {
+    pub rel_tolerance: f64,
+    pub abs_tolerance: f64,
+    pub initial_step: Option<f64>,
+    pub max_step: Option<f64>,
+    pub max_steps: usize,
+}
+
+impl Default for OdeOptions {
+    fn default() -> Self {
+        OdeOptions {
+            rel_tolerance: 1e-6,
+            abs_tolerance: 1e-9,
+            initial_step: None,
+            max_step: None,
+            max_steps: 100_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OdeSolution {
+    pub t: Vec<f64>,
+    pub y: Vec<Vec<f64>>,
+    pub derivatives: Vec<Vec<f64>>,
+    pub evaluations: usize,
+    pub rejected_steps: usize,
+}
+
+impl OdeSolution {
+    pub fn dimension(&self) -> usize {
+        self.y.first().map_or(0, Vec::len)
+    }
+
+    pub fn final_state(&self) -> &[f64] {
+        self.y.last().map_or(&[], Vec::as_slice)
+    }
+
+    pub fn evaluate(&self, t: f64) -> Result<Vec<f64>> {
+        let (&first, &last) = match (self.t.first(), self.t.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => bail!("Solution has no steps"),
+        };
+        let (low, high) = (first.min(last), first.max(last));
+        if !(low..=high).contains(&t) {
+            bail!("t = {} is outside the solved interval [{}, {}]", t, low, high);
+        }
+        if self.t.len() == 1 {
+            return Ok(self.y[0].clone());
+        }
+
+        let forward = last >= first;
+        let index = self.t.partition_point(|&ti| if forward { ti < t } else { ti > t }).clamp(1, self.t.len() - 1);
+        let (t0, t1) = (self.t[index - 1], self.t[index]);
+        let h = t1 - t0;
+        let s = (t - t0) / h;
+        let (h00, h10, h01, h11) = (
+            (1.0 + 2.0 * s) * (1.0 - s) * (1.0 - s),
+            s * (1.0 - s) * (1.0 - s),
+            s * s * (3.0 - 2.0 * s),
+            s * s * (s - 1.0),
+        );
+        Ok((0..self.dimension())
+            .map(|i| {
+                h00 * self.y[index - 1][i]
+                    + h10 * h * self.derivatives[index - 1][i]
+                    + h01 * self.y[index][i]
+                    + h11 * h * self.derivatives[index][i]
+            })
+            .collect())
+    }
+
+    pub fn evaluate_many(&self, times: &[f64]) -> Result<Vec<Vec<f64>>> {
+        times.iter().map(|&t| self.evaluate(t)).collect()
+    }
+}
+
+struct System<F> {
+    f: F,
+    dimension: usize,
+    evaluations: usize,
+}
+
+impl<F: FnMut(f64, &[f64]) -> Vec<f64>> System<F> {
+    fn derivative(&mut self, t: f64, y: &[f64]) -> Result<Vec<f64>> {
+        let dy = (self.f)(t, y);
+        self.evaluations += 1;
+        if dy.len() != self.dimension {
+            bail!("Derivative returned {} values, expected {}", dy.len(), self.dimension);
+        }
+        if dy.iter().any(|value| !value.is_finite()) {
+            bail!("Derivative returned a non-finite value at t = {}", t);
+        }
+        Ok(dy)
+    }
+}
+
+fn check_problem(t0: f64, y0: &[f64], t_end: f64) -> Result<()> {
+    if y0.is_empty() {
+        bail!("Initial state must have at least one component");
+    }
+    if !t0.is_finite() || !t_end.is_finite() || y0.iter().any(|value| !value.is_finite()) {
+        bail!("Initial time, final time and state must be finite");
+    }
+    Ok(())
+}
+
+fn axpy(y: &[f64], h: f64, terms: &[(f64, &[f64])]) -> Vec<f64> {
+    (0..y.len()).map(|i| y[i] + h * terms.iter().map(|(weight, k)| weight * k[i]).sum::<f64>()).collect()
+}
+
+pub fn rk4<F>(f: F, t0: f64, y0: &[f64], t_end: f64, steps: usize) -> Result<OdeSolution>
+where F: FnMut(f64, &[f64]) -> Vec<f64> {
+    check_problem(t0, y0, t_end)?;
+    if steps == 0 {
+        bail!("Number of steps must be greater than zero");
+    }
+
+    let mut system = System { f, dimension: y0.len(), evaluations: 0 };
+    let h = (t_end - t0) / steps as f64;
+    let mut t_values = vec![t0];
+    let mut y_values = vec![y0.to_vec()];
+    let mut derivatives = vec![system.derivative(t0, y0)?];
+
+    for step in 0..steps {
+        let (t, y, k1) = (t_values[step], &y_values[step], &derivatives[step]);
+        let k2 = system.derivative(t + h / 2.0, &axpy(y, h / 2.0, &[(1.0, k1)]))?;
+        let k3 = system.derivative(t + h / 2.0, &axpy(y, h / 2.0, &[(1.0, &k2)]))?;
+        let k4 = system.derivative(t + h, &axpy(y, h, &[(1.0, &k3)]))?;
+        let next = axpy(y, h / 6.0, &[(1.0, k1), (2.0, &k2), (2.0, &k3), (1.0, &k4)]);
+        let t_next = if step + 1 == steps { t_end } else { t0 + (step + 1) as f64 * h };
+        derivatives.push(system.derivative(t_next, &next)?);
+        t_values.push(t_next);
+        y_values.push(next);
+    }
+
+    Ok(OdeSolution { t: t_values, y: y_values, derivatives, evaluations: system.evaluations, rejected_steps: 0 })
+}
+
+fn rms_norm(values: &[f64], scale: &[f64]) -> f64 {
+    (values.iter().zip(scale).map(|(v, s)| (v / s).powi(2)).sum::<f64>() / values.len() as f64).sqrt()
+}
+
+pub fn rk45<F>(f: F, t0: f64, y0: &[f64], t_end: f64, options: OdeOptions) -> Result<OdeSolution>
+where F: FnMut(f64, &[f64]) -> Vec<f64> {
+    check_problem(t0, y0, t_end)?;
+    if options.rel_tolerance <= 0.0 && options.abs_tolerance <= 0.0 {
+        bail!("At least one of the tolerances must be positive");
+    }
+
+    let mut system = System { f, dimension: y0.len(), evaluations: 0 };
+    let direction = if t_end >= t0 { 1.0 } else { -1.0 };
+    let span = (t_end - t0).abs();
+    let max_step = options.max_step.unwrap_or(span).min(span);
+    let scale_of = |y: &[f64]| -> Vec<f64> { y.iter().map(|v| options.abs_tolerance + options.rel_tolerance * v.abs()).collect() };
+
+    let mut t = t0;
+    let mut y = y0.to_vec();
+    let mut k1 = system.derivative(t, &y)?;
+    let mut solution = OdeSolution {
+        t: vec![t],
+        y: vec![y.clone()],
+        derivatives: vec![k1.clone()],
+        evaluations: 0,
+        rejected_steps: 0,
+    };
+    if span == 0.0 {
+        solution.evaluations = system.evaluations;
+        return Ok(solution);
+    }
+
+    let mut h = match options.initial_step {
+        Some(step) => step.abs(),
+        None => {
+            let scale = scale_of(&y);
+            let (d0, d1) = (rms_norm(&y, &scale), rms_norm(&k1, &scale));
+            let h0 = if d0 < 1e-5 || d1 < 1e-5 { 1e-6 } else { 0.01 * d0 / d1 };
+            let probe = system.derivative(t + direction * h0, &axpy(&y, direction * h0, &[(1.0, &k1)]))?;
+            let difference: Vec<f64> = probe.iter().zip(&k1).map(|(a, b)| a - b).collect();
+            let d2 = rms_norm(&difference, &scale) / h0;
+            let h1 = if d1.max(d2) <= 1e-15 { (h0 * 1e-3).max(1e-6) } else { (0.01 / d1.max(d2)).powf(0.2) };
+            (100.0 * h0).min(h1)
+        }
+    }
+    .min(max_step);
+
+    let mut steps = 0;
+    while (t_end - t) * direction > 0.0 {
+        steps += 1;
+        if steps > options.max_steps {
+            bail!("RK45 exceeded {} steps before reaching t = {}", options.max_steps, t_end);
+        }
+        h = h.min(max_step).min((t_end - t).abs());
+        if h <= 16.0 * f64::EPSILON * t.abs().max(1.0) {
+            bail!("Step size became too small at t = {}", t);
+        }
+        let signed_h = direction * h;
+
+        let mut stages: Vec<Vec<f64>> = vec![k1.clone()];
+        for i in 1..6 {
+            let terms: Vec<(f64, &[f64])> = (0..i).map(|j| (A[i][j], stages[j].as_slice())).collect();
+            let state = axpy(&y, signed_h, &terms);
+            stages.push(system.derivative(t + C[i] * signed_h, &state)?);
+        }
+        let terms: Vec<(f64, &[f64])> = (0..6).map(|j| (A[6][j], stages[j].as_slice())).collect();
+        let next = axpy(&y, signed_h, &terms);
+        let t_next = if (t_end - (t + signed_h)) * direction <= 0.0 { t_end } else { t + signed_h };
+        stages.push(system.derivative(t_next, &next)?);
+
+        let error: Vec<f64> = (0..y.len()).map(|i| signed_h * (0..7).map(|j| ERROR[j] * stages[j][i]).sum::<f64>()).collect();
+        let scale: Vec<f64> = y.iter().zip(&next)
+            .map(|(a, b)| options.abs_tolerance + options.rel_tolerance * a.abs().max(b.abs()))
+            .collect();
+        let error_norm = rms_norm(&error, &scale);
+
+        let factor = if error_norm == 0.0 { 5.0 } else { (0.9 * error_norm.powf(-0.2)).clamp(0.2, 5.0) };
+        if error_norm <= 1.0 {
+            t = t_next;
+            y = next;
+            k1 = stages.swap_remove(6);
+            solution.t.push(t);
+            solution.y.push(y.clone());
+            solution.derivatives.push(k1.clone());
+            h *= factor;
+        } else {
+            solution.rejected_steps += 1;
+            h *= factor.min(1.0);
+        }
+    }
+
+    solution.evaluations = system.evaluations;
+    Ok(solution)
+}
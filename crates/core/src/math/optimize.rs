@@ -0,0 +1,272 @@
+use anyhow::{bail, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptimizeOptions {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    pub initial_step: Option<f64>,
+}
+
+impl Default for OptimizeOptions {
+    fn default() -> Self {
+        OptimizeOptions {
+            tolerance: 1e-8,
+            max_iterations: 1000,
+            initial_step: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Minimum {
+    pub point: Vec<f64>,
+    pub value: f64,
+    pub iterations: usize,
+    pub evaluations: usize,
+    pub converged: bool,
+}
+
+pub type BatchedGradient<'a> = &'a mut dyn FnMut(&[f64]) -> Vec<f64>;
+pub type Gradient<'a> = &'a dyn Fn(&[f64]) -> Vec<f64>;
+
+struct Objective<F> {
+    f: F,
+    evaluations: usize,
+}
+
+impl<F: FnMut(&[Vec<f64>]) -> Vec<f64>> Objective<F> {
+    fn evaluate(&mut self, points: &[Vec<f64>]) -> Result<Vec<f64>> {
+        let values = (self.f)(points);
+        self.evaluations += points.len();
+        if values.len() != points.len() {
+            bail!("Objective returned {} values for {} points", values.len(), points.len());
+        }
+        if values.iter().any(|value| value.is_nan()) {
+            bail!("Objective returned NaN");
+        }
+        Ok(values)
+    }
+
+    fn evaluate_one(&mut self, point: Vec<f64>) -> Result<f64> {
+        Ok(self.evaluate(&[point])?[0])
+    }
+}
+
+fn check_start(x0: &[f64]) -> Result<()> {
+    if x0.is_empty() {
+        bail!("Starting point must have at least one dimension");
+    }
+    if x0.iter().any(|value| !value.is_finite()) {
+        bail!("Starting point must be finite");
+    }
+    Ok(())
+}
+
+fn combine(a: &[f64], b: &[f64], t: f64) -> Vec<f64> {
+    a.iter().zip(b).map(|(x, y)| x + t * (y - x)).collect()
+}
+
+fn identity(n: usize) -> Vec<Vec<f64>> {
+    (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect()
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+pub fn nelder_mead_batched<F>(f: F, x0: &[f64], options: OptimizeOptions) -> Result<Minimum>
+where F: FnMut(&[Vec<f64>]) -> Vec<f64> {
+    check_start(x0)?;
+    let n = x0.len();
+    let mut objective = Objective { f, evaluations: 0 };
+
+    let mut simplex = vec![x0.to_vec()];
+    for i in 0..n {
+        let mut vertex = x0.to_vec();
+        vertex[i] += options.initial_step.unwrap_or(if x0[i] != 0.0 { 0.05 * x0[i] } else { 0.00025 });
+        simplex.push(vertex);
+    }
+    let mut values = objective.evaluate(&simplex)?;
+
+    for iteration in 0..options.max_iterations {
+        let mut order: Vec<usize> = (0..=n).collect();
+        order.sort_by(|&i, &j| values[i].total_cmp(&values[j]));
+        simplex = order.iter().map(|&i| simplex[i].clone()).collect();
+        values = order.iter().map(|&i| values[i]).collect();
+
+        let spread = simplex[1..]
+            .iter()
+            .flat_map(|vertex| vertex.iter().zip(&simplex[0]).map(|(a, b)| (a - b).abs()))
+            .fold(0.0, f64::max);
+        let value_spread = values[1..].iter().map(|value| (value - values[0]).abs()).fold(0.0, f64::max);
+        if spread <= options.tolerance && value_spread <= options.tolerance {
+            return Ok(Minimum {
+                point: simplex.swap_remove(0),
+                value: values[0],
+                iterations: iteration,
+                evaluations: objective.evaluations,
+                converged: true,
+            });
+        }
+
+        let centroid: Vec<f64> = (0..n).map(|j| simplex[..n].iter().map(|vertex| vertex[j]).sum::<f64>() / n as f64).collect();
+        let worst = &simplex[n];
+        let reflected = combine(&centroid, worst, -1.0);
+        let reflected_value = objective.evaluate_one(reflected.clone())?;
+
+        if reflected_value < values[0] {
+            let expanded = combine(&centroid, worst, -2.0);
+            let expanded_value = objective.evaluate_one(expanded.clone())?;
+            if expanded_value < reflected_value {
+                (simplex[n], values[n]) = (expanded, expanded_value);
+            } else {
+                (simplex[n], values[n]) = (reflected, reflected_value);
+            }
+            continue;
+        }
+        if reflected_value < values[n - 1] {
+            (simplex[n], values[n]) = (reflected, reflected_value);
+            continue;
+        }
+
+        let (contracted, threshold) = if reflected_value < values[n] {
+            (combine(&centroid, &reflected, 0.5), reflected_value)
+        } else {
+            (combine(&centroid, worst, 0.5), values[n])
+        };
+        let contracted_value = objective.evaluate_one(contracted.clone())?;
+        if contracted_value < threshold {
+            (simplex[n], values[n]) = (contracted, contracted_value);
+            continue;
+        }
+
+        let best = simplex[0].clone();
+        let shrunk: Vec<Vec<f64>> = simplex[1..].iter().map(|vertex| combine(&best, vertex, 0.5)).collect();
+        let shrunk_values = objective.evaluate(&shrunk)?;
+        for (i, (vertex, value)) in shrunk.into_iter().zip(shrunk_values).enumerate() {
+            simplex[i + 1] = vertex;
+            values[i + 1] = value;
+        }
+    }
+
+    let best = (0..=n).min_by(|&i, &j| values[i].total_cmp(&values[j])).unwrap_or(0);
+    Ok(Minimum {
+        point: simplex.swap_remove(best),
+        value: values[best],
+        iterations: options.max_iterations,
+        evaluations: objective.evaluations,
+        converged: false,
+    })
+}
+
+pub fn nelder_mead<F>(f: F, x0: &[f64], options: OptimizeOptions) -> Result<Minimum>
+where F: Fn(&[f64]) -> f64 {
+    nelder_mead_batched(|points| points.iter().map(|point| f(point)).collect(), x0, options)
+}
+
+fn numeric_gradient<F>(objective: &mut Objective<F>, x: &[f64]) -> Result<Vec<f64>>
+where F: FnMut(&[Vec<f64>]) -> Vec<f64> {
+    let steps: Vec<f64> = x.iter().map(|value| f64::EPSILON.cbrt() * value.abs().max(1.0)).collect();
+    let points: Vec<Vec<f64>> = (0..x.len())
+        .flat_map(|i| {
+            [-1.0, 1.0].map(|sign| {
+                let mut point = x.to_vec();
+                point[i] += sign * steps[i];
+                point
+            })
+        })
+        .collect();
+    let values = objective.evaluate(&points)?;
+    Ok(values.chunks(2).zip(&steps).map(|(pair, step)| (pair[1] - pair[0]) / (2.0 * step)).collect())
+}
+
+pub fn bfgs_batched<F>(f: F, mut gradient: Option<BatchedGradient>, x0: &[f64],
+                       options: OptimizeOptions) -> Result<Minimum>
+where F: FnMut(&[Vec<f64>]) -> Vec<f64> {
+    check_start(x0)?;
+    let n = x0.len();
+    let mut objective = Objective { f, evaluations: 0 };
+    let mut compute_gradient = |objective: &mut Objective<F>, x: &[f64]| -> Result<Vec<f64>> {
+        let g = match gradient.as_mut() {
+            Some(gradient) => gradient(x),
+            None => numeric_gradient(objective, x)?,
+        };
+        if g.len() != n || g.iter().any(|value| !value.is_finite()) {
+            bail!("Gradient must return {} finite values", n);
+        }
+        Ok(g)
+    };
+
+    let mut x = x0.to_vec();
+    let mut value = objective.evaluate_one(x.clone())?;
+    let mut g = compute_gradient(&mut objective, &x)?;
+    let mut inverse_hessian = identity(n);
+
+    for iteration in 0..options.max_iterations {
+        if g.iter().fold(0.0, |acc: f64, value| acc.max(value.abs())) <= options.tolerance {
+            return Ok(Minimum { point: x, value, iterations: iteration, evaluations: objective.evaluations, converged: true });
+        }
+
+        let mut direction: Vec<f64> = inverse_hessian.iter().map(|row| -dot(row, &g)).collect();
+        let mut slope = dot(&direction, &g);
+        if slope >= 0.0 {
+            direction = g.iter().map(|value| -value).collect();
+            slope = -dot(&g, &g);
+            inverse_hessian = identity(n);
+        }
+
+        let mut step = options.initial_step.unwrap_or(1.0);
+        let mut accepted = None;
+        for _ in 0..60 {
+            let candidate: Vec<f64> = x.iter().zip(&direction).map(|(xi, di)| xi + step * di).collect();
+            let candidate_value = objective.evaluate_one(candidate.clone())?;
+            if candidate_value <= value + 1e-4 * step * slope {
+                accepted = Some((candidate, candidate_value));
+                break;
+            }
+            step *= 0.5;
+        }
+        let Some((x_next, value_next)) = accepted else {
+            return Ok(Minimum { point: x, value, iterations: iteration, evaluations: objective.evaluations, converged: false });
+        };
+
+        let g_next = compute_gradient(&mut objective, &x_next)?;
+        let s: Vec<f64> = x_next.iter().zip(&x).map(|(a, b)| a - b).collect();
+        let y: Vec<f64> = g_next.iter().zip(&g).map(|(a, b)| a - b).collect();
+        let sy = dot(&s, &y);
+        let step_size = s.iter().fold(0.0, |acc: f64, value| acc.max(value.abs()));
+
+        x = x_next;
+        value = value_next;
+        g = g_next;
+
+        if sy > 1e-12 {
+            let rho = 1.0 / sy;
+            let hy: Vec<f64> = inverse_hessian.iter().map(|row| dot(row, &y)).collect();
+            let yhy = dot(&y, &hy);
+            for i in 0..n {
+                for j in 0..n {
+                    inverse_hessian[i][j] += rho * ((1.0 + rho * yhy) * s[i] * s[j] - hy[i] * s[j] - s[i] * hy[j]);
+                }
+            }
+        }
+
+        let scale = x.iter().fold(1.0, |acc: f64, value| acc.max(value.abs()));
+        if step_size <= options.tolerance * scale {
+            return Ok(Minimum { point: x, value, iterations: iteration + 1, evaluations: objective.evaluations, converged: true });
+        }
+    }
+
+    Ok(Minimum { point: x, value, iterations: options.max_iterations, evaluations: objective.evaluations, converged: false })
+}
+
+pub fn bfgs<F>(f: F, gradient: Option<Gradient>, x0: &[f64], options: OptimizeOptions) -> Result<Minimum>
+where F: Fn(&[f64]) -> f64 {
+    let mut gradient = gradient.map(|gradient| move |x: &[f64]| gradient(x));
+    bfgs_batched(
+        |points| points.iter().map(|point| f(point)).collect(),
+        gradient.as_mut().map(|gradient| gradient as BatchedGradient),
+        x0,
+        options,
+    )
+}
@@ -0,0 +1,123 @@
+use anyhow::{bail, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootOptions {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for RootOptions {
+    fn default() -> Self {
+        RootOptions {
+            tolerance: 1e-12,
+            max_iterations: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Root {
+    pub x: f64,
+    pub value: f64,
+    pub iterations: usize,
+}
+
+fn evaluate<F: FnMut(f64) -> f64>(f: &mut F, x: f64) -> Result<f64> {
+    let value = f(x);
+    if value.is_nan() {
+        bail!("Function returned NaN at x = {}", x);
+    }
+    Ok(value)
+}
+
+pub fn bracket_root<F>(mut f: F, a: f64, b: f64, max_expansions: usize) -> Result<(f64, f64)>
+where F: FnMut(f64) -> f64 {
+    if a == b {
+        bail!("Initial bracket must have distinct endpoints");
+    }
+    let (mut a, mut b) = (a.min(b), a.max(b));
+    let (mut fa, mut fb) = (evaluate(&mut f, a)?, evaluate(&mut f, b)?);
+    for _ in 0..max_expansions {
+        if fa.signum() != fb.signum() || fa == 0.0 || fb == 0.0 {
+            return Ok((a, b));
+        }
+        let width = b - a;
+        if fa.abs() < fb.abs() {
+            a -= 1.6 * width;
+            fa = evaluate(&mut f, a)?;
+        } else {
+            b += 1.6 * width;
+            fb = evaluate(&mut f, b)?;
+        }
+    }
+    bail!("Could not bracket a root starting from [{}, {}]", a, b)
+}
+
+pub fn brent<F>(mut f: F, a: f64, b: f64, options: RootOptions) -> Result<Root>
+where F: FnMut(f64) -> f64 {
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (evaluate(&mut f, a)?, evaluate(&mut f, b)?);
+    if fa == 0.0 {
+        return Ok(Root { x: a, value: fa, iterations: 0 });
+    }
+    if fb == 0.0 {
+        return Ok(Root { x: b, value: fb, iterations: 0 });
+    }
+    if fa.signum() == fb.signum() {
+        bail!("Root is not bracketed: f({}) and f({}) have the same sign", a, b);
+    }
+
+    let (mut c, mut fc) = (b, fb);
+    let (mut d, mut e) = (b - a, b - a);
+
+    for iteration in 1..=options.max_iterations {
+        if fb.signum() == fc.signum() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            (a, b, c) = (b, c, b);
+            (fa, fb, fc) = (fb, fc, fb);
+        }
+
+        let tolerance = 2.0 * f64::EPSILON * b.abs() + 0.5 * options.tolerance;
+        let middle = 0.5 * (c - b);
+        if middle.abs() <= tolerance || fb == 0.0 {
+            return Ok(Root { x: b, value: fb, iterations: iteration });
+        }
+
+        if e.abs() >= tolerance && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * middle * s, 1.0 - s)
+            } else {
+                let (q, r) = (fa / fc, fb / fc);
+                (s * (2.0 * middle * q * (q - r) - (b - a) * (r - 1.0)), (q - 1.0) * (r - 1.0) * (s - 1.0))
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            let limit = (3.0 * middle * q - (tolerance * q).abs()).min((e * q).abs());
+            if 2.0 * p < limit {
+                e = d;
+                d = p / q;
+            } else {
+                d = middle;
+                e = d;
+            }
+        } else {
+            d = middle;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tolerance { d } else { tolerance.copysign(middle) };
+        fb = evaluate(&mut f, b)?;
+    }
+
+    bail!("Brent's method did not converge within {} iterations", options.max_iterations)
+}
@@ -0,0 +1,877 @@
+use anyhow::{bail, Result};
+use num_complex::Complex64;
+use super::linalg::{Matrix, Vector};
+use std::f64::consts::PI;
+use std::str::FromStr;
+
+fn radix2(data: &mut [Complex64], inverse: bool) {
+    let n = data.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let twiddles: Vec<Complex64> = (0..n / 2).map(|k| Complex64::from_polar(1.0, sign * 2.0 * PI * k as f64 / n as f64)).collect();
+    let mut size = 2;
+    while size <= n {
+        let stride = n / size;
+        for block in data.chunks_mut(size) {
+            let (low, high) = block.split_at_mut(size / 2);
+            for (k, (a, b)) in low.iter_mut().zip(high.iter_mut()).enumerate() {
+                let t = twiddles[k * stride] * *b;
+                *b = *a - t;
+                *a += t;
+            }
+        }
+        size *= 2;
+    }
+}
+
+fn bluestein(data: &mut [Complex64], inverse: bool) {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let chirp: Vec<Complex64> = (0..n)
+        .map(|k| Complex64::from_polar(1.0, sign * PI * ((k * k) % (2 * n)) as f64 / n as f64))
+        .collect();
+
+    let mut a = vec![Complex64::new(0.0, 0.0); m];
+    for (slot, (x, w)) in a.iter_mut().zip(data.iter().zip(&chirp)) {
+        *slot = x * w;
+    }
+    let mut b = vec![Complex64::new(0.0, 0.0); m];
+    b[0] = chirp[0].conj();
+    for k in 1..n {
+        b[k] = chirp[k].conj();
+        b[m - k] = chirp[k].conj();
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (x, y) in a.iter_mut().zip(&b) {
+        *x *= y;
+    }
+    radix2(&mut a, true);
+
+    let scale = 1.0 / m as f64;
+    for (slot, (x, w)) in data.iter_mut().zip(a.iter().zip(&chirp)) {
+        *slot = x * w * scale;
+    }
+}
+
+fn transform(data: &mut [Complex64], inverse: bool) {
+    match data.len() {
+        0 | 1 => {}
+        n if n.is_power_of_two() => radix2(data, inverse),
+        _ => bluestein(data, inverse),
+    }
+}
+
+pub fn fft_complex(input: &[Complex64]) -> Vec<Complex64> {
+    let mut data = input.to_vec();
+    transform(&mut data, false);
+    data
+}
+
+pub fn ifft_complex(input: &[Complex64]) -> Vec<Complex64> {
+    let mut data = input.to_vec();
+    transform(&mut data, true);
+    let scale = 1.0 / data.len().max(1) as f64;
+    data.iter_mut().for_each(|value| *value *= scale);
+    data
+}
+
+pub fn rfft(signal: &[f64]) -> Vec<Complex64> {
+    let n = signal.len();
+    if n < 2 || !n.is_multiple_of(2) {
+        let spectrum = fft_complex(&signal.iter().map(|&x| Complex64::new(x, 0.0)).collect::<Vec<_>>());
+        return spectrum.into_iter().take(n / 2 + 1).collect();
+    }
+
+    let half = n / 2;
+    let packed: Vec<Complex64> = signal.chunks(2).map(|pair| Complex64::new(pair[0], pair[1])).collect();
+    let z = fft_complex(&packed);
+    (0..=half)
+        .map(|k| {
+            let (zk, zr) = (z[k % half], z[(half - k) % half].conj());
+            let even = (zk + zr) * 0.5;
+            let odd = (zk - zr) * Complex64::new(0.0, -0.5);
+            even + Complex64::from_polar(1.0, -2.0 * PI * k as f64 / n as f64) * odd
+        })
+        .collect()
+}
+
+pub fn irfft(spectrum: &[Complex64], n: usize) -> Result<Vec<f64>> {
+    if spectrum.is_empty() || n == 0 {
+        bail!("Spectrum and output length must be non-empty");
+    }
+    let mut full = vec![Complex64::new(0.0, 0.0); n];
+    for (k, value) in spectrum.iter().take(n / 2 + 1).enumerate() {
+        full[k] = *value;
+        if k > 0 && n - k != k {
+            full[n - k] = value.conj();
+        }
+    }
+    Ok(ifft_complex(&full).into_iter().map(|value| value.re).collect())
+}
+
+pub fn fft_frequencies(n: usize, sample_rate: f64) -> Vec<f64> {
+    let step = sample_rate / n.max(1) as f64;
+    (0..n).map(|k| if k < n.div_ceil(2) { k as f64 } else { k as f64 - n as f64 } * step).collect()
+}
+
+pub fn rfft_frequencies(n: usize, sample_rate: f64) -> Vec<f64> {
+    let step = sample_rate / n.max(1) as f64;
+    (0..=n / 2).map(|k| k as f64 * step).collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Window {
+    Rectangular,
+    #[default]
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+impl Window {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Window::Rectangular => "rectangular",
+            Window::Hann => "hann",
+            Window::Hamming => "hamming",
+            Window::Blackman => "blackman",
+        }
+    }
+
+    pub fn coefficients(&self, length: usize, periodic: bool) -> Vec<f64> {
+        if length == 1 {
+            return vec![1.0];
+        }
+        let denominator = if periodic { length } else { length - 1 } as f64;
+        (0..length)
+            .map(|i| {
+                let phase = 2.0 * PI * i as f64 / denominator;
+                match self {
+                    Window::Rectangular => 1.0,
+                    Window::Hann => 0.5 - 0.5 * phase.cos(),
+                    Window::Hamming => 0.54 - 0.46 * phase.cos(),
+                    Window::Blackman => 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos(),
+                }
+            })
+            .collect()
+    }
+
+    pub fn apply(&self, signal: &[f64]) -> Vec<f64> {
+        signal.iter().zip(self.coefficients(signal.len(), false)).map(|(x, w)| x * w).collect()
+    }
+}
+
+impl FromStr for Window {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "rectangular" | "rect" | "boxcar" | "none" => Ok(Window::Rectangular),
+            "hann" | "hanning" => Ok(Window::Hann),
+            "hamming" => Ok(Window::Hamming),
+            "blackman" => Ok(Window::Blackman),
+            _ => bail!("Unsupported window: {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StftOptions {
+    pub frame_size: usize,
+    pub hop_size: Option<usize>,
+    pub window: Window,
+    pub center: bool,
+}
+
+impl Default for StftOptions {
+    fn default() -> Self {
+        StftOptions {
+            frame_size: 512,
+            hop_size: None,
+            window: Window::Hann,
+            center: true,
+        }
+    }
+}
+
+impl StftOptions {
+    pub fn hop(&self) -> usize {
+        self.hop_size.unwrap_or(self.frame_size / 4).max(1)
+    }
+
+    fn padding(&self) -> usize {
+        if self.center { self.frame_size / 2 } else { 0 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stft {
+    pub frames: Vec<Vec<Complex64>>,
+    pub options: StftOptions,
+    pub signal_length: usize,
+}
+
+impl Stft {
+    pub fn frequencies(&self, sample_rate: f64) -> Vec<f64> {
+        rfft_frequencies(self.options.frame_size, sample_rate)
+    }
+
+    pub fn times(&self, sample_rate: f64) -> Vec<f64> {
+        let offset = if self.options.center { 0.0 } else { self.options.frame_size as f64 / 2.0 };
+        (0..self.frames.len()).map(|i| (i as f64 * self.options.hop() as f64 + offset) / sample_rate).collect()
+    }
+
+    pub fn inverse(&self) -> Result<Vec<f64>> {
+        let StftOptions { frame_size, window, .. } = self.options;
+        let hop = self.options.hop();
+        let padding = self.options.padding();
+        let window = window.coefficients(frame_size, true);
+        let total = frame_size + hop * self.frames.len().saturating_sub(1);
+        let mut output = vec![0.0; total];
+        let mut weights = vec![0.0; total];
+
+        for (i, frame) in self.frames.iter().enumerate() {
+            let samples = irfft(frame, frame_size)?;
+            for (j, (sample, w)) in samples.iter().zip(&window).enumerate() {
+                output[i * hop + j] += sample * w;
+                weights[i * hop + j] += w * w;
+            }
+        }
+        Ok(output
+            .iter()
+            .zip(&weights)
+            .skip(padding)
+            .take(self.signal_length)
+            .map(|(value, weight)| if *weight > 1e-10 { value / weight } else { 0.0 })
+            .collect())
+    }
+}
+
+pub fn stft(signal: &[f64], options: StftOptions) -> Result<Stft> {
+    if signal.is_empty() {
+        bail!("Signal must not be empty");
+    }
+    if options.frame_size < 2 {
+        bail!("Frame size must be at least 2");
+    }
+    let frame_size = options.frame_size;
+    let hop = options.hop();
+    let padding = options.padding();
+    let padded_length = (signal.len() + 2 * padding).max(frame_size);
+    let count = 1 + (padded_length - frame_size).div_ceil(hop);
+    let mut padded = vec![0.0; frame_size + hop * (count - 1)];
+    padded[padding..padding + signal.len()].copy_from_slice(signal);
+
+    let window = options.window.coefficients(frame_size, true);
+    let frames = (0..count)
+        .map(|i| {
+            let frame: Vec<f64> = padded[i * hop..i * hop + frame_size].iter().zip(&window).map(|(x, w)| x * w).collect();
+            rfft(&frame)
+        })
+        .collect();
+    Ok(Stft { frames, options, signal_length: signal.len() })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spectrogram {
+    pub times: Vec<f64>,
+    pub frequencies: Vec<f64>,
+    pub power: Vec<Vec<f64>>,
+}
+
+impl Spectrogram {
+    pub fn decibels(&self, dynamic_range: Option<f64>) -> Vec<Vec<f64>> {
+        let mut db: Vec<Vec<f64>> = self.power.iter()
+            .map(|frame| frame.iter().map(|p| 10.0 * p.max(1e-20).log10()).collect())
+            .collect();
+        if let Some(range) = dynamic_range {
+            let floor = db.iter().flatten().fold(f64::NEG_INFINITY, |acc, &value| acc.max(value)) - range.abs();
+            db.iter_mut().flatten().for_each(|value| *value = value.max(floor));
+        }
+        db
+    }
+}
+
+pub fn spectrogram(signal: &[f64], sample_rate: f64, options: StftOptions) -> Result<Spectrogram> {
+    if sample_rate <= 0.0 || !sample_rate.is_finite() {
+        bail!("Sample rate must be positive");
+    }
+    let transform = stft(signal, options)?;
+    let window_sum: f64 = options.window.coefficients(options.frame_size, true).iter().sum();
+    let scale = 1.0 / (window_sum * window_sum);
+    let bins = options.frame_size / 2 + 1;
+    let nyquist = if options.frame_size.is_multiple_of(2) { bins - 1 } else { bins };
+    let power = transform.frames
+        .iter()
+        .map(|frame| {
+            frame.iter().enumerate()
+                .map(|(k, value)| value.norm_sqr() * scale * if k == 0 || k == nyquist { 1.0 } else { 2.0 })
+                .collect()
+        })
+        .collect();
+    Ok(Spectrogram {
+        times: transform.times(sample_rate),
+        frequencies: transform.frequencies(sample_rate),
+        power,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterType {
+    LowPass,
+    HighPass,
+    BandPass,
+    BandStop,
+}
+
+impl FilterType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FilterType::LowPass => "lowpass",
+            FilterType::HighPass => "highpass",
+            FilterType::BandPass => "bandpass",
+            FilterType::BandStop => "bandstop",
+        }
+    }
+
+    fn normalized_cutoffs(&self, cutoffs: &[f64], sample_rate: f64) -> Result<Vec<f64>> {
+        let expected = match self {
+            FilterType::LowPass | FilterType::HighPass => 1,
+            FilterType::BandPass | FilterType::BandStop => 2,
+        };
+        if cutoffs.len() != expected {
+            bail!("A {} filter needs {} cutoff frequencies, got {}", self.name(), expected, cutoffs.len());
+        }
+        if sample_rate <= 0.0 || !sample_rate.is_finite() {
+            bail!("Sample rate must be positive");
+        }
+        let normalized: Vec<f64> = cutoffs.iter().map(|cutoff| cutoff / (sample_rate / 2.0)).collect();
+        if normalized.iter().any(|&w| w.is_nan() || w <= 0.0 || w >= 1.0) {
+            bail!("Cutoff frequencies must lie strictly between 0 and the Nyquist frequency {}", sample_rate / 2.0);
+        }
+        if expected == 2 && normalized[0] >= normalized[1] {
+            bail!("Band edges must be increasing");
+        }
+        Ok(normalized)
+    }
+}
+
+impl FromStr for FilterType {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "lowpass" | "low" | "lp" => Ok(FilterType::LowPass),
+            "highpass" | "high" | "hp" => Ok(FilterType::HighPass),
+            "bandpass" | "band" | "bp" => Ok(FilterType::BandPass),
+            "bandstop" | "notch" | "bs" => Ok(FilterType::BandStop),
+            _ => bail!("Unsupported filter type: {}", name),
+        }
+    }
+}
+
+fn sinc(x: f64) -> f64 {
+    if x == 0.0 { 1.0 } else { (PI * x).sin() / (PI * x) }
+}
+
+pub fn design_fir(taps: usize, filter_type: FilterType, cutoffs: &[f64], sample_rate: f64, window: Window) -> Result<Vec<f64>> {
+    let w = filter_type.normalized_cutoffs(cutoffs, sample_rate)?;
+    if taps == 0 {
+        bail!("Filter must have at least one tap");
+    }
+    let bands = match filter_type {
+        FilterType::LowPass => vec![(0.0, w[0])],
+        FilterType::HighPass => vec![(w[0], 1.0)],
+        FilterType::BandPass => vec![(w[0], w[1])],
+        FilterType::BandStop => vec![(0.0, w[0]), (w[1], 1.0)],
+    };
+    if taps.is_multiple_of(2) && bands.iter().any(|&(_, right)| right == 1.0) {
+        bail!("A {} FIR filter needs an odd number of taps", filter_type.name());
+    }
+
+    let center = (taps - 1) as f64 / 2.0;
+    let coefficients = window.coefficients(taps, false);
+    let mut h: Vec<f64> = coefficients
+        .iter()
+        .enumerate()
+        .map(|(i, w)| {
+            let m = i as f64 - center;
+            w * bands.iter().map(|&(left, right)| right * sinc(right * m) - left * sinc(left * m)).sum::<f64>()
+        })
+        .collect();
+
+    let (left, right) = bands[0];
+    let reference = if left == 0.0 { 0.0 } else if right == 1.0 { 1.0 } else { 0.5 * (left + right) };
+    let gain: f64 = h.iter().enumerate().map(|(i, value)| value * (PI * (i as f64 - center) * reference).cos()).sum();
+    h.iter_mut().for_each(|value| *value /= gain);
+    Ok(h)
+}
+
+fn normalize_coefficients(b: &[f64], a: &[f64]) -> Result<(Vec<f64>, Vec<f64>)> {
+    if b.is_empty() || a.is_empty() {
+        bail!("Filter coefficients must not be empty");
+    }
+    if a[0] == 0.0 {
+        bail!("First denominator coefficient must be non-zero");
+    }
+    let order = b.len().max(a.len());
+    let mut nb: Vec<f64> = b.iter().map(|value| value / a[0]).collect();
+    let mut na: Vec<f64> = a.iter().map(|value| value / a[0]).collect();
+    nb.resize(order, 0.0);
+    na.resize(order, 0.0);
+    Ok((nb, na))
+}
+
+fn filter_with_state(b: &[f64], a: &[f64], signal: &[f64], state: &mut [f64]) -> Vec<f64> {
+    let order = state.len();
+    signal
+        .iter()
+        .map(|&x| {
+            let y = b[0] * x + state.first().copied().unwrap_or(0.0);
+            for i in 0..order {
+                let next = if i + 1 < order { state[i + 1] } else { 0.0 };
+                state[i] = next + b[i + 1] * x - a[i + 1] * y;
+            }
+            y
+        })
+        .collect()
+}
+
+fn steady_state(b: &[f64], a: &[f64]) -> Result<Vec<f64>> {
+    let order = b.len() - 1;
+    if order == 0 {
+        return Ok(Vec::new());
+    }
+    let system = Matrix::from_fn(order, order, |i, j| {
+        let identity = if i == j { 1.0 } else { 0.0 };
+        let companion = if j == 0 { -a[i + 1] } else if j == i + 1 { 1.0 } else { 0.0 };
+        identity - companion
+    });
+    let rhs = Vector::from((1..=order).map(|i| b[i] - a[i] * b[0]).collect::<Vec<f64>>());
+    Ok(system.solve(&rhs)?.into_vec())
+}
+
+pub fn lfilter(b: &[f64], a: &[f64], signal: &[f64]) -> Result<Vec<f64>> {
+    let (b, a) = normalize_coefficients(b, a)?;
+    let mut state = vec![0.0; b.len() - 1];
+    Ok(filter_with_state(&b, &a, signal, &mut state))
+}
+
+pub fn fir_filter(coefficients: &[f64], signal: &[f64]) -> Result<Vec<f64>> {
+    lfilter(coefficients, &[1.0], signal)
+}
+
+fn odd_extension(signal: &[f64], padding: usize) -> Result<Vec<f64>> {
+    let n = signal.len();
+    if n <= padding {
+        bail!("Signal must be longer than {} samples for zero-phase filtering", padding);
+    }
+    let (first, last) = (signal[0], signal[n - 1]);
+    let mut extended: Vec<f64> = signal[1..=padding].iter().rev().map(|x| 2.0 * first - x).collect();
+    extended.extend_from_slice(signal);
+    extended.extend(signal[n - 1 - padding..n - 1].iter().rev().map(|x| 2.0 * last - x));
+    Ok(extended)
+}
+
+fn zero_phase<F>(signal: &[f64], padding: usize, mut pass: F) -> Result<Vec<f64>>
+where F: FnMut(&[f64]) -> Vec<f64> {
+    let extended = odd_extension(signal, padding)?;
+    let mut forward = pass(&extended);
+    forward.reverse();
+    let mut backward = pass(&forward);
+    backward.reverse();
+    Ok(backward[padding..padding + signal.len()].to_vec())
+}
+
+pub fn filtfilt(b: &[f64], a: &[f64], signal: &[f64]) -> Result<Vec<f64>> {
+    let padding = 3 * b.len().max(a.len());
+    let (b, a) = normalize_coefficients(b, a)?;
+    let initial = steady_state(&b, &a)?;
+    zero_phase(signal, padding, |x| {
+        let mut state: Vec<f64> = initial.iter().map(|value| value * x[0]).collect();
+        filter_with_state(&b, &a, x, &mut state)
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Biquad {
+    pub b: [f64; 3],
+    pub a: [f64; 3],
+}
+
+impl Biquad {
+    pub fn new(b: [f64; 3], a: [f64; 3]) -> Result<Self> {
+        if a[0] == 0.0 {
+            bail!("First denominator coefficient must be non-zero");
+        }
+        Ok(Biquad { b: b.map(|value| value / a[0]), a: a.map(|value| value / a[0]) })
+    }
+
+    pub fn response(&self, frequency: f64, sample_rate: f64) -> Complex64 {
+        let z = Complex64::from_polar(1.0, -2.0 * PI * frequency / sample_rate);
+        let polynomial = |c: &[f64; 3]| c[0] + z * (c[1] + z * c[2]);
+        polynomial(&self.b) / polynomial(&self.a)
+    }
+
+    pub fn filter(&self, signal: &[f64]) -> Vec<f64> {
+        filter_with_state(&self.b, &self.a, signal, &mut [0.0; 2])
+    }
+}
+
+pub fn sos_filter(sections: &[Biquad], signal: &[f64]) -> Vec<f64> {
+    sections.iter().fold(signal.to_vec(), |x, section| section.filter(&x))
+}
+
+pub fn sos_filtfilt(sections: &[Biquad], signal: &[f64]) -> Result<Vec<f64>> {
+    if sections.is_empty() {
+        bail!("Filter must have at least one section");
+    }
+    let mut initial = Vec::with_capacity(sections.len());
+    let mut scale = 1.0;
+    for section in sections {
+        initial.push(steady_state(&section.b, &section.a)?.into_iter().map(|value| value * scale).collect::<Vec<f64>>());
+        scale *= section.b.iter().sum::<f64>() / section.a.iter().sum::<f64>();
+    }
+    let padding = 3 * (2 * sections.len() + 1);
+    zero_phase(signal, padding, |x| {
+        let mut output = x.to_vec();
+        for (section, zi) in sections.iter().zip(&initial) {
+            let mut state: Vec<f64> = zi.iter().map(|value| value * x[0]).collect();
+            output = filter_with_state(&section.b, &section.a, &output, &mut state);
+        }
+        output
+    })
+}
+
+pub fn frequency_response(b: &[f64], a: &[f64], frequencies: &[f64], sample_rate: f64) -> Vec<Complex64> {
+    let polynomial = |coefficients: &[f64], z: Complex64| coefficients.iter().rev().fold(Complex64::new(0.0, 0.0), |acc, &c| acc * z + c);
+    frequencies
+        .iter()
+        .map(|&frequency| {
+            let z = Complex64::from_polar(1.0, -2.0 * PI * frequency / sample_rate);
+            polynomial(b, z) / polynomial(a, z)
+        })
+        .collect()
+}
+
+pub fn sos_frequency_response(sections: &[Biquad], frequencies: &[f64], sample_rate: f64) -> Vec<Complex64> {
+    frequencies
+        .iter()
+        .map(|&frequency| sections.iter().map(|section| section.response(frequency, sample_rate)).product())
+        .collect()
+}
+
+fn pair_roots(roots: &[Complex64]) -> Vec<(Complex64, Option<Complex64>)> {
+    let mut complex: Vec<Complex64> = roots.iter().copied().filter(|root| root.im > 1e-12).collect();
+    let mut real: Vec<f64> = roots.iter().filter(|root| root.im.abs() <= 1e-12).map(|root| root.re).collect();
+    complex.sort_by(|x, y| (1.0 - x.norm()).abs().total_cmp(&(1.0 - y.norm()).abs()));
+    real.sort_by(|x, y| (1.0 - x.abs()).abs().total_cmp(&(1.0 - y.abs()).abs()));
+
+    let mut pairs: Vec<(Complex64, Option<Complex64>)> = complex.into_iter().map(|root| (root, Some(root.conj()))).collect();
+    for chunk in real.chunks(2) {
+        pairs.push((Complex64::new(chunk[0], 0.0), chunk.get(1).map(|&re| Complex64::new(re, 0.0))));
+    }
+    pairs
+}
+
+fn quadratic(pair: (Complex64, Option<Complex64>)) -> [f64; 3] {
+    match pair {
+        (root, Some(other)) => [1.0, -(root + other).re, (root * other).re],
+        (root, None) => [1.0, -root.re, 0.0],
+    }
+}
+
+pub fn butterworth(order: usize, filter_type: FilterType, cutoffs: &[f64], sample_rate: f64) -> Result<Vec<Biquad>> {
+    if order == 0 {
+        bail!("Filter order must be at least 1");
+    }
+    let normalized = filter_type.normalized_cutoffs(cutoffs, sample_rate)?;
+    let fs2 = 4.0;
+    let warped: Vec<f64> = normalized.iter().map(|w| fs2 * (PI * w / 2.0).tan()).collect();
+
+    let prototype: Vec<Complex64> = (0..order)
+        .map(|k| Complex64::from_polar(1.0, PI * (2 * k + order + 1) as f64 / (2 * order) as f64))
+        .collect();
+    let zero = Complex64::new(0.0, 0.0);
+    let pole_product = |poles: &[Complex64]| poles.iter().map(|p| -p).product::<Complex64>();
+
+    let (zeros, poles, gain) = match filter_type {
+        FilterType::LowPass => {
+            let poles: Vec<Complex64> = prototype.iter().map(|p| p * warped[0]).collect();
+            (Vec::new(), poles, warped[0].powi(order as i32))
+        }
+        FilterType::HighPass => {
+            let poles: Vec<Complex64> = prototype.iter().map(|p| warped[0] / p).collect();
+            (vec![zero; order], poles, (1.0 / pole_product(&prototype)).re)
+        }
+        FilterType::BandPass | FilterType::BandStop => {
+            let bandwidth = warped[1] - warped[0];
+            let center_sq = warped[0] * warped[1];
+            let scaled: Vec<Complex64> = if filter_type == FilterType::BandPass {
+                prototype.iter().map(|p| p * bandwidth / 2.0).collect()
+            } else {
+                prototype.iter().map(|p| (bandwidth / 2.0) / p).collect()
+            };
+            let poles: Vec<Complex64> = scaled
+                .iter()
+                .flat_map(|&p| {
+                    let offset = (p * p - center_sq).sqrt();
+                    [p + offset, p - offset]
+                })
+                .collect();
+            if filter_type == FilterType::BandPass {
+                (vec![zero; order], poles, bandwidth.powi(order as i32))
+            } else {
+                let notch = Complex64::new(0.0, center_sq.sqrt());
+                let zeros = (0..order).flat_map(|_| [notch, notch.conj()]).collect();
+                (zeros, poles, (1.0 / pole_product(&prototype)).re)
+            }
+        }
+    };
+
+    let fs2 = Complex64::new(fs2, 0.0);
+    let digital = |roots: &[Complex64]| -> Vec<Complex64> { roots.iter().map(|r| (fs2 + r) / (fs2 - r)).collect() };
+    let mut digital_zeros = digital(&zeros);
+    let digital_poles = digital(&poles);
+    let difference_gain = zeros.iter().map(|z| fs2 - z).product::<Complex64>() / poles.iter().map(|p| fs2 - p).product::<Complex64>();
+    let gain = gain * difference_gain.re;
+    digital_zeros.resize(digital_poles.len(), Complex64::new(-1.0, 0.0));
+
+    let pole_pairs = pair_roots(&digital_poles);
+    let mut zero_pairs = pair_roots(&digital_zeros);
+    let mut sections = Vec::with_capacity(pole_pairs.len());
+    for (index, poles) in pole_pairs.into_iter().enumerate() {
+        let nearest = zero_pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, zeros)| zeros.1.is_some() == poles.1.is_some())
+            .min_by(|(_, x), (_, y)| (x.0 - poles.0).norm().total_cmp(&(y.0 - poles.0).norm()))
+            .map_or(0, |(i, _)| i);
+        let zeros = zero_pairs.swap_remove(nearest);
+        let mut b = quadratic(zeros);
+        if index == 0 {
+            b = b.map(|value| value * gain);
+        }
+        sections.push(Biquad::new(b, quadratic(poles))?);
+    }
+    Ok(sections)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConvolutionMode {
+    #[default]
+    Full,
+    Same,
+    Valid,
+}
+
+impl FromStr for ConvolutionMode {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "full" => Ok(ConvolutionMode::Full),
+            "same" => Ok(ConvolutionMode::Same),
+            "valid" => Ok(ConvolutionMode::Valid),
+            _ => bail!("Unsupported convolution mode: {}", name),
+        }
+    }
+}
+
+pub fn convolve(a: &[f64], b: &[f64], mode: ConvolutionMode) -> Result<Vec<f64>> {
+    if a.is_empty() || b.is_empty() {
+        bail!("Convolution inputs must not be empty");
+    }
+    let length = a.len() + b.len() - 1;
+    let size = length.next_power_of_two().max(2);
+    let padded = |x: &[f64]| {
+        let mut padded = x.to_vec();
+        padded.resize(size, 0.0);
+        rfft(&padded)
+    };
+    let product: Vec<Complex64> = padded(a).iter().zip(padded(b)).map(|(x, y)| x * y).collect();
+    let full = irfft(&product, size)?;
+
+    let (start, count) = match mode {
+        ConvolutionMode::Full => (0, length),
+        ConvolutionMode::Same => ((b.len() - 1) / 2, a.len()),
+        ConvolutionMode::Valid => (a.len().min(b.len()) - 1, a.len().max(b.len()) - a.len().min(b.len()) + 1),
+    };
+    Ok(full[start..start + count].to_vec())
+}
+
+pub fn correlate(a: &[f64], b: &[f64], mode: ConvolutionMode) -> Result<Vec<f64>> {
+    let reversed: Vec<f64> = b.iter().rev().copied().collect();
+    convolve(a, &reversed, mode)
+}
+
+pub fn resample(signal: &[f64], length: usize) -> Result<Vec<f64>> {
+    let n = signal.len();
+    if n == 0 || length == 0 {
+        bail!("Signal and target length must be non-empty");
+    }
+    if length == n {
+        return Ok(signal.to_vec());
+    }
+    let spectrum = rfft(signal);
+    let shared = n.min(length);
+    let mut resized = vec![Complex64::new(0.0, 0.0); length / 2 + 1];
+    resized[..shared / 2 + 1].copy_from_slice(&spectrum[..shared / 2 + 1]);
+    if shared.is_multiple_of(2) {
+        resized[shared / 2] *= if length < n { 2.0 } else { 0.5 };
+    }
+    let scale = length as f64 / n as f64;
+    Ok(irfft(&resized, length)?.into_iter().map(|value| value * scale).collect())
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+pub fn resample_poly(signal: &[f64], up: usize, down: usize) -> Result<Vec<f64>> {
+    if signal.is_empty() {
+        bail!("Signal must not be empty");
+    }
+    if up == 0 || down == 0 {
+        bail!("Resampling factors must be positive");
+    }
+    let divisor = gcd(up, down);
+    let (up, down) = (up / divisor, down / divisor);
+    if up == 1 && down == 1 {
+        return Ok(signal.to_vec());
+    }
+
+    let factor = up.max(down);
+    let half = 10 * factor;
+    let taps = 2 * half + 1;
+    let cutoff = 1.0 / factor as f64;
+    let h: Vec<f64> = design_fir(taps, FilterType::LowPass, &[cutoff], 2.0, Window::Hamming)?
+        .into_iter()
+        .map(|value| value * up as f64)
+        .collect();
+
+    let upsampled = signal.len() * up;
+    let length = upsampled.div_ceil(down);
+    Ok((0..length)
+        .map(|i| {
+            let position = i * down + half;
+            let mut sum = 0.0;
+            let mut k = position % up;
+            while k < taps && k <= position {
+                let j = (position - k) / up;
+                if j < signal.len() {
+                    sum += h[k] * signal[j];
+                }
+                k += up;
+            }
+            sum
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PeakOptions {
+    pub min_height: Option<f64>,
+    pub min_prominence: Option<f64>,
+    pub min_distance: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Peak {
+    pub index: usize,
+    pub value: f64,
+    pub prominence: f64,
+    pub left_base: usize,
+    pub right_base: usize,
+}
+
+fn local_maxima(signal: &[f64]) -> Vec<usize> {
+    let mut peaks = Vec::new();
+    let mut i = 1;
+    while i + 1 < signal.len() {
+        if signal[i - 1] < signal[i] {
+            let mut ahead = i + 1;
+            while ahead + 1 < signal.len() && signal[ahead] == signal[i] {
+                ahead += 1;
+            }
+            if signal[ahead] < signal[i] {
+                peaks.push((i + ahead - 1) / 2);
+                i = ahead;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    peaks
+}
+
+fn prominence(signal: &[f64], index: usize) -> (f64, usize, usize) {
+    let height = signal[index];
+    let (mut left_min, mut left_base) = (height, index);
+    for (i, &value) in signal[..=index].iter().enumerate().rev() {
+        if value > height {
+            break;
+        }
+        if value < left_min {
+            (left_min, left_base) = (value, i);
+        }
+    }
+    let (mut right_min, mut right_base) = (height, index);
+    for (i, &value) in signal.iter().enumerate().skip(index) {
+        if value > height {
+            break;
+        }
+        if value < right_min {
+            (right_min, right_base) = (value, i);
+        }
+    }
+    (height - left_min.max(right_min), left_base, right_base)
+}
+
+pub fn find_peaks(signal: &[f64], options: PeakOptions) -> Vec<Peak> {
+    let mut indices = local_maxima(signal);
+    if let Some(min_height) = options.min_height {
+        indices.retain(|&i| signal[i] >= min_height);
+    }
+
+    if let Some(distance) = options.min_distance.filter(|&distance| distance > 1) {
+        let mut keep = vec![true; indices.len()];
+        let mut order: Vec<usize> = (0..indices.len()).collect();
+        order.sort_by(|&x, &y| signal[indices[y]].total_cmp(&signal[indices[x]]));
+        for &current in &order {
+            if !keep[current] {
+                continue;
+            }
+            let mut other = current;
+            while other > 0 && indices[current] - indices[other - 1] < distance {
+                other -= 1;
+                keep[other] = false;
+            }
+            other = current + 1;
+            while other < indices.len() && indices[other] - indices[current] < distance {
+                keep[other] = false;
+                other += 1;
+            }
+        }
+        indices = indices.into_iter().zip(keep).filter(|(_, keep)| *keep).map(|(index, _)| index).collect();
+    }
+
+    indices
+        .into_iter()
+        .map(|index| {
+            let (prominence, left_base, right_base) = prominence(signal, index);
+            Peak { index, value: signal[index], prominence, left_base, right_base }
+        })
+        .filter(|peak| options.min_prominence.is_none_or(|min| peak.prominence >= min))
+        .collect()
+}
@@ -0,0 +1,284 @@
+use anyhow::{bail, Result};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuantileMethod {
+    #[default]
+    Linear,
+    Lower,
+    Higher,
+    Nearest,
+    Midpoint,
+    Hazen,
+    Weibull,
+    MedianUnbiased,
+}
+
+impl QuantileMethod {
+    pub fn name(&self) -> &'static str {
+        match self {
+            QuantileMethod::Linear => "linear",
+            QuantileMethod::Lower => "lower",
+            QuantileMethod::Higher => "higher",
+            QuantileMethod::Nearest => "nearest",
+            QuantileMethod::Midpoint => "midpoint",
+            QuantileMethod::Hazen => "hazen",
+            QuantileMethod::Weibull => "weibull",
+            QuantileMethod::MedianUnbiased => "median-unbiased",
+        }
+    }
+
+    fn position(&self, n: usize, p: f64) -> f64 {
+        let n = n as f64;
+        let position = match self {
+            QuantileMethod::Hazen => n * p - 0.5,
+            QuantileMethod::Weibull => (n + 1.0) * p - 1.0,
+            QuantileMethod::MedianUnbiased => (n + 1.0 / 3.0) * p - 2.0 / 3.0,
+            _ => (n - 1.0) * p,
+        };
+        position.clamp(0.0, n - 1.0)
+    }
+}
+
+impl FromStr for QuantileMethod {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "linear" | "r7" | "inclusive" => Ok(QuantileMethod::Linear),
+            "lower" => Ok(QuantileMethod::Lower),
+            "higher" => Ok(QuantileMethod::Higher),
+            "nearest" => Ok(QuantileMethod::Nearest),
+            "midpoint" => Ok(QuantileMethod::Midpoint),
+            "hazen" | "r5" => Ok(QuantileMethod::Hazen),
+            "weibull" | "r6" | "exclusive" => Ok(QuantileMethod::Weibull),
+            "median-unbiased" | "median_unbiased" | "r8" => Ok(QuantileMethod::MedianUnbiased),
+            _ => bail!("Unsupported quantile method: {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VarianceMode {
+    Population,
+    #[default]
+    Sample,
+}
+
+impl VarianceMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VarianceMode::Population => "population",
+            VarianceMode::Sample => "sample",
+        }
+    }
+
+    fn ddof(&self) -> usize {
+        match self {
+            VarianceMode::Population => 0,
+            VarianceMode::Sample => 1,
+        }
+    }
+}
+
+impl FromStr for VarianceMode {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "population" | "pop" => Ok(VarianceMode::Population),
+            "sample" => Ok(VarianceMode::Sample),
+            _ => bail!("Unsupported variance mode: {}", name),
+        }
+    }
+}
+
+pub fn sorted(data: &[f64]) -> Vec<f64> {
+    let mut values = data.to_vec();
+    values.sort_by(f64::total_cmp);
+    values
+}
+
+pub fn mean(data: &[f64]) -> Option<f64> {
+    (!data.is_empty()).then(|| data.iter().sum::<f64>() / data.len() as f64)
+}
+
+fn central_moment(data: &[f64], mean: f64, power: i32) -> f64 {
+    data.iter().map(|&x| (x - mean).powi(power)).sum::<f64>() / data.len() as f64
+}
+
+pub fn variance(data: &[f64], mode: VarianceMode) -> Option<f64> {
+    let mean = mean(data)?;
+    let denominator = data.len().checked_sub(mode.ddof()).filter(|&d| d > 0)?;
+    Some(data.iter().map(|&x| (x - mean).powi(2)).sum::<f64>() / denominator as f64)
+}
+
+pub fn std_dev(data: &[f64], mode: VarianceMode) -> Option<f64> {
+    variance(data, mode).map(f64::sqrt)
+}
+
+pub fn skewness(data: &[f64], mode: VarianceMode) -> Option<f64> {
+    let n = data.len() as f64;
+    let mean = mean(data)?;
+    let m2 = central_moment(data, mean, 2);
+    if m2 == 0.0 {
+        return None;
+    }
+    let g1 = central_moment(data, mean, 3) / m2.powf(1.5);
+    match mode {
+        VarianceMode::Population => Some(g1),
+        VarianceMode::Sample if n > 2.0 => Some(g1 * (n * (n - 1.0)).sqrt() / (n - 2.0)),
+        VarianceMode::Sample => None,
+    }
+}
+
+pub fn kurtosis(data: &[f64], mode: VarianceMode) -> Option<f64> {
+    let n = data.len() as f64;
+    let mean = mean(data)?;
+    let m2 = central_moment(data, mean, 2);
+    if m2 == 0.0 {
+        return None;
+    }
+    let g2 = central_moment(data, mean, 4) / (m2 * m2) - 3.0;
+    match mode {
+        VarianceMode::Population => Some(g2),
+        VarianceMode::Sample if n > 3.0 => Some(((n + 1.0) * g2 + 6.0) * (n - 1.0) / ((n - 2.0) * (n - 3.0))),
+        VarianceMode::Sample => None,
+    }
+}
+
+pub fn quantile_sorted(sorted: &[f64], p: f64, method: QuantileMethod) -> Result<f64> {
+    if sorted.is_empty() {
+        bail!("Cannot compute a quantile of an empty dataset");
+    }
+    if !(0.0..=1.0).contains(&p) {
+        bail!("Quantile probability must be between 0 and 1, got {}", p);
+    }
+
+    let position = method.position(sorted.len(), p);
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    let fraction = position - lower as f64;
+    Ok(match method {
+        QuantileMethod::Lower => sorted[lower],
+        QuantileMethod::Higher => sorted[upper],
+        QuantileMethod::Nearest => sorted[position.round_ties_even() as usize],
+        QuantileMethod::Midpoint => (sorted[lower] + sorted[upper]) / 2.0,
+        _ => sorted[lower] + fraction * (sorted[upper] - sorted[lower]),
+    })
+}
+
+pub fn quantile(data: &[f64], p: f64, method: QuantileMethod) -> Result<f64> {
+    quantile_sorted(&sorted(data), p, method)
+}
+
+pub fn quantiles(data: &[f64], probabilities: &[f64], method: QuantileMethod) -> Result<Vec<f64>> {
+    let values = sorted(data);
+    probabilities.iter().map(|&p| quantile_sorted(&values, p, method)).collect()
+}
+
+pub fn median(data: &[f64]) -> Option<f64> {
+    quantile(data, 0.5, QuantileMethod::Linear).ok()
+}
+
+fn check_paired(x: &[f64], y: &[f64]) -> Result<()> {
+    if x.len() != y.len() {
+        bail!("Samples must have the same length, got {} and {}", x.len(), y.len());
+    }
+    if x.len() < 2 {
+        bail!("At least two paired observations are required");
+    }
+    Ok(())
+}
+
+pub fn covariance(x: &[f64], y: &[f64], mode: VarianceMode) -> Result<f64> {
+    check_paired(x, y)?;
+    let (mean_x, mean_y) = (mean(x).unwrap_or(0.0), mean(y).unwrap_or(0.0));
+    let sum: f64 = x.iter().zip(y).map(|(a, b)| (a - mean_x) * (b - mean_y)).sum();
+    Ok(sum / (x.len() - mode.ddof()) as f64)
+}
+
+pub fn pearson(x: &[f64], y: &[f64]) -> Result<f64> {
+    check_paired(x, y)?;
+    let (mean_x, mean_y) = (mean(x).unwrap_or(0.0), mean(y).unwrap_or(0.0));
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    for (a, b) in x.iter().zip(y) {
+        let (dx, dy) = (a - mean_x, b - mean_y);
+        sxy += dx * dy;
+        sxx += dx * dx;
+        syy += dy * dy;
+    }
+    if sxx == 0.0 || syy == 0.0 {
+        bail!("Correlation is undefined for a constant sample");
+    }
+    Ok((sxy / (sxx * syy).sqrt()).clamp(-1.0, 1.0))
+}
+
+pub fn rank(data: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..data.len()).collect();
+    order.sort_by(|&a, &b| data[a].total_cmp(&data[b]));
+
+    let mut ranks = vec![0.0; data.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && data[order[end]] == data[order[start]] {
+            end += 1;
+        }
+        let average = (start + end + 1) as f64 / 2.0;
+        for &index in &order[start..end] {
+            ranks[index] = average;
+        }
+        start = end;
+    }
+    ranks
+}
+
+pub fn spearman(x: &[f64], y: &[f64]) -> Result<f64> {
+    check_paired(x, y)?;
+    pearson(&rank(x), &rank(y))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub mean: f64,
+    pub median: f64,
+    pub variance: f64,
+    pub std_dev: f64,
+    pub skewness: f64,
+    pub kurtosis: f64,
+    pub min: f64,
+    pub max: f64,
+    pub q1: f64,
+    pub q3: f64,
+    pub iqr: f64,
+    pub range: f64,
+    pub count: usize,
+}
+
+pub fn describe(data: &[f64], method: QuantileMethod, mode: VarianceMode) -> Option<Summary> {
+    let values = sorted(data);
+    let (&min, &max) = (values.first()?, values.last()?);
+    let quartile = |p| quantile_sorted(&values, p, method).unwrap_or(f64::NAN);
+    let (q1, median, q3) = (quartile(0.25), quartile(0.5), quartile(0.75));
+    let variance = variance(&values, mode).unwrap_or(0.0);
+
+    Some(Summary {
+        mean: mean(&values)?,
+        median,
+        variance,
+        std_dev: variance.sqrt(),
+        skewness: skewness(&values, mode).unwrap_or(f64::NAN),
+        kurtosis: kurtosis(&values, mode).unwrap_or(f64::NAN),
+        min,
+        max,
+        q1,
+        q3,
+        iqr: q3 - q1,
+        range: max - min,
+        count: values.len(),
+    })
+}
+
+pub fn summary(data: &[f64]) -> Option<Summary> {
+    describe(data, QuantileMethod::default(), VarianceMode::default())
+}
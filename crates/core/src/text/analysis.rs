@@ -0,0 +1,109 @@
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+use unicode_segmentation::UnicodeSegmentation;
+use super::stemming::Stemmer;
+
+pub fn segment_words(text: &str) -> Vec<&str> {
+    text.unicode_words().collect()
+}
+
+pub fn word_indices(text: &str) -> Vec<(usize, &str)> {
+    text.unicode_word_indices().collect()
+}
+
+pub fn fold_diacritics(text: &str) -> String {
+    text.nfd().filter(|&c| !is_combining_mark(c)).nfc().collect()
+}
+
+pub fn char_ngrams(text: &str, n: usize, pad: bool) -> Vec<String> {
+    let chars: Vec<char> = if pad {
+        std::iter::once(' ').chain(text.chars()).chain(std::iter::once(' ')).collect()
+    } else {
+        text.chars().collect()
+    };
+    if n == 0 {
+        return Vec::new();
+    }
+    chars.windows(n).map(|window| window.iter().collect()).collect()
+}
+
+pub fn word_ngrams<S: AsRef<str>>(tokens: &[S], n: usize) -> Vec<String> {
+    if n == 0 {
+        return Vec::new();
+    }
+    tokens
+        .windows(n)
+        .map(|window| window.iter().map(AsRef::as_ref).collect::<Vec<&str>>().join(" "))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalyzerOptions {
+    pub lowercase: bool,
+    pub fold_diacritics: bool,
+    pub stemmer: Option<Stemmer>,
+    pub stop_words: Vec<String>,
+    pub min_length: usize,
+    pub ngram_range: (usize, usize),
+}
+
+impl Default for AnalyzerOptions {
+    fn default() -> Self {
+        AnalyzerOptions {
+            lowercase: true,
+            fold_diacritics: true,
+            stemmer: None,
+            stop_words: Vec::new(),
+            min_length: 1,
+            ngram_range: (1, 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Analyzer {
+    options: AnalyzerOptions,
+    stop_words: HashSet<String>,
+}
+
+impl Analyzer {
+    pub fn new(options: AnalyzerOptions) -> Result<Self> {
+        let (min, max) = options.ngram_range;
+        if min == 0 || min > max {
+            bail!("Invalid n-gram range ({}, {})", min, max);
+        }
+        let mut analyzer = Analyzer { options, stop_words: HashSet::new() };
+        analyzer.stop_words = analyzer.options.stop_words.iter().map(|word| analyzer.normalize(word)).collect();
+        Ok(analyzer)
+    }
+
+    pub fn options(&self) -> &AnalyzerOptions {
+        &self.options
+    }
+
+    fn normalize(&self, word: &str) -> String {
+        let word = if self.options.lowercase { word.to_lowercase() } else { word.to_string() };
+        if self.options.fold_diacritics { fold_diacritics(&word) } else { word }
+    }
+
+    pub fn tokens(&self, text: &str) -> Vec<String> {
+        text.unicode_words()
+            .map(|word| self.normalize(word))
+            .filter(|word| word.chars().count() >= self.options.min_length && !self.stop_words.contains(word))
+            .map(|word| match self.options.stemmer {
+                Some(stemmer) => stemmer.stem(&word),
+                None => word,
+            })
+            .collect()
+    }
+
+    pub fn analyze(&self, text: &str) -> Vec<String> {
+        let tokens = self.tokens(text);
+        let (min, max) = self.options.ngram_range;
+        if (min, max) == (1, 1) {
+            return tokens;
+        }
+        (min..=max).flat_map(|n| word_ngrams(&tokens, n)).collect()
+    }
+}
@@ -0,0 +1,11 @@
+pub mod text_processing;
+pub mod stemming;
+pub mod analysis;
+pub mod similarity;
+pub mod search;
+
+pub use text_processing::*;
+pub use stemming::*;
+pub use analysis::*;
+pub use similarity::*;
+pub use search::*;
@@ -0,0 +1,332 @@
+use anyhow::{bail, Result};
+use std::collections::HashMap;
+use super::analysis::Analyzer;
+use super::similarity::{damerau_levenshtein_distance, jaro_winkler};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SparseVector {
+    pub indices: Vec<usize>,
+    pub values: Vec<f64>,
+}
+
+impl SparseVector {
+    pub fn norm(&self) -> f64 {
+        self.values.iter().map(|v| v * v).sum::<f64>().sqrt()
+    }
+
+    pub fn dot(&self, other: &SparseVector) -> f64 {
+        let (mut i, mut j, mut sum) = (0, 0, 0.0);
+        while i < self.indices.len() && j < other.indices.len() {
+            match self.indices[i].cmp(&other.indices[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    sum += self.values[i] * other.values[j];
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        sum
+    }
+}
+
+pub fn cosine_similarity(a: &SparseVector, b: &SparseVector) -> f64 {
+    let norm = a.norm() * b.norm();
+    if norm == 0.0 { 0.0 } else { a.dot(b) / norm }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchHit {
+    pub index: usize,
+    pub score: f64,
+}
+
+fn rank(scores: impl IntoIterator<Item = f64>, limit: usize) -> Vec<SearchHit> {
+    let mut hits: Vec<SearchHit> = scores
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, score)| score > 0.0)
+        .map(|(index, score)| SearchHit { index, score })
+        .collect();
+    hits.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.index.cmp(&b.index)));
+    hits.truncate(limit);
+    hits
+}
+
+fn term_counts(tokens: Vec<String>) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for token in tokens {
+        *counts.entry(token).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TfIdfOptions {
+    pub sublinear_tf: bool,
+    pub smooth_idf: bool,
+}
+
+impl Default for TfIdfOptions {
+    fn default() -> Self {
+        TfIdfOptions { sublinear_tf: false, smooth_idf: true }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TfIdf {
+    analyzer: Analyzer,
+    options: TfIdfOptions,
+    vocabulary: HashMap<String, usize>,
+    terms: Vec<String>,
+    idf: Vec<f64>,
+    documents: Vec<SparseVector>,
+}
+
+impl TfIdf {
+    pub fn fit<S: AsRef<str>>(documents: &[S], analyzer: Analyzer, options: TfIdfOptions) -> Self {
+        let counts: Vec<HashMap<String, usize>> =
+            documents.iter().map(|document| term_counts(analyzer.analyze(document.as_ref()))).collect();
+
+        let mut terms: Vec<String> = counts.iter().flat_map(|c| c.keys().cloned()).collect();
+        terms.sort();
+        terms.dedup();
+        let vocabulary: HashMap<String, usize> = terms.iter().enumerate().map(|(i, term)| (term.clone(), i)).collect();
+
+        let mut document_frequency = vec![0usize; terms.len()];
+        for c in &counts {
+            for term in c.keys() {
+                document_frequency[vocabulary[term]] += 1;
+            }
+        }
+        let n = documents.len() as f64;
+        let idf = document_frequency
+            .iter()
+            .map(|&df| {
+                let df = df as f64;
+                if options.smooth_idf { ((1.0 + n) / (1.0 + df)).ln() + 1.0 } else { (n / df).ln() + 1.0 }
+            })
+            .collect();
+
+        let mut model = TfIdf { analyzer, options, vocabulary, terms, idf, documents: Vec::new() };
+        model.documents = counts.iter().map(|c| model.weigh(c)).collect();
+        model
+    }
+
+    fn weigh(&self, counts: &HashMap<String, usize>) -> SparseVector {
+        let mut entries: Vec<(usize, f64)> = counts
+            .iter()
+            .filter_map(|(term, &count)| {
+                let index = *self.vocabulary.get(term)?;
+                let tf = if self.options.sublinear_tf { 1.0 + (count as f64).ln() } else { count as f64 };
+                Some((index, tf * self.idf[index]))
+            })
+            .collect();
+        entries.sort_by_key(|&(index, _)| index);
+
+        let norm = entries.iter().map(|(_, v)| v * v).sum::<f64>().sqrt();
+        let scale = if norm > 0.0 { 1.0 / norm } else { 0.0 };
+        SparseVector {
+            indices: entries.iter().map(|&(index, _)| index).collect(),
+            values: entries.iter().map(|&(_, value)| value * scale).collect(),
+        }
+    }
+
+    pub fn transform(&self, text: &str) -> SparseVector {
+        self.weigh(&term_counts(self.analyzer.analyze(text)))
+    }
+
+    pub fn document_vector(&self, index: usize) -> Result<&SparseVector> {
+        match self.documents.get(index) {
+            Some(vector) => Ok(vector),
+            None => bail!("Document index {} out of range for {} documents", index, self.documents.len()),
+        }
+    }
+
+    pub fn similarity(&self, a: &str, b: &str) -> f64 {
+        cosine_similarity(&self.transform(a), &self.transform(b))
+    }
+
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchHit> {
+        let query = self.transform(query);
+        rank(self.documents.iter().map(|document| query.dot(document)), limit)
+    }
+
+    pub fn top_terms(&self, index: usize, limit: usize) -> Result<Vec<(String, f64)>> {
+        let vector = self.document_vector(index)?;
+        let mut terms: Vec<(String, f64)> =
+            vector.indices.iter().zip(&vector.values).map(|(&i, &value)| (self.terms[i].clone(), value)).collect();
+        terms.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        terms.truncate(limit);
+        Ok(terms)
+    }
+
+    pub fn terms(&self) -> &[String] {
+        &self.terms
+    }
+
+    pub fn idf(&self, term: &str) -> Option<f64> {
+        self.vocabulary.get(term).map(|&index| self.idf[index])
+    }
+
+    pub fn vocabulary_size(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bm25Options {
+    pub k1: f64,
+    pub b: f64,
+}
+
+impl Default for Bm25Options {
+    fn default() -> Self {
+        Bm25Options { k1: 1.2, b: 0.75 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FuzzyOptions {
+    pub max_edits: Option<usize>,
+    pub prefix: bool,
+}
+
+impl FuzzyOptions {
+    pub fn edits_for(&self, term: &str) -> usize {
+        self.max_edits.unwrap_or_else(|| match term.chars().count() {
+            0..=2 => 0,
+            3..=5 => 1,
+            _ => 2,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bm25 {
+    analyzer: Analyzer,
+    options: Bm25Options,
+    documents: Vec<HashMap<String, usize>>,
+    lengths: Vec<usize>,
+    document_frequency: HashMap<String, usize>,
+    total_length: usize,
+}
+
+impl Bm25 {
+    pub fn new<S: AsRef<str>>(documents: &[S], analyzer: Analyzer, options: Bm25Options) -> Result<Self> {
+        if !options.k1.is_finite() || options.k1 < 0.0 {
+            bail!("k1 must be a non-negative number, got {}", options.k1);
+        }
+        if !(0.0..=1.0).contains(&options.b) {
+            bail!("b must be between 0 and 1, got {}", options.b);
+        }
+        let mut index = Bm25 {
+            analyzer,
+            options,
+            documents: Vec::new(),
+            lengths: Vec::new(),
+            document_frequency: HashMap::new(),
+            total_length: 0,
+        };
+        for document in documents {
+            index.add(document.as_ref());
+        }
+        Ok(index)
+    }
+
+    pub fn add(&mut self, document: &str) -> usize {
+        let tokens = self.analyzer.analyze(document);
+        self.lengths.push(tokens.len());
+        self.total_length += tokens.len();
+        let counts = term_counts(tokens);
+        for term in counts.keys() {
+            *self.document_frequency.entry(term.clone()).or_insert(0) += 1;
+        }
+        self.documents.push(counts);
+        self.documents.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    pub fn idf(&self, term: &str) -> f64 {
+        let n = self.documents.len() as f64;
+        let df = self.document_frequency.get(term).copied().unwrap_or(0) as f64;
+        (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+    }
+
+    fn term_score(&self, term: &str, idf: f64, document: usize) -> f64 {
+        let Some(&tf) = self.documents[document].get(term) else {
+            return 0.0;
+        };
+        let tf = tf as f64;
+        let average = self.total_length as f64 / self.documents.len() as f64;
+        let length = if average > 0.0 { self.lengths[document] as f64 / average } else { 0.0 };
+        let Bm25Options { k1, b } = self.options;
+        idf * tf * (k1 + 1.0) / (tf + k1 * (1.0 - b + b * length))
+    }
+
+    fn score_terms(&self, terms: &[(String, f64)], limit: usize) -> Vec<SearchHit> {
+        let weighted: Vec<(&str, f64)> = terms.iter().map(|(term, weight)| (term.as_str(), weight * self.idf(term))).collect();
+        rank(
+            (0..self.documents.len())
+                .map(|document| weighted.iter().map(|&(term, idf)| self.term_score(term, idf, document)).sum()),
+            limit,
+        )
+    }
+
+    pub fn score(&self, query: &str, document: usize) -> Result<f64> {
+        if document >= self.documents.len() {
+            bail!("Document index {} out of range for {} documents", document, self.documents.len());
+        }
+        Ok(self
+            .analyzer
+            .analyze(query)
+            .iter()
+            .map(|term| self.term_score(term, self.idf(term), document))
+            .sum())
+    }
+
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchHit> {
+        let terms: Vec<(String, f64)> = self.analyzer.analyze(query).into_iter().map(|term| (term, 1.0)).collect();
+        self.score_terms(&terms, limit)
+    }
+
+    pub fn search_fuzzy(&self, query: &str, limit: usize, fuzzy: &FuzzyOptions) -> Vec<SearchHit> {
+        let mut vocabulary: Vec<&String> = self.document_frequency.keys().collect();
+        vocabulary.sort();
+
+        let mut terms = Vec::new();
+        for term in self.analyzer.analyze(query) {
+            if self.document_frequency.contains_key(&term) {
+                terms.push((term, 1.0));
+                continue;
+            }
+            let max_edits = fuzzy.edits_for(&term);
+            let length = term.chars().count();
+            for candidate in &vocabulary {
+                let matches = (fuzzy.prefix && candidate.starts_with(term.as_str()))
+                    || (candidate.chars().count().abs_diff(length) <= max_edits
+                        && damerau_levenshtein_distance(&term, candidate) <= max_edits);
+                if matches {
+                    terms.push(((*candidate).clone(), jaro_winkler(&term, candidate)));
+                }
+            }
+        }
+        self.score_terms(&terms, limit)
+    }
+}
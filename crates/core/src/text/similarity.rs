@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+    for (i, &c) in a.iter().enumerate() {
+        let end = (i + window + 1).min(b.len());
+        for j in i.saturating_sub(window)..end {
+            if !b_matched[j] && b[j] == c {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let a_sequence = a.iter().zip(&a_matched).filter(|(_, &matched)| matched).map(|(c, _)| c);
+    let b_sequence = b.iter().zip(&b_matched).filter(|(_, &matched)| matched).map(|(c, _)| c);
+    let transpositions = a_sequence.zip(b_sequence).filter(|(x, y)| x != y).count() / 2;
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let similarity = jaro(a, b);
+    if similarity <= 0.7 {
+        return similarity;
+    }
+    let prefix = a.chars().zip(b.chars()).take(4).take_while(|(x, y)| x == y).count();
+    similarity + 0.1 * prefix as f64 * (1.0 - similarity)
+}
+
+pub fn damerau_levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (rows, cols) = (a.len() + 2, b.len() + 2);
+    let infinity = a.len() + b.len();
+    let mut d = vec![vec![0; cols]; rows];
+    for (i, row) in d.iter_mut().enumerate().skip(1) {
+        row[0] = infinity;
+        row[1] = i - 1;
+    }
+    d[0].fill(infinity);
+    for (j, cell) in d[1].iter_mut().enumerate().skip(1) {
+        *cell = j - 1;
+    }
+
+    let mut last_row: HashMap<char, usize> = HashMap::new();
+    for i in 1..=a.len() {
+        let mut last_column = 0;
+        for j in 1..=b.len() {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_column;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_column = j;
+                0
+            } else {
+                1
+            };
+            d[i + 1][j + 1] = (d[i][j] + cost)
+                .min(d[i + 1][j] + 1)
+                .min(d[i][j + 1] + 1)
+                .min(d[k][l] + (i - k - 1) + 1 + (j - l - 1));
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    d[a.len() + 1][b.len() + 1]
+}
@@ -0,0 +1,432 @@
+use anyhow::{bail, Result};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Stemmer {
+    Porter,
+    #[default]
+    English,
+    German,
+}
+
+impl Stemmer {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stemmer::Porter => "porter",
+            Stemmer::English => "english",
+            Stemmer::German => "german",
+        }
+    }
+
+    pub fn stem(&self, word: &str) -> String {
+        let word = word.to_lowercase();
+        match self {
+            Stemmer::Porter => porter(&word),
+            Stemmer::English => english(&word),
+            Stemmer::German => german(&word),
+        }
+    }
+}
+
+impl FromStr for Stemmer {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "porter" => Ok(Stemmer::Porter),
+            "english" | "en" | "porter2" | "snowball" => Ok(Stemmer::English),
+            "german" | "de" | "deutsch" => Ok(Stemmer::German),
+            _ => bail!("Unsupported stemmer language: {}", name),
+        }
+    }
+}
+
+fn ends(word: &[char], suffix: &str) -> bool {
+    let length = suffix.chars().count();
+    word.len() >= length && word[word.len() - length..].iter().copied().eq(suffix.chars())
+}
+
+fn replace_suffix(word: &mut Vec<char>, length: usize, replacement: &str) {
+    word.truncate(word.len() - length);
+    word.extend(replacement.chars());
+}
+
+fn longest_suffix<'a, T>(word: &[char], rules: &'a [(&'a str, T)]) -> Option<(usize, &'a T)> {
+    rules
+        .iter()
+        .filter(|(suffix, _)| ends(word, suffix))
+        .max_by_key(|(suffix, _)| suffix.chars().count())
+        .map(|(suffix, action)| (suffix.chars().count(), action))
+}
+
+fn porter_consonant(word: &[char], i: usize) -> bool {
+    match word[i] {
+        'a' | 'e' | 'i' | 'o' | 'u' => false,
+        'y' => i == 0 || !porter_consonant(word, i - 1),
+        _ => true,
+    }
+}
+
+fn porter_measure(word: &[char]) -> usize {
+    let n = word.len();
+    let mut i = 0;
+    while i < n && porter_consonant(word, i) {
+        i += 1;
+    }
+    let mut measure = 0;
+    loop {
+        while i < n && !porter_consonant(word, i) {
+            i += 1;
+        }
+        if i >= n {
+            return measure;
+        }
+        while i < n && porter_consonant(word, i) {
+            i += 1;
+        }
+        measure += 1;
+    }
+}
+
+fn porter_has_vowel(word: &[char]) -> bool {
+    (0..word.len()).any(|i| !porter_consonant(word, i))
+}
+
+fn porter_double(word: &[char]) -> bool {
+    let n = word.len();
+    n >= 2 && word[n - 1] == word[n - 2] && porter_consonant(word, n - 1)
+}
+
+fn porter_cvc(word: &[char]) -> bool {
+    let n = word.len();
+    n >= 3
+        && porter_consonant(word, n - 3)
+        && !porter_consonant(word, n - 2)
+        && porter_consonant(word, n - 1)
+        && !matches!(word[n - 1], 'w' | 'x' | 'y')
+}
+
+fn porter_rules(word: &mut Vec<char>, rules: &[(&str, &str)], min_measure: usize) {
+    if let Some(&(suffix, replacement)) = rules.iter().find(|(suffix, _)| ends(word, suffix)) {
+        let length = suffix.len();
+        if porter_measure(&word[..word.len() - length]) > min_measure {
+            replace_suffix(word, length, replacement);
+        }
+    }
+}
+
+fn porter(word: &str) -> String {
+    let mut w: Vec<char> = word.chars().collect();
+    if w.len() <= 2 {
+        return word.to_string();
+    }
+
+    if ends(&w, "sses") || ends(&w, "ies") {
+        w.truncate(w.len() - 2);
+    } else if ends(&w, "s") && !ends(&w, "ss") {
+        w.pop();
+    }
+
+    if ends(&w, "eed") {
+        if porter_measure(&w[..w.len() - 3]) > 0 {
+            w.pop();
+        }
+    } else if let Some(length) = [("ed", 2), ("ing", 3)].iter().find(|(suffix, _)| ends(&w, suffix)).map(|(_, length)| *length) {
+        if porter_has_vowel(&w[..w.len() - length]) {
+            w.truncate(w.len() - length);
+            if ends(&w, "at") || ends(&w, "bl") || ends(&w, "iz") {
+                w.push('e');
+            } else if porter_double(&w) {
+                if !matches!(w[w.len() - 1], 'l' | 's' | 'z') {
+                    w.pop();
+                }
+            } else if porter_measure(&w) == 1 && porter_cvc(&w) {
+                w.push('e');
+            }
+        }
+    }
+
+    if ends(&w, "y") && porter_has_vowel(&w[..w.len() - 1]) {
+        let last = w.len() - 1;
+        w[last] = 'i';
+    }
+
+    porter_rules(&mut w, &[
+        ("ational", "ate"), ("tional", "tion"), ("enci", "ence"), ("anci", "ance"), ("izer", "ize"),
+        ("bli", "ble"), ("alli", "al"), ("entli", "ent"), ("eli", "e"), ("ousli", "ous"),
+        ("ization", "ize"), ("ation", "ate"), ("ator", "ate"), ("alism", "al"), ("iveness", "ive"),
+        ("fulness", "ful"), ("ousness", "ous"), ("aliti", "al"), ("iviti", "ive"), ("biliti", "ble"),
+        ("logi", "log"),
+    ], 0);
+    porter_rules(&mut w, &[
+        ("icate", "ic"), ("ative", ""), ("alize", "al"), ("iciti", "ic"), ("ical", "ic"), ("ful", ""), ("ness", ""),
+    ], 0);
+
+    let step4 = [
+        "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent",
+        "ion", "ou", "ism", "ate", "iti", "ous", "ive", "ize",
+    ];
+    let matched = step4.iter().filter(|suffix| ends(&w, suffix)).max_by_key(|suffix| suffix.len());
+    if let Some(suffix) = matched {
+        let stem = &w[..w.len() - suffix.len()];
+        let allowed = *suffix != "ion" || matches!(stem.last(), Some('s' | 't'));
+        if allowed && porter_measure(stem) > 1 {
+            w.truncate(stem.len());
+        }
+    }
+
+    if ends(&w, "e") {
+        let stem = &w[..w.len() - 1];
+        let measure = porter_measure(stem);
+        if measure > 1 || (measure == 1 && !porter_cvc(stem)) {
+            w.pop();
+        }
+    }
+    if ends(&w, "ll") && porter_measure(&w) > 1 {
+        w.pop();
+    }
+
+    w.into_iter().collect()
+}
+
+fn next_region(word: &[char], start: usize, is_vowel: impl Fn(char) -> bool) -> usize {
+    (start + 1..word.len())
+        .find(|&i| is_vowel(word[i - 1]) && !is_vowel(word[i]))
+        .map_or(word.len(), |i| i + 1)
+}
+
+fn english_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+fn english_short_syllable(word: &[char]) -> bool {
+    match word.len() {
+        0 | 1 => false,
+        2 => english_vowel(word[0]) && !english_vowel(word[1]),
+        n => {
+            !english_vowel(word[n - 3])
+                && english_vowel(word[n - 2])
+                && !english_vowel(word[n - 1])
+                && !matches!(word[n - 1], 'w' | 'x' | 'Y')
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Step1b {
+    Eed,
+    Ed,
+}
+
+#[derive(Clone, Copy)]
+enum Condition {
+    Always,
+    PrecededBy(&'static [char]),
+    InR2,
+}
+
+fn english_rules(word: &mut Vec<char>, region: usize, r2: usize, rules: &[(&str, (&str, Condition))]) {
+    if let Some((length, &(replacement, condition))) = longest_suffix(word, rules) {
+        let start = word.len() - length;
+        let allowed = start >= region
+            && match condition {
+                Condition::Always => true,
+                Condition::PrecededBy(letters) => start > 0 && letters.contains(&word[start - 1]),
+                Condition::InR2 => start >= r2,
+            };
+        if allowed {
+            replace_suffix(word, length, replacement);
+        }
+    }
+}
+
+fn english(word: &str) -> String {
+    const EXCEPTIONS: [(&str, &str); 18] = [
+        ("skis", "ski"), ("skies", "sky"), ("dying", "die"), ("lying", "lie"), ("tying", "tie"),
+        ("idly", "idl"), ("gently", "gentl"), ("ugly", "ugli"), ("early", "earli"), ("only", "onli"),
+        ("singly", "singl"), ("sky", "sky"), ("news", "news"), ("howe", "howe"), ("atlas", "atlas"),
+        ("cosmos", "cosmos"), ("bias", "bias"), ("andes", "andes"),
+    ];
+    const INVARIANT: [&str; 8] = ["inning", "outing", "canning", "herring", "earring", "proceed", "exceed", "succeed"];
+    const LI_ENDINGS: &[char] = &['c', 'd', 'e', 'g', 'h', 'k', 'm', 'n', 'r', 't'];
+    const DOUBLES: [&str; 9] = ["bb", "dd", "ff", "gg", "mm", "nn", "pp", "rr", "tt"];
+
+    let mut w: Vec<char> = word.chars().map(|c| if matches!(c, '\u{2018}' | '\u{2019}' | '\u{201b}') { '\'' } else { c }).collect();
+    if w.len() <= 2 {
+        return w.into_iter().collect();
+    }
+    let normalized: String = w.iter().collect();
+    if let Some((_, stem)) = EXCEPTIONS.iter().find(|(form, _)| *form == normalized) {
+        return stem.to_string();
+    }
+
+    if w[0] == '\'' {
+        w.remove(0);
+    }
+    for i in 0..w.len() {
+        if w[i] == 'y' && (i == 0 || english_vowel(w[i - 1])) {
+            w[i] = 'Y';
+        }
+    }
+    let r1 = ["gener", "commun", "arsen"]
+        .iter()
+        .find(|prefix| w.starts_with(&prefix.chars().collect::<Vec<_>>()))
+        .map_or_else(|| next_region(&w, 0, english_vowel), |prefix| prefix.len());
+    let r2 = next_region(&w, r1, english_vowel);
+
+    if let Some((length, _)) = longest_suffix(&w, &[("'s'", ()), ("'s", ()), ("'", ())]) {
+        w.truncate(w.len() - length);
+    }
+
+    if ends(&w, "sses") {
+        w.truncate(w.len() - 2);
+    } else if ends(&w, "ied") || ends(&w, "ies") {
+        let replacement = if w.len() > 4 { "i" } else { "ie" };
+        replace_suffix(&mut w, 3, replacement);
+    } else if ends(&w, "s") && !ends(&w, "us") && !ends(&w, "ss") && w.len() >= 2 && w[..w.len() - 2].iter().any(|&c| english_vowel(c)) {
+        w.pop();
+    }
+
+    let current: String = w.iter().collect();
+    if INVARIANT.contains(&current.as_str()) {
+        return current;
+    }
+
+    let step1b = [("eedly", Step1b::Eed), ("eed", Step1b::Eed), ("ingly", Step1b::Ed), ("edly", Step1b::Ed), ("ing", Step1b::Ed), ("ed", Step1b::Ed)];
+    match longest_suffix(&w, &step1b) {
+        Some((length, Step1b::Eed)) if w.len() - length >= r1 => replace_suffix(&mut w, length, "ee"),
+        Some((length, Step1b::Ed)) if w[..w.len() - length].iter().any(|&c| english_vowel(c)) => {
+            w.truncate(w.len() - length);
+            if ends(&w, "at") || ends(&w, "bl") || ends(&w, "iz") {
+                w.push('e');
+            } else if DOUBLES.iter().any(|double| ends(&w, double)) {
+                w.pop();
+            } else if english_short_syllable(&w) && r1 >= w.len() {
+                w.push('e');
+            }
+        }
+        _ => {}
+    }
+
+    let n = w.len();
+    if n > 2 && matches!(w[n - 1], 'y' | 'Y') && !english_vowel(w[n - 2]) {
+        w[n - 1] = 'i';
+    }
+
+    use Condition::*;
+    english_rules(&mut w, r1, r2, &[
+        ("tional", ("tion", Always)), ("enci", ("ence", Always)), ("anci", ("ance", Always)), ("abli", ("able", Always)),
+        ("entli", ("ent", Always)), ("izer", ("ize", Always)), ("ization", ("ize", Always)), ("ational", ("ate", Always)),
+        ("ation", ("ate", Always)), ("ator", ("ate", Always)), ("alism", ("al", Always)), ("aliti", ("al", Always)),
+        ("alli", ("al", Always)), ("fulness", ("ful", Always)), ("ousli", ("ous", Always)), ("ousness", ("ous", Always)),
+        ("iveness", ("ive", Always)), ("iviti", ("ive", Always)), ("biliti", ("ble", Always)), ("bli", ("ble", Always)),
+        ("ogi", ("og", PrecededBy(&['l']))), ("fulli", ("ful", Always)), ("lessli", ("less", Always)),
+        ("li", ("", PrecededBy(LI_ENDINGS))),
+    ]);
+    english_rules(&mut w, r1, r2, &[
+        ("tional", ("tion", Always)), ("ational", ("ate", Always)), ("alize", ("al", Always)), ("icate", ("ic", Always)),
+        ("iciti", ("ic", Always)), ("ative", ("", InR2)), ("ical", ("ic", Always)), ("ful", ("", Always)), ("ness", ("", Always)),
+    ]);
+    english_rules(&mut w, r2, r2, &[
+        ("al", ("", Always)), ("ance", ("", Always)), ("ence", ("", Always)), ("er", ("", Always)), ("ic", ("", Always)),
+        ("able", ("", Always)), ("ible", ("", Always)), ("ant", ("", Always)), ("ement", ("", Always)), ("ment", ("", Always)),
+        ("ent", ("", Always)), ("ism", ("", Always)), ("ate", ("", Always)), ("iti", ("", Always)), ("ous", ("", Always)),
+        ("ive", ("", Always)), ("ize", ("", Always)), ("ion", ("", PrecededBy(&['s', 't']))),
+    ]);
+
+    let n = w.len();
+    let delete_e = ends(&w, "e") && (n > r2 || (n > r1 && !english_short_syllable(&w[..n - 1])));
+    if delete_e || (ends(&w, "ll") && n > r2) {
+        w.pop();
+    }
+
+    w.into_iter().map(|c| if c == 'Y' { 'y' } else { c }).collect()
+}
+
+fn german_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'ä' | 'ö' | 'ü')
+}
+
+#[derive(Clone, Copy)]
+enum GermanSuffix {
+    A,
+    B,
+    C,
+}
+
+fn german(word: &str) -> String {
+    const S_ENDINGS: &[char] = &['b', 'd', 'f', 'g', 'h', 'k', 'l', 'm', 'n', 'r', 't'];
+    const ST_ENDINGS: &[char] = &['b', 'd', 'f', 'g', 'h', 'k', 'l', 'm', 'n', 't'];
+
+    let mut w: Vec<char> = word.replace('ß', "ss").chars().collect();
+    for i in 1..w.len().saturating_sub(1) {
+        if german_vowel(w[i - 1]) && german_vowel(w[i + 1]) {
+            match w[i] {
+                'u' => w[i] = 'U',
+                'y' => w[i] = 'Y',
+                _ => {}
+            }
+        }
+    }
+    let r1 = next_region(&w, 0, german_vowel).max(3);
+    let r2 = next_region(&w, r1, german_vowel);
+    let in_region = |w: &[char], length: usize, region: usize| w.len() >= length && w.len() - length >= region;
+
+    use GermanSuffix::*;
+    match longest_suffix(&w, &[("em", A), ("ern", A), ("er", A), ("e", B), ("en", B), ("es", B), ("s", C)]) {
+        Some((length, A)) if in_region(&w, length, r1) => w.truncate(w.len() - length),
+        Some((length, B)) if in_region(&w, length, r1) => {
+            w.truncate(w.len() - length);
+            if ends(&w, "niss") {
+                w.pop();
+            }
+        }
+        Some((1, C)) if in_region(&w, 1, r1) && w.len() >= 2 && S_ENDINGS.contains(&w[w.len() - 2]) => {
+            w.pop();
+        }
+        _ => {}
+    }
+
+    match longest_suffix(&w, &[("en", A), ("er", A), ("est", A), ("st", B)]) {
+        Some((length, A)) if in_region(&w, length, r1) => w.truncate(w.len() - length),
+        Some((2, B)) if in_region(&w, 2, r1) && w.len() >= 6 && ST_ENDINGS.contains(&w[w.len() - 3]) => {
+            w.truncate(w.len() - 2);
+        }
+        _ => {}
+    }
+
+    let preceded_by_e = |w: &[char], length: usize| w.len() > length && w[w.len() - length - 1] == 'e';
+    match longest_suffix(&w, &[("end", A), ("ung", A), ("ig", B), ("ik", B), ("isch", B), ("lich", C), ("heit", C), ("keit", C)]) {
+        Some((length, A)) if in_region(&w, length, r2) => {
+            w.truncate(w.len() - length);
+            if ends(&w, "ig") && in_region(&w, 2, r2) && !preceded_by_e(&w, 2) {
+                w.truncate(w.len() - 2);
+            }
+        }
+        Some((length, B)) if in_region(&w, length, r2) && !preceded_by_e(&w, length) => w.truncate(w.len() - length),
+        Some((length, C)) if in_region(&w, length, r2) => {
+            let keit = ends(&w, "keit");
+            w.truncate(w.len() - length);
+            if keit {
+                if let Some((length, _)) = longest_suffix(&w, &[("lich", ()), ("ig", ())]) {
+                    if in_region(&w, length, r2) {
+                        w.truncate(w.len() - length);
+                    }
+                }
+            } else if (ends(&w, "er") || ends(&w, "en")) && in_region(&w, 2, r1) {
+                w.truncate(w.len() - 2);
+            }
+        }
+        _ => {}
+    }
+
+    w.into_iter()
+        .map(|c| match c {
+            'U' | 'ü' => 'u',
+            'Y' => 'y',
+            'ä' => 'a',
+            'ö' => 'o',
+            other => other,
+        })
+        .collect()
+}
@@ -0,0 +1,208 @@
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateMatch {
+    pub text: String,
+    pub index: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Entities {
+    pub emails: Vec<String>,
+    pub urls: Vec<String>,
+    pub phones: Vec<String>,
+    pub hashtags: Vec<String>,
+    pub mentions: Vec<String>,
+}
+
+pub const DEFAULT_STOP_WORDS: &[&str] = &[
+    "a", "an", "the", "and", "or", "but", "is", "are", "was", "were", 
+    "be", "been", "being", "in", "on", "at", "to", "for", "with", "by", 
+    "about", "against", "between", "into", "through", "during", "before", 
+    "after", "above", "below", "from", "up", "down", "of", "off", "over", "under",
+];
+
+fn find_all(pattern: &str, text: &str) -> Vec<String> {
+    Regex::new(pattern).unwrap()
+        .find_iter(text)
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+
+pub fn word_count(text: &str) -> usize {
+    Regex::new(r"\S+").unwrap().find_iter(text).count()
+}
+
+pub fn sentence_count(text: &str) -> usize {
+    let count = Regex::new(r"[.!?]+\s*").unwrap().find_iter(text).count();
+    if count == 0 && !text.trim().is_empty() {
+        1
+    } else {
+        count
+    }
+}
+
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    
+    if a_chars.is_empty() {
+        return b_chars.len();
+    }
+    if b_chars.is_empty() {
+        return a_chars.len();
+    }
+    
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+    
+    for (i, a_char) in a_chars.iter().enumerate() {
+        current[0] = i + 1;
+        
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let cost = if a_char == b_char { 0 } else { 1 };
+            
+            current[j + 1] = (previous[j + 1] + 1)
+                .min(current[j] + 1)
+                .min(previous[j] + cost);
+        }
+        
+        std::mem::swap(&mut previous, &mut current);
+    }
+    
+    previous[b_chars.len()]
+}
+
+pub fn frequency_analysis(text: &str) -> HashMap<char, usize> {
+    let mut freq = HashMap::new();
+    
+    for c in text.chars() {
+        *freq.entry(c).or_insert(0) += 1;
+    }
+    
+    freq
+}
+
+pub fn slugify(text: &str) -> String {
+    let mut result = String::new();
+    let mut prev_dash = true;
+    
+    for c in text.to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            result.push(c);
+            prev_dash = false;
+        } else if !prev_dash {
+            result.push('-');
+            prev_dash = true;
+        }
+    }
+    
+    if result.ends_with('-') {
+        result.pop();
+    }
+    
+    result
+}
+
+pub fn extract_dates(text: &str) -> Vec<DateMatch> {
+    let date_patterns = [
+        r"\b\d{4}-\d{2}-\d{2}\b",
+        r"\b\d{2}/\d{2}/\d{4}\b",
+        r"\b\d{2}\.\d{2}\.\d{4}\b",
+        r"\b(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)[a-z]* \d{1,2},? \d{4}\b",
+    ];
+    
+    date_patterns
+        .iter()
+        .flat_map(|pattern| {
+            Regex::new(pattern).unwrap()
+                .find_iter(text)
+                .map(|m| DateMatch { text: m.as_str().to_string(), index: m.start() })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+pub fn tokenize(text: &str) -> Vec<String> {
+    find_all(r"\b\w+\b", text)
+}
+
+pub fn is_email(text: &str) -> bool {
+    Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap().is_match(text)
+}
+
+pub fn is_url(text: &str) -> bool {
+    Regex::new(r"^(https?|ftp)://[^\s/$.?#].[^\s]*$").unwrap().is_match(text)
+}
+
+pub fn is_ipv4(text: &str) -> bool {
+    Regex::new(r"^(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)$").unwrap().is_match(text)
+}
+
+pub fn is_ipv6(text: &str) -> bool {
+    Regex::new(r"^(([0-9a-fA-F]{1,4}:){7,7}[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,7}:|([0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,5}(:[0-9a-fA-F]{1,4}){1,2}|([0-9a-fA-F]{1,4}:){1,4}(:[0-9a-fA-F]{1,4}){1,3}|([0-9a-fA-F]{1,4}:){1,3}(:[0-9a-fA-F]{1,4}){1,4}|([0-9a-fA-F]{1,4}:){1,2}(:[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:((:[0-9a-fA-F]{1,4}){1,6})|:((:[0-9a-fA-F]{1,4}){1,7}|:)|fe80:(:[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]{1,}|::(ffff(:0{1,4}){0,1}:){0,1}((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])|([0-9a-fA-F]{1,4}:){1,4}:((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9]))$").unwrap().is_match(text)
+}
+
+pub fn pluralize(word: &str) -> String {
+    let special_cases = [
+        ("child", "children"),
+        ("goose", "geese"),
+        ("man", "men"),
+        ("woman", "women"),
+        ("tooth", "teeth"),
+        ("foot", "feet"),
+        ("mouse", "mice"),
+        ("person", "people"),
+    ];
+    
+    if let Some((_, plural)) = special_cases.iter().find(|(singular, _)| *singular == word) {
+        return plural.to_string();
+    }
+    
+    let lowercase = word.to_lowercase();
+    
+    if ["s", "x", "z", "ch", "sh"].iter().any(|suffix| lowercase.ends_with(suffix)) {
+        return format!("{}es", word);
+    }
+    
+    let mut chars = lowercase.chars().rev();
+    if let (Some('y'), Some(penultimate)) = (chars.next(), chars.next()) {
+        if !['a', 'e', 'i', 'o', 'u'].contains(&penultimate) {
+            return format!("{}ies", &word[..word.len() - 1]);
+        }
+    }
+    
+    format!("{}s", word)
+}
+
+pub fn extract_entities(text: &str) -> Entities {
+    Entities {
+        emails: find_all(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Z|a-z]{2,}\b", text),
+        urls: find_all(r"https?://(?:www\.)?[-a-zA-Z0-9@:%._\+~#=]{1,256}\.[a-zA-Z0-9()]{1,6}\b(?:[-a-zA-Z0-9()@:%_\+.~#?&//=]*)", text),
+        phones: find_all(r"\b(?:\+\d{1,3}[- ]?)?\(?\d{3}\)?[- ]?\d{3}[- ]?\d{4}\b", text),
+        hashtags: find_all(r"#[a-zA-Z0-9_]+", text),
+        mentions: find_all(r"@[a-zA-Z0-9_]+", text),
+    }
+}
+
+pub fn extract_keywords(text: &str, stop_words: Option<&[String]>) -> Vec<(String, usize)> {
+    let stop_words_set: HashSet<String> = match stop_words {
+        Some(words) => words.iter().cloned().collect(),
+        None => DEFAULT_STOP_WORDS.iter().map(|s| s.to_string()).collect(),
+    };
+    
+    let mut word_counts: HashMap<String, usize> = HashMap::new();
+    
+    for word in find_all(r"\b[a-zA-Z]{3,}\b", &text.to_lowercase()) {
+        if !stop_words_set.contains(&word) {
+            *word_counts.entry(word).or_insert(0) += 1;
+        }
+    }
+    
+    let mut words: Vec<(String, usize)> = word_counts.into_iter().collect();
+    words.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    words.truncate(10);
+    
+    words
+}
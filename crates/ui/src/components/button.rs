@@ -37,7 +37,7 @@ impl Default for ButtonSize {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum ButtonState {
     Normal,
     Pressed,
@@ -0,0 +1,106 @@
+use serde::{Serialize, Deserialize};
+use crate::shared::edge_insets::EdgeInsets;
+
+use super::Color;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CheckboxProps {
+    pub control_id: String,
+    pub label: Option<String>,
+    pub state: Option<CheckState>,
+    pub allows_indeterminate: Option<bool>,
+    pub disabled: Option<bool>,
+    pub on_change: Option<String>,
+
+    pub box_size: Option<f32>,
+    pub tint_color: Option<Color>,
+    pub check_color: Option<Color>,
+    pub border_color: Option<Color>,
+    pub label_color: Option<Color>,
+    pub font_size: Option<f32>,
+
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub padding: Option<f32>,
+    pub edge_insets: Option<EdgeInsets>,
+    pub opacity: Option<f32>,
+
+    pub accessibility_label: Option<String>,
+    pub accessibility_hint: Option<String>,
+}
+
+impl Default for CheckboxProps {
+    fn default() -> Self {
+        Self {
+            control_id: String::new(),
+            label: None,
+            state: None,
+            allows_indeterminate: Some(false),
+            disabled: Some(false),
+            on_change: None,
+
+            box_size: Some(20.0),
+            tint_color: Some(Color::Blue),
+            check_color: Some(Color::White),
+            border_color: Some(Color::Gray),
+            label_color: Some(Color::Black),
+            font_size: Some(16.0),
+
+            width: None,
+            height: None,
+            padding: None,
+            edge_insets: None,
+            opacity: None,
+
+            accessibility_label: None,
+            accessibility_hint: None,
+        }
+    }
+}
+
+impl CheckboxProps {
+    pub fn new(control_id: &str) -> Self {
+        Self {
+            control_id: control_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.state = Some(if checked { CheckState::Checked } else { CheckState::Unchecked });
+        self
+    }
+
+    pub fn state(mut self, state: CheckState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn allows_indeterminate(mut self, allows: bool) -> Self {
+        self.allows_indeterminate = Some(allows);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    pub fn on_change(mut self, handler_id: &str) -> Self {
+        self.on_change = Some(handler_id.to_string());
+        self
+    }
+}
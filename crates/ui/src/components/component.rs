@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 
-use super::{ButtonProps, DividerProps, HStackProps, ImageProps, ScrollProps, SpacerProps, TextProps, VStackProps, ZStackProps};
+use super::{ButtonProps, CheckboxProps, DividerProps, HStackProps, ImageProps, ListProps, PickerProps, RadioGroupProps, ScrollProps, SliderProps, SpacerProps, StepperProps, TextInputProps, TextProps, ToggleProps, VStackProps, ZStackProps};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
@@ -14,4 +14,13 @@ pub enum UIComponent {
     Scroll(ScrollProps),
     Spacer(SpacerProps),
     Divider(DividerProps),
+    List(ListProps),
+    TextField(TextInputProps),
+    TextArea(TextInputProps),
+    Toggle(ToggleProps),
+    Checkbox(CheckboxProps),
+    RadioGroup(RadioGroupProps),
+    Slider(SliderProps),
+    Stepper(StepperProps),
+    Picker(PickerProps),
 }
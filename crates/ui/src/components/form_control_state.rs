@@ -0,0 +1,712 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+use crate::events::{Event, EventPayload, EventSource, EventType};
+use crate::render::node::RenderNode;
+use crate::render::property::{Property, keys};
+
+use super::button::ButtonState;
+use super::checkbox::CheckState;
+
+const SLIDER_DEFAULT_STEPS: f32 = 100.0;
+const SLIDER_PAGE_STEPS: i32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormControlKind {
+    Toggle,
+    Checkbox,
+    RadioGroup,
+    Slider,
+    Stepper,
+    Picker,
+}
+
+impl FormControlKind {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            FormControlKind::Toggle => "Toggle",
+            FormControlKind::Checkbox => "Checkbox",
+            FormControlKind::RadioGroup => "RadioGroup",
+            FormControlKind::Slider => "Slider",
+            FormControlKind::Stepper => "Stepper",
+            FormControlKind::Picker => "Picker",
+        }
+    }
+
+    pub fn from_type_name(type_name: &str) -> Option<Self> {
+        match type_name {
+            "Toggle" => Some(FormControlKind::Toggle),
+            "Checkbox" => Some(FormControlKind::Checkbox),
+            "RadioGroup" => Some(FormControlKind::RadioGroup),
+            "Slider" => Some(FormControlKind::Slider),
+            "Stepper" => Some(FormControlKind::Stepper),
+            "Picker" => Some(FormControlKind::Picker),
+            _ => None,
+        }
+    }
+
+    pub fn accessibility_role(&self) -> &'static str {
+        match self {
+            FormControlKind::Toggle => "switch",
+            FormControlKind::Checkbox => "checkbox",
+            FormControlKind::RadioGroup => "radiogroup",
+            FormControlKind::Slider => "slider",
+            FormControlKind::Stepper => "spinbutton",
+            FormControlKind::Picker => "combobox",
+        }
+    }
+
+    fn default_value(&self) -> FormValue {
+        match self {
+            FormControlKind::Toggle => FormValue::Bool(false),
+            FormControlKind::Checkbox => FormValue::Check(CheckState::Unchecked),
+            FormControlKind::RadioGroup | FormControlKind::Picker => FormValue::Selection(None),
+            FormControlKind::Slider | FormControlKind::Stepper => FormValue::Number(0.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FormValue {
+    Bool(bool),
+    Check(CheckState),
+    Selection(Option<usize>),
+    Number(f32),
+}
+
+impl FormValue {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FormValue::Bool(value) => Some(*value),
+            FormValue::Check(state) => Some(*state == CheckState::Checked),
+            _ => None,
+        }
+    }
+
+    pub fn as_selection(&self) -> Option<usize> {
+        match self {
+            FormValue::Selection(index) => *index,
+            _ => None,
+        }
+    }
+
+    pub fn as_number(&self) -> Option<f32> {
+        match self {
+            FormValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FormValuePayload {
+    pub control_id: String,
+    pub kind: FormControlKind,
+    pub value: FormValue,
+    pub selected_option: Option<String>,
+    pub is_final: bool,
+}
+
+impl EventPayload for FormValuePayload {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn EventPayload> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormControlConfig {
+    pub enabled: bool,
+    pub options: Vec<String>,
+    pub min: f32,
+    pub max: f32,
+    pub step: Option<f32>,
+    pub wraps: bool,
+    pub allows_indeterminate: bool,
+}
+
+impl Default for FormControlConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            options: Vec::new(),
+            min: 0.0,
+            max: 1.0,
+            step: None,
+            wraps: false,
+            allows_indeterminate: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FormControlState {
+    kind: FormControlKind,
+    config: FormControlConfig,
+    value: FormValue,
+    synced_value: Option<FormValue>,
+    interaction: ButtonState,
+    focused: bool,
+    expanded: bool,
+    highlighted: Option<usize>,
+    dragging: bool,
+}
+
+impl FormControlState {
+    pub fn new(kind: FormControlKind) -> Self {
+        Self {
+            kind,
+            config: FormControlConfig::default(),
+            value: kind.default_value(),
+            synced_value: None,
+            interaction: ButtonState::Normal,
+            focused: false,
+            expanded: false,
+            highlighted: None,
+            dragging: false,
+        }
+    }
+
+    pub fn from_node(node: &RenderNode) -> Option<Self> {
+        let kind = FormControlKind::from_type_name(&node.type_name)?;
+        let options = match node.get_prop("options") {
+            Some(Property::Array(options)) => options.iter()
+                .map(|option| option.to_string_value())
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let mut state = Self::new(kind);
+        state.configure(FormControlConfig {
+            enabled: node.get_prop_bool(keys::ENABLED).unwrap_or(true),
+            options,
+            min: node.get_prop_f32("min").unwrap_or(0.0),
+            max: node.get_prop_f32("max").unwrap_or(1.0),
+            step: node.get_prop_f32("step"),
+            wraps: node.get_prop_bool("wraps").unwrap_or(false),
+            allows_indeterminate: node.get_prop_bool("allows_indeterminate").unwrap_or(false),
+        });
+
+        let value = match kind {
+            FormControlKind::Toggle => node.get_prop_bool("is_on").map(FormValue::Bool),
+            FormControlKind::Checkbox => node.get_prop_as_string("check_state").map(|state| {
+                FormValue::Check(match state.as_str() {
+                    "Checked" => CheckState::Checked,
+                    "Indeterminate" => CheckState::Indeterminate,
+                    _ => CheckState::Unchecked,
+                })
+            }),
+            FormControlKind::RadioGroup | FormControlKind::Picker => Some(FormValue::Selection(
+                node.get_prop_f32("selected_index").map(|index| index as usize)
+            )),
+            FormControlKind::Slider | FormControlKind::Stepper => node.get_prop_f32("value").map(FormValue::Number),
+        };
+
+        state.sync_value(value);
+        state.focused = node.get_prop_bool(keys::FOCUSED).unwrap_or(false);
+
+        Some(state)
+    }
+
+    pub fn write_to(&self, node: &mut RenderNode) {
+        match self.value {
+            FormValue::Bool(is_on) => node.set_prop("is_on", is_on),
+            FormValue::Check(state) => node.set_prop("check_state", format!("{:?}", state)),
+            FormValue::Selection(Some(index)) => node.set_prop("selected_index", index as i32),
+            FormValue::Selection(None) => node,
+            FormValue::Number(value) => node.set_prop("value", value),
+        };
+
+        node.set_prop(keys::ENABLED, self.config.enabled);
+        node.set_prop(keys::FOCUSABLE, self.config.enabled);
+        node.set_prop(keys::FOCUSED, self.focused);
+        node.set_prop("button_state", format!("{:?}", self.button_state()));
+        node.set_prop("min", self.config.min);
+        node.set_prop("max", self.config.max);
+        node.set_prop("wraps", self.config.wraps);
+        node.set_prop("allows_indeterminate", self.config.allows_indeterminate);
+
+        if let Some(step) = self.config.step {
+            node.set_prop("step", step);
+        }
+
+        if !self.config.options.is_empty() {
+            node.set_prop("options", Property::Array(
+                self.config.options.iter().cloned().map(Property::String).collect()
+            ));
+        }
+
+        node.set_prop(keys::IS_ACCESSIBILITY_ELEMENT, true);
+        node.set_prop(keys::ACCESSIBILITY_ROLE, self.kind.accessibility_role());
+        node.set_prop(keys::ACCESSIBILITY_VALUE, self.accessibility_value());
+    }
+
+    pub fn configure(&mut self, config: FormControlConfig) {
+        if self.config == config {
+            return;
+        }
+
+        self.config = config;
+
+        if !self.config.enabled {
+            self.expanded = false;
+            self.dragging = false;
+        }
+
+        self.value = self.normalize(self.value);
+    }
+
+    pub fn sync_value(&mut self, value: Option<FormValue>) {
+        let Some(value) = value else {
+            return;
+        };
+
+        if self.synced_value == Some(value) {
+            return;
+        }
+
+        self.synced_value = Some(value);
+        self.value = self.normalize(value);
+    }
+
+    pub fn kind(&self) -> FormControlKind {
+        self.kind
+    }
+
+    pub fn config(&self) -> &FormControlConfig {
+        &self.config
+    }
+
+    pub fn value(&self) -> FormValue {
+        self.value
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    pub fn button_state(&self) -> ButtonState {
+        if !self.config.enabled {
+            ButtonState::Disabled
+        } else if matches!(self.interaction, ButtonState::Pressed | ButtonState::Hovered) {
+            self.interaction.clone()
+        } else if self.focused {
+            ButtonState::Focused
+        } else {
+            ButtonState::Normal
+        }
+    }
+
+    pub fn set_interaction(&mut self, interaction: ButtonState) -> bool {
+        if self.interaction == interaction {
+            return false;
+        }
+
+        self.interaction = interaction;
+        true
+    }
+
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+
+        if !focused {
+            self.expanded = false;
+            self.highlighted = None;
+        }
+    }
+
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.expanded = expanded && self.config.enabled;
+        self.highlighted = if self.expanded {
+            self.value.as_selection().or(Some(0))
+        } else {
+            None
+        };
+    }
+
+    pub fn set_dragging(&mut self, dragging: bool) {
+        self.dragging = dragging && self.config.enabled;
+    }
+
+    pub fn fraction(&self) -> f32 {
+        let range = self.config.max - self.config.min;
+        let value = self.value.as_number().unwrap_or(self.config.min);
+
+        if range <= 0.0 {
+            0.0
+        } else {
+            ((value - self.config.min) / range).clamp(0.0, 1.0)
+        }
+    }
+
+    pub fn selected_option(&self) -> Option<&str> {
+        self.value.as_selection()
+            .and_then(|index| self.config.options.get(index))
+            .map(String::as_str)
+    }
+
+    pub fn can_step(&self, direction: i32) -> bool {
+        let value = self.value.as_number().unwrap_or(self.config.min);
+
+        self.config.enabled && (self.config.wraps || match direction.signum() {
+            1 => value < self.config.max,
+            -1 => value > self.config.min,
+            _ => false,
+        })
+    }
+
+    pub fn activate(&mut self) -> bool {
+        if !self.config.enabled {
+            return false;
+        }
+
+        match self.kind {
+            FormControlKind::Toggle => {
+                let is_on = self.value.as_bool().unwrap_or(false);
+                self.set_value(FormValue::Bool(!is_on))
+            },
+            FormControlKind::Checkbox => {
+                let next = match self.value {
+                    FormValue::Check(CheckState::Checked) => CheckState::Unchecked,
+                    _ => CheckState::Checked,
+                };
+                self.set_value(FormValue::Check(next))
+            },
+            FormControlKind::RadioGroup => match self.highlighted {
+                Some(index) => self.select(index),
+                None => false,
+            },
+            FormControlKind::Picker => {
+                if self.expanded {
+                    let changed = self.highlighted.is_some_and(|index| self.select(index));
+                    self.set_expanded(false);
+                    changed
+                } else {
+                    self.set_expanded(true);
+                    false
+                }
+            },
+            FormControlKind::Stepper => self.step_by(1),
+            FormControlKind::Slider => false,
+        }
+    }
+
+    pub fn select(&mut self, index: usize) -> bool {
+        if !self.config.enabled || index >= self.config.options.len() {
+            return false;
+        }
+
+        self.highlighted = Some(index).filter(|_| self.expanded || self.kind == FormControlKind::RadioGroup);
+        self.set_value(FormValue::Selection(Some(index)))
+    }
+
+    pub fn set_number(&mut self, value: f32) -> bool {
+        if !self.config.enabled {
+            return false;
+        }
+
+        self.set_value(FormValue::Number(value))
+    }
+
+    pub fn set_fraction(&mut self, fraction: f32) -> bool {
+        let value = self.config.min + fraction.clamp(0.0, 1.0) * (self.config.max - self.config.min);
+        self.set_number(value)
+    }
+
+    pub fn step_by(&mut self, steps: i32) -> bool {
+        if !self.config.enabled || steps == 0 {
+            return false;
+        }
+
+        let value = self.value.as_number().unwrap_or(self.config.min);
+        let mut next = value + self.step_size() * steps as f32;
+
+        if self.config.wraps {
+            if next > self.config.max + f32::EPSILON {
+                next = self.config.min;
+            } else if next < self.config.min - f32::EPSILON {
+                next = self.config.max;
+            }
+        }
+
+        self.set_number(next)
+    }
+
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if !self.config.enabled {
+            return false;
+        }
+
+        match self.kind {
+            FormControlKind::Toggle | FormControlKind::Checkbox => match key {
+                " " | "Enter" => self.activate(),
+                _ => false,
+            },
+            FormControlKind::RadioGroup => {
+                let count = self.config.options.len();
+                if count == 0 {
+                    return false;
+                }
+
+                let current = self.value.as_selection();
+                let target = match key {
+                    "ArrowDown" | "ArrowRight" => current.map(|index| (index + 1) % count).unwrap_or(0),
+                    "ArrowUp" | "ArrowLeft" => current.map(|index| (index + count - 1) % count).unwrap_or(count - 1),
+                    "Home" => 0,
+                    "End" => count - 1,
+                    " " | "Enter" => current.unwrap_or(0),
+                    _ => return false,
+                };
+
+                self.select(target)
+            },
+            FormControlKind::Slider => match key {
+                "ArrowRight" | "ArrowUp" => self.step_by(1),
+                "ArrowLeft" | "ArrowDown" => self.step_by(-1),
+                "PageUp" => self.step_by(SLIDER_PAGE_STEPS),
+                "PageDown" => self.step_by(-SLIDER_PAGE_STEPS),
+                "Home" => self.set_number(self.config.min),
+                "End" => self.set_number(self.config.max),
+                _ => false,
+            },
+            FormControlKind::Stepper => match key {
+                "ArrowRight" | "ArrowUp" | "+" => self.step_by(1),
+                "ArrowLeft" | "ArrowDown" | "-" => self.step_by(-1),
+                "Home" => self.set_number(self.config.min),
+                "End" => self.set_number(self.config.max),
+                _ => false,
+            },
+            FormControlKind::Picker => self.handle_picker_key(key),
+        }
+    }
+
+    pub fn accessibility_value(&self) -> String {
+        match self.value {
+            FormValue::Bool(is_on) => if is_on { "on" } else { "off" }.to_string(),
+            FormValue::Check(CheckState::Checked) => "checked".to_string(),
+            FormValue::Check(CheckState::Unchecked) => "unchecked".to_string(),
+            FormValue::Check(CheckState::Indeterminate) => "mixed".to_string(),
+            FormValue::Selection(Some(index)) => format!(
+                "{}, {} of {}",
+                self.selected_option().unwrap_or_default(),
+                index + 1,
+                self.config.options.len()
+            ),
+            FormValue::Selection(None) => String::new(),
+            FormValue::Number(value) => self.format_number(value),
+        }
+    }
+
+    pub fn format_number(&self, value: f32) -> String {
+        let step = self.step_size();
+        let decimals = if step.fract().abs() < f32::EPSILON {
+            0
+        } else {
+            (-step.fract().abs().log10().floor()).clamp(0.0, 4.0) as usize
+        };
+
+        format!("{:.*}", decimals, value)
+    }
+
+    pub fn payload(&self, control_id: &str, is_final: bool) -> FormValuePayload {
+        FormValuePayload {
+            control_id: control_id.to_string(),
+            kind: self.kind,
+            value: self.value,
+            selected_option: self.selected_option().map(str::to_string),
+            is_final,
+        }
+    }
+
+    pub fn value_change_event(&self, control_id: &str, source: EventSource, is_final: bool) -> Event {
+        let value = match self.value {
+            FormValue::Bool(is_on) => is_on.to_string(),
+            FormValue::Check(state) => format!("{:?}", state),
+            FormValue::Selection(index) => index.map(|index| index.to_string()).unwrap_or_default(),
+            FormValue::Number(value) => value.to_string(),
+        };
+
+        Event::new(EventType::ValueChange, source)
+            .with_property("control_id", control_id)
+            .with_property("value", &value)
+            .with_property("is_final", &is_final.to_string())
+            .with_payload(self.payload(control_id, is_final))
+    }
+
+    fn handle_picker_key(&mut self, key: &str) -> bool {
+        let count = self.config.options.len();
+
+        if !self.expanded {
+            if matches!(key, " " | "Enter" | "ArrowDown" | "ArrowUp") {
+                self.set_expanded(true);
+            }
+            return false;
+        }
+
+        match key {
+            "ArrowDown" if count > 0 => {
+                self.highlighted = Some(self.highlighted.map(|index| (index + 1).min(count - 1)).unwrap_or(0));
+                false
+            },
+            "ArrowUp" if count > 0 => {
+                self.highlighted = Some(self.highlighted.map(|index| index.saturating_sub(1)).unwrap_or(0));
+                false
+            },
+            "Home" if count > 0 => {
+                self.highlighted = Some(0);
+                false
+            },
+            "End" if count > 0 => {
+                self.highlighted = Some(count - 1);
+                false
+            },
+            " " | "Enter" => self.activate(),
+            "Escape" | "Tab" => {
+                self.set_expanded(false);
+                false
+            },
+            _ => false,
+        }
+    }
+
+    fn set_value(&mut self, value: FormValue) -> bool {
+        let value = self.normalize(value);
+
+        if value == self.value {
+            return false;
+        }
+
+        self.value = value;
+        true
+    }
+
+    fn step_size(&self) -> f32 {
+        match self.config.step.filter(|step| *step > 0.0) {
+            Some(step) => step,
+            None if self.kind == FormControlKind::Slider => {
+                (self.config.max - self.config.min).abs() / SLIDER_DEFAULT_STEPS
+            },
+            None => 1.0,
+        }
+    }
+
+    fn normalize(&self, value: FormValue) -> FormValue {
+        match value {
+            FormValue::Check(CheckState::Indeterminate) if !self.config.allows_indeterminate => {
+                FormValue::Check(CheckState::Unchecked)
+            },
+            FormValue::Selection(Some(index)) if index >= self.config.options.len() => {
+                FormValue::Selection(None)
+            },
+            FormValue::Number(number) => {
+                let (min, max) = (self.config.min, self.config.max.max(self.config.min));
+                let mut number = number.clamp(min, max);
+
+                if let Some(step) = self.config.step.filter(|step| *step > 0.0) {
+                    number = (min + ((number - min) / step).round() * step).min(max);
+                }
+
+                FormValue::Number(number)
+            },
+            value => value,
+        }
+    }
+}
+
+static FORM_CONTROL_STATES: OnceLock<RwLock<HashMap<String, FormControlState>>> = OnceLock::new();
+
+pub fn form_control_registry() -> &'static RwLock<HashMap<String, FormControlState>> {
+    FORM_CONTROL_STATES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+pub fn with_form_control_state<R>(
+    control_id: &str,
+    kind: FormControlKind,
+    f: impl FnOnce(&mut FormControlState) -> R
+) -> R {
+    let mut registry = form_control_registry().write().unwrap();
+    let state = registry.entry(control_id.to_string())
+        .or_insert_with(|| FormControlState::new(kind));
+
+    if state.kind != kind {
+        *state = FormControlState::new(kind);
+    }
+
+    f(state)
+}
+
+pub fn form_control_state_for_node(node: &RenderNode) -> Option<FormControlState> {
+    let live = node.get_prop_as_string("control_id").and_then(|control_id| {
+        let registry = form_control_registry().read().ok()?;
+        registry.get(&control_id).cloned()
+    });
+
+    live.or_else(|| FormControlState::from_node(node))
+}
+
+pub fn focused_form_control() -> Option<String> {
+    form_control_registry().read().unwrap()
+        .iter()
+        .find(|(_, state)| state.is_focused())
+        .map(|(control_id, _)| control_id.clone())
+}
+
+pub fn focus_form_control(control_id: &str) -> Option<String> {
+    let previous = blur_form_controls().filter(|previous| previous != control_id);
+
+    if let Some(state) = form_control_registry().write().unwrap().get_mut(control_id) {
+        state.set_focused(true);
+    }
+
+    previous
+}
+
+pub fn blur_form_controls() -> Option<String> {
+    let mut registry = form_control_registry().write().unwrap();
+    let mut previous = None;
+
+    for (control_id, state) in registry.iter_mut() {
+        if state.is_focused() {
+            state.set_focused(false);
+            previous = Some(control_id.clone());
+        }
+    }
+
+    previous
+}
+
+pub fn collapse_pickers() -> bool {
+    let mut registry = form_control_registry().write().unwrap();
+    let mut collapsed = false;
+
+    for state in registry.values_mut() {
+        if state.is_expanded() {
+            state.set_expanded(false);
+            collapsed = true;
+        }
+    }
+
+    collapsed
+}
+
+pub fn remove_form_control_state(control_id: &str) -> Option<FormControlState> {
+    form_control_registry().write().unwrap().remove(control_id)
+}
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use milost_core::image::{apply_filters, Filter};
 use crate::shared::styles::{BorderStyle, ShadowEffect};
 use crate::shared::edge_insets::EdgeInsets;
 
@@ -60,6 +61,30 @@ pub enum ImageFilter {
     Invert(bool),
 }
 
+impl ImageFilter {
+    pub fn to_filter(&self) -> Filter {
+        match *self {
+            ImageFilter::Blur(radius) => Filter::GaussianBlur(radius as f64),
+            ImageFilter::Grayscale(intensity) => Filter::Grayscale(intensity as f64),
+            ImageFilter::Sepia(intensity) => Filter::Sepia(intensity as f64),
+            ImageFilter::Saturation(value) => Filter::Saturate(value as f64),
+            ImageFilter::Brightness(value) => Filter::Brightness(value as f64),
+            ImageFilter::Contrast(value) => Filter::Contrast(value as f64),
+            ImageFilter::Hue(value) => Filter::HueRotate(value as f64 * 360.0),
+            ImageFilter::Invert(enabled) => Filter::Invert(if enabled { 1.0 } else { 0.0 }),
+        }
+    }
+
+    pub fn to_css(&self) -> String {
+        self.to_filter().to_css().unwrap_or_default()
+    }
+}
+
+pub fn filter_rgba(data: &[u8], width: u32, height: u32, filters: &[ImageFilter]) -> Result<Vec<u8>, String> {
+    let filters: Vec<Filter> = filters.iter().map(ImageFilter::to_filter).collect();
+    apply_filters(data, width, height, &filters).map_err(|e| e.to_string())
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ImageProps {
     pub source: ImageSource,
@@ -0,0 +1,212 @@
+use std::fmt;
+use std::sync::Arc;
+use serde::{Serialize, Deserialize};
+use crate::shared::styles::BorderStyle;
+use crate::shared::edge_insets::EdgeInsets;
+
+use super::{Color, UIComponent};
+
+pub type ListItemBuilderFn = Arc<dyn Fn(usize) -> UIComponent + Send + Sync>;
+
+#[derive(Clone)]
+pub struct ListItemBuilder {
+    builder: ListItemBuilderFn,
+}
+
+impl ListItemBuilder {
+    pub fn new<F>(builder: F) -> Self
+    where
+        F: Fn(usize) -> UIComponent + Send + Sync + 'static
+    {
+        Self {
+            builder: Arc::new(builder),
+        }
+    }
+
+    pub fn build(&self, index: usize) -> UIComponent {
+        (self.builder)(index)
+    }
+}
+
+impl fmt::Debug for ListItemBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ListItemBuilder(Fn)")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum ListScrollAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+    Nearest,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ListSection {
+    pub start_index: usize,
+    pub header: UIComponent,
+    pub header_height: Option<f32>,
+}
+
+impl ListSection {
+    pub fn new(start_index: usize, header: UIComponent) -> Self {
+        Self {
+            start_index,
+            header,
+            header_height: None,
+        }
+    }
+
+    pub fn with_header_height(mut self, height: f32) -> Self {
+        self.header_height = Some(height);
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ListProps {
+    pub list_id: String,
+    pub item_count: usize,
+    #[serde(skip)]
+    pub item_builder: Option<ListItemBuilder>,
+
+    pub estimated_item_height: Option<f32>,
+    pub fixed_item_height: Option<f32>,
+    pub overscan: Option<usize>,
+
+    pub sections: Vec<ListSection>,
+    pub sticky_headers: Option<bool>,
+
+    pub initial_scroll_index: Option<usize>,
+    pub initial_scroll_alignment: Option<ListScrollAlignment>,
+
+    pub shows_indicators: Option<bool>,
+    pub scrollbar_color: Option<Color>,
+    pub scrollbar_width: Option<f32>,
+    pub scrollbar_margin: Option<f32>,
+
+    pub accessibility_label: Option<String>,
+
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub min_width: Option<f32>,
+    pub max_width: Option<f32>,
+    pub min_height: Option<f32>,
+    pub max_height: Option<f32>,
+
+    pub background_color: Option<Color>,
+    pub border_width: Option<f32>,
+    pub border_color: Option<Color>,
+    pub border_radius: Option<f32>,
+    pub border_style: Option<BorderStyle>,
+
+    pub padding: Option<f32>,
+    pub edge_insets: Option<EdgeInsets>,
+    pub opacity: Option<f32>,
+}
+
+impl Default for ListProps {
+    fn default() -> Self {
+        Self {
+            list_id: String::new(),
+            item_count: 0,
+            item_builder: None,
+
+            estimated_item_height: Some(44.0),
+            fixed_item_height: None,
+            overscan: Some(3),
+
+            sections: Vec::new(),
+            sticky_headers: Some(true),
+
+            initial_scroll_index: None,
+            initial_scroll_alignment: None,
+
+            shows_indicators: Some(true),
+            scrollbar_color: Some(Color::Gray),
+            scrollbar_width: Some(6.0),
+            scrollbar_margin: Some(2.0),
+
+            accessibility_label: None,
+
+            width: None,
+            height: None,
+            min_width: None,
+            max_width: None,
+            min_height: None,
+            max_height: None,
+
+            background_color: None,
+            border_width: None,
+            border_color: None,
+            border_radius: None,
+            border_style: None,
+
+            padding: None,
+            edge_insets: None,
+            opacity: None,
+        }
+    }
+}
+
+impl ListProps {
+    pub fn new<F>(list_id: &str, item_count: usize, item_builder: F) -> Self
+    where
+        F: Fn(usize) -> UIComponent + Send + Sync + 'static
+    {
+        Self {
+            list_id: list_id.to_string(),
+            item_count,
+            item_builder: Some(ListItemBuilder::new(item_builder)),
+            ..Default::default()
+        }
+    }
+
+    pub fn estimated_item_height(mut self, height: f32) -> Self {
+        self.estimated_item_height = Some(height);
+        self
+    }
+
+    pub fn fixed_item_height(mut self, height: f32) -> Self {
+        self.fixed_item_height = Some(height);
+        self
+    }
+
+    pub fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = Some(rows);
+        self
+    }
+
+    pub fn add_section(mut self, section: ListSection) -> Self {
+        self.sections.push(section);
+        self.sections.sort_by_key(|section| section.start_index);
+        self
+    }
+
+    pub fn sticky_headers(mut self, sticky: bool) -> Self {
+        self.sticky_headers = Some(sticky);
+        self
+    }
+
+    pub fn initial_scroll_index(mut self, index: usize, alignment: ListScrollAlignment) -> Self {
+        self.initial_scroll_index = Some(index);
+        self.initial_scroll_alignment = Some(alignment);
+        self
+    }
+
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn build_item(&self, index: usize) -> Option<UIComponent> {
+        self.item_builder.as_ref().map(|builder| builder.build(index))
+    }
+}
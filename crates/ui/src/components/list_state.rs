@@ -0,0 +1,460 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::{OnceLock, RwLock};
+use crate::render::node::RenderNode;
+use crate::render::transformers::utils::generate_unique_id;
+
+use super::list::ListScrollAlignment;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListRow {
+    Header(usize),
+    Item(usize),
+}
+
+impl ListRow {
+    pub fn write_to(&self, node: &mut RenderNode) {
+        let (kind, index) = match self {
+            ListRow::Header(section) => ("Header", *section),
+            ListRow::Item(index) => ("Item", *index),
+        };
+
+        node.set_prop("_list_row_kind", kind);
+        node.set_prop("_list_row_index", index as i32);
+    }
+
+    pub fn read_from(node: &RenderNode) -> Option<Self> {
+        let index = node.properties.get_integer("_list_row_index")? as usize;
+
+        match node.get_prop_string("_list_row_kind")?.as_str() {
+            "Header" => Some(ListRow::Header(index)),
+            "Item" => Some(ListRow::Item(index)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ListState {
+    item_count: usize,
+    section_starts: Vec<usize>,
+    estimated_item_height: f32,
+    fixed_item_height: Option<f32>,
+    estimated_header_heights: Vec<f32>,
+
+    rows: Vec<ListRow>,
+    item_heights: Vec<Option<f32>>,
+    header_heights: Vec<Option<f32>>,
+    offsets: Vec<f32>,
+    offsets_dirty: bool,
+
+    scroll_offset: f32,
+    viewport_height: f32,
+    pending_scroll: Option<(usize, ListScrollAlignment)>,
+    scroll_target: Option<(usize, ListScrollAlignment)>,
+    initial_scroll_applied: bool,
+
+    assigned_ids: HashMap<ListRow, String>,
+    free_ids: Vec<String>,
+}
+
+impl Default for ListState {
+    fn default() -> Self {
+        Self {
+            item_count: 0,
+            section_starts: Vec::new(),
+            estimated_item_height: 44.0,
+            fixed_item_height: None,
+            estimated_header_heights: Vec::new(),
+
+            rows: Vec::new(),
+            item_heights: Vec::new(),
+            header_heights: Vec::new(),
+            offsets: vec![0.0],
+            offsets_dirty: false,
+
+            scroll_offset: 0.0,
+            viewport_height: 0.0,
+            pending_scroll: None,
+            scroll_target: None,
+            initial_scroll_applied: false,
+
+            assigned_ids: HashMap::new(),
+            free_ids: Vec::new(),
+        }
+    }
+}
+
+impl ListState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn configure(
+        &mut self,
+        item_count: usize,
+        section_starts: Vec<usize>,
+        estimated_header_heights: Vec<f32>,
+        estimated_item_height: f32,
+        fixed_item_height: Option<f32>
+    ) {
+        let structure_changed = item_count != self.item_count || section_starts != self.section_starts;
+
+        if structure_changed {
+            self.item_count = item_count;
+            self.section_starts = section_starts;
+            self.item_heights.resize(item_count, None);
+            self.header_heights.resize(self.section_starts.len(), None);
+            self.rebuild_rows();
+            self.offsets_dirty = true;
+        }
+
+        if estimated_header_heights != self.estimated_header_heights
+            || estimated_item_height != self.estimated_item_height
+            || fixed_item_height != self.fixed_item_height
+        {
+            self.estimated_header_heights = estimated_header_heights;
+            self.estimated_item_height = estimated_item_height;
+            self.fixed_item_height = fixed_item_height;
+            self.offsets_dirty = true;
+        }
+
+        if structure_changed {
+            self.scroll_offset = self.clamp_offset(self.scroll_offset);
+        }
+    }
+
+    fn rebuild_rows(&mut self) {
+        self.rows.clear();
+        self.rows.reserve(self.item_count + self.section_starts.len());
+
+        let mut next_section = 0;
+
+        for index in 0..self.item_count {
+            while next_section < self.section_starts.len() && self.section_starts[next_section] <= index {
+                self.rows.push(ListRow::Header(next_section));
+                next_section += 1;
+            }
+
+            self.rows.push(ListRow::Item(index));
+        }
+
+        while next_section < self.section_starts.len() {
+            self.rows.push(ListRow::Header(next_section));
+            next_section += 1;
+        }
+    }
+
+    fn ensure_offsets(&mut self) {
+        if !self.offsets_dirty && self.offsets.len() == self.rows.len() + 1 {
+            return;
+        }
+
+        let mut offsets = Vec::with_capacity(self.rows.len() + 1);
+        let mut total = 0.0;
+        offsets.push(total);
+
+        for row in &self.rows {
+            total += self.row_height(*row);
+            offsets.push(total);
+        }
+
+        self.offsets = offsets;
+        self.offsets_dirty = false;
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn row_at(&self, row_index: usize) -> Option<ListRow> {
+        self.rows.get(row_index).copied()
+    }
+
+    pub fn row_index_of(&self, row: ListRow) -> Option<usize> {
+        match row {
+            ListRow::Item(index) if index < self.item_count => {
+                let headers_before = self.section_starts.partition_point(|&start| start <= index);
+                Some(index + headers_before)
+            },
+            ListRow::Header(section) if section < self.section_starts.len() => {
+                Some(self.section_starts[section].min(self.item_count) + section)
+            },
+            _ => None,
+        }
+    }
+
+    pub fn row_height(&self, row: ListRow) -> f32 {
+        match row {
+            ListRow::Item(index) => self.fixed_item_height
+                .or_else(|| self.item_heights.get(index).copied().flatten())
+                .unwrap_or(self.estimated_item_height),
+            ListRow::Header(section) => self.header_heights.get(section).copied().flatten()
+                .or_else(|| self.estimated_header_heights.get(section).copied())
+                .unwrap_or(self.estimated_item_height),
+        }
+    }
+
+    pub fn row_offset(&mut self, row: ListRow) -> f32 {
+        self.ensure_offsets();
+
+        self.row_index_of(row)
+            .map(|row_index| self.offsets[row_index])
+            .unwrap_or(0.0)
+    }
+
+    pub fn total_height(&mut self) -> f32 {
+        self.ensure_offsets();
+        self.offsets.last().copied().unwrap_or(0.0)
+    }
+
+    pub fn viewport_height(&self) -> f32 {
+        self.viewport_height
+    }
+
+    pub fn set_viewport_height(&mut self, height: f32) {
+        self.viewport_height = height.max(0.0);
+
+        if let Some((index, alignment)) = self.pending_scroll.take() {
+            self.scroll_to_index(index, alignment);
+        } else {
+            self.scroll_offset = self.clamp_offset(self.scroll_offset);
+        }
+    }
+
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
+
+    pub fn max_scroll_offset(&mut self) -> f32 {
+        (self.total_height() - self.viewport_height).max(0.0)
+    }
+
+    fn clamp_offset(&mut self, offset: f32) -> f32 {
+        offset.clamp(0.0, self.max_scroll_offset())
+    }
+
+    pub fn set_scroll_offset(&mut self, offset: f32) {
+        self.pending_scroll = None;
+        self.scroll_target = None;
+        self.scroll_offset = self.clamp_offset(offset);
+    }
+
+    pub fn offset_for_index(&mut self, index: usize, alignment: ListScrollAlignment) -> f32 {
+        let row = ListRow::Item(index.min(self.item_count.saturating_sub(1)));
+        let start = self.row_offset(row);
+        let height = self.row_height(row);
+        let viewport = self.viewport_height;
+
+        let target = match alignment {
+            ListScrollAlignment::Start => start - self.sticky_header_height_at(start),
+            ListScrollAlignment::Center => start - (viewport - height) / 2.0,
+            ListScrollAlignment::End => start + height - viewport,
+            ListScrollAlignment::Nearest => {
+                if start < self.scroll_offset {
+                    start - self.sticky_header_height_at(start)
+                } else if start + height > self.scroll_offset + viewport {
+                    start + height - viewport
+                } else {
+                    self.scroll_offset
+                }
+            },
+        };
+
+        self.clamp_offset(target)
+    }
+
+    pub fn scroll_to_index(&mut self, index: usize, alignment: ListScrollAlignment) {
+        if self.item_count == 0 {
+            return;
+        }
+
+        if self.viewport_height <= 0.0 {
+            self.pending_scroll = Some((index, alignment));
+            return;
+        }
+
+        self.scroll_offset = self.offset_for_index(index, alignment);
+        self.scroll_target = Some((index, alignment));
+    }
+
+    pub fn apply_initial_scroll(&mut self, index: usize, alignment: ListScrollAlignment) {
+        if !self.initial_scroll_applied {
+            self.initial_scroll_applied = true;
+            self.scroll_to_index(index, alignment);
+        }
+    }
+
+    pub fn visible_rows(&mut self, overscan: usize) -> Range<usize> {
+        self.ensure_offsets();
+
+        let row_count = self.rows.len();
+        if row_count == 0 {
+            return 0..0;
+        }
+
+        let top = self.scroll_offset;
+        let bottom = if self.viewport_height > 0.0 {
+            top + self.viewport_height
+        } else {
+            top + self.estimated_item_height * 10.0
+        };
+
+        let first = self.offsets[1..].partition_point(|&end| end <= top);
+        let last = self.offsets[..row_count].partition_point(|&start| start < bottom);
+
+        first.saturating_sub(overscan)..(last + overscan).min(row_count)
+    }
+
+    fn section_at(&mut self, offset: f32) -> Option<usize> {
+        self.ensure_offsets();
+
+        let mut current = None;
+        for section in 0..self.section_starts.len() {
+            let header_offset = self.row_index_of(ListRow::Header(section))
+                .map(|row_index| self.offsets[row_index])
+                .unwrap_or(f32::MAX);
+
+            if header_offset <= offset {
+                current = Some(section);
+            } else {
+                break;
+            }
+        }
+
+        current
+    }
+
+    fn sticky_header_height_at(&mut self, offset: f32) -> f32 {
+        self.section_at(offset)
+            .map(|section| self.row_height(ListRow::Header(section)))
+            .unwrap_or(0.0)
+    }
+
+    pub fn sticky_header(&mut self) -> Option<(usize, f32)> {
+        let section = self.section_at(self.scroll_offset)?;
+        let header_height = self.row_height(ListRow::Header(section));
+
+        let mut position = 0.0;
+
+        if section + 1 < self.section_starts.len() {
+            let next_header = self.row_offset(ListRow::Header(section + 1)) - self.scroll_offset;
+            if next_header < header_height {
+                position = next_header - header_height;
+            }
+        }
+
+        Some((section, position))
+    }
+
+    pub fn record_row_heights(&mut self, measured: &[(ListRow, f32)]) {
+        self.ensure_offsets();
+
+        let anchor = self.offsets[1..].partition_point(|&end| end <= self.scroll_offset);
+        let mut anchor_delta = 0.0;
+
+        for (row, height) in measured {
+            let previous = self.row_height(*row);
+
+            let slot = match row {
+                ListRow::Item(_) if self.fixed_item_height.is_some() => continue,
+                ListRow::Item(index) => self.item_heights.get_mut(*index),
+                ListRow::Header(section) => self.header_heights.get_mut(*section),
+            };
+
+            if let Some(slot) = slot {
+                *slot = Some(*height);
+            } else {
+                continue;
+            }
+
+            if (previous - height).abs() > f32::EPSILON {
+                self.offsets_dirty = true;
+
+                if self.row_index_of(*row).is_some_and(|row_index| row_index < anchor) {
+                    anchor_delta += height - previous;
+                }
+            }
+        }
+
+        if let Some((index, alignment)) = self.scroll_target.take() {
+            self.scroll_offset = self.offset_for_index(index, alignment);
+        } else if anchor_delta != 0.0 {
+            let offset = self.scroll_offset + anchor_delta;
+            self.scroll_offset = self.clamp_offset(offset);
+        }
+    }
+
+    pub fn invalidate_item(&mut self, index: usize) {
+        if let Some(slot) = self.item_heights.get_mut(index) {
+            *slot = None;
+            self.offsets_dirty = true;
+        }
+    }
+
+    pub fn invalidate_all(&mut self) {
+        self.item_heights.iter_mut().for_each(|slot| *slot = None);
+        self.header_heights.iter_mut().for_each(|slot| *slot = None);
+        self.offsets_dirty = true;
+    }
+
+    pub fn assign_node_ids(&mut self, rows: &[ListRow]) -> Vec<String> {
+        let wanted: HashSet<ListRow> = rows.iter().copied().collect();
+
+        let released: Vec<ListRow> = self.assigned_ids.keys()
+            .filter(|row| !wanted.contains(row))
+            .copied()
+            .collect();
+
+        for row in released {
+            if let Some(id) = self.assigned_ids.remove(&row) {
+                self.free_ids.push(id);
+            }
+        }
+
+        rows.iter()
+            .map(|row| {
+                if let Some(id) = self.assigned_ids.get(row) {
+                    return id.clone();
+                }
+
+                let id = self.free_ids.pop()
+                    .unwrap_or_else(|| generate_unique_id("list-row"));
+                self.assigned_ids.insert(*row, id.clone());
+                id
+            })
+            .collect()
+    }
+
+    pub fn recycled_id_count(&self) -> usize {
+        self.free_ids.len()
+    }
+}
+
+static LIST_STATES: OnceLock<RwLock<HashMap<String, ListState>>> = OnceLock::new();
+
+pub fn list_state_registry() -> &'static RwLock<HashMap<String, ListState>> {
+    LIST_STATES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+pub fn with_list_state<R>(list_id: &str, f: impl FnOnce(&mut ListState) -> R) -> R {
+    let mut registry = list_state_registry().write().unwrap();
+    let state = registry.entry(list_id.to_string()).or_default();
+    f(state)
+}
+
+pub fn scroll_list_to_index(list_id: &str, index: usize, alignment: ListScrollAlignment) {
+    with_list_state(list_id, |state| state.scroll_to_index(index, alignment));
+}
+
+pub fn set_list_scroll_offset(list_id: &str, offset: f32) {
+    with_list_state(list_id, |state| state.set_scroll_offset(offset));
+}
+
+pub fn remove_list_state(list_id: &str) -> Option<ListState> {
+    list_state_registry().write().unwrap().remove(list_id)
+}
@@ -6,8 +6,21 @@ pub mod component;
 pub mod zstack;
 pub mod image;
 pub mod scroll;
+pub mod scroll_controller;
 pub mod spacer;
 pub mod divider;
+pub mod list;
+pub mod list_state;
+pub mod text_input;
+pub mod text_input_state;
+pub mod toggle;
+pub mod checkbox;
+pub mod radio_group;
+pub mod slider;
+pub mod stepper;
+pub mod picker;
+pub mod form_control_state;
+pub mod validation_state;
 pub mod registry;
 
 pub use text::{
@@ -44,6 +57,7 @@ pub use zstack::{
 pub use image::{
     ImageProps,
     ImageFilter,
+    filter_rgba,
     ImageSource,
     ResizeMode,
     ContentMode,
@@ -57,6 +71,18 @@ pub use scroll::{
     ScrollInsets,
 };
 
+pub use scroll_controller::{
+    ScrollController,
+    ScrollPhase,
+    ScrollPayload,
+    scroll_controller_registry,
+    with_scroll_controller,
+    scroll_to,
+    step_scroll_controllers,
+    is_any_scroll_animating,
+    remove_scroll_controller,
+};
+
 pub use spacer::{
     SpacerProps,
     SpacerStrategy,
@@ -69,6 +95,75 @@ pub use divider::{
     divider_color_schemes,
 };
 
+pub use list::{
+    ListProps,
+    ListSection,
+    ListItemBuilder,
+    ListItemBuilderFn,
+    ListScrollAlignment,
+};
+
+pub use list_state::{
+    ListState,
+    ListRow,
+    list_state_registry,
+    with_list_state,
+    scroll_list_to_index,
+    set_list_scroll_offset,
+    remove_list_state,
+};
+
+pub use text_input::TextInputProps;
+
+pub use text_input_state::{
+    TextInputState,
+    TextSelection,
+    TextMovement,
+    TextInputAction,
+    Composition,
+    KeyModifiers,
+    text_input_registry,
+    with_text_input_state,
+    focused_text_input,
+    focus_text_input,
+    blur_text_inputs,
+    remove_text_input_state,
+};
+
+pub use toggle::ToggleProps;
+pub use checkbox::{CheckboxProps, CheckState};
+pub use radio_group::RadioGroupProps;
+pub use slider::SliderProps;
+pub use stepper::StepperProps;
+pub use picker::PickerProps;
+
+pub use form_control_state::{
+    FormControlState,
+    FormControlKind,
+    FormControlConfig,
+    FormValue,
+    FormValuePayload,
+    form_control_registry,
+    with_form_control_state,
+    form_control_state_for_node,
+    focused_form_control,
+    focus_form_control,
+    blur_form_controls,
+    collapse_pickers,
+    remove_form_control_state,
+};
+
+pub use validation_state::{
+    FieldValidation,
+    field_validation_registry,
+    with_field_validation,
+    field_validation_for_node,
+    set_field_errors,
+    set_field_pending,
+    clear_field_validation,
+    remove_field_validation,
+};
+
 pub use registry::{
     ComponentTransformerRegistry,
     ComponentTransformerFn,
@@ -0,0 +1,93 @@
+use serde::{Serialize, Deserialize};
+use crate::shared::edge_insets::EdgeInsets;
+
+use super::Color;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PickerProps {
+    pub control_id: String,
+    pub options: Vec<String>,
+    pub selected_index: Option<usize>,
+    pub placeholder: Option<String>,
+    pub disabled: Option<bool>,
+    pub on_change: Option<String>,
+
+    pub max_visible_options: Option<usize>,
+    pub tint_color: Option<Color>,
+    pub text_color: Option<Color>,
+    pub placeholder_color: Option<Color>,
+    pub background_color: Option<Color>,
+    pub border_color: Option<Color>,
+    pub border_radius: Option<f32>,
+    pub font_size: Option<f32>,
+
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub padding: Option<f32>,
+    pub edge_insets: Option<EdgeInsets>,
+    pub opacity: Option<f32>,
+
+    pub accessibility_label: Option<String>,
+    pub accessibility_hint: Option<String>,
+}
+
+impl Default for PickerProps {
+    fn default() -> Self {
+        Self {
+            control_id: String::new(),
+            options: Vec::new(),
+            selected_index: None,
+            placeholder: None,
+            disabled: Some(false),
+            on_change: None,
+
+            max_visible_options: Some(6),
+            tint_color: Some(Color::Blue),
+            text_color: Some(Color::Black),
+            placeholder_color: Some(Color::Placeholder),
+            background_color: Some(Color::White),
+            border_color: Some(Color::LightGray),
+            border_radius: Some(6.0),
+            font_size: Some(16.0),
+
+            width: None,
+            height: None,
+            padding: Some(8.0),
+            edge_insets: None,
+            opacity: None,
+
+            accessibility_label: None,
+            accessibility_hint: None,
+        }
+    }
+}
+
+impl PickerProps {
+    pub fn new(control_id: &str, options: Vec<String>) -> Self {
+        Self {
+            control_id: control_id.to_string(),
+            options,
+            ..Default::default()
+        }
+    }
+
+    pub fn selected_index(mut self, index: usize) -> Self {
+        self.selected_index = Some(index);
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    pub fn on_change(mut self, handler_id: &str) -> Self {
+        self.on_change = Some(handler_id.to_string());
+        self
+    }
+}
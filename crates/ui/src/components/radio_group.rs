@@ -0,0 +1,87 @@
+use serde::{Serialize, Deserialize};
+use crate::shared::edge_insets::EdgeInsets;
+
+use super::Color;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RadioGroupProps {
+    pub control_id: String,
+    pub options: Vec<String>,
+    pub selected_index: Option<usize>,
+    pub disabled: Option<bool>,
+    pub on_change: Option<String>,
+
+    pub option_spacing: Option<f32>,
+    pub indicator_size: Option<f32>,
+    pub tint_color: Option<Color>,
+    pub border_color: Option<Color>,
+    pub label_color: Option<Color>,
+    pub font_size: Option<f32>,
+
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub padding: Option<f32>,
+    pub edge_insets: Option<EdgeInsets>,
+    pub opacity: Option<f32>,
+
+    pub accessibility_label: Option<String>,
+    pub accessibility_hint: Option<String>,
+}
+
+impl Default for RadioGroupProps {
+    fn default() -> Self {
+        Self {
+            control_id: String::new(),
+            options: Vec::new(),
+            selected_index: None,
+            disabled: Some(false),
+            on_change: None,
+
+            option_spacing: Some(8.0),
+            indicator_size: Some(20.0),
+            tint_color: Some(Color::Blue),
+            border_color: Some(Color::Gray),
+            label_color: Some(Color::Black),
+            font_size: Some(16.0),
+
+            width: None,
+            height: None,
+            padding: None,
+            edge_insets: None,
+            opacity: None,
+
+            accessibility_label: None,
+            accessibility_hint: None,
+        }
+    }
+}
+
+impl RadioGroupProps {
+    pub fn new(control_id: &str, options: Vec<String>) -> Self {
+        Self {
+            control_id: control_id.to_string(),
+            options,
+            ..Default::default()
+        }
+    }
+
+    pub fn selected_index(mut self, index: usize) -> Self {
+        self.selected_index = Some(index);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    pub fn on_change(mut self, handler_id: &str) -> Self {
+        self.on_change = Some(handler_id.to_string());
+        self
+    }
+
+    pub fn option_spacing(mut self, spacing: f32) -> Self {
+        self.option_spacing = Some(spacing);
+        self
+    }
+}
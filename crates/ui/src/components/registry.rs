@@ -47,6 +47,15 @@ impl ComponentTransformerRegistry {
             UIComponent::Scroll(_) => "Scroll",
             UIComponent::Spacer(_) => "Spacer",
             UIComponent::Divider(_) => "Divider",
+            UIComponent::List(_) => "List",
+            UIComponent::TextField(_) => "TextField",
+            UIComponent::TextArea(_) => "TextArea",
+            UIComponent::Toggle(_) => "Toggle",
+            UIComponent::Checkbox(_) => "Checkbox",
+            UIComponent::RadioGroup(_) => "RadioGroup",
+            UIComponent::Slider(_) => "Slider",
+            UIComponent::Stepper(_) => "Stepper",
+            UIComponent::Picker(_) => "Picker",
         };
         
         self.get_transformer(component_type)
@@ -127,6 +136,78 @@ impl ComponentTransformerRegistry {
                 panic!("Expected Divider component")
             }
         });
+        
+        self.register_transformer("List", |component| {
+            if let UIComponent::List(props) = component {
+                transform_list(props)
+            } else {
+                panic!("Expected List component")
+            }
+        });
+        
+        self.register_transformer("TextField", |component| {
+            if let UIComponent::TextField(props) = component {
+                transform_text_field(props)
+            } else {
+                panic!("Expected TextField component")
+            }
+        });
+        
+        self.register_transformer("TextArea", |component| {
+            if let UIComponent::TextArea(props) = component {
+                transform_text_area(props)
+            } else {
+                panic!("Expected TextArea component")
+            }
+        });
+        
+        self.register_transformer("Toggle", |component| {
+            if let UIComponent::Toggle(props) = component {
+                transform_toggle(props)
+            } else {
+                panic!("Expected Toggle component")
+            }
+        });
+        
+        self.register_transformer("Checkbox", |component| {
+            if let UIComponent::Checkbox(props) = component {
+                transform_checkbox(props)
+            } else {
+                panic!("Expected Checkbox component")
+            }
+        });
+        
+        self.register_transformer("RadioGroup", |component| {
+            if let UIComponent::RadioGroup(props) = component {
+                transform_radio_group(props)
+            } else {
+                panic!("Expected RadioGroup component")
+            }
+        });
+        
+        self.register_transformer("Slider", |component| {
+            if let UIComponent::Slider(props) = component {
+                transform_slider(props)
+            } else {
+                panic!("Expected Slider component")
+            }
+        });
+        
+        self.register_transformer("Stepper", |component| {
+            if let UIComponent::Stepper(props) = component {
+                transform_stepper(props)
+            } else {
+                panic!("Expected Stepper component")
+            }
+        });
+        
+        self.register_transformer("Picker", |component| {
+            if let UIComponent::Picker(props) = component {
+                transform_picker(props)
+            } else {
+                panic!("Expected Picker component")
+            }
+        });
     }
 }
 
@@ -194,6 +275,15 @@ impl<T: DrawingContext> ComponentRendererRegistry<T> {
         self.register_renderer("Scroll", ScrollRenderer);
         self.register_renderer("Spacer", SpacerRenderer);
         self.register_renderer("Divider", DividerRenderer);
+        self.register_renderer("List", ListRenderer);
+        self.register_renderer("TextField", TextInputRenderer);
+        self.register_renderer("TextArea", TextInputRenderer);
+        self.register_renderer("Toggle", ToggleRenderer);
+        self.register_renderer("Checkbox", CheckboxRenderer);
+        self.register_renderer("RadioGroup", RadioGroupRenderer);
+        self.register_renderer("Slider", SliderRenderer);
+        self.register_renderer("Stepper", StepperRenderer);
+        self.register_renderer("Picker", PickerRenderer);
     }
 }
 
@@ -212,6 +302,10 @@ pub fn transformer_registry() -> &'static RwLock<ComponentTransformerRegistry> {
 }
 
 pub fn transform_component(component: &UIComponent) -> RenderNode {
+    crate::render::transformers::utils::with_render_pass(|| transform_registered(component))
+}
+
+fn transform_registered(component: &UIComponent) -> RenderNode {
     let registry = transformer_registry().read().unwrap();
     
     if let Some(node) = registry.transform(component) {
@@ -228,6 +322,15 @@ pub fn transform_component(component: &UIComponent) -> RenderNode {
         UIComponent::Scroll(props) => crate::render::transformers::transform_scroll(props),
         UIComponent::Spacer(props) => crate::render::transformers::transform_spacer(props),
         UIComponent::Divider(props) => crate::render::transformers::transform_divider(props),
+        UIComponent::List(props) => crate::render::transformers::transform_list(props),
+        UIComponent::TextField(props) => crate::render::transformers::transform_text_field(props),
+        UIComponent::TextArea(props) => crate::render::transformers::transform_text_area(props),
+        UIComponent::Toggle(props) => crate::render::transformers::transform_toggle(props),
+        UIComponent::Checkbox(props) => crate::render::transformers::transform_checkbox(props),
+        UIComponent::RadioGroup(props) => crate::render::transformers::transform_radio_group(props),
+        UIComponent::Slider(props) => crate::render::transformers::transform_slider(props),
+        UIComponent::Stepper(props) => crate::render::transformers::transform_stepper(props),
+        UIComponent::Picker(props) => crate::render::transformers::transform_picker(props),
     }
 }
 
@@ -25,6 +25,16 @@ pub enum DecelerationRate {
     Custom(f32),
 }
 
+impl DecelerationRate {
+    pub fn per_millisecond(&self) -> f32 {
+        match self {
+            DecelerationRate::Normal => 0.998,
+            DecelerationRate::Fast => 0.99,
+            DecelerationRate::Custom(rate) => rate.clamp(0.5, 0.9999),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct ScrollInsets {
     pub top: Option<f32>,
@@ -35,6 +45,7 @@ pub struct ScrollInsets {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ScrollProps {
+    pub scroll_id: Option<String>,
     pub direction: ScrollDirection,
     pub children: Vec<UIComponent>,
 
@@ -43,6 +54,7 @@ pub struct ScrollProps {
     pub scrollbar_width: Option<f32>,
     pub scrollbar_margin: Option<f32>,
 
+    pub bounces: Option<bool>,
     pub always_bounces_horizontal: Option<bool>,
     pub always_bounces_vertical: Option<bool>,
 
@@ -78,12 +90,14 @@ pub struct ScrollProps {
 impl Default for ScrollProps {
     fn default() -> Self {
         Self {
+            scroll_id: None,
             direction: ScrollDirection::Vertical,
             children: Vec::new(),
             shows_indicators: Some(false),
             scrollbar_color: Some(Color::Gray),
             scrollbar_width: Some(6.0),
             scrollbar_margin: Some(2.0),
+            bounces: Some(true),
             always_bounces_horizontal: Some(false),
             always_bounces_vertical: Some(false),
             scroll_enabled: Some(true),
@@ -132,6 +146,11 @@ impl ScrollProps {
         }
     }
 
+    pub fn scroll_id(mut self, scroll_id: &str) -> Self {
+        self.scroll_id = Some(scroll_id.to_string());
+        self
+    }
+
     pub fn add_child(mut self, child: UIComponent) -> Self {
         self.children.push(child);
         self
@@ -0,0 +1,536 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+use crate::events::{Event, EventPayload, EventSource, EventType};
+
+use super::scroll::ScrollDirection;
+
+const RUBBER_BAND_COEFFICIENT: f32 = 0.55;
+const SPRING_STIFFNESS: f32 = 170.0;
+const SPRING_DAMPING: f32 = 26.0;
+const SIMULATION_STEP: f32 = 1.0 / 240.0;
+const MIN_VELOCITY: f32 = 10.0;
+const REST_DISTANCE: f32 = 0.5;
+const VELOCITY_SAMPLE_WINDOW_MS: u128 = 100;
+const PAGING_VELOCITY_THRESHOLD: f32 = 300.0;
+const ANIMATION_DURATION: f32 = 0.3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollPhase {
+    Idle,
+    Dragging,
+    Decelerating,
+    Bouncing,
+    Animating,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScrollPayload {
+    pub scroll_id: String,
+    pub offset: f32,
+    pub velocity: f32,
+    pub phase: ScrollPhase,
+    pub page: Option<usize>,
+}
+
+impl EventPayload for ScrollPayload {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn EventPayload> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ScrollAnimation {
+    from: f32,
+    to: f32,
+    elapsed: f32,
+    duration: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DragSample {
+    position: f32,
+    timestamp: u128,
+}
+
+#[derive(Clone)]
+pub struct ScrollController {
+    scroll_id: String,
+    direction: ScrollDirection,
+    scroll_enabled: bool,
+    paging_enabled: bool,
+    bounces: bool,
+    always_bounces: bool,
+    deceleration_rate: f32,
+
+    viewport_extent: f32,
+    content_extent: f32,
+
+    offset: f32,
+    velocity: f32,
+    phase: ScrollPhase,
+
+    drag_origin: Option<(f32, f32)>,
+    drag_samples: Vec<DragSample>,
+    animation: Option<ScrollAnimation>,
+    events: Vec<Event>,
+}
+
+impl ScrollController {
+    pub fn new(scroll_id: &str) -> Self {
+        Self {
+            scroll_id: scroll_id.to_string(),
+            direction: ScrollDirection::Vertical,
+            scroll_enabled: true,
+            paging_enabled: false,
+            bounces: true,
+            always_bounces: false,
+            deceleration_rate: 0.998,
+
+            viewport_extent: 0.0,
+            content_extent: 0.0,
+
+            offset: 0.0,
+            velocity: 0.0,
+            phase: ScrollPhase::Idle,
+
+            drag_origin: None,
+            drag_samples: Vec::new(),
+            animation: None,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn configure(
+        &mut self,
+        direction: ScrollDirection,
+        scroll_enabled: bool,
+        paging_enabled: bool,
+        bounces: bool,
+        always_bounces: bool,
+        deceleration_rate: f32
+    ) {
+        self.direction = direction;
+        self.scroll_enabled = scroll_enabled;
+        self.paging_enabled = paging_enabled;
+        self.bounces = bounces;
+        self.always_bounces = always_bounces;
+        self.deceleration_rate = deceleration_rate;
+    }
+
+    pub fn set_extents(&mut self, viewport_extent: f32, content_extent: f32) {
+        self.viewport_extent = viewport_extent.max(0.0);
+        self.content_extent = content_extent.max(0.0);
+
+        if self.phase == ScrollPhase::Idle {
+            self.offset = self.offset.clamp(0.0, self.max_offset());
+        }
+    }
+
+    pub fn scroll_id(&self) -> &str {
+        &self.scroll_id
+    }
+
+    pub fn direction(&self) -> &ScrollDirection {
+        &self.direction
+    }
+
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    pub fn phase(&self) -> ScrollPhase {
+        self.phase
+    }
+
+    pub fn viewport_extent(&self) -> f32 {
+        self.viewport_extent
+    }
+
+    pub fn content_extent(&self) -> f32 {
+        self.content_extent
+    }
+
+    pub fn max_offset(&self) -> f32 {
+        (self.content_extent - self.viewport_extent).max(0.0)
+    }
+
+    pub fn is_animating(&self) -> bool {
+        matches!(
+            self.phase,
+            ScrollPhase::Decelerating | ScrollPhase::Bouncing | ScrollPhase::Animating
+        )
+    }
+
+    pub fn page_count(&self) -> usize {
+        if self.viewport_extent <= 0.0 {
+            return 1;
+        }
+
+        ((self.content_extent / self.viewport_extent).ceil() as usize).max(1)
+    }
+
+    pub fn current_page(&self) -> Option<usize> {
+        if !self.paging_enabled || self.viewport_extent <= 0.0 {
+            return None;
+        }
+
+        let page = (self.offset / self.viewport_extent).round().max(0.0) as usize;
+        Some(page.min(self.page_count() - 1))
+    }
+
+    pub fn axis_position(&self, x: f32, y: f32) -> f32 {
+        match self.direction {
+            ScrollDirection::Horizontal => x,
+            ScrollDirection::Vertical => y,
+        }
+    }
+
+    pub fn is_scrollable(&self) -> bool {
+        self.scroll_enabled && (self.max_offset() > 0.0 || self.always_bounces)
+    }
+
+    pub fn begin_drag(&mut self, x: f32, y: f32, timestamp: u128) -> bool {
+        if !self.is_scrollable() {
+            return false;
+        }
+
+        let was_idle = self.phase == ScrollPhase::Idle;
+        let position = self.axis_position(x, y);
+
+        self.animation = None;
+        self.velocity = 0.0;
+        self.phase = ScrollPhase::Dragging;
+        self.drag_origin = Some((position, self.offset));
+        self.drag_samples.clear();
+        self.drag_samples.push(DragSample { position, timestamp });
+
+        if was_idle {
+            self.emit(EventType::ScrollStart);
+        }
+
+        true
+    }
+
+    pub fn drag_to(&mut self, x: f32, y: f32, timestamp: u128) {
+        let Some((origin_position, origin_offset)) = self.drag_origin else {
+            return;
+        };
+
+        let position = self.axis_position(x, y);
+        let raw_offset = origin_offset + (origin_position - position);
+
+        self.drag_samples.push(DragSample { position, timestamp });
+        self.drag_samples.retain(|sample| timestamp.saturating_sub(sample.timestamp) <= VELOCITY_SAMPLE_WINDOW_MS);
+
+        self.set_offset_and_notify(self.rubber_band(raw_offset));
+    }
+
+    pub fn end_drag(&mut self, timestamp: u128) {
+        let Some((_, origin_offset)) = self.drag_origin.take() else {
+            return;
+        };
+
+        self.drag_samples.retain(|sample| timestamp.saturating_sub(sample.timestamp) <= VELOCITY_SAMPLE_WINDOW_MS);
+        self.velocity = self.release_velocity();
+        self.drag_samples.clear();
+
+        if self.paging_enabled && self.viewport_extent > 0.0 {
+            let target = self.page_target(origin_offset);
+            self.start_animation(target);
+        } else if self.is_out_of_bounds() {
+            self.phase = ScrollPhase::Bouncing;
+        } else if self.velocity.abs() > MIN_VELOCITY {
+            self.phase = ScrollPhase::Decelerating;
+        } else {
+            self.finish();
+        }
+    }
+
+    pub fn cancel_drag(&mut self) {
+        if self.drag_origin.take().is_none() {
+            return;
+        }
+
+        self.velocity = 0.0;
+        self.drag_samples.clear();
+
+        if self.is_out_of_bounds() {
+            self.phase = ScrollPhase::Bouncing;
+        } else {
+            self.finish();
+        }
+    }
+
+    pub fn scroll_to(&mut self, offset: f32, animated: bool) {
+        let target = offset.clamp(0.0, self.max_offset());
+
+        self.drag_origin = None;
+        self.drag_samples.clear();
+
+        if animated && (target - self.offset).abs() > REST_DISTANCE {
+            if self.phase == ScrollPhase::Idle {
+                self.emit(EventType::ScrollStart);
+            }
+
+            self.start_animation(target);
+            return;
+        }
+
+        let was_moving = self.phase != ScrollPhase::Idle;
+
+        self.animation = None;
+        self.velocity = 0.0;
+        self.phase = ScrollPhase::Idle;
+        self.set_offset_and_notify(target);
+
+        if was_moving {
+            self.emit(EventType::ScrollEnd);
+        }
+    }
+
+    pub fn scroll_to_page(&mut self, page: usize, animated: bool) {
+        let page = page.min(self.page_count() - 1);
+        self.scroll_to(page as f32 * self.viewport_extent, animated);
+    }
+
+    pub fn step(&mut self, dt: Duration) -> bool {
+        let dt = dt.as_secs_f32();
+
+        if dt <= 0.0 {
+            return self.is_animating();
+        }
+
+        match self.phase {
+            ScrollPhase::Decelerating => self.step_momentum(dt),
+            ScrollPhase::Bouncing => self.step_bounce(dt),
+            ScrollPhase::Animating => self.step_animation(dt),
+            ScrollPhase::Idle | ScrollPhase::Dragging => {},
+        }
+
+        self.is_animating()
+    }
+
+    pub fn take_events(&mut self) -> Vec<Event> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn step_momentum(&mut self, dt: f32) {
+        let dt_ms = dt * 1000.0;
+        let decay = self.deceleration_rate.powf(dt_ms);
+        let displacement = self.velocity * (decay - 1.0) / (1000.0 * self.deceleration_rate.ln());
+
+        self.velocity *= decay;
+        self.set_offset_and_notify(self.offset + displacement);
+
+        if self.is_out_of_bounds() {
+            if self.bounces {
+                self.phase = ScrollPhase::Bouncing;
+            } else {
+                let clamped = self.offset.clamp(0.0, self.max_offset());
+                self.set_offset_and_notify(clamped);
+                self.finish();
+            }
+        } else if self.velocity.abs() < MIN_VELOCITY {
+            self.finish();
+        }
+    }
+
+    fn step_bounce(&mut self, dt: f32) {
+        let target = self.offset.clamp(0.0, self.max_offset());
+        let mut position = self.offset - target;
+        let mut velocity = self.velocity;
+        let mut remaining = dt;
+
+        while remaining > 0.0 {
+            let step = remaining.min(SIMULATION_STEP);
+            let acceleration = -SPRING_STIFFNESS * position - SPRING_DAMPING * velocity;
+
+            velocity += acceleration * step;
+            position += velocity * step;
+            remaining -= step;
+        }
+
+        self.velocity = velocity;
+
+        if position.abs() < REST_DISTANCE && velocity.abs() < MIN_VELOCITY {
+            self.set_offset_and_notify(target);
+            self.finish();
+        } else {
+            self.set_offset_and_notify(target + position);
+        }
+    }
+
+    fn step_animation(&mut self, dt: f32) {
+        let Some(mut animation) = self.animation else {
+            self.finish();
+            return;
+        };
+
+        animation.elapsed += dt;
+
+        let progress = (animation.elapsed / animation.duration).min(1.0);
+        let eased = 1.0 - (1.0 - progress).powi(3);
+        let offset = animation.from + (animation.to - animation.from) * eased;
+
+        self.velocity = (offset - self.offset) / dt;
+        self.set_offset_and_notify(offset);
+
+        if progress >= 1.0 {
+            self.animation = None;
+            self.finish();
+        } else {
+            self.animation = Some(animation);
+        }
+    }
+
+    fn start_animation(&mut self, target: f32) {
+        self.animation = Some(ScrollAnimation {
+            from: self.offset,
+            to: target,
+            elapsed: 0.0,
+            duration: ANIMATION_DURATION,
+        });
+        self.phase = ScrollPhase::Animating;
+    }
+
+    fn finish(&mut self) {
+        self.velocity = 0.0;
+        self.animation = None;
+        self.phase = ScrollPhase::Idle;
+        self.emit(EventType::ScrollEnd);
+    }
+
+    fn page_target(&self, origin_offset: f32) -> f32 {
+        let page_size = self.viewport_extent;
+        let last_page = self.page_count() as f32 - 1.0;
+        let origin_page = (origin_offset / page_size).round();
+
+        let page = if self.velocity > PAGING_VELOCITY_THRESHOLD {
+            origin_page + 1.0
+        } else if self.velocity < -PAGING_VELOCITY_THRESHOLD {
+            origin_page - 1.0
+        } else {
+            (self.offset / page_size).round()
+        };
+
+        (page.clamp(0.0, last_page) * page_size).min(self.max_offset())
+    }
+
+    fn release_velocity(&self) -> f32 {
+        let (Some(first), Some(last)) = (self.drag_samples.first(), self.drag_samples.last()) else {
+            return 0.0;
+        };
+
+        let elapsed_ms = last.timestamp.saturating_sub(first.timestamp) as f32;
+
+        if elapsed_ms <= 0.0 {
+            return 0.0;
+        }
+
+        (first.position - last.position) / elapsed_ms * 1000.0
+    }
+
+    fn rubber_band(&self, raw_offset: f32) -> f32 {
+        let max_offset = self.max_offset();
+
+        if !self.bounces {
+            return raw_offset.clamp(0.0, max_offset);
+        }
+
+        let dimension = self.viewport_extent.max(1.0);
+        let resist = |overshoot: f32| {
+            (1.0 - 1.0 / (overshoot * RUBBER_BAND_COEFFICIENT / dimension + 1.0)) * dimension
+        };
+
+        if raw_offset < 0.0 {
+            -resist(-raw_offset)
+        } else if raw_offset > max_offset {
+            max_offset + resist(raw_offset - max_offset)
+        } else {
+            raw_offset
+        }
+    }
+
+    fn is_out_of_bounds(&self) -> bool {
+        self.offset < 0.0 || self.offset > self.max_offset()
+    }
+
+    fn set_offset_and_notify(&mut self, offset: f32) {
+        if (offset - self.offset).abs() <= f32::EPSILON {
+            return;
+        }
+
+        self.offset = offset;
+        self.emit(EventType::Scroll);
+    }
+
+    fn emit(&mut self, event_type: EventType) {
+        let payload = ScrollPayload {
+            scroll_id: self.scroll_id.clone(),
+            offset: self.offset,
+            velocity: self.velocity,
+            phase: self.phase,
+            page: self.current_page(),
+        };
+
+        let event = Event::new(
+            event_type,
+            EventSource::Custom {
+                category: "scroll".to_string(),
+                subcategory: None,
+            }
+        )
+        .with_property("scroll_id", &self.scroll_id)
+        .with_property("offset", &self.offset.to_string())
+        .with_payload(payload);
+
+        self.events.push(event);
+    }
+}
+
+static SCROLL_CONTROLLERS: OnceLock<RwLock<HashMap<String, ScrollController>>> = OnceLock::new();
+
+pub fn scroll_controller_registry() -> &'static RwLock<HashMap<String, ScrollController>> {
+    SCROLL_CONTROLLERS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+pub fn with_scroll_controller<R>(scroll_id: &str, f: impl FnOnce(&mut ScrollController) -> R) -> R {
+    let mut registry = scroll_controller_registry().write().unwrap();
+    let controller = registry.entry(scroll_id.to_string())
+        .or_insert_with(|| ScrollController::new(scroll_id));
+    f(controller)
+}
+
+pub fn scroll_to(scroll_id: &str, offset: f32, animated: bool) {
+    with_scroll_controller(scroll_id, |controller| controller.scroll_to(offset, animated));
+}
+
+pub fn step_scroll_controllers(dt: Duration) -> Vec<Event> {
+    let mut registry = scroll_controller_registry().write().unwrap();
+
+    registry.values_mut()
+        .flat_map(|controller| {
+            controller.step(dt);
+            controller.take_events()
+        })
+        .collect()
+}
+
+pub fn is_any_scroll_animating() -> bool {
+    scroll_controller_registry().read().unwrap()
+        .values()
+        .any(|controller| controller.is_animating())
+}
+
+pub fn remove_scroll_controller(scroll_id: &str) -> Option<ScrollController> {
+    scroll_controller_registry().write().unwrap().remove(scroll_id)
+}
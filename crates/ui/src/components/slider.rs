@@ -0,0 +1,101 @@
+use serde::{Serialize, Deserialize};
+use crate::shared::edge_insets::EdgeInsets;
+
+use super::Color;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SliderProps {
+    pub control_id: String,
+    pub value: Option<f32>,
+    pub min: f32,
+    pub max: f32,
+    pub step: Option<f32>,
+    pub disabled: Option<bool>,
+    pub on_change: Option<String>,
+
+    pub track_height: Option<f32>,
+    pub thumb_size: Option<f32>,
+    pub track_color: Option<Color>,
+    pub fill_color: Option<Color>,
+    pub thumb_color: Option<Color>,
+    pub shows_ticks: Option<bool>,
+
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub padding: Option<f32>,
+    pub edge_insets: Option<EdgeInsets>,
+    pub opacity: Option<f32>,
+
+    pub accessibility_label: Option<String>,
+    pub accessibility_hint: Option<String>,
+}
+
+impl Default for SliderProps {
+    fn default() -> Self {
+        Self {
+            control_id: String::new(),
+            value: None,
+            min: 0.0,
+            max: 1.0,
+            step: None,
+            disabled: Some(false),
+            on_change: None,
+
+            track_height: Some(4.0),
+            thumb_size: Some(24.0),
+            track_color: Some(Color::LightGray),
+            fill_color: Some(Color::Blue),
+            thumb_color: Some(Color::White),
+            shows_ticks: Some(false),
+
+            width: None,
+            height: None,
+            padding: None,
+            edge_insets: None,
+            opacity: None,
+
+            accessibility_label: None,
+            accessibility_hint: None,
+        }
+    }
+}
+
+impl SliderProps {
+    pub fn new(control_id: &str) -> Self {
+        Self {
+            control_id: control_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    pub fn on_change(mut self, handler_id: &str) -> Self {
+        self.on_change = Some(handler_id.to_string());
+        self
+    }
+
+    pub fn shows_ticks(mut self, shows_ticks: bool) -> Self {
+        self.shows_ticks = Some(shows_ticks);
+        self
+    }
+}
@@ -0,0 +1,108 @@
+use serde::{Serialize, Deserialize};
+use crate::shared::edge_insets::EdgeInsets;
+
+use super::Color;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StepperProps {
+    pub control_id: String,
+    pub label: Option<String>,
+    pub value: Option<f32>,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    pub wraps: Option<bool>,
+    pub shows_value: Option<bool>,
+    pub disabled: Option<bool>,
+    pub on_change: Option<String>,
+
+    pub tint_color: Option<Color>,
+    pub background_color: Option<Color>,
+    pub label_color: Option<Color>,
+    pub font_size: Option<f32>,
+
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub padding: Option<f32>,
+    pub edge_insets: Option<EdgeInsets>,
+    pub opacity: Option<f32>,
+
+    pub accessibility_label: Option<String>,
+    pub accessibility_hint: Option<String>,
+}
+
+impl Default for StepperProps {
+    fn default() -> Self {
+        Self {
+            control_id: String::new(),
+            label: None,
+            value: None,
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            wraps: Some(false),
+            shows_value: Some(true),
+            disabled: Some(false),
+            on_change: None,
+
+            tint_color: Some(Color::Blue),
+            background_color: Some(Color::Custom(238, 238, 239)),
+            label_color: Some(Color::Black),
+            font_size: Some(16.0),
+
+            width: None,
+            height: None,
+            padding: None,
+            edge_insets: None,
+            opacity: None,
+
+            accessibility_label: None,
+            accessibility_hint: None,
+        }
+    }
+}
+
+impl StepperProps {
+    pub fn new(control_id: &str) -> Self {
+        Self {
+            control_id: control_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn wraps(mut self, wraps: bool) -> Self {
+        self.wraps = Some(wraps);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    pub fn on_change(mut self, handler_id: &str) -> Self {
+        self.on_change = Some(handler_id.to_string());
+        self
+    }
+}
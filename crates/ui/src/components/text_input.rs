@@ -0,0 +1,181 @@
+use serde::{Serialize, Deserialize};
+use crate::shared::styles::BorderStyle;
+use crate::shared::edge_insets::EdgeInsets;
+
+use super::Color;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TextInputProps {
+    pub input_id: String,
+    pub value: Option<String>,
+    pub placeholder: Option<String>,
+
+    pub multiline: bool,
+    pub rows: Option<u32>,
+    pub secure_entry: Option<bool>,
+    pub max_length: Option<usize>,
+    pub editable: Option<bool>,
+    pub autofocus: Option<bool>,
+    pub submit_on_enter: Option<bool>,
+
+    pub font_size: Option<f32>,
+    pub font_family: Option<String>,
+    pub line_height: Option<f32>,
+    pub text_color: Option<Color>,
+    pub placeholder_color: Option<Color>,
+    pub caret_color: Option<Color>,
+    pub selection_color: Option<Color>,
+    pub focus_border_color: Option<Color>,
+
+    pub on_change: Option<String>,
+    pub on_submit: Option<String>,
+    pub on_focus: Option<String>,
+    pub on_blur: Option<String>,
+
+    pub accessibility_label: Option<String>,
+
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub min_width: Option<f32>,
+    pub max_width: Option<f32>,
+    pub min_height: Option<f32>,
+    pub max_height: Option<f32>,
+
+    pub background_color: Option<Color>,
+    pub border_width: Option<f32>,
+    pub border_color: Option<Color>,
+    pub border_radius: Option<f32>,
+    pub border_style: Option<BorderStyle>,
+
+    pub padding: Option<f32>,
+    pub edge_insets: Option<EdgeInsets>,
+    pub opacity: Option<f32>,
+}
+
+impl Default for TextInputProps {
+    fn default() -> Self {
+        Self {
+            input_id: String::new(),
+            value: None,
+            placeholder: None,
+
+            multiline: false,
+            rows: None,
+            secure_entry: Some(false),
+            max_length: None,
+            editable: Some(true),
+            autofocus: Some(false),
+            submit_on_enter: None,
+
+            font_size: Some(16.0),
+            font_family: None,
+            line_height: Some(1.2),
+            text_color: Some(Color::Black),
+            placeholder_color: Some(Color::Placeholder),
+            caret_color: Some(Color::Blue),
+            selection_color: Some(Color::CustomWithAlpha(0, 122, 255, 0.3)),
+            focus_border_color: Some(Color::Blue),
+
+            on_change: None,
+            on_submit: None,
+            on_focus: None,
+            on_blur: None,
+
+            accessibility_label: None,
+
+            width: None,
+            height: None,
+            min_width: None,
+            max_width: None,
+            min_height: None,
+            max_height: None,
+
+            background_color: Some(Color::White),
+            border_width: Some(1.0),
+            border_color: Some(Color::LightGray),
+            border_radius: Some(4.0),
+            border_style: Some(BorderStyle::Solid),
+
+            padding: Some(8.0),
+            edge_insets: None,
+            opacity: None,
+        }
+    }
+}
+
+impl TextInputProps {
+    pub fn text_field(input_id: &str) -> Self {
+        Self {
+            input_id: input_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn text_area(input_id: &str) -> Self {
+        Self {
+            input_id: input_id.to_string(),
+            multiline: true,
+            rows: Some(3),
+            ..Default::default()
+        }
+    }
+
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    pub fn secure_entry(mut self, secure: bool) -> Self {
+        self.secure_entry = Some(secure);
+        self
+    }
+
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn rows(mut self, rows: u32) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = Some(editable);
+        self
+    }
+
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = Some(autofocus);
+        self
+    }
+
+    pub fn on_change(mut self, handler_id: &str) -> Self {
+        self.on_change = Some(handler_id.to_string());
+        self
+    }
+
+    pub fn on_submit(mut self, handler_id: &str) -> Self {
+        self.on_submit = Some(handler_id.to_string());
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn submits_on_enter(&self) -> bool {
+        self.submit_on_enter.unwrap_or(!self.multiline)
+    }
+}
@@ -0,0 +1,813 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::{OnceLock, RwLock};
+use unicode_segmentation::{GraphemeCursor, UnicodeSegmentation};
+
+const MAX_UNDO_ENTRIES: usize = 100;
+const SECURE_MASK: char = '•';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextSelection {
+    pub anchor: usize,
+    pub focus: usize,
+}
+
+impl TextSelection {
+    pub fn new(anchor: usize, focus: usize) -> Self {
+        Self { anchor, focus }
+    }
+
+    pub fn caret(offset: usize) -> Self {
+        Self::new(offset, offset)
+    }
+
+    pub fn start(&self) -> usize {
+        self.anchor.min(self.focus)
+    }
+
+    pub fn end(&self) -> usize {
+        self.anchor.max(self.focus)
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.start()..self.end()
+    }
+
+    pub fn is_collapsed(&self) -> bool {
+        self.anchor == self.focus
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Composition {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextMovement {
+    Left,
+    Right,
+    WordLeft,
+    WordRight,
+    LineStart,
+    LineEnd,
+    Up,
+    Down,
+    DocumentStart,
+    DocumentEnd,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct KeyModifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+impl KeyModifiers {
+    pub fn from_properties(properties: &HashMap<String, String>) -> Self {
+        let flag = |key: &str| properties.get(key).is_some_and(|value| value == "true");
+
+        Self {
+            shift: flag("shift"),
+            ctrl: flag("ctrl"),
+            alt: flag("alt"),
+            meta: flag("meta"),
+        }
+    }
+
+    pub fn primary(&self) -> bool {
+        self.ctrl || self.meta
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextInputAction {
+    None,
+    SelectionChanged,
+    ValueChanged,
+    Submit,
+    Copy(String),
+    Cut(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Typing,
+    Deletion,
+    Other,
+}
+
+#[derive(Debug, Clone)]
+struct UndoEntry {
+    value: String,
+    selection: TextSelection,
+}
+
+#[derive(Debug, Clone)]
+pub struct TextInputState {
+    value: String,
+    selection: TextSelection,
+    composition: Option<Composition>,
+
+    multiline: bool,
+    secure: bool,
+    editable: bool,
+    submit_on_enter: bool,
+    max_length: Option<usize>,
+
+    focused: bool,
+    synced_value: Option<String>,
+    autofocus_applied: bool,
+    preferred_column: Option<usize>,
+
+    undo_stack: Vec<UndoEntry>,
+    redo_stack: Vec<UndoEntry>,
+    last_edit: Option<EditKind>,
+}
+
+impl Default for TextInputState {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            selection: TextSelection::default(),
+            composition: None,
+
+            multiline: false,
+            secure: false,
+            editable: true,
+            submit_on_enter: true,
+            max_length: None,
+
+            focused: false,
+            synced_value: None,
+            autofocus_applied: false,
+            preferred_column: None,
+
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            last_edit: None,
+        }
+    }
+}
+
+impl TextInputState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn configure(
+        &mut self,
+        multiline: bool,
+        secure: bool,
+        editable: bool,
+        submit_on_enter: bool,
+        max_length: Option<usize>
+    ) {
+        self.multiline = multiline;
+        self.secure = secure;
+        self.editable = editable;
+        self.submit_on_enter = submit_on_enter;
+        self.max_length = max_length;
+    }
+
+    pub fn sync_value(&mut self, value: Option<&str>) {
+        let Some(value) = value else {
+            return;
+        };
+
+        if self.synced_value.as_deref() == Some(value) {
+            return;
+        }
+
+        self.synced_value = Some(value.to_string());
+
+        if value != self.value {
+            let value = self.sanitize(value);
+            let value = self.truncate_to_max_length(&value);
+
+            self.selection = TextSelection::caret(value.len());
+            self.value = value;
+            self.composition = None;
+            self.undo_stack.clear();
+            self.redo_stack.clear();
+            self.last_edit = None;
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn selection(&self) -> TextSelection {
+        self.selection
+    }
+
+    pub fn composition(&self) -> Option<Composition> {
+        self.composition
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn is_secure(&self) -> bool {
+        self.secure
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        self.multiline
+    }
+
+    pub fn set_focused(&mut self, focused: bool) {
+        if self.focused && !focused && self.composition.is_some() {
+            self.commit_composition(None);
+        }
+
+        self.focused = focused;
+        self.last_edit = None;
+    }
+
+    pub fn apply_autofocus(&mut self) -> bool {
+        if self.autofocus_applied {
+            return false;
+        }
+
+        self.autofocus_applied = true;
+        true
+    }
+
+    pub fn grapheme_count(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
+    pub fn selected_text(&self) -> &str {
+        &self.value[self.selection.range()]
+    }
+
+    pub fn display_text(&self) -> String {
+        if self.secure {
+            std::iter::repeat_n(SECURE_MASK, self.grapheme_count()).collect()
+        } else {
+            self.value.clone()
+        }
+    }
+
+    pub fn to_display_offset(&self, offset: usize) -> usize {
+        if self.secure {
+            self.value[..offset].graphemes(true).count() * SECURE_MASK.len_utf8()
+        } else {
+            offset
+        }
+    }
+
+    pub fn from_display_offset(&self, display_offset: usize) -> usize {
+        if self.secure {
+            let graphemes = display_offset / SECURE_MASK.len_utf8();
+            self.value.grapheme_indices(true)
+                .nth(graphemes)
+                .map(|(offset, _)| offset)
+                .unwrap_or(self.value.len())
+        } else {
+            self.snap_to_boundary(display_offset)
+        }
+    }
+
+    pub fn set_selection(&mut self, anchor: usize, focus: usize) -> bool {
+        let selection = TextSelection::new(self.snap_to_boundary(anchor), self.snap_to_boundary(focus));
+        self.update_selection(selection)
+    }
+
+    pub fn select_all(&mut self) -> bool {
+        self.update_selection(TextSelection::new(0, self.value.len()))
+    }
+
+    pub fn select_word_at(&mut self, offset: usize) -> bool {
+        if self.secure {
+            return self.select_all();
+        }
+
+        let offset = self.snap_to_boundary(offset);
+        let word = self.value.split_word_bound_indices()
+            .map(|(start, word)| start..start + word.len())
+            .find(|range| range.contains(&offset) || range.end == offset);
+
+        match word {
+            Some(range) => self.update_selection(TextSelection::new(range.start, range.end)),
+            None => false,
+        }
+    }
+
+    pub fn move_caret(&mut self, movement: TextMovement, extend: bool) -> bool {
+        if !matches!(movement, TextMovement::Up | TextMovement::Down) {
+            self.preferred_column = None;
+        }
+
+        let target = if !extend && !self.selection.is_collapsed() {
+            match movement {
+                TextMovement::Left => self.selection.start(),
+                TextMovement::Right => self.selection.end(),
+                _ => self.movement_target(self.selection.focus, movement),
+            }
+        } else {
+            self.movement_target(self.selection.focus, movement)
+        };
+
+        let selection = if extend {
+            TextSelection::new(self.selection.anchor, target)
+        } else {
+            TextSelection::caret(target)
+        };
+
+        self.update_selection(selection)
+    }
+
+    pub fn insert_text(&mut self, text: &str) -> bool {
+        let changed = self.replace_selection(text, EditKind::Typing);
+
+        if text.chars().any(char::is_whitespace) {
+            self.last_edit = None;
+        }
+
+        changed
+    }
+
+    pub fn delete_backward(&mut self) -> bool {
+        self.delete_towards(TextMovement::Left)
+    }
+
+    pub fn delete_forward(&mut self) -> bool {
+        self.delete_towards(TextMovement::Right)
+    }
+
+    pub fn delete_word_backward(&mut self) -> bool {
+        self.delete_towards(TextMovement::WordLeft)
+    }
+
+    pub fn delete_word_forward(&mut self) -> bool {
+        self.delete_towards(TextMovement::WordRight)
+    }
+
+    pub fn delete_to_line_start(&mut self) -> bool {
+        self.delete_towards(TextMovement::LineStart)
+    }
+
+    pub fn copy(&self) -> Option<String> {
+        if self.secure || self.selection.is_collapsed() {
+            return None;
+        }
+
+        Some(self.selected_text().to_string())
+    }
+
+    pub fn cut(&mut self) -> Option<String> {
+        let text = self.copy()?;
+
+        if self.replace_selection("", EditKind::Other) {
+            Some(text)
+        } else {
+            None
+        }
+    }
+
+    pub fn paste(&mut self, text: &str) -> bool {
+        self.replace_selection(text, EditKind::Other)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(entry) = self.undo_stack.pop() else {
+            return false;
+        };
+
+        self.redo_stack.push(self.snapshot());
+        self.restore(entry);
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(entry) = self.redo_stack.pop() else {
+            return false;
+        };
+
+        self.undo_stack.push(self.snapshot());
+        self.restore(entry);
+        true
+    }
+
+    pub fn begin_composition(&mut self) -> bool {
+        if !self.editable || self.composition.is_some() {
+            return false;
+        }
+
+        self.record_undo(EditKind::Other);
+        self.last_edit = None;
+
+        let range = self.selection.range();
+        self.value.replace_range(range.clone(), "");
+        self.selection = TextSelection::caret(range.start);
+        self.composition = Some(Composition {
+            start: range.start,
+            end: range.start,
+        });
+
+        true
+    }
+
+    pub fn update_composition(&mut self, text: &str) -> bool {
+        if self.composition.is_none() && !self.begin_composition() {
+            return false;
+        }
+
+        let Some(composition) = self.composition else {
+            return false;
+        };
+
+        let text = self.sanitize(text);
+        let end = composition.start + text.len();
+
+        self.value.replace_range(composition.start..composition.end, &text);
+        self.selection = TextSelection::caret(end);
+        self.composition = Some(Composition {
+            start: composition.start,
+            end,
+        });
+
+        true
+    }
+
+    pub fn commit_composition(&mut self, text: Option<&str>) -> bool {
+        let Some(composition) = self.composition.take() else {
+            return text.is_some_and(|text| self.insert_text(text));
+        };
+
+        let committed = text.map(|text| self.sanitize(text))
+            .unwrap_or_else(|| self.value[composition.start..composition.end].to_string());
+
+        self.value.replace_range(composition.start..composition.end, "");
+        self.selection = TextSelection::caret(composition.start);
+
+        let committed = self.fit_max_length(&committed, composition.start..composition.start);
+        self.value.insert_str(composition.start, &committed);
+        self.selection = TextSelection::caret(composition.start + committed.len());
+
+        self.discard_unchanged_undo_entry()
+    }
+
+    pub fn cancel_composition(&mut self) -> bool {
+        let Some(composition) = self.composition.take() else {
+            return false;
+        };
+
+        self.value.replace_range(composition.start..composition.end, "");
+        self.selection = TextSelection::caret(composition.start);
+
+        self.discard_unchanged_undo_entry()
+    }
+
+    pub fn handle_key(&mut self, key: &str, modifiers: KeyModifiers) -> TextInputAction {
+        if self.composition.is_some() {
+            return TextInputAction::None;
+        }
+
+        let word = modifiers.alt || modifiers.ctrl;
+        let extend = modifiers.shift;
+
+        let moved = |state: &mut Self, movement: TextMovement| {
+            if state.move_caret(movement, extend) {
+                TextInputAction::SelectionChanged
+            } else {
+                TextInputAction::None
+            }
+        };
+
+        let edited = |changed: bool| {
+            if changed {
+                TextInputAction::ValueChanged
+            } else {
+                TextInputAction::None
+            }
+        };
+
+        match key {
+            "ArrowLeft" if modifiers.meta => moved(self, TextMovement::LineStart),
+            "ArrowLeft" if word => moved(self, TextMovement::WordLeft),
+            "ArrowLeft" => moved(self, TextMovement::Left),
+            "ArrowRight" if modifiers.meta => moved(self, TextMovement::LineEnd),
+            "ArrowRight" if word => moved(self, TextMovement::WordRight),
+            "ArrowRight" => moved(self, TextMovement::Right),
+            "ArrowUp" if modifiers.meta => moved(self, TextMovement::DocumentStart),
+            "ArrowUp" => moved(self, TextMovement::Up),
+            "ArrowDown" if modifiers.meta => moved(self, TextMovement::DocumentEnd),
+            "ArrowDown" => moved(self, TextMovement::Down),
+            "Home" if modifiers.ctrl => moved(self, TextMovement::DocumentStart),
+            "Home" => moved(self, TextMovement::LineStart),
+            "End" if modifiers.ctrl => moved(self, TextMovement::DocumentEnd),
+            "End" => moved(self, TextMovement::LineEnd),
+
+            "Backspace" if modifiers.meta => edited(self.delete_to_line_start()),
+            "Backspace" if word => edited(self.delete_word_backward()),
+            "Backspace" => edited(self.delete_backward()),
+            "Delete" if word => edited(self.delete_word_forward()),
+            "Delete" => edited(self.delete_forward()),
+
+            "Enter" if self.submit_on_enter && !modifiers.shift => TextInputAction::Submit,
+            "Enter" if self.multiline => edited(self.insert_text("\n")),
+            "Enter" => TextInputAction::None,
+
+            _ if modifiers.primary() => match key.to_lowercase().as_str() {
+                "a" => {
+                    if self.select_all() {
+                        TextInputAction::SelectionChanged
+                    } else {
+                        TextInputAction::None
+                    }
+                },
+                "c" => self.copy().map(TextInputAction::Copy).unwrap_or(TextInputAction::None),
+                "x" => self.cut().map(TextInputAction::Cut).unwrap_or(TextInputAction::None),
+                "z" if modifiers.shift => edited(self.redo()),
+                "z" => edited(self.undo()),
+                "y" => edited(self.redo()),
+                _ => TextInputAction::None,
+            },
+
+            _ if is_printable_key(key) => edited(self.insert_text(key)),
+            _ => TextInputAction::None,
+        }
+    }
+
+    fn delete_towards(&mut self, movement: TextMovement) -> bool {
+        if self.selection.is_collapsed() {
+            let caret = self.selection.focus;
+            let target = self.movement_target(caret, movement);
+
+            if target == caret {
+                return false;
+            }
+
+            self.selection = TextSelection::new(caret, target);
+        }
+
+        self.replace_selection("", EditKind::Deletion)
+    }
+
+    fn replace_selection(&mut self, text: &str, kind: EditKind) -> bool {
+        if !self.editable {
+            return false;
+        }
+
+        let range = self.selection.range();
+        let text = self.sanitize(text);
+        let text = self.fit_max_length(&text, range.clone());
+
+        if text.is_empty() && range.is_empty() {
+            return false;
+        }
+
+        self.record_undo(kind);
+        self.value.replace_range(range.clone(), &text);
+        self.selection = TextSelection::caret(range.start + text.len());
+        self.preferred_column = None;
+
+        true
+    }
+
+    fn update_selection(&mut self, selection: TextSelection) -> bool {
+        self.last_edit = None;
+
+        if selection == self.selection {
+            return false;
+        }
+
+        self.selection = selection;
+        true
+    }
+
+    fn movement_target(&mut self, from: usize, movement: TextMovement) -> usize {
+        match movement {
+            TextMovement::Left => self.prev_boundary(from),
+            TextMovement::Right => self.next_boundary(from),
+            TextMovement::WordLeft if self.secure => 0,
+            TextMovement::WordRight if self.secure => self.value.len(),
+            TextMovement::WordLeft => self.value.unicode_word_indices()
+                .rev()
+                .map(|(start, _)| start)
+                .find(|&start| start < from)
+                .unwrap_or(0),
+            TextMovement::WordRight => self.value.unicode_word_indices()
+                .map(|(start, word)| start + word.len())
+                .find(|&end| end > from)
+                .unwrap_or(self.value.len()),
+            TextMovement::LineStart => self.line_start(from),
+            TextMovement::LineEnd => self.line_end(from),
+            TextMovement::Up | TextMovement::Down if !self.multiline => {
+                if movement == TextMovement::Up { 0 } else { self.value.len() }
+            },
+            TextMovement::Up => {
+                let column = self.preferred_column.unwrap_or_else(|| self.column(from));
+                self.preferred_column = Some(column);
+
+                let line_start = self.line_start(from);
+                if line_start == 0 {
+                    return 0;
+                }
+
+                let previous_start = self.line_start(line_start - 1);
+                self.offset_at_column(previous_start, column)
+            },
+            TextMovement::Down => {
+                let column = self.preferred_column.unwrap_or_else(|| self.column(from));
+                self.preferred_column = Some(column);
+
+                let line_end = self.line_end(from);
+                if line_end >= self.value.len() {
+                    return self.value.len();
+                }
+
+                self.offset_at_column(line_end + 1, column)
+            },
+            TextMovement::DocumentStart => 0,
+            TextMovement::DocumentEnd => self.value.len(),
+        }
+    }
+
+    fn line_start(&self, offset: usize) -> usize {
+        if !self.multiline {
+            return 0;
+        }
+
+        self.value[..offset].rfind('\n').map(|index| index + 1).unwrap_or(0)
+    }
+
+    fn line_end(&self, offset: usize) -> usize {
+        if !self.multiline {
+            return self.value.len();
+        }
+
+        self.value[offset..].find('\n').map(|index| offset + index).unwrap_or(self.value.len())
+    }
+
+    fn column(&self, offset: usize) -> usize {
+        self.value[self.line_start(offset)..offset].graphemes(true).count()
+    }
+
+    fn offset_at_column(&self, line_start: usize, column: usize) -> usize {
+        let line_end = self.line_end(line_start);
+
+        self.value[line_start..line_end].grapheme_indices(true)
+            .nth(column)
+            .map(|(offset, _)| line_start + offset)
+            .unwrap_or(line_end)
+    }
+
+    fn prev_boundary(&self, offset: usize) -> usize {
+        let mut cursor = GraphemeCursor::new(offset, self.value.len(), true);
+        cursor.prev_boundary(&self.value, 0).ok().flatten().unwrap_or(0)
+    }
+
+    fn next_boundary(&self, offset: usize) -> usize {
+        let mut cursor = GraphemeCursor::new(offset, self.value.len(), true);
+        cursor.next_boundary(&self.value, 0).ok().flatten().unwrap_or(self.value.len())
+    }
+
+    fn snap_to_boundary(&self, offset: usize) -> usize {
+        let mut offset = offset.min(self.value.len());
+
+        while !self.value.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let mut cursor = GraphemeCursor::new(offset, self.value.len(), true);
+
+        if cursor.is_boundary(&self.value, 0).unwrap_or(true) {
+            offset
+        } else {
+            self.prev_boundary(offset)
+        }
+    }
+
+    fn sanitize(&self, text: &str) -> String {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+
+        if self.multiline {
+            text
+        } else {
+            text.replace('\n', " ")
+        }
+    }
+
+    fn fit_max_length(&self, text: &str, replaced: Range<usize>) -> String {
+        let Some(max_length) = self.max_length else {
+            return text.to_string();
+        };
+
+        let remaining = self.grapheme_count() - self.value[replaced].graphemes(true).count();
+        let available = max_length.saturating_sub(remaining);
+
+        text.graphemes(true).take(available).collect()
+    }
+
+    fn truncate_to_max_length(&self, text: &str) -> String {
+        match self.max_length {
+            Some(max_length) => text.graphemes(true).take(max_length).collect(),
+            None => text.to_string(),
+        }
+    }
+
+    fn record_undo(&mut self, kind: EditKind) {
+        let coalesce = kind != EditKind::Other && self.last_edit == Some(kind);
+
+        if !coalesce {
+            self.undo_stack.push(self.snapshot());
+
+            if self.undo_stack.len() > MAX_UNDO_ENTRIES {
+                self.undo_stack.remove(0);
+            }
+        }
+
+        self.redo_stack.clear();
+        self.last_edit = Some(kind);
+    }
+
+    fn discard_unchanged_undo_entry(&mut self) -> bool {
+        let unchanged = self.undo_stack.last().is_some_and(|entry| entry.value == self.value);
+
+        if unchanged {
+            self.undo_stack.pop();
+        }
+
+        !unchanged
+    }
+
+    fn snapshot(&self) -> UndoEntry {
+        UndoEntry {
+            value: self.value.clone(),
+            selection: self.selection,
+        }
+    }
+
+    fn restore(&mut self, entry: UndoEntry) {
+        self.value = entry.value;
+        self.selection = entry.selection;
+        self.composition = None;
+        self.preferred_column = None;
+        self.last_edit = None;
+    }
+}
+
+fn is_printable_key(key: &str) -> bool {
+    key.graphemes(true).count() == 1 && !key.chars().any(char::is_control)
+}
+
+static TEXT_INPUT_STATES: OnceLock<RwLock<HashMap<String, TextInputState>>> = OnceLock::new();
+
+pub fn text_input_registry() -> &'static RwLock<HashMap<String, TextInputState>> {
+    TEXT_INPUT_STATES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+pub fn with_text_input_state<R>(input_id: &str, f: impl FnOnce(&mut TextInputState) -> R) -> R {
+    let mut registry = text_input_registry().write().unwrap();
+    let state = registry.entry(input_id.to_string())
+        .or_default();
+    f(state)
+}
+
+pub fn focused_text_input() -> Option<String> {
+    text_input_registry().read().unwrap()
+        .iter()
+        .find(|(_, state)| state.is_focused())
+        .map(|(input_id, _)| input_id.clone())
+}
+
+pub fn focus_text_input(input_id: &str) -> Option<String> {
+    let previous = blur_text_inputs().filter(|previous| previous != input_id);
+    with_text_input_state(input_id, |state| state.set_focused(true));
+    previous
+}
+
+pub fn blur_text_inputs() -> Option<String> {
+    let mut registry = text_input_registry().write().unwrap();
+    let mut previous = None;
+
+    for (input_id, state) in registry.iter_mut() {
+        if state.is_focused() {
+            state.set_focused(false);
+            previous = Some(input_id.clone());
+        }
+    }
+
+    previous
+}
+
+pub fn remove_text_input_state(input_id: &str) -> Option<TextInputState> {
+    text_input_registry().write().unwrap().remove(input_id)
+}
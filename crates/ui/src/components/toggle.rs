@@ -0,0 +1,89 @@
+use serde::{Serialize, Deserialize};
+use crate::shared::edge_insets::EdgeInsets;
+
+use super::Color;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ToggleProps {
+    pub control_id: String,
+    pub label: Option<String>,
+    pub is_on: Option<bool>,
+    pub disabled: Option<bool>,
+    pub on_change: Option<String>,
+
+    pub on_color: Option<Color>,
+    pub off_color: Option<Color>,
+    pub thumb_color: Option<Color>,
+    pub label_color: Option<Color>,
+    pub font_size: Option<f32>,
+
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub padding: Option<f32>,
+    pub edge_insets: Option<EdgeInsets>,
+    pub opacity: Option<f32>,
+
+    pub accessibility_label: Option<String>,
+    pub accessibility_hint: Option<String>,
+}
+
+impl Default for ToggleProps {
+    fn default() -> Self {
+        Self {
+            control_id: String::new(),
+            label: None,
+            is_on: None,
+            disabled: Some(false),
+            on_change: None,
+
+            on_color: Some(Color::Custom(52, 199, 89)),
+            off_color: Some(Color::Custom(233, 233, 235)),
+            thumb_color: Some(Color::White),
+            label_color: Some(Color::Black),
+            font_size: Some(16.0),
+
+            width: None,
+            height: None,
+            padding: None,
+            edge_insets: None,
+            opacity: None,
+
+            accessibility_label: None,
+            accessibility_hint: None,
+        }
+    }
+}
+
+impl ToggleProps {
+    pub fn new(control_id: &str) -> Self {
+        Self {
+            control_id: control_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    pub fn is_on(mut self, is_on: bool) -> Self {
+        self.is_on = Some(is_on);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    pub fn on_change(mut self, handler_id: &str) -> Self {
+        self.on_change = Some(handler_id.to_string());
+        self
+    }
+
+    pub fn on_color(mut self, color: Color) -> Self {
+        self.on_color = Some(color);
+        self
+    }
+}
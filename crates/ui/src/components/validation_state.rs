@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+use crate::render::node::RenderNode;
+use crate::render::property::{Property, keys};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldValidation {
+    errors: Vec<String>,
+    pending: bool,
+}
+
+impl FieldValidation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_node(node: &RenderNode) -> Option<Self> {
+        node.get_prop_as_string("validation_id")?;
+
+        let errors = match node.get_prop("validation_errors") {
+            Some(Property::Array(errors)) => errors.iter()
+                .map(|error| error.to_string_value())
+                .collect(),
+            _ => node.get_prop_as_string(keys::ERROR_MESSAGE).into_iter().collect(),
+        };
+
+        Some(Self {
+            errors,
+            pending: node.get_prop_bool("validation_pending").unwrap_or(false),
+        })
+    }
+
+    pub fn write_to(&self, node: &mut RenderNode) {
+        node.set_prop(keys::INVALID, !self.is_valid());
+        node.set_prop("validation_pending", self.pending);
+        node.set_prop("validation_errors", Property::Array(
+            self.errors.iter().cloned().map(Property::String).collect()
+        ));
+
+        if let Some(message) = self.message() {
+            node.set_prop(keys::ERROR_MESSAGE, message.to_string());
+        }
+    }
+
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.errors.first().map(String::as_str)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    pub fn set_errors(&mut self, errors: Vec<String>) -> bool {
+        if self.errors == errors {
+            return false;
+        }
+
+        self.errors = errors;
+        true
+    }
+
+    pub fn set_pending(&mut self, pending: bool) -> bool {
+        if self.pending == pending {
+            return false;
+        }
+
+        self.pending = pending;
+        true
+    }
+}
+
+static FIELD_VALIDATIONS: OnceLock<RwLock<HashMap<String, FieldValidation>>> = OnceLock::new();
+
+pub fn field_validation_registry() -> &'static RwLock<HashMap<String, FieldValidation>> {
+    FIELD_VALIDATIONS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+pub fn with_field_validation<R>(field_id: &str, f: impl FnOnce(&mut FieldValidation) -> R) -> R {
+    let mut registry = field_validation_registry().write().unwrap();
+    f(registry.entry(field_id.to_string()).or_default())
+}
+
+pub fn field_validation_for_node(node: &RenderNode) -> Option<FieldValidation> {
+    let live = node.get_prop_as_string("validation_id").and_then(|field_id| {
+        let registry = field_validation_registry().read().ok()?;
+        registry.get(&field_id).cloned()
+    });
+
+    live.or_else(|| FieldValidation::from_node(node))
+}
+
+pub fn set_field_errors(field_id: &str, errors: Vec<String>) -> bool {
+    with_field_validation(field_id, |validation| validation.set_errors(errors))
+}
+
+pub fn set_field_pending(field_id: &str, pending: bool) -> bool {
+    with_field_validation(field_id, |validation| validation.set_pending(pending))
+}
+
+pub fn clear_field_validation(field_id: &str) -> bool {
+    with_field_validation(field_id, |validation| {
+        let pending = validation.set_pending(false);
+        validation.set_errors(Vec::new()) || pending
+    })
+}
+
+pub fn remove_field_validation(field_id: &str) -> Option<FieldValidation> {
+    field_validation_registry().write().unwrap().remove(field_id)
+}
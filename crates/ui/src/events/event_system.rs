@@ -70,9 +70,21 @@ pub enum EventType {
     Drag,
     DragEnd,
     
+    ScrollStart,
+    Scroll,
+    ScrollEnd,
+    
     KeyDown,
     KeyUp,
     
+    Copy,
+    Cut,
+    Paste,
+    
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
+    
     LoadingStart,
     LoadingEnd,
     
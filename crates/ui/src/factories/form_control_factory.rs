@@ -0,0 +1,58 @@
+use crate::components::{
+    CheckboxProps,
+    PickerProps,
+    RadioGroupProps,
+    SliderProps,
+    StepperProps,
+    ToggleProps,
+    UIComponent,
+};
+
+pub fn create_toggle(control_id: &str, label: &str, is_on: bool) -> UIComponent {
+    UIComponent::Toggle(
+        ToggleProps::new(control_id)
+            .label(label)
+            .is_on(is_on)
+    )
+}
+
+pub fn create_checkbox(control_id: &str, label: &str, checked: bool) -> UIComponent {
+    UIComponent::Checkbox(
+        CheckboxProps::new(control_id)
+            .label(label)
+            .checked(checked)
+    )
+}
+
+pub fn create_radio_group(control_id: &str, options: &[&str], selected_index: Option<usize>) -> UIComponent {
+    let props = RadioGroupProps::new(control_id, options.iter().map(|option| option.to_string()).collect());
+
+    UIComponent::RadioGroup(match selected_index {
+        Some(index) => props.selected_index(index),
+        None => props,
+    })
+}
+
+pub fn create_slider(control_id: &str, min: f32, max: f32, value: f32) -> UIComponent {
+    UIComponent::Slider(
+        SliderProps::new(control_id)
+            .range(min, max)
+            .value(value)
+    )
+}
+
+pub fn create_stepper(control_id: &str, label: &str, min: f32, max: f32, value: f32) -> UIComponent {
+    UIComponent::Stepper(
+        StepperProps::new(control_id)
+            .label(label)
+            .range(min, max)
+            .value(value)
+    )
+}
+
+pub fn create_picker(control_id: &str, options: &[&str], placeholder: &str) -> UIComponent {
+    UIComponent::Picker(
+        PickerProps::new(control_id, options.iter().map(|option| option.to_string()).collect())
+            .placeholder(placeholder)
+    )
+}
@@ -0,0 +1,42 @@
+use crate::components::{ListProps, ListSection, UIComponent};
+
+pub fn create_list<F>(list_id: &str, item_count: usize, item_builder: F) -> UIComponent
+where
+    F: Fn(usize) -> UIComponent + Send + Sync + 'static
+{
+    UIComponent::List(
+        ListProps::new(list_id, item_count, item_builder)
+    )
+}
+
+pub fn create_fixed_height_list<F>(
+    list_id: &str, 
+    item_count: usize, 
+    item_height: f32, 
+    item_builder: F
+) -> UIComponent
+where
+    F: Fn(usize) -> UIComponent + Send + Sync + 'static
+{
+    UIComponent::List(
+        ListProps::new(list_id, item_count, item_builder)
+            .fixed_item_height(item_height)
+    )
+}
+
+pub fn create_sectioned_list<F>(
+    list_id: &str, 
+    item_count: usize, 
+    sections: Vec<ListSection>, 
+    item_builder: F
+) -> UIComponent
+where
+    F: Fn(usize) -> UIComponent + Send + Sync + 'static
+{
+    let props = sections.into_iter().fold(
+        ListProps::new(list_id, item_count, item_builder),
+        |props, section| props.add_section(section)
+    );
+    
+    UIComponent::List(props.sticky_headers(true))
+}
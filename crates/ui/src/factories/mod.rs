@@ -3,9 +3,15 @@ mod layout_factory;
 mod zstack_factory;
 mod image_factory;
 mod scroll_factory;
+mod list_factory;
+mod text_input_factory;
+mod form_control_factory;
 
 pub use text_factory::*;
 pub use layout_factory::*;
 pub use zstack_factory::*;
 pub use image_factory::*;
-pub use scroll_factory::*;
\ No newline at end of file
+pub use scroll_factory::*;
+pub use list_factory::*;
+pub use text_input_factory::*;
+pub use form_control_factory::*;
\ No newline at end of file
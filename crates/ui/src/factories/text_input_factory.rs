@@ -0,0 +1,24 @@
+use crate::components::{TextInputProps, UIComponent};
+
+pub fn create_text_field(input_id: &str, placeholder: &str) -> UIComponent {
+    UIComponent::TextField(
+        TextInputProps::text_field(input_id)
+            .placeholder(placeholder)
+    )
+}
+
+pub fn create_secure_field(input_id: &str, placeholder: &str) -> UIComponent {
+    UIComponent::TextField(
+        TextInputProps::text_field(input_id)
+            .placeholder(placeholder)
+            .secure_entry(true)
+    )
+}
+
+pub fn create_text_area(input_id: &str, placeholder: &str, rows: u32) -> UIComponent {
+    UIComponent::TextArea(
+        TextInputProps::text_area(input_id)
+            .placeholder(placeholder)
+            .rows(rows)
+    )
+}
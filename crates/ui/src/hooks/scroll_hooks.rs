@@ -43,6 +43,7 @@ pub struct ScrollBehaviorOptions {
     pub direction: Option<ScrollDirection>,
     pub scroll_enabled: Option<bool>,
     pub paging_enabled: Option<bool>,
+    pub bounces: Option<bool>,
     pub always_bounces_horizontal: Option<bool>,
     pub always_bounces_vertical: Option<bool>,
     pub deceleration_rate: Option<DecelerationRate>,
@@ -62,6 +63,10 @@ pub fn use_scroll_behavior(options: ScrollBehaviorOptions) -> impl Fn(ScrollProp
             props.paging_enabled = Some(paging_enabled);
         }
         
+        if let Some(bounces) = options.bounces {
+            props.bounces = Some(bounces);
+        }
+        
         if let Some(always_bounces_horizontal) = options.always_bounces_horizontal {
             props.always_bounces_horizontal = Some(always_bounces_horizontal);
         }
@@ -0,0 +1,127 @@
+use crate::render::node::RenderNode;
+use crate::render::property::{Property, keys};
+use crate::layout::types::Size;
+use crate::components::validation_state::field_validation_for_node;
+
+pub const TOGGLE_SIZE: Size = Size { width: 51.0, height: 31.0 };
+pub const STEPPER_SIZE: Size = Size { width: 94.0, height: 32.0 };
+
+const LABEL_GAP: f32 = 8.0;
+const CHEVRON_WIDTH: f32 = 24.0;
+
+pub fn estimate_text_width(text: &str, font_size: f32) -> f32 {
+    text.chars().count() as f32 * font_size * 0.55
+}
+
+pub fn option_labels(node: &RenderNode) -> Vec<String> {
+    match node.get_prop("options") {
+        Some(Property::Array(options)) => options.iter()
+            .map(|option| option.to_string_value())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub fn validation_message_height(node: &RenderNode) -> f32 {
+    let has_message = field_validation_for_node(node)
+        .is_some_and(|validation| validation.message().is_some());
+
+    if has_message {
+        let font_size = node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0) * 0.8;
+        font_size * 1.2 + 4.0
+    } else {
+        0.0
+    }
+}
+
+fn font_metrics(node: &RenderNode) -> (f32, f32) {
+    let font_size = node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0);
+    (font_size, font_size * 1.2)
+}
+
+fn resolve(node: &RenderNode, width: f32, height: f32) -> Size {
+    let padding = node.get_prop_f32(keys::PADDING).unwrap_or(0.0);
+
+    Size::new(
+        node.get_prop_f32(keys::WIDTH).unwrap_or(width + padding * 2.0),
+        node.get_prop_f32(keys::HEIGHT).unwrap_or(height + padding * 2.0) + validation_message_height(node)
+    )
+}
+
+pub fn measure_toggle(node: &RenderNode, available_size: Size) -> Size {
+    let (_, line_height) = font_metrics(node);
+
+    let width = if node.get_prop_as_string(keys::LABEL).is_some() {
+        available_size.width
+    } else {
+        TOGGLE_SIZE.width
+    };
+
+    resolve(node, width, TOGGLE_SIZE.height.max(line_height))
+}
+
+pub fn measure_checkbox(node: &RenderNode, _available_size: Size) -> Size {
+    let (font_size, line_height) = font_metrics(node);
+    let box_size = node.get_prop_f32("box_size").unwrap_or(20.0);
+
+    let label_width = node.get_prop_as_string(keys::LABEL)
+        .map(|label| LABEL_GAP + estimate_text_width(&label, font_size))
+        .unwrap_or(0.0);
+
+    resolve(node, box_size + label_width, box_size.max(line_height))
+}
+
+pub fn radio_row_height(node: &RenderNode) -> f32 {
+    let (_, line_height) = font_metrics(node);
+    node.get_prop_f32("indicator_size").unwrap_or(20.0).max(line_height)
+}
+
+pub fn measure_radio_group(node: &RenderNode, _available_size: Size) -> Size {
+    let (font_size, _) = font_metrics(node);
+    let options = option_labels(node);
+    let indicator_size = node.get_prop_f32("indicator_size").unwrap_or(20.0);
+    let spacing = node.get_prop_f32("option_spacing").unwrap_or(8.0);
+    let row_height = radio_row_height(node);
+
+    let label_width = options.iter()
+        .map(|option| estimate_text_width(option, font_size))
+        .fold(0.0, f32::max);
+
+    let count = options.len() as f32;
+    let height = (count * row_height + (count - 1.0).max(0.0) * spacing).max(row_height);
+
+    resolve(node, indicator_size + LABEL_GAP + label_width, height)
+}
+
+pub fn measure_slider(node: &RenderNode, available_size: Size) -> Size {
+    let thumb_size = node.get_prop_f32("thumb_size").unwrap_or(24.0);
+    let padding = node.get_prop_f32(keys::PADDING).unwrap_or(0.0);
+
+    resolve(node, available_size.width - padding * 2.0, thumb_size.max(28.0))
+}
+
+pub fn measure_stepper(node: &RenderNode, available_size: Size) -> Size {
+    let (_, line_height) = font_metrics(node);
+    let has_text = node.get_prop_as_string(keys::LABEL).is_some()
+        || node.get_prop_bool("shows_value").unwrap_or(true);
+
+    let width = if has_text {
+        available_size.width
+    } else {
+        STEPPER_SIZE.width
+    };
+
+    resolve(node, width, STEPPER_SIZE.height.max(line_height))
+}
+
+pub fn measure_picker(node: &RenderNode, available_size: Size) -> Size {
+    let (font_size, line_height) = font_metrics(node);
+    let padding = node.get_prop_f32(keys::PADDING).unwrap_or(0.0);
+
+    let content_width = option_labels(node).iter()
+        .chain(node.get_prop_as_string("placeholder").iter())
+        .map(|option| estimate_text_width(option, font_size))
+        .fold(0.0, f32::max) + CHEVRON_WIDTH;
+
+    resolve(node, content_width.min(available_size.width - padding * 2.0), line_height)
+}
@@ -14,6 +14,11 @@ use super::image_layout::measure_image;
 use super::scroll_layout::{measure_scroll, position_scroll_children};
 use super::spacer_layout::measure_spacer;
 use super::divider_layout::measure_divider;
+use super::list_layout::{measure_list, position_list_children};
+use super::text_input_layout::measure_text_input;
+use super::form_control_layout::{
+    measure_toggle, measure_checkbox, measure_radio_group, measure_slider, measure_stepper, measure_picker
+};
 
 pub trait LayoutMeasurement {
     fn measure_node(&mut self, node: &RenderNode, available_size: Size) -> Size;
@@ -71,6 +76,14 @@ impl LayoutEngine {
             "Scroll" => measure_scroll(node, available_size, self),
             "Spacer" => measure_spacer(node, available_size),
             "Divider" => measure_divider(node, available_size),
+            "List" => measure_list(node, available_size, self),
+            "TextField" | "TextArea" => measure_text_input(node, available_size),
+            "Toggle" => measure_toggle(node, available_size),
+            "Checkbox" => measure_checkbox(node, available_size),
+            "RadioGroup" => measure_radio_group(node, available_size),
+            "Slider" => measure_slider(node, available_size),
+            "Stepper" => measure_stepper(node, available_size),
+            "Picker" => measure_picker(node, available_size),
             _ => Size::zero(),
         };
         
@@ -132,6 +145,7 @@ impl LayoutEngine {
             "HStack" => position_hstack_children(node, inner_frame, self),
             "ZStack" => position_zstack_children(node, inner_frame, self),
             "Scroll" => position_scroll_children(node, inner_frame, self),
+            "List" => position_list_children(node, inner_frame, self),
             _ => (),
         }
     }
@@ -0,0 +1,83 @@
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::list_state::{with_list_state, ListRow};
+use crate::layout::{
+    layout_engine::{LayoutMeasurement, LayoutPositioning},
+    types::{Rect, Size}
+};
+
+pub fn measure_list(
+    node: &RenderNode,
+    available_size: Size,
+    engine: &mut impl LayoutMeasurement
+) -> Size {
+    let width = node.get_prop_f32(keys::WIDTH).unwrap_or(available_size.width);
+    let height = node.get_prop_f32(keys::HEIGHT).unwrap_or(available_size.height);
+    let padding = node.get_prop_f32(keys::PADDING).unwrap_or(0.0);
+
+    let viewport = Size::new(
+        (width - padding * 2.0).max(0.0),
+        (height - padding * 2.0).max(0.0)
+    );
+
+    let fixed_item_height = node.get_prop_f32("fixed_item_height");
+
+    let mut measured = Vec::with_capacity(node.children.len());
+
+    for child in &node.children {
+        let child_size = engine.measure_node(child, viewport);
+
+        if let Some(row) = ListRow::read_from(child) {
+            let row_height = match row {
+                ListRow::Item(_) => fixed_item_height.unwrap_or(child_size.height),
+                ListRow::Header(_) => child_size.height,
+            };
+
+            measured.push((row, row_height));
+        }
+    }
+
+    if let Some(list_id) = node.get_prop_as_string("list_id") {
+        with_list_state(&list_id, |state| {
+            state.set_viewport_height(viewport.height);
+            state.record_row_heights(&measured);
+        });
+    }
+
+    Size::new(width, height)
+}
+
+pub fn position_list_children(
+    node: &RenderNode,
+    frame: Rect,
+    engine: &mut impl LayoutPositioning
+) {
+    let Some(list_id) = node.get_prop_as_string("list_id") else {
+        return;
+    };
+
+    let child_frames: Vec<(usize, Rect)> = with_list_state(&list_id, |state| {
+        let scroll_offset = state.scroll_offset();
+        let sticky_position = state.sticky_header().map(|(_, position)| position);
+
+        node.children.iter()
+            .enumerate()
+            .filter_map(|(index, child)| {
+                let row = ListRow::read_from(child)?;
+                let row_height = state.row_height(row);
+
+                let y = if child.get_prop_bool("_list_sticky").unwrap_or(false) {
+                    frame.y + sticky_position.unwrap_or(0.0)
+                } else {
+                    frame.y + state.row_offset(row) - scroll_offset
+                };
+
+                Some((index, Rect::new(frame.x, y, frame.width, row_height)))
+            })
+            .collect()
+    });
+
+    for (index, child_frame) in child_frames {
+        engine.position_node(&node.children[index], child_frame);
+    }
+}
@@ -7,6 +7,9 @@ mod image_layout;
 mod scroll_layout;
 mod spacer_layout;
 mod divider_layout;
+mod list_layout;
+mod text_input_layout;
+pub(crate) mod form_control_layout;
 mod layout_info;
 mod layout_utils;
 mod layout_engine;
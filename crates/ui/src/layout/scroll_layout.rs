@@ -1,9 +1,32 @@
 use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::scroll_controller::with_scroll_controller;
 use crate::layout::{
     layout_engine::{LayoutMeasurement, LayoutPositioning}, 
     types::{Rect, Size}
 };
 
+fn parse_content_inset(node: &RenderNode) -> Option<(f32, f32, f32, f32)> {
+    node.get_prop_as_string("content_inset")
+        .and_then(|inset_str| {
+            let parts: Vec<f32> = inset_str.split(',')
+                .filter_map(|s| s.parse().ok())
+                .collect();
+            
+            if parts.len() == 4 {
+                Some((parts[0], parts[1], parts[2], parts[3]))
+            } else {
+                None
+            }
+        })
+}
+
+fn scroll_offset(node: &RenderNode) -> f32 {
+    node.get_prop_as_string("scroll_id")
+        .map(|scroll_id| with_scroll_controller(&scroll_id, |controller| controller.offset()))
+        .unwrap_or(0.0)
+}
+
 pub fn measure_scroll(
     node: &RenderNode, 
     available_size: Size, 
@@ -19,18 +42,7 @@ pub fn measure_scroll(
     let paging_enabled = node.get_prop_bool("paging_enabled")
         .unwrap_or(false);
     
-    let content_inset = node.get_prop_as_string("content_inset")
-        .and_then(|inset_str| {
-            let parts: Vec<f32> = inset_str.split(',')
-                .filter_map(|s| s.parse().ok())
-                .collect();
-            
-            if parts.len() == 4 {
-                Some((parts[0], parts[1], parts[2], parts[3]))
-            } else {
-                None
-            }
-        });
+    let content_inset = parse_content_inset(node);
     
     let child = &node.children[0];
     let mut child_size = engine.measure_node(child, available_size);
@@ -40,6 +52,25 @@ pub fn measure_scroll(
         child_size.height += top + bottom;
     }
     
+    if let Some(scroll_id) = node.get_prop_as_string("scroll_id") {
+        let padding = node.get_prop_f32(keys::PADDING).unwrap_or(0.0);
+        
+        let (viewport_extent, content_extent) = match direction.as_str() {
+            "Horizontal" => (
+                node.get_prop_f32(keys::WIDTH).unwrap_or(available_size.width) - padding * 2.0,
+                child_size.width
+            ),
+            _ => (
+                node.get_prop_f32(keys::HEIGHT).unwrap_or(available_size.height) - padding * 2.0,
+                child_size.height
+            ),
+        };
+        
+        with_scroll_controller(&scroll_id, |controller| {
+            controller.set_extents(viewport_extent, content_extent);
+        });
+    }
+    
     if paging_enabled {
         match direction.as_str() {
             "Horizontal" => {
@@ -79,26 +110,16 @@ pub fn position_scroll_children(
                 let direction = node.get_prop_as_string("direction")
                     .unwrap_or_else(|| "Vertical".to_string());
                 
-                let content_inset = node.get_prop_as_string("content_inset")
-                    .and_then(|inset_str| {
-                        let parts: Vec<f32> = inset_str.split(',')
-                            .filter_map(|s| s.parse().ok())
-                            .collect();
-                        
-                        if parts.len() == 4 {
-                            Some((parts[0], parts[1], parts[2], parts[3]))
-                        } else {
-                            None
-                        }
-                    });
+                let content_inset = parse_content_inset(node);
+                let offset = scroll_offset(node);
                 
                 child_frame = if direction == "Horizontal" {
-                    let x = frame.x + content_inset.map(|i| i.1).unwrap_or(0.0);
+                    let x = frame.x + content_inset.map(|i| i.1).unwrap_or(0.0) - offset;
                     let y = frame.y + content_inset.map(|i| i.0).unwrap_or(0.0);
                     Rect::new(x, y, child_size.width, frame.height)
                 } else {
                     let x = frame.x + content_inset.map(|i| i.1).unwrap_or(0.0);
-                    let y = frame.y + content_inset.map(|i| i.0).unwrap_or(0.0);
+                    let y = frame.y + content_inset.map(|i| i.0).unwrap_or(0.0) - offset;
                     Rect::new(x, y, frame.width, child_size.height)
                 };
             } else {
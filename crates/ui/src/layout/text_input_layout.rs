@@ -0,0 +1,23 @@
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::layout::types::Size;
+use super::form_control_layout::validation_message_height;
+
+pub fn measure_text_input(node: &RenderNode, available_size: Size) -> Size {
+    let font_size = node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0);
+    let line_height = node.get_prop_f32(keys::LINE_HEIGHT).unwrap_or(1.2) * font_size;
+    let padding = node.get_prop_f32(keys::PADDING).unwrap_or(0.0);
+
+    let multiline = node.get_prop_bool("multiline").unwrap_or(false);
+    let rows = if multiline {
+        node.get_prop_f32("rows").unwrap_or(3.0).max(1.0)
+    } else {
+        1.0
+    };
+
+    let width = node.get_prop_f32(keys::WIDTH).unwrap_or(available_size.width);
+    let height = node.get_prop_f32(keys::HEIGHT)
+        .unwrap_or(rows * line_height + padding * 2.0);
+
+    Size::new(width, height + validation_message_height(node))
+}
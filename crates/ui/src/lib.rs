@@ -71,6 +71,19 @@ pub use components::{
     ScrollProps,
     SpacerProps,
     DividerProps,
+    ListProps,
+    ListSection,
+    ListScrollAlignment,
+    TextInputProps,
+    ToggleProps,
+    CheckboxProps,
+    CheckState,
+    RadioGroupProps,
+    SliderProps,
+    StepperProps,
+    PickerProps,
+    FormValue,
+    FormValuePayload,
     UIComponent,
     transform_component,
 };
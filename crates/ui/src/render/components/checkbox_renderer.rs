@@ -0,0 +1,111 @@
+use crate::render::renderer::{DrawingContext, ComponentRenderer};
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::button::ButtonState;
+use crate::components::checkbox::CheckState;
+use crate::components::form_control_state::{form_control_state_for_node, FormValue};
+use crate::components::validation_state::field_validation_for_node;
+use crate::layout::Rect;
+use super::shared::{
+    draw_validation_message,
+    ERROR_COLOR,
+    draw_rounded_rect,
+    draw_focus_ring,
+    content_frame,
+    parse_color
+};
+
+pub struct CheckboxRenderer;
+
+impl<T: DrawingContext> ComponentRenderer<T> for CheckboxRenderer {
+    fn render(&self, node: &RenderNode, context: &T, frame: Rect) -> Result<(), String> {
+        let Some(state) = form_control_state_for_node(node) else {
+            return Ok(());
+        };
+
+        let color = |key: &str, default: &str| node.get_prop_as_string(key)
+            .map(|c| parse_color(&c))
+            .unwrap_or_else(|| default.to_string());
+
+        let check_state = match state.value() {
+            FormValue::Check(check_state) => check_state,
+            _ => CheckState::Unchecked,
+        };
+        let button_state = state.button_state();
+        let content = content_frame(node, frame);
+        let size = node.get_prop_f32("box_size").unwrap_or(20.0);
+        let check_box = Rect::new(content.x, content.y + (content.height - size) / 2.0, size, size);
+        let tint = color(keys::TINT_COLOR, "#007aff");
+        let invalid = field_validation_for_node(node).is_some_and(|validation| !validation.is_valid());
+
+        context.save_drawing_state()?;
+
+        if button_state == ButtonState::Disabled {
+            context.set_global_alpha(0.5)?;
+        }
+
+        context.begin_path()?;
+        draw_rounded_rect(context, check_box.x, check_box.y, size, size, 4.0)?;
+
+        if check_state == CheckState::Unchecked {
+            context.set_fill_color("#ffffff")?;
+            context.fill()?;
+            context.set_stroke_color(&if invalid {
+                ERROR_COLOR.to_string()
+            } else if button_state == ButtonState::Hovered {
+                tint.clone()
+            } else {
+                color(keys::BORDER_COLOR, "#8e8e93")
+            })?;
+            context.set_line_width(1.5)?;
+            context.stroke()?;
+        } else {
+            context.set_fill_color(&tint)?;
+            context.fill()?;
+        }
+
+        if button_state == ButtonState::Pressed {
+            context.set_fill_color("rgba(0, 0, 0, 0.1)")?;
+            context.fill()?;
+        }
+
+        let check_color = color("check_color", "#ffffff");
+
+        match check_state {
+            CheckState::Checked => {
+                context.begin_path()?;
+                context.move_to(check_box.x + size * 0.22, check_box.y + size * 0.52)?;
+                context.line_to(check_box.x + size * 0.42, check_box.y + size * 0.72)?;
+                context.line_to(check_box.x + size * 0.78, check_box.y + size * 0.3)?;
+                context.set_stroke_color(&check_color)?;
+                context.set_line_width((size * 0.1).max(1.5))?;
+                context.stroke()?;
+            },
+            CheckState::Indeterminate => {
+                context.set_fill_color(&check_color)?;
+                context.fill_rect(check_box.x + size * 0.25, check_box.y + size * 0.45, size * 0.5, size * 0.1)?;
+            },
+            CheckState::Unchecked => {},
+        }
+
+        if let Some(label) = node.get_prop_as_string(keys::LABEL) {
+            let font_size = node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0);
+
+            context.set_font(&format!("{}px sans-serif", font_size))?;
+            context.set_text_align("left")?;
+            context.set_text_baseline("middle")?;
+            context.set_fill_color(&color(keys::TEXT_COLOR, "#000000"))?;
+            context.fill_text(&label, check_box.right() + 8.0, check_box.y + size / 2.0)?;
+        }
+
+        if button_state == ButtonState::Focused {
+            draw_focus_ring(context, check_box, 4.0)?;
+        }
+
+        context.restore_drawing_state()?;
+
+        draw_validation_message(context, node, frame)?;
+
+        Ok(())
+    }
+}
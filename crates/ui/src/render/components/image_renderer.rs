@@ -2,7 +2,7 @@ use crate::render::renderer::{DrawingContext, ComponentRenderer};
 use crate::render::node::RenderNode;
 use crate::layout::Rect;
 use crate::render::property::keys;
-use crate::components::image::{ImageSource, ResizeMode, ContentMode};
+use crate::components::image::{ImageSource, ResizeMode, ContentMode, ImageFilter};
 use super::shared::{
     draw_rounded_rect, 
     draw_background,
@@ -54,7 +54,11 @@ impl<T: DrawingContext> ComponentRenderer<T> for ImageRenderer {
             let alt = node.get_prop_as_string("alt").unwrap_or_default();
             
             if !src.is_empty() {
+                let filtered = self.apply_filters(context, node)?;
                 self.draw_image(context, node, frame, &source_type, &src, &alt)?;
+                if filtered {
+                    context.clear_filter()?;
+                }
             } else {
                 self.draw_empty_placeholder(context, frame, &alt)?;
             }
@@ -64,8 +68,6 @@ impl<T: DrawingContext> ComponentRenderer<T> for ImageRenderer {
             self.apply_tint(context, frame, &tint)?;
         }
         
-        self.apply_filters(context, node, frame)?;
-        
         if corner_radius > 0.0 {
             context.restore_drawing_state()?;
         }
@@ -266,54 +268,47 @@ impl ImageRenderer {
         Ok(())
     }
     
-    fn apply_filters<T: DrawingContext>(
-        &self,
-        context: &T,
-        node: &RenderNode,
-        frame: Rect
-    ) -> Result<(), String> {
+    fn node_filters(node: &RenderNode) -> Result<Vec<ImageFilter>, String> {
         let filter_count = node.get_prop_f32("filter_count").unwrap_or(0.0) as usize;
+        let mut filters = Vec::with_capacity(filter_count);
         
         for i in 0..filter_count {
-            let filter_type_key = format!("filter_{}_type", i);
-            let filter_value_key = format!("filter_{}_value", i);
+            let Some(filter_type) = node.get_prop_as_string(&format!("filter_{}_type", i)) else {
+                continue;
+            };
+            let value_key = format!("filter_{}_value", i);
+            let value = node.get_prop_f32(&value_key);
             
-            if let (Some(filter_type), Some(filter_value)) = (
-                node.get_prop_as_string(&filter_type_key), 
-                node.get_prop_f32(&filter_value_key)
-            ) {
-                match filter_type.to_lowercase().as_str() {
-                    "blur" => {
-                        context.apply_filter(&format!("blur({}px)", filter_value))?;
-                    },
-                    "grayscale" => {
-                        context.apply_filter(&format!("grayscale({}%)", filter_value * 100.0))?;
-                    },
-                    "sepia" => {
-                        context.apply_filter(&format!("sepia({}%)", filter_value * 100.0))?;
-                    },
-                    "saturation" => {
-                        context.apply_filter(&format!("saturate({}%)", filter_value * 100.0))?;
-                    },
-                    "brightness" => {
-                        context.apply_filter(&format!("brightness({}%)", filter_value * 100.0))?;
-                    },
-                    "contrast" => {
-                        context.apply_filter(&format!("contrast({}%)", filter_value * 100.0))?;
-                    },
-                    "hue" => {
-                        context.apply_filter(&format!("hue-rotate({}deg)", filter_value * 360.0))?;
-                    },
-                    "invert" => {
-                        context.apply_filter("invert(100%)")?;
-                    },
-                    _ => {
-                        return Err(format!("Unsupported filter type: {}", filter_type));
-                    }
-                }
-            }
+            let filter = match filter_type.to_lowercase().as_str() {
+                "blur" => value.map(ImageFilter::Blur),
+                "grayscale" => value.map(ImageFilter::Grayscale),
+                "sepia" => value.map(ImageFilter::Sepia),
+                "saturation" => value.map(ImageFilter::Saturation),
+                "brightness" => value.map(ImageFilter::Brightness),
+                "contrast" => value.map(ImageFilter::Contrast),
+                "hue" => value.map(ImageFilter::Hue),
+                "invert" => node.get_prop_bool(&value_key).map(ImageFilter::Invert),
+                _ => return Err(format!("Unsupported filter type: {}", filter_type)),
+            };
+            filters.extend(filter);
         }
         
-        Ok(())
+        Ok(filters)
+    }
+    
+    fn apply_filters<T: DrawingContext>(
+        &self,
+        context: &T,
+        node: &RenderNode
+    ) -> Result<bool, String> {
+        let filters = Self::node_filters(node)?;
+        if filters.is_empty() {
+            return Ok(false);
+        }
+        
+        let css: Vec<String> = filters.iter().map(ImageFilter::to_css).collect();
+        context.apply_filter(&css.join(" "))?;
+        
+        Ok(true)
     }
 }
\ No newline at end of file
@@ -3,9 +3,17 @@ pub mod button_renderer;
 pub mod divider_renderer;
 pub mod hstack_renderer;
 pub mod image_renderer;
+pub mod list_renderer;
 pub mod scroll_renderer;
 pub mod spacer_renderer;
 pub mod text_renderer;
+pub mod text_input_renderer;
+pub mod toggle_renderer;
+pub mod checkbox_renderer;
+pub mod radio_group_renderer;
+pub mod slider_renderer;
+pub mod stepper_renderer;
+pub mod picker_renderer;
 pub mod vstack_renderer;
 pub mod zstack_renderer;
 pub mod canvas_renderer;
@@ -16,9 +24,17 @@ pub use button_renderer::ButtonRenderer;
 pub use divider_renderer::DividerRenderer;
 pub use hstack_renderer::HStackRenderer;
 pub use image_renderer::ImageRenderer;
+pub use list_renderer::ListRenderer;
 pub use scroll_renderer::ScrollRenderer;
 pub use spacer_renderer::SpacerRenderer;
 pub use text_renderer::TextRenderer;
+pub use text_input_renderer::{TextInputRenderer, TextInputMetrics, TextInputSnapshot};
+pub use toggle_renderer::ToggleRenderer;
+pub use checkbox_renderer::CheckboxRenderer;
+pub use radio_group_renderer::RadioGroupRenderer;
+pub use slider_renderer::SliderRenderer;
+pub use stepper_renderer::StepperRenderer;
+pub use picker_renderer::PickerRenderer;
 pub use vstack_renderer::VStackRenderer;
 pub use zstack_renderer::ZStackRenderer;
 
@@ -47,4 +63,8 @@ pub use shared::{
     clear_shadow,
     create_gradient,
     draw_scrollbar,
+    content_frame,
+    control_frame,
+    draw_focus_ring,
+    draw_validation_message,
 };
\ No newline at end of file
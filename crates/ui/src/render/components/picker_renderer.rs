@@ -0,0 +1,212 @@
+use crate::render::renderer::{DrawingContext, ComponentRenderer};
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::button::ButtonState;
+use crate::components::form_control_state::{form_control_state_for_node, FormControlState};
+use crate::components::validation_state::field_validation_for_node;
+use crate::layout::{Rect, Point};
+use crate::layout::form_control_layout::option_labels;
+use super::shared::{
+    draw_validation_message,
+    control_frame,
+    ERROR_COLOR,
+    draw_rounded_rect,
+    draw_focus_ring,
+    content_frame,
+    parse_color
+};
+
+pub struct PickerRenderer;
+
+pub fn picker_row_height(node: &RenderNode) -> f32 {
+    (node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0) * 2.0).max(32.0)
+}
+
+fn visible_option_count(node: &RenderNode, count: usize) -> usize {
+    let max_visible = node.get_prop_f32("max_visible_options").unwrap_or(6.0).max(1.0) as usize;
+
+    count.min(max_visible)
+}
+
+fn first_visible_option(state: &FormControlState, visible: usize) -> usize {
+    state.highlighted()
+        .map(|highlighted| (highlighted + 1).saturating_sub(visible))
+        .unwrap_or(0)
+}
+
+pub fn picker_dropdown_frame(node: &RenderNode, frame: Rect, count: usize) -> Rect {
+    let visible = visible_option_count(node, count);
+    let frame = control_frame(node, frame);
+
+    Rect::new(frame.x, frame.bottom() + 4.0, frame.width, visible as f32 * picker_row_height(node))
+}
+
+pub fn picker_option_at(node: &RenderNode, frame: Rect, x: f32, y: f32) -> Option<usize> {
+    let state = form_control_state_for_node(node)?;
+    let count = state.config().options.len();
+    let dropdown = picker_dropdown_frame(node, frame, count);
+
+    if !dropdown.contains(Point::new(x, y)) {
+        return None;
+    }
+
+    let row = ((y - dropdown.y) / picker_row_height(node)).floor() as usize;
+    let index = first_visible_option(&state, visible_option_count(node, count)) + row;
+
+    (index < count).then_some(index)
+}
+
+pub fn draw_picker_dropdown<T: DrawingContext>(
+    context: &T,
+    node: &RenderNode,
+    frame: Rect
+) -> Result<(), String> {
+    let Some(state) = form_control_state_for_node(node) else {
+        return Ok(());
+    };
+
+    if !state.is_expanded() {
+        return Ok(());
+    }
+
+    let color = |key: &str, default: &str| node.get_prop_as_string(key)
+        .map(|c| parse_color(&c))
+        .unwrap_or_else(|| default.to_string());
+
+    let options = option_labels(node);
+    let dropdown = picker_dropdown_frame(node, frame, options.len());
+    let row_height = picker_row_height(node);
+    let visible = visible_option_count(node, options.len());
+    let first = first_visible_option(&state, visible);
+    let padding = node.get_prop_f32(keys::PADDING).unwrap_or(8.0);
+    let font_size = node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0);
+    let tint = color(keys::TINT_COLOR, "#007aff");
+    let selected = state.value().as_selection();
+
+    context.save_drawing_state()?;
+
+    context.set_shadow(0.0, 4.0, 12.0, "rgba(0, 0, 0, 0.2)")?;
+    context.begin_path()?;
+    draw_rounded_rect(context, dropdown.x, dropdown.y, dropdown.width, dropdown.height, 8.0)?;
+    context.set_fill_color("#ffffff")?;
+    context.fill()?;
+    context.clear_shadow()?;
+
+    context.clip_rounded_rect(dropdown.x, dropdown.y, dropdown.width, dropdown.height, 8.0)?;
+    context.set_font(&format!("{}px sans-serif", font_size))?;
+    context.set_text_align("left")?;
+    context.set_text_baseline("middle")?;
+
+    for (row, (index, option)) in options.iter().enumerate().skip(first).take(visible).enumerate() {
+        let row_y = dropdown.y + row as f32 * row_height;
+
+        if state.highlighted() == Some(index) {
+            context.set_fill_color("rgba(0, 122, 255, 0.12)")?;
+            context.fill_rect(dropdown.x, row_y, dropdown.width, row_height)?;
+        }
+
+        context.set_fill_color(&if selected == Some(index) {
+            tint.clone()
+        } else {
+            color(keys::TEXT_COLOR, "#000000")
+        })?;
+        context.fill_text(option, dropdown.x + padding, row_y + row_height / 2.0)?;
+    }
+
+    context.restore_drawing_state()?;
+
+    Ok(())
+}
+
+impl<T: DrawingContext> ComponentRenderer<T> for PickerRenderer {
+    fn render(&self, node: &RenderNode, context: &T, frame: Rect) -> Result<(), String> {
+        let Some(state) = form_control_state_for_node(node) else {
+            return Ok(());
+        };
+
+        let color = |key: &str, default: &str| node.get_prop_as_string(key)
+            .map(|c| parse_color(&c))
+            .unwrap_or_else(|| default.to_string());
+
+        let button_state = state.button_state();
+        let content = content_frame(node, frame);
+        let message_frame = frame;
+        let frame = control_frame(node, frame);
+        let invalid = field_validation_for_node(node).is_some_and(|validation| !validation.is_valid());
+        let radius = node.get_prop_f32(keys::BORDER_RADIUS).unwrap_or(6.0);
+        let font_size = node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0);
+        let center_y = content.y + content.height / 2.0;
+        let chevron_x = content.right() - 8.0;
+
+        context.save_drawing_state()?;
+
+        if button_state == ButtonState::Disabled {
+            context.set_global_alpha(0.5)?;
+        }
+
+        context.begin_path()?;
+        draw_rounded_rect(context, frame.x, frame.y, frame.width, frame.height, radius)?;
+        context.set_fill_color(&color(keys::BACKGROUND, "#ffffff"))?;
+        context.fill()?;
+
+        if matches!(button_state, ButtonState::Pressed | ButtonState::Hovered) {
+            context.set_fill_color(if button_state == ButtonState::Pressed {
+                "rgba(0, 0, 0, 0.08)"
+            } else {
+                "rgba(0, 0, 0, 0.03)"
+            })?;
+            context.fill()?;
+        }
+
+        context.set_stroke_color(&if state.is_expanded() {
+            color(keys::TINT_COLOR, "#007aff")
+        } else if invalid {
+            ERROR_COLOR.to_string()
+        } else {
+            color(keys::BORDER_COLOR, "#c7c7cc")
+        })?;
+        context.set_line_width(1.0)?;
+        context.stroke()?;
+
+        context.save_drawing_state()?;
+        context.clip_rect(content.x, frame.y, (chevron_x - 16.0 - content.x).max(0.0), frame.height)?;
+        context.set_font(&format!("{}px sans-serif", font_size))?;
+        context.set_text_align("left")?;
+        context.set_text_baseline("middle")?;
+
+        match state.selected_option() {
+            Some(option) => {
+                context.set_fill_color(&color(keys::TEXT_COLOR, "#000000"))?;
+                context.fill_text(option, content.x, center_y)?;
+            },
+            None => {
+                if let Some(placeholder) = node.get_prop_as_string("placeholder") {
+                    context.set_fill_color(&color("placeholder_color", "#a0a0a0"))?;
+                    context.fill_text(&placeholder, content.x, center_y)?;
+                }
+            },
+        }
+
+        context.restore_drawing_state()?;
+
+        let direction = if state.is_expanded() { -1.0 } else { 1.0 };
+
+        context.begin_path()?;
+        context.move_to(chevron_x - 10.0, center_y - 2.5 * direction)?;
+        context.line_to(chevron_x - 5.0, center_y + 2.5 * direction)?;
+        context.line_to(chevron_x, center_y - 2.5 * direction)?;
+        context.set_stroke_color(&color(keys::TINT_COLOR, "#8e8e93"))?;
+        context.set_line_width(1.5)?;
+        context.stroke()?;
+
+        if button_state == ButtonState::Focused {
+            draw_focus_ring(context, frame, radius)?;
+        }
+
+        context.restore_drawing_state()?;
+
+        draw_validation_message(context, node, message_frame)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::f32::consts::PI;
+use crate::render::renderer::{DrawingContext, ComponentRenderer};
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::button::ButtonState;
+use crate::components::form_control_state::form_control_state_for_node;
+use crate::layout::Rect;
+use crate::layout::form_control_layout::{option_labels, radio_row_height};
+use super::shared::{
+    draw_validation_message,
+    content_frame,
+    parse_color
+};
+
+pub struct RadioGroupRenderer;
+
+pub fn radio_option_at(node: &RenderNode, frame: Rect, y: f32) -> Option<usize> {
+    let content = content_frame(node, frame);
+    let row_height = radio_row_height(node);
+    let spacing = node.get_prop_f32("option_spacing").unwrap_or(8.0);
+    let offset = y - content.y;
+
+    if offset < 0.0 {
+        return None;
+    }
+
+    let index = (offset / (row_height + spacing)).floor() as usize;
+    let within_row = offset - index as f32 * (row_height + spacing) <= row_height;
+
+    (within_row && index < option_labels(node).len()).then_some(index)
+}
+
+impl<T: DrawingContext> ComponentRenderer<T> for RadioGroupRenderer {
+    fn render(&self, node: &RenderNode, context: &T, frame: Rect) -> Result<(), String> {
+        let Some(state) = form_control_state_for_node(node) else {
+            return Ok(());
+        };
+
+        let color = |key: &str, default: &str| node.get_prop_as_string(key)
+            .map(|c| parse_color(&c))
+            .unwrap_or_else(|| default.to_string());
+
+        let button_state = state.button_state();
+        let selected = state.value().as_selection();
+        let content = content_frame(node, frame);
+        let size = node.get_prop_f32("indicator_size").unwrap_or(20.0);
+        let spacing = node.get_prop_f32("option_spacing").unwrap_or(8.0);
+        let row_height = radio_row_height(node);
+        let font_size = node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0);
+        let tint = color(keys::TINT_COLOR, "#007aff");
+        let focus_index = selected.unwrap_or(0);
+
+        context.save_drawing_state()?;
+
+        if button_state == ButtonState::Disabled {
+            context.set_global_alpha(0.5)?;
+        }
+
+        context.set_font(&format!("{}px sans-serif", font_size))?;
+        context.set_text_align("left")?;
+        context.set_text_baseline("middle")?;
+
+        for (index, option) in option_labels(node).iter().enumerate() {
+            let center_x = content.x + size / 2.0;
+            let center_y = content.y + index as f32 * (row_height + spacing) + row_height / 2.0;
+            let is_selected = selected == Some(index);
+
+            context.begin_path()?;
+            context.arc(center_x, center_y, size / 2.0 - 1.0, 0.0, 2.0 * PI, false)?;
+            context.set_fill_color("#ffffff")?;
+            context.fill()?;
+
+            if is_selected {
+                context.set_stroke_color(&tint)?;
+                context.set_line_width(2.0)?;
+            } else {
+                context.set_stroke_color(&color(keys::BORDER_COLOR, "#8e8e93"))?;
+                context.set_line_width(1.5)?;
+            }
+            context.stroke()?;
+
+            if is_selected {
+                context.begin_path()?;
+                context.arc(center_x, center_y, size * 0.25, 0.0, 2.0 * PI, false)?;
+                context.set_fill_color(&tint)?;
+                context.fill()?;
+            }
+
+            if button_state == ButtonState::Focused && index == focus_index {
+                context.begin_path()?;
+                context.arc(center_x, center_y, size / 2.0 + 3.0, 0.0, 2.0 * PI, false)?;
+                context.set_stroke_color("rgba(0, 122, 255, 0.6)")?;
+                context.set_line_width(2.0)?;
+                context.stroke()?;
+            }
+
+            context.set_fill_color(&color(keys::TEXT_COLOR, "#000000"))?;
+            context.fill_text(option, content.x + size + 8.0, center_y)?;
+        }
+
+        context.restore_drawing_state()?;
+
+        draw_validation_message(context, node, frame)?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::render::renderer::{DrawingContext, ComponentRenderer};
 use crate::render::node::RenderNode;
+use crate::components::scroll_controller::scroll_controller_registry;
 use crate::layout::Rect;
 use super::shared::{
     draw_background, 
@@ -24,8 +25,16 @@ impl<T: DrawingContext> ComponentRenderer<T> for ScrollRenderer {
         let direction = node.get_prop_as_string("direction")
             .unwrap_or_else(|| "Vertical".to_string());
         
-        let content_size = node.get_prop_f32("content_size").unwrap_or(0.0);
-        let scroll_position = node.get_prop_f32("scroll_position").unwrap_or(0.0);
+        let live_metrics = node.get_prop_as_string("scroll_id").and_then(|scroll_id| {
+            let registry = scroll_controller_registry().read().ok()?;
+            let controller = registry.get(&scroll_id)?;
+            Some((controller.content_extent(), controller.offset()))
+        });
+        
+        let (content_size, scroll_position) = live_metrics.unwrap_or_else(|| (
+            node.get_prop_f32("content_size").unwrap_or(0.0),
+            node.get_prop_f32("scroll_position").unwrap_or(0.0),
+        ));
         
         let scrollbar_color = node.get_prop_as_string("scrollbar_color")
             .map(|c| parse_color(&c))
@@ -2,8 +2,12 @@ use crate::render::renderer::DrawingContext;
 use crate::render::node::RenderNode;
 use crate::layout::Rect;
 use crate::shared::Color;
+use crate::layout::form_control_layout::validation_message_height;
+use crate::components::validation_state::field_validation_for_node;
 use std::f32::consts::PI;
 
+pub const ERROR_COLOR: &str = "#ff3b30";
+
 pub fn parse_color(color: &str) -> String {
     Color::from_hex(color).to_css_string()
 }
@@ -185,6 +189,26 @@ pub fn create_gradient<T: DrawingContext>(
     Ok(Some(gradient_id))
 }
 
+fn scrollbar_thumb(track_length: f32, content_size: f32, scroll_position: f32) -> (f32, f32) {
+    let visible_ratio = if content_size > track_length {
+        track_length / content_size
+    } else {
+        1.0
+    };
+    
+    let max_scroll = (content_size - track_length).max(0.0);
+    let overscroll = (-scroll_position).max(0.0) + (scroll_position - max_scroll).max(0.0);
+    
+    let thumb_length = (track_length * visible_ratio - overscroll).max(30.0).min(track_length);
+    let scroll_ratio = if max_scroll > 0.0 {
+        (scroll_position / max_scroll).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    
+    ((track_length - thumb_length) * scroll_ratio, thumb_length)
+}
+
 pub fn draw_scrollbar<T: DrawingContext>(
     context: &T, 
     frame: Rect, 
@@ -197,21 +221,8 @@ pub fn draw_scrollbar<T: DrawingContext>(
 ) -> Result<(), String> {
     match direction {
         "Horizontal" => {
-            let visible_ratio = if content_size > frame.width {
-                frame.width / content_size
-            } else {
-                1.0
-            };
-            
-            let scrollbar_length = (frame.width * visible_ratio).max(30.0);
-            let max_scroll = content_size - frame.width;
-            let scroll_ratio = if max_scroll > 0.0 {
-                scroll_position / max_scroll
-            } else {
-                0.0
-            };
-            
-            let scrollbar_pos = frame.x + (frame.width - scrollbar_length) * scroll_ratio;
+            let (scrollbar_offset, scrollbar_length) = scrollbar_thumb(frame.width, content_size, scroll_position);
+            let scrollbar_pos = frame.x + scrollbar_offset;
             
             context.set_fill_color("#eeeeee")?;
             context.fill_rect(
@@ -230,21 +241,8 @@ pub fn draw_scrollbar<T: DrawingContext>(
             )?;
         },
         _ => {
-            let visible_ratio = if content_size > frame.height {
-                frame.height / content_size
-            } else {
-                1.0
-            };
-            
-            let scrollbar_length = (frame.height * visible_ratio).max(30.0);
-            let max_scroll = content_size - frame.height;
-            let scroll_ratio = if max_scroll > 0.0 {
-                scroll_position / max_scroll
-            } else {
-                0.0
-            };
-            
-            let scrollbar_pos = frame.y + (frame.height - scrollbar_length) * scroll_ratio;
+            let (scrollbar_offset, scrollbar_length) = scrollbar_thumb(frame.height, content_size, scroll_position);
+            let scrollbar_pos = frame.y + scrollbar_offset;
             
             context.set_fill_color("#eeeeee")?;
             context.fill_rect(
@@ -265,4 +263,65 @@ pub fn draw_scrollbar<T: DrawingContext>(
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+pub fn control_frame(node: &RenderNode, frame: Rect) -> Rect {
+    let message_height = validation_message_height(node);
+    
+    Rect::new(frame.x, frame.y, frame.width, (frame.height - message_height).max(0.0))
+}
+
+pub fn content_frame(node: &RenderNode, frame: Rect) -> Rect {
+    let padding = node.get_prop_f32("padding").unwrap_or(0.0);
+    let frame = control_frame(node, frame);
+    
+    Rect::new(
+        frame.x + padding,
+        frame.y + padding,
+        (frame.width - padding * 2.0).max(0.0),
+        (frame.height - padding * 2.0).max(0.0)
+    )
+}
+
+pub fn draw_focus_ring<T: DrawingContext>(
+    context: &T, 
+    frame: Rect, 
+    radius: f32
+) -> Result<(), String> {
+    context.begin_path()?;
+    draw_rounded_rect(
+        context, 
+        frame.x - 3.0, 
+        frame.y - 3.0, 
+        frame.width + 6.0, 
+        frame.height + 6.0, 
+        radius + 3.0
+    )?;
+    context.set_stroke_color("rgba(0, 122, 255, 0.6)")?;
+    context.set_line_width(2.0)?;
+    context.stroke()?;
+    
+    Ok(())
+}
+
+pub fn draw_validation_message<T: DrawingContext>(
+    context: &T, 
+    node: &RenderNode, 
+    frame: Rect
+) -> Result<(), String> {
+    let Some(message) = field_validation_for_node(node)
+        .and_then(|validation| validation.message().map(str::to_string)) else {
+        return Ok(());
+    };
+    
+    let font_size = node.get_prop_f32("font_size").unwrap_or(16.0) * 0.8;
+    context.save_drawing_state()?;
+    context.clip_rect(frame.x, frame.y, frame.width, frame.height)?;
+    context.set_font(&format!("{}px sans-serif", font_size))?;
+    context.set_text_align("left")?;
+    context.set_text_baseline("bottom")?;
+    context.set_fill_color(ERROR_COLOR)?;
+    context.fill_text(&message, frame.x, frame.bottom())?;
+    context.restore_drawing_state()?;
+    
+    Ok(())
+}
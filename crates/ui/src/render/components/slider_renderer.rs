@@ -0,0 +1,119 @@
+use std::f32::consts::PI;
+use crate::render::renderer::{DrawingContext, ComponentRenderer};
+use crate::render::node::RenderNode;
+use crate::components::button::ButtonState;
+use crate::components::form_control_state::form_control_state_for_node;
+use crate::layout::Rect;
+use super::shared::{
+    draw_validation_message,
+    draw_rounded_rect,
+    content_frame,
+    parse_color
+};
+
+pub struct SliderRenderer;
+
+pub fn slider_track(node: &RenderNode, frame: Rect) -> (f32, f32, f32) {
+    let content = content_frame(node, frame);
+    let thumb_size = node.get_prop_f32("thumb_size").unwrap_or(24.0);
+
+    (
+        content.x + thumb_size / 2.0,
+        (content.right() - thumb_size / 2.0).max(content.x + thumb_size / 2.0),
+        content.y + content.height / 2.0
+    )
+}
+
+pub fn slider_fraction_at(node: &RenderNode, frame: Rect, x: f32) -> f32 {
+    let (start_x, end_x, _) = slider_track(node, frame);
+
+    if end_x <= start_x {
+        0.0
+    } else {
+        ((x - start_x) / (end_x - start_x)).clamp(0.0, 1.0)
+    }
+}
+
+impl<T: DrawingContext> ComponentRenderer<T> for SliderRenderer {
+    fn render(&self, node: &RenderNode, context: &T, frame: Rect) -> Result<(), String> {
+        let Some(state) = form_control_state_for_node(node) else {
+            return Ok(());
+        };
+
+        let color = |key: &str, default: &str| node.get_prop_as_string(key)
+            .map(|c| parse_color(&c))
+            .unwrap_or_else(|| default.to_string());
+
+        let button_state = state.button_state();
+        let (start_x, end_x, center_y) = slider_track(node, frame);
+        let track_height = node.get_prop_f32("track_height").unwrap_or(4.0);
+        let thumb_size = node.get_prop_f32("thumb_size").unwrap_or(24.0);
+        let thumb_x = start_x + (end_x - start_x) * state.fraction();
+        let track_y = center_y - track_height / 2.0;
+
+        context.save_drawing_state()?;
+
+        if button_state == ButtonState::Disabled {
+            context.set_global_alpha(0.5)?;
+        }
+
+        context.begin_path()?;
+        draw_rounded_rect(context, start_x, track_y, end_x - start_x, track_height, track_height / 2.0)?;
+        context.set_fill_color(&color("track_color", "#e5e5ea"))?;
+        context.fill()?;
+
+        context.begin_path()?;
+        draw_rounded_rect(context, start_x, track_y, thumb_x - start_x, track_height, track_height / 2.0)?;
+        context.set_fill_color(&color("fill_color", "#007aff"))?;
+        context.fill()?;
+
+        let config = state.config();
+        let range = config.max - config.min;
+        let tick_count = config.step
+            .filter(|step| *step > 0.0 && range > 0.0)
+            .map(|step| (range / step).round() as usize)
+            .filter(|count| *count <= 20);
+
+        if let (true, Some(count)) = (node.get_prop_bool("shows_ticks").unwrap_or(false), tick_count) {
+            context.set_fill_color("#c7c7cc")?;
+
+            for tick in 0..=count {
+                let x = start_x + (end_x - start_x) * tick as f32 / count.max(1) as f32;
+                context.fill_rect(x - 0.5, center_y + track_height / 2.0 + 3.0, 1.0, 4.0)?;
+            }
+        }
+
+        let radius = if state.is_dragging() || button_state == ButtonState::Pressed {
+            thumb_size * 0.55
+        } else {
+            thumb_size / 2.0
+        };
+
+        context.set_shadow(0.0, 1.0, 3.0, "rgba(0, 0, 0, 0.25)")?;
+        context.begin_path()?;
+        context.arc(thumb_x, center_y, radius, 0.0, 2.0 * PI, false)?;
+        context.set_fill_color(&color("thumb_color", "#ffffff"))?;
+        context.fill()?;
+        context.clear_shadow()?;
+
+        if button_state == ButtonState::Hovered {
+            context.set_stroke_color("#c7c7cc")?;
+            context.set_line_width(1.0)?;
+            context.stroke()?;
+        }
+
+        if button_state == ButtonState::Focused {
+            context.begin_path()?;
+            context.arc(thumb_x, center_y, radius + 3.0, 0.0, 2.0 * PI, false)?;
+            context.set_stroke_color("rgba(0, 122, 255, 0.6)")?;
+            context.set_line_width(2.0)?;
+            context.stroke()?;
+        }
+
+        context.restore_drawing_state()?;
+
+        draw_validation_message(context, node, frame)?;
+
+        Ok(())
+    }
+}
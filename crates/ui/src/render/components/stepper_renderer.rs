@@ -0,0 +1,127 @@
+use crate::render::renderer::{DrawingContext, ComponentRenderer};
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::button::ButtonState;
+use crate::components::form_control_state::form_control_state_for_node;
+use crate::layout::{Rect, Point};
+use crate::layout::form_control_layout::STEPPER_SIZE;
+use super::shared::{
+    draw_validation_message,
+    draw_rounded_rect,
+    draw_focus_ring,
+    content_frame,
+    parse_color
+};
+
+pub struct StepperRenderer;
+
+pub fn stepper_control_frame(node: &RenderNode, frame: Rect) -> Rect {
+    let content = content_frame(node, frame);
+
+    Rect::new(
+        content.right() - STEPPER_SIZE.width,
+        content.y + (content.height - STEPPER_SIZE.height) / 2.0,
+        STEPPER_SIZE.width,
+        STEPPER_SIZE.height
+    )
+}
+
+pub fn stepper_button_at(node: &RenderNode, frame: Rect, x: f32, y: f32) -> Option<i32> {
+    let control = stepper_control_frame(node, frame);
+
+    if !control.contains(Point::new(x, y)) {
+        return None;
+    }
+
+    Some(if x < control.x + control.width / 2.0 { -1 } else { 1 })
+}
+
+impl<T: DrawingContext> ComponentRenderer<T> for StepperRenderer {
+    fn render(&self, node: &RenderNode, context: &T, frame: Rect) -> Result<(), String> {
+        let Some(state) = form_control_state_for_node(node) else {
+            return Ok(());
+        };
+
+        let color = |key: &str, default: &str| node.get_prop_as_string(key)
+            .map(|c| parse_color(&c))
+            .unwrap_or_else(|| default.to_string());
+
+        let button_state = state.button_state();
+        let content = content_frame(node, frame);
+        let control = stepper_control_frame(node, frame);
+        let center_y = control.y + control.height / 2.0;
+        let font_size = node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0);
+
+        context.save_drawing_state()?;
+
+        if button_state == ButtonState::Disabled {
+            context.set_global_alpha(0.5)?;
+        }
+
+        let value = state.value().as_number()
+            .filter(|_| node.get_prop_bool("shows_value").unwrap_or(true))
+            .map(|value| state.format_number(value));
+        let text = match (node.get_prop_as_string(keys::LABEL), value) {
+            (Some(label), Some(value)) => Some(format!("{}: {}", label, value)),
+            (label, value) => label.or(value),
+        };
+
+        if let Some(text) = text {
+            context.set_font(&format!("{}px sans-serif", font_size))?;
+            context.set_text_align("left")?;
+            context.set_text_baseline("middle")?;
+            context.set_fill_color(&color(keys::TEXT_COLOR, "#000000"))?;
+            context.fill_text(&text, content.x, center_y)?;
+        }
+
+        context.begin_path()?;
+        draw_rounded_rect(context, control.x, control.y, control.width, control.height, 8.0)?;
+        context.set_fill_color(&color(keys::BACKGROUND, "#eeeeef"))?;
+        context.fill()?;
+
+        if matches!(button_state, ButtonState::Pressed | ButtonState::Hovered) {
+            context.set_fill_color(if button_state == ButtonState::Pressed {
+                "rgba(0, 0, 0, 0.1)"
+            } else {
+                "rgba(0, 0, 0, 0.04)"
+            })?;
+            context.fill()?;
+        }
+
+        let middle_x = control.x + control.width / 2.0;
+
+        context.set_fill_color("#c7c7cc")?;
+        context.fill_rect(middle_x - 0.5, control.y + 8.0, 1.0, control.height - 16.0)?;
+
+        let tint = color(keys::TINT_COLOR, "#000000");
+        let glyph = 6.0;
+
+        context.set_line_width(2.0)?;
+
+        for direction in [-1, 1] {
+            let glyph_x = middle_x + direction as f32 * control.width / 4.0;
+
+            context.set_stroke_color(if state.can_step(direction) { &tint } else { "#c7c7cc" })?;
+            context.begin_path()?;
+            context.move_to(glyph_x - glyph, center_y)?;
+            context.line_to(glyph_x + glyph, center_y)?;
+
+            if direction > 0 {
+                context.move_to(glyph_x, center_y - glyph)?;
+                context.line_to(glyph_x, center_y + glyph)?;
+            }
+
+            context.stroke()?;
+        }
+
+        if button_state == ButtonState::Focused {
+            draw_focus_ring(context, control, 8.0)?;
+        }
+
+        context.restore_drawing_state()?;
+
+        draw_validation_message(context, node, frame)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+use unicode_segmentation::UnicodeSegmentation;
+use crate::render::renderer::{DrawingContext, ComponentRenderer};
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::text_input_state::text_input_registry;
+use crate::layout::Rect;
+use crate::components::validation_state::field_validation_for_node;
+use super::shared::{
+    control_frame,
+    draw_validation_message,
+    ERROR_COLOR,
+    draw_background,
+    draw_border,
+    draw_rounded_rect,
+    parse_color
+};
+
+pub struct TextInputRenderer;
+
+pub struct TextInputSnapshot {
+    pub text: String,
+    pub selection: (usize, usize),
+    pub composition: Option<(usize, usize)>,
+    pub focused: bool,
+}
+
+impl TextInputSnapshot {
+    pub fn read(node: &RenderNode) -> Self {
+        let live = node.get_prop_as_string("input_id").and_then(|input_id| {
+            let registry = text_input_registry().read().ok()?;
+            let state = registry.get(&input_id)?;
+            let selection = state.selection();
+
+            Some(Self {
+                text: state.display_text(),
+                selection: (
+                    state.to_display_offset(selection.start()),
+                    state.to_display_offset(selection.end()),
+                ),
+                composition: state.composition().map(|composition| (
+                    state.to_display_offset(composition.start),
+                    state.to_display_offset(composition.end),
+                )),
+                focused: state.is_focused(),
+            })
+        });
+
+        live.unwrap_or_else(|| Self {
+            text: node.get_prop_as_string("display_text").unwrap_or_default(),
+            selection: (
+                node.get_prop_f32("selection_start").unwrap_or(0.0) as usize,
+                node.get_prop_f32("selection_end").unwrap_or(0.0) as usize,
+            ),
+            composition: node.get_prop_f32("composition_start")
+                .zip(node.get_prop_f32("composition_end"))
+                .map(|(start, end)| (start as usize, end as usize)),
+            focused: node.get_prop_bool("focused").unwrap_or(false),
+        })
+    }
+}
+
+pub struct TextInputMetrics {
+    pub font: String,
+    pub font_size: f32,
+    pub line_height: f32,
+    pub content: Rect,
+    pub scroll_x: f32,
+    pub scroll_y: f32,
+    text: String,
+    lines: Vec<(usize, usize)>,
+}
+
+impl TextInputMetrics {
+    pub fn new<T: DrawingContext>(
+        node: &RenderNode,
+        snapshot: &TextInputSnapshot,
+        context: &T,
+        frame: Rect
+    ) -> Result<Self, String> {
+        let font_size = node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0);
+        let font_family = node.get_prop_as_string("font_family").unwrap_or_else(|| "sans-serif".to_string());
+        let line_height = node.get_prop_f32(keys::LINE_HEIGHT).unwrap_or(1.2) * font_size;
+        let padding = node.get_prop_f32(keys::PADDING).unwrap_or(0.0);
+        let frame = control_frame(node, frame);
+
+        let font = format!("{}px {}", font_size, font_family);
+        context.set_font(&font)?;
+
+        let text = snapshot.text.clone();
+        let multiline = node.get_prop_bool("multiline").unwrap_or(false);
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+
+        if multiline {
+            for (index, _) in text.match_indices('\n') {
+                lines.push((start, index));
+                start = index + 1;
+            }
+        }
+        lines.push((start, text.len()));
+
+        let content = Rect::new(
+            frame.x + padding,
+            frame.y + padding,
+            (frame.width - padding * 2.0).max(0.0),
+            (frame.height - padding * 2.0).max(0.0)
+        );
+
+        let mut metrics = Self {
+            font,
+            font_size,
+            line_height,
+            content,
+            scroll_x: 0.0,
+            scroll_y: 0.0,
+            text,
+            lines,
+        };
+
+        let (caret_x, caret_y) = metrics.caret_position(context, snapshot.selection.1)?;
+
+        if multiline {
+            metrics.scroll_y = (caret_y + line_height - content.bottom()).max(0.0);
+        } else {
+            metrics.scroll_x = (caret_x - content.right() + 1.0).max(0.0);
+        }
+
+        Ok(metrics)
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn line_top(&self, line: usize) -> f32 {
+        self.content.y + line as f32 * self.line_height - self.scroll_y
+    }
+
+    pub fn baseline(&self, line: usize) -> f32 {
+        self.line_top(line) + (self.line_height + self.font_size) / 2.0 - self.font_size * 0.1
+    }
+
+    pub fn line_for_offset(&self, offset: usize) -> usize {
+        self.lines.iter()
+            .position(|&(_, end)| offset <= end)
+            .unwrap_or(self.lines.len() - 1)
+    }
+
+    pub fn caret_position<T: DrawingContext>(&self, context: &T, offset: usize) -> Result<(f32, f32), String> {
+        let offset = offset.min(self.text.len());
+        let line = self.line_for_offset(offset);
+        let (start, _) = self.lines[line];
+        let width = context.measure_text(&self.text[start..offset])?;
+
+        Ok((self.content.x + width - self.scroll_x, self.line_top(line)))
+    }
+
+    pub fn offset_at_point<T: DrawingContext>(&self, context: &T, x: f32, y: f32) -> Result<usize, String> {
+        let line = ((y - self.content.y + self.scroll_y) / self.line_height).floor().max(0.0) as usize;
+        let (start, end) = self.lines[line.min(self.lines.len() - 1)];
+        let target = x - self.content.x + self.scroll_x;
+
+        let mut previous_width = 0.0;
+
+        for (index, grapheme) in self.text[start..end].grapheme_indices(true) {
+            let width = context.measure_text(&self.text[start..start + index + grapheme.len()])?;
+
+            if target < (previous_width + width) / 2.0 {
+                return Ok(start + index);
+            }
+
+            previous_width = width;
+        }
+
+        Ok(end)
+    }
+
+    fn segments(&self, range_start: usize, range_end: usize) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+        self.lines.iter()
+            .enumerate()
+            .filter_map(move |(line, &(start, end))| {
+                let from = range_start.max(start);
+                let to = range_end.min(end);
+                let spans_newline = range_start <= end && end < range_end;
+
+                (from < to || spans_newline).then_some((line, from, to))
+            })
+    }
+}
+
+impl<T: DrawingContext> ComponentRenderer<T> for TextInputRenderer {
+    fn render(&self, node: &RenderNode, context: &T, frame: Rect) -> Result<(), String> {
+        let snapshot = TextInputSnapshot::read(node);
+        let focused = snapshot.focused;
+        let border_radius = node.get_prop_f32("border_radius").unwrap_or(0.0);
+        let invalid = field_validation_for_node(node).is_some_and(|validation| !validation.is_valid());
+        let field = control_frame(node, frame);
+
+        draw_background(context, node, field)?;
+        draw_border(context, node, field)?;
+
+        if invalid && !focused {
+            let border_width = node.get_prop_f32("border_width").unwrap_or(1.0).max(1.0);
+
+            context.begin_path()?;
+            draw_rounded_rect(context, field.x, field.y, field.width, field.height, border_radius)?;
+            context.set_stroke_color(ERROR_COLOR)?;
+            context.set_line_width(border_width)?;
+            context.stroke()?;
+        }
+
+        if focused {
+            let focus_color = if invalid {
+                ERROR_COLOR.to_string()
+            } else {
+                node.get_prop_as_string("focus_border_color")
+                    .map(|c| parse_color(&c))
+                    .unwrap_or_else(|| "#007aff".to_string())
+            };
+            let border_width = node.get_prop_f32("border_width").unwrap_or(1.0).max(1.0);
+
+            context.begin_path()?;
+            draw_rounded_rect(context, field.x, field.y, field.width, field.height, border_radius)?;
+            context.set_stroke_color(&focus_color)?;
+            context.set_line_width(border_width + 1.0)?;
+            context.stroke()?;
+        }
+
+        context.save_drawing_state()?;
+        context.set_text_align("left")?;
+        context.set_text_baseline("alphabetic")?;
+
+        let metrics = TextInputMetrics::new(node, &snapshot, context, frame)?;
+        context.clip_rect(metrics.content.x, metrics.content.y, metrics.content.width, metrics.content.height)?;
+
+        let text = metrics.text();
+        let (selection_start, selection_end) = snapshot.selection;
+
+        if focused && selection_start < selection_end {
+            let selection_color = node.get_prop_as_string("selection_color")
+                .map(|c| parse_color(&c))
+                .unwrap_or_else(|| "rgba(0, 122, 255, 0.3)".to_string());
+            context.set_fill_color(&selection_color)?;
+
+            for (_, from, to) in metrics.segments(selection_start, selection_end) {
+                let (x1, y) = metrics.caret_position(context, from)?;
+                let (x2, _) = metrics.caret_position(context, to)?;
+                let width = (x2 - x1).max(metrics.font_size * 0.3);
+
+                context.fill_rect(x1, y, width, metrics.line_height)?;
+            }
+        }
+
+        if text.is_empty() {
+            if let Some(placeholder) = node.get_prop_as_string("placeholder") {
+                let color = node.get_prop_as_string("placeholder_color")
+                    .map(|c| parse_color(&c))
+                    .unwrap_or_else(|| "#a0a0a0".to_string());
+
+                context.set_fill_color(&color)?;
+                context.fill_text(&placeholder, metrics.content.x, metrics.baseline(0))?;
+            }
+        } else {
+            let color = node.get_prop_as_string(keys::TEXT_COLOR)
+                .map(|c| parse_color(&c))
+                .unwrap_or_else(|| "#000000".to_string());
+
+            context.set_fill_color(&color)?;
+
+            for (line, from, to) in metrics.segments(0, text.len()) {
+                context.fill_text(&text[from..to], metrics.content.x - metrics.scroll_x, metrics.baseline(line))?;
+            }
+        }
+
+        let text_color = node.get_prop_as_string(keys::TEXT_COLOR)
+            .map(|c| parse_color(&c))
+            .unwrap_or_else(|| "#000000".to_string());
+
+        if let Some((start, end)) = snapshot.composition.filter(|(start, end)| start < end) {
+            context.set_stroke_color(&text_color)?;
+            context.set_line_width(1.0)?;
+
+            for (line, from, to) in metrics.segments(start, end) {
+                let (x1, _) = metrics.caret_position(context, from)?;
+                let (x2, _) = metrics.caret_position(context, to)?;
+                let y = metrics.baseline(line) + 2.0;
+
+                context.begin_path()?;
+                context.move_to(x1, y)?;
+                context.line_to(x2, y)?;
+                context.stroke()?;
+            }
+        }
+
+        if focused && selection_start == selection_end {
+            let caret_color = node.get_prop_as_string("caret_color")
+                .map(|c| parse_color(&c))
+                .unwrap_or(text_color);
+            let (x, y) = metrics.caret_position(context, selection_end)?;
+
+            context.set_fill_color(&caret_color)?;
+            context.fill_rect(x, y + metrics.line_height * 0.1, 1.5, metrics.line_height * 0.8)?;
+        }
+
+        context.restore_drawing_state()?;
+
+        draw_validation_message(context, node, frame)?;
+
+        Ok(())
+    }
+}
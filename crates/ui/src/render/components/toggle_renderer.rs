@@ -0,0 +1,104 @@
+use crate::render::renderer::{DrawingContext, ComponentRenderer};
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::button::ButtonState;
+use crate::components::form_control_state::form_control_state_for_node;
+use crate::layout::Rect;
+use crate::layout::form_control_layout::TOGGLE_SIZE;
+use super::shared::{
+    draw_validation_message,
+    draw_rounded_rect,
+    draw_focus_ring,
+    content_frame,
+    parse_color
+};
+
+pub struct ToggleRenderer;
+
+pub fn toggle_track_frame(node: &RenderNode, frame: Rect) -> Rect {
+    let content = content_frame(node, frame);
+
+    Rect::new(
+        content.right() - TOGGLE_SIZE.width,
+        content.y + (content.height - TOGGLE_SIZE.height) / 2.0,
+        TOGGLE_SIZE.width,
+        TOGGLE_SIZE.height
+    )
+}
+
+impl<T: DrawingContext> ComponentRenderer<T> for ToggleRenderer {
+    fn render(&self, node: &RenderNode, context: &T, frame: Rect) -> Result<(), String> {
+        let Some(state) = form_control_state_for_node(node) else {
+            return Ok(());
+        };
+
+        let color = |key: &str, default: &str| node.get_prop_as_string(key)
+            .map(|c| parse_color(&c))
+            .unwrap_or_else(|| default.to_string());
+
+        let is_on = state.value().as_bool().unwrap_or(false);
+        let button_state = state.button_state();
+        let content = content_frame(node, frame);
+        let track = toggle_track_frame(node, frame);
+        let radius = track.height / 2.0;
+
+        context.save_drawing_state()?;
+
+        if button_state == ButtonState::Disabled {
+            context.set_global_alpha(0.5)?;
+        }
+
+        if let Some(label) = node.get_prop_as_string(keys::LABEL) {
+            let font_size = node.get_prop_f32(keys::FONT_SIZE).unwrap_or(16.0);
+
+            context.set_font(&format!("{}px sans-serif", font_size))?;
+            context.set_text_align("left")?;
+            context.set_text_baseline("middle")?;
+            context.set_fill_color(&color(keys::TEXT_COLOR, "#000000"))?;
+            context.fill_text(&label, content.x, track.y + radius)?;
+        }
+
+        context.begin_path()?;
+        draw_rounded_rect(context, track.x, track.y, track.width, track.height, radius)?;
+        context.set_fill_color(&if is_on {
+            color("on_color", "#34c759")
+        } else {
+            color("off_color", "#e9e9eb")
+        })?;
+        context.fill()?;
+
+        if button_state == ButtonState::Hovered {
+            context.set_fill_color("rgba(0, 0, 0, 0.06)")?;
+            context.fill()?;
+        }
+
+        let thumb_height = track.height - 4.0;
+        let thumb_width = if button_state == ButtonState::Pressed {
+            thumb_height + 6.0
+        } else {
+            thumb_height
+        };
+        let thumb_x = if is_on {
+            track.right() - 2.0 - thumb_width
+        } else {
+            track.x + 2.0
+        };
+
+        context.set_shadow(0.0, 1.0, 3.0, "rgba(0, 0, 0, 0.25)")?;
+        context.begin_path()?;
+        draw_rounded_rect(context, thumb_x, track.y + 2.0, thumb_width, thumb_height, thumb_height / 2.0)?;
+        context.set_fill_color(&color("thumb_color", "#ffffff"))?;
+        context.fill()?;
+        context.clear_shadow()?;
+
+        if button_state == ButtonState::Focused {
+            draw_focus_ring(context, track, radius)?;
+        }
+
+        context.restore_drawing_state()?;
+
+        draw_validation_message(context, node, frame)?;
+
+        Ok(())
+    }
+}
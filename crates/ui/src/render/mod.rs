@@ -28,9 +28,17 @@ pub use components::{
     DividerRenderer,
     HStackRenderer,
     ImageRenderer, 
+    ListRenderer,
     ScrollRenderer,
     SpacerRenderer,
     TextRenderer,
+    TextInputRenderer,
+    ToggleRenderer,
+    CheckboxRenderer,
+    RadioGroupRenderer,
+    SliderRenderer,
+    StepperRenderer,
+    PickerRenderer,
     VStackRenderer,
     ZStackRenderer,
     
@@ -49,6 +57,15 @@ pub use transformers::{
     transform_scroll,
     transform_spacer,
     transform_divider,
+    transform_list,
+    transform_text_field,
+    transform_text_area,
+    transform_toggle,
+    transform_checkbox,
+    transform_radio_group,
+    transform_slider,
+    transform_stepper,
+    transform_picker,
     utils as transformer_utils,
 };
 
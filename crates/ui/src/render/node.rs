@@ -109,6 +109,30 @@ impl RenderNode {
         self
     }
 
+    pub fn on_focus(&mut self, handler_id: &str) -> &mut Self {
+        self.on_event(EventType::Focus, handler_id);
+        self.node_events.on_focus = Some(handler_id.to_string());
+        self
+    }
+
+    pub fn on_blur(&mut self, handler_id: &str) -> &mut Self {
+        self.on_event(EventType::Blur, handler_id);
+        self.node_events.on_blur = Some(handler_id.to_string());
+        self
+    }
+
+    pub fn on_value_change(&mut self, handler_id: &str) -> &mut Self {
+        self.on_event(EventType::ValueChange, handler_id);
+        self.node_events.on_value_change = Some(handler_id.to_string());
+        self
+    }
+
+    pub fn on_submit(&mut self, handler_id: &str) -> &mut Self {
+        self.on_event(EventType::Submit, handler_id);
+        self.node_events.on_submit = Some(handler_id.to_string());
+        self
+    }
+
     pub fn on_swipe(&mut self, direction: &str, handler_id: &str) -> &mut Self {
         use crate::events::SwipeDirection;
         
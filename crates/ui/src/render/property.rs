@@ -55,10 +55,14 @@ pub mod keys {
     pub const PRESSED: &str = "pressed";
     pub const HOVERED: &str = "hovered";
     pub const FOCUSED: &str = "focused";
+    pub const INVALID: &str = "invalid";
+    pub const ERROR_MESSAGE: &str = "error_message";
     
     pub const ACCESSIBILITY_LABEL: &str = "accessibility_label";
     pub const ACCESSIBILITY_HINT: &str = "accessibility_hint";
     pub const IS_ACCESSIBILITY_ELEMENT: &str = "is_accessibility_element";
+    pub const ACCESSIBILITY_ROLE: &str = "accessibility_role";
+    pub const ACCESSIBILITY_VALUE: &str = "accessibility_value";
     
     pub const LABEL: &str = "label";
     pub const BUTTON_STYLE: &str = "button_style";
@@ -1,5 +1,21 @@
 use crate::{events::SwipeDirection, render::node::RenderNode};
 use crate::layout::Rect;
+use crate::components::scroll_controller::{with_scroll_controller, step_scroll_controllers, is_any_scroll_animating};
+use crate::components::text_input_state::{
+    with_text_input_state, focus_text_input, blur_text_inputs, focused_text_input,
+    KeyModifiers, TextInputAction
+};
+use crate::components::form_control_state::{
+    with_form_control_state, focus_form_control, blur_form_controls, focused_form_control,
+    collapse_pickers, FormControlKind
+};
+use crate::components::button::ButtonState;
+use crate::render::components::{TextInputMetrics, TextInputSnapshot};
+use crate::render::components::radio_group_renderer::radio_option_at;
+use crate::render::components::slider_renderer::slider_fraction_at;
+use crate::render::components::stepper_renderer::stepper_button_at;
+use crate::render::components::picker_renderer::{picker_option_at, draw_picker_dropdown};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use crate::events::{
     Event, EventType, EventDispatcher, HandlerResult, 
@@ -73,6 +89,28 @@ pub trait ComponentRenderer<T: DrawingContext> {
     fn render(&self, node: &RenderNode, context: &T, frame: Rect) -> Result<(), String>;
 }
 
+struct ScrollRegion {
+    scroll_id: String,
+    node_id: String,
+    frame: Rect,
+}
+
+struct TextInputRegion {
+    input_id: String,
+    node: RenderNode,
+    frame: Rect,
+    hit_frame: Rect,
+}
+
+#[derive(Clone)]
+struct FormControlRegion {
+    control_id: String,
+    kind: FormControlKind,
+    node: RenderNode,
+    frame: Rect,
+    hit_frame: Rect,
+}
+
 pub struct Renderer<T: DrawingContext> {
     context: T,
     renderers: HashMap<&'static str, Box<dyn ComponentRenderer<T>>>,
@@ -82,7 +120,12 @@ pub struct Renderer<T: DrawingContext> {
     event_dispatcher: EventDispatcher,
     event_middleware: EventMiddlewarePipeline,
     
-    node_layout_map: HashMap<String, Rect>,
+    node_layout_map: RefCell<HashMap<String, Rect>>,
+    scroll_regions: RefCell<Vec<ScrollRegion>>,
+    text_input_regions: RefCell<Vec<TextInputRegion>>,
+    form_control_regions: RefCell<Vec<FormControlRegion>>,
+    active_scroll_id: Option<String>,
+    active_slider_id: Option<String>,
     focused_node_id: Option<String>,
     hovered_node_id: Option<String>,
 }
@@ -105,7 +148,12 @@ impl<T: DrawingContext> Renderer<T> {
             event_dispatcher: EventDispatcher::new(),
             event_middleware,
             
-            node_layout_map: HashMap::new(),
+            node_layout_map: RefCell::new(HashMap::new()),
+            scroll_regions: RefCell::new(Vec::new()),
+            text_input_regions: RefCell::new(Vec::new()),
+            form_control_regions: RefCell::new(Vec::new()),
+            active_scroll_id: None,
+            active_slider_id: None,
             focused_node_id: None,
             hovered_node_id: None,
         };
@@ -127,6 +175,15 @@ impl<T: DrawingContext> Renderer<T> {
         self.register_renderer("Scroll", Box::new(ScrollRenderer));
         self.register_renderer("Spacer", Box::new(SpacerRenderer));
         self.register_renderer("Divider", Box::new(DividerRenderer));
+        self.register_renderer("List", Box::new(ListRenderer));
+        self.register_renderer("TextField", Box::new(TextInputRenderer));
+        self.register_renderer("TextArea", Box::new(TextInputRenderer));
+        self.register_renderer("Toggle", Box::new(ToggleRenderer));
+        self.register_renderer("Checkbox", Box::new(CheckboxRenderer));
+        self.register_renderer("RadioGroup", Box::new(RadioGroupRenderer));
+        self.register_renderer("Slider", Box::new(SliderRenderer));
+        self.register_renderer("Stepper", Box::new(StepperRenderer));
+        self.register_renderer("Picker", Box::new(PickerRenderer));
     }
     
     pub fn register_renderer(
@@ -138,11 +195,32 @@ impl<T: DrawingContext> Renderer<T> {
     }
     
     pub fn render(&self, node: &RenderNode) -> Result<(), String> {
-        self.render_node(node, false)
+        self.reset_hit_regions();
+        self.render_node(node, false, None)?;
+        self.render_overlays()
     }
     
     pub fn render_with_clipping(&self, node: &RenderNode) -> Result<(), String> {
-        self.render_node(node, true)
+        self.reset_hit_regions();
+        self.render_node(node, true, None)?;
+        self.render_overlays()
+    }
+    
+    fn render_overlays(&self) -> Result<(), String> {
+        for region in self.form_control_regions.borrow().iter() {
+            if region.kind == FormControlKind::Picker {
+                draw_picker_dropdown(&self.context, &region.node, region.frame)?;
+            }
+        }
+        
+        Ok(())
+    }
+    
+    fn reset_hit_regions(&self) {
+        self.node_layout_map.borrow_mut().clear();
+        self.scroll_regions.borrow_mut().clear();
+        self.text_input_regions.borrow_mut().clear();
+        self.form_control_regions.borrow_mut().clear();
     }
     
     pub fn mark_dirty(&mut self, region: Rect) {
@@ -155,7 +233,7 @@ impl<T: DrawingContext> Renderer<T> {
     
     pub fn render_dirty_regions(&self, node: &RenderNode, container_rect: Rect) -> Result<(), String> {
         if self.needs_full_render {
-            return self.render_node(node, true);
+            return self.render_with_clipping(node);
         }
         
         for region in &self.dirty_regions {
@@ -217,7 +295,7 @@ impl<T: DrawingContext> Renderer<T> {
         Ok(())
     }
     
-    fn render_node(&self, node: &RenderNode, enable_clipping: bool) -> Result<(), String> {
+    fn render_node(&self, node: &RenderNode, enable_clipping: bool, hit_clip: Option<Rect>) -> Result<(), String> {
         let x = node.get_prop_f32("x").unwrap_or(0.0);
         let y = node.get_prop_f32("y").unwrap_or(0.0);
         let width = node.get_prop_f32("width").unwrap_or(0.0);
@@ -225,11 +303,50 @@ impl<T: DrawingContext> Renderer<T> {
         
         let frame = Rect::new(x, y, width, height);
         
-        unsafe {
-            let this = self as *const Self as *mut Self;
-            (*this).node_layout_map.insert(node.id.clone(), frame);
+        let hit_frame = hit_clip.map(|clip| intersect_rects(frame, clip)).unwrap_or(frame);
+        let is_hittable = hit_clip.is_none() || (hit_frame.width > 0.0 && hit_frame.height > 0.0);
+        
+        if is_hittable {
+            self.node_layout_map.borrow_mut().insert(node.id.clone(), hit_frame);
+            
+            if let Some(scroll_id) = node.get_prop_as_string("scroll_id") {
+                self.scroll_regions.borrow_mut().push(ScrollRegion {
+                    scroll_id,
+                    node_id: node.id.clone(),
+                    frame: hit_frame,
+                });
+            }
+            
+            if matches!(node.type_name.as_str(), "TextField" | "TextArea") {
+                if let Some(input_id) = node.get_prop_as_string("input_id") {
+                    self.text_input_regions.borrow_mut().push(TextInputRegion {
+                        input_id,
+                        node: node.clone(),
+                        frame,
+                        hit_frame,
+                    });
+                }
+            }
+            
+            if let Some(kind) = FormControlKind::from_type_name(&node.type_name) {
+                if let Some(control_id) = node.get_prop_as_string("control_id") {
+                    self.form_control_regions.borrow_mut().push(FormControlRegion {
+                        control_id,
+                        kind,
+                        node: node.clone(),
+                        frame,
+                        hit_frame,
+                    });
+                }
+            }
         }
         
+        let child_hit_clip = if node.get_prop_bool("clip_to_bounds").unwrap_or(false) {
+            Some(hit_frame)
+        } else {
+            hit_clip
+        };
+        
         let opacity = node.get_prop_f32("opacity");
         let has_opacity = opacity.is_some() && opacity.unwrap() < 1.0;
         
@@ -284,7 +401,7 @@ impl<T: DrawingContext> Renderer<T> {
         }
         
         for child in &node.children {
-            self.render_node(child, enable_clipping)?;
+            self.render_node(child, enable_clipping, child_hit_clip)?;
         }
         
         if should_clip {
@@ -326,6 +443,22 @@ impl<T: DrawingContext> Renderer<T> {
                     }
                 }
                 
+                let (control_events, captured) = self.handle_form_control(&mut event);
+                
+                for mut control_event in control_events {
+                    let _ = self.event_dispatcher.dispatch(&mut control_event);
+                }
+                
+                if !captured {
+                    for mut scroll_event in self.handle_scroll_gesture(&event) {
+                        let _ = self.event_dispatcher.dispatch(&mut scroll_event);
+                    }
+                    
+                    for mut input_event in self.handle_text_input(&mut event) {
+                        let _ = self.event_dispatcher.dispatch(&mut input_event);
+                    }
+                }
+                
                 if matches!(event.event_type, EventType::PointerMove) {
                     self.handle_hover_events(&mut event);
                 }
@@ -348,8 +481,434 @@ impl<T: DrawingContext> Renderer<T> {
         }
     }
     
+    pub fn advance_scroll(&mut self, dt: Duration) -> bool {
+        let events = step_scroll_controllers(dt);
+        
+        if !events.is_empty() {
+            self.needs_full_render = true;
+        }
+        
+        for mut event in events {
+            event.target_id = self.scroll_node_id(&event);
+            let _ = self.event_dispatcher.dispatch(&mut event);
+        }
+        
+        is_any_scroll_animating()
+    }
+    
+    fn handle_scroll_gesture(&mut self, event: &Event) -> Vec<Event> {
+        let scroll_id = match event.event_type {
+            EventType::DragStart => {
+                let Some((x, y)) = event.position else {
+                    return Vec::new();
+                };
+                
+                let candidates: Vec<String> = self.scroll_regions.borrow().iter()
+                    .rev()
+                    .filter(|region| region.frame.contains_point(x, y))
+                    .map(|region| region.scroll_id.clone())
+                    .collect();
+                
+                self.active_scroll_id = candidates.into_iter().find(|scroll_id| {
+                    with_scroll_controller(scroll_id, |controller| {
+                        controller.begin_drag(x, y, event.timestamp)
+                    })
+                });
+                
+                self.active_scroll_id.clone()
+            },
+            EventType::Drag => {
+                if let (Some(scroll_id), Some((x, y))) = (&self.active_scroll_id, event.position) {
+                    with_scroll_controller(scroll_id, |controller| {
+                        controller.drag_to(x, y, event.timestamp)
+                    });
+                }
+                
+                self.active_scroll_id.clone()
+            },
+            EventType::DragEnd => {
+                if let Some(scroll_id) = &self.active_scroll_id {
+                    with_scroll_controller(scroll_id, |controller| {
+                        if let Some((x, y)) = event.position {
+                            controller.drag_to(x, y, event.timestamp);
+                        }
+                        
+                        controller.end_drag(event.timestamp);
+                    });
+                }
+                
+                self.active_scroll_id.take()
+            },
+            _ => None,
+        };
+        
+        let Some(scroll_id) = scroll_id else {
+            return Vec::new();
+        };
+        
+        let mut events = with_scroll_controller(&scroll_id, |controller| controller.take_events());
+        
+        if !events.is_empty() {
+            self.needs_full_render = true;
+        }
+        
+        for scroll_event in &mut events {
+            scroll_event.target_id = self.scroll_node_id(scroll_event);
+        }
+        
+        events
+    }
+    
+    fn handle_text_input(&mut self, event: &mut Event) -> Vec<Event> {
+        match event.event_type {
+            EventType::Tap | EventType::PointerDown | EventType::DoubleTap => {
+                self.place_text_input_caret(event);
+                return Vec::new();
+            },
+            EventType::KeyDown | EventType::Paste | EventType::Copy | EventType::Cut |
+            EventType::CompositionStart | EventType::CompositionUpdate | EventType::CompositionEnd => {},
+            _ => return Vec::new(),
+        }
+        
+        let Some(input_id) = focused_text_input() else {
+            return Vec::new();
+        };
+        
+        let node_id = self.text_input_node_id(&input_id);
+        
+        if event.target_id.is_none() {
+            event.target_id = node_id.clone();
+        }
+        
+        let data = |key: &str| event.properties.get(key).cloned().unwrap_or_default();
+        
+        let action = with_text_input_state(&input_id, |state| {
+            let changed = match event.event_type {
+                EventType::KeyDown => {
+                    let modifiers = KeyModifiers::from_properties(&event.properties);
+                    return state.handle_key(&data("key"), modifiers);
+                },
+                EventType::Copy => {
+                    return state.copy().map(TextInputAction::Copy).unwrap_or(TextInputAction::None);
+                },
+                EventType::Cut => {
+                    return state.cut().map(TextInputAction::Cut).unwrap_or(TextInputAction::None);
+                },
+                EventType::Paste => state.paste(&data("text")),
+                EventType::CompositionStart => {
+                    state.begin_composition();
+                    false
+                },
+                EventType::CompositionUpdate => {
+                    state.update_composition(&data("data"));
+                    false
+                },
+                EventType::CompositionEnd => state.commit_composition(event.properties.get("data").map(String::as_str)),
+                _ => false,
+            };
+            
+            if changed {
+                TextInputAction::ValueChanged
+            } else {
+                TextInputAction::SelectionChanged
+            }
+        });
+        
+        self.needs_full_render = true;
+        
+        let value = with_text_input_state(&input_id, |state| state.value().to_string());
+        let source = event.metadata.source.clone();
+        let mut events = Vec::new();
+        
+        let value_change = || {
+            let mut value_event = Event::new(EventType::ValueChange, source.clone())
+                .with_property("input_id", &input_id)
+                .with_property("value", &value);
+            value_event.target_id = node_id.clone();
+            value_event
+        };
+        
+        match action {
+            TextInputAction::ValueChanged => events.push(value_change()),
+            TextInputAction::Submit => {
+                let mut submit = Event::new(EventType::Submit, source.clone())
+                    .with_property("input_id", &input_id)
+                    .with_property("value", &value);
+                submit.target_id = node_id.clone();
+                events.push(submit);
+            },
+            TextInputAction::Copy(text) => {
+                event.properties.insert("text".to_string(), text.clone());
+                
+                if event.event_type == EventType::KeyDown {
+                    let mut copy = Event::new(EventType::Copy, source.clone())
+                        .with_property("input_id", &input_id)
+                        .with_property("text", &text);
+                    copy.target_id = node_id.clone();
+                    events.push(copy);
+                }
+            },
+            TextInputAction::Cut(text) => {
+                event.properties.insert("text".to_string(), text.clone());
+                
+                if event.event_type == EventType::KeyDown {
+                    let mut cut = Event::new(EventType::Cut, source.clone())
+                        .with_property("input_id", &input_id)
+                        .with_property("text", &text);
+                    cut.target_id = node_id.clone();
+                    events.push(cut);
+                }
+                
+                events.push(value_change());
+            },
+            TextInputAction::SelectionChanged | TextInputAction::None => {},
+        }
+        
+        events
+    }
+    
+    fn place_text_input_caret(&mut self, event: &Event) {
+        let Some((x, y)) = event.position else {
+            return;
+        };
+        
+        let regions = self.text_input_regions.borrow();
+        let Some(region) = regions.iter().rev().find(|region| region.hit_frame.contains_point(x, y)) else {
+            if event.event_type != EventType::DoubleTap && blur_text_inputs().is_some() {
+                self.needs_full_render = true;
+            }
+            return;
+        };
+        
+        if region.node.get_prop_bool("editable") == Some(false) {
+            return;
+        }
+        
+        focus_text_input(&region.input_id);
+        
+        if blur_form_controls().is_some() {
+            self.needs_full_render = true;
+        }
+        
+        let snapshot = TextInputSnapshot::read(&region.node);
+        let display_offset = TextInputMetrics::new(&region.node, &snapshot, &self.context, region.frame)
+            .and_then(|metrics| metrics.offset_at_point(&self.context, x, y))
+            .unwrap_or(snapshot.text.len());
+        let extend = event.properties.get("shift").is_some_and(|shift| shift == "true");
+        
+        with_text_input_state(&region.input_id, |state| {
+            let offset = state.from_display_offset(display_offset);
+            
+            if event.event_type == EventType::DoubleTap {
+                state.select_word_at(offset);
+            } else if extend {
+                state.set_selection(state.selection().anchor, offset);
+            } else {
+                state.set_selection(offset, offset);
+            }
+        });
+        
+        drop(regions);
+        self.needs_full_render = true;
+    }
+    
+    fn handle_form_control(&mut self, event: &mut Event) -> (Vec<Event>, bool) {
+        let position = event.position;
+        let hit = position.and_then(|(x, y)| self.form_control_at(x, y));
+        let mut changes = Vec::new();
+        let mut captured = false;
+        
+        match event.event_type {
+            EventType::Tap => {
+                let Some((x, y)) = position else {
+                    return (Vec::new(), false);
+                };
+                
+                let dropdown_hit = self.form_control_regions.borrow().iter()
+                    .filter(|region| region.kind == FormControlKind::Picker)
+                    .find_map(|region| picker_option_at(&region.node, region.frame, x, y)
+                        .map(|index| (region.clone(), index)));
+                
+                if let Some((region, index)) = dropdown_hit {
+                    let changed = with_form_control_state(&region.control_id, region.kind, |state| {
+                        let changed = state.select(index);
+                        state.set_expanded(false);
+                        changed
+                    });
+                    
+                    if changed {
+                        changes.push((region, true));
+                    }
+                    
+                    captured = true;
+                    self.needs_full_render = true;
+                } else if let Some(region) = hit {
+                    let enabled = with_form_control_state(&region.control_id, region.kind, |state| state.is_enabled());
+                    
+                    if enabled {
+                        focus_form_control(&region.control_id);
+                        blur_text_inputs();
+                    }
+                    
+                    let changed = with_form_control_state(&region.control_id, region.kind, |state| match region.kind {
+                        FormControlKind::RadioGroup => radio_option_at(&region.node, region.frame, y)
+                            .is_some_and(|index| state.select(index)),
+                        FormControlKind::Slider => state.set_fraction(slider_fraction_at(&region.node, region.frame, x)),
+                        FormControlKind::Stepper => stepper_button_at(&region.node, region.frame, x, y)
+                            .is_some_and(|direction| state.step_by(direction)),
+                        FormControlKind::Picker => {
+                            let expanded = state.is_expanded();
+                            state.set_expanded(!expanded);
+                            false
+                        },
+                        FormControlKind::Toggle | FormControlKind::Checkbox => state.activate(),
+                    });
+                    
+                    if changed {
+                        changes.push((region, true));
+                    }
+                    
+                    self.needs_full_render = true;
+                } else if blur_form_controls().is_some() | collapse_pickers() {
+                    self.needs_full_render = true;
+                }
+            },
+            EventType::PointerDown | EventType::PointerUp | EventType::PointerMove => {
+                let active = match event.event_type {
+                    EventType::PointerDown => ButtonState::Pressed,
+                    EventType::PointerUp => ButtonState::Normal,
+                    _ => ButtonState::Hovered,
+                };
+                
+                let regions = self.form_control_regions.borrow().clone();
+                
+                for region in regions {
+                    let is_hit = hit.as_ref().is_some_and(|hit| hit.control_id == region.control_id);
+                    let interaction = if is_hit { active.clone() } else { ButtonState::Normal };
+                    
+                    if with_form_control_state(&region.control_id, region.kind, |state| state.set_interaction(interaction)) {
+                        self.needs_full_render = true;
+                    }
+                }
+            },
+            EventType::DragStart => {
+                if let (Some(region), Some((x, _))) = (hit.filter(|region| region.kind == FormControlKind::Slider), position) {
+                    let enabled = with_form_control_state(&region.control_id, region.kind, |state| {
+                        state.set_dragging(true);
+                        state.set_fraction(slider_fraction_at(&region.node, region.frame, x));
+                        state.is_enabled()
+                    });
+                    
+                    if enabled {
+                        focus_form_control(&region.control_id);
+                        blur_text_inputs();
+                        self.active_slider_id = Some(region.control_id.clone());
+                        changes.push((region, false));
+                        captured = true;
+                    }
+                }
+            },
+            EventType::Drag | EventType::DragEnd => {
+                let Some(region) = self.active_slider_id.clone().and_then(|control_id| self.form_control_region(&control_id)) else {
+                    return (Vec::new(), false);
+                };
+                let is_final = event.event_type == EventType::DragEnd;
+                
+                let changed = with_form_control_state(&region.control_id, region.kind, |state| {
+                    let changed = position.is_some_and(|(x, _)| {
+                        state.set_fraction(slider_fraction_at(&region.node, region.frame, x))
+                    });
+                    
+                    if is_final {
+                        state.set_dragging(false);
+                    }
+                    
+                    changed
+                });
+                
+                if is_final {
+                    self.active_slider_id = None;
+                }
+                
+                if changed || is_final {
+                    changes.push((region, is_final));
+                }
+                
+                captured = true;
+            },
+            EventType::KeyDown => {
+                let Some(region) = focused_form_control().and_then(|control_id| self.form_control_region(&control_id)) else {
+                    return (Vec::new(), false);
+                };
+                let key = event.properties.get("key").cloned().unwrap_or_default();
+                let (changed, expanded) = with_form_control_state(&region.control_id, region.kind, |state| {
+                    let expanded = state.is_expanded();
+                    (state.handle_key(&key), expanded != state.is_expanded())
+                });
+                
+                if event.target_id.is_none() {
+                    event.target_id = Some(region.node.id.clone());
+                }
+                
+                if changed {
+                    changes.push((region, true));
+                }
+                
+                if changed || expanded {
+                    self.needs_full_render = true;
+                }
+            },
+            _ => {},
+        }
+        
+        if !changes.is_empty() {
+            self.needs_full_render = true;
+        }
+        
+        let source = event.metadata.source.clone();
+        let events = changes.into_iter()
+            .map(|(region, is_final)| {
+                let mut value_event = with_form_control_state(&region.control_id, region.kind, |state| {
+                    state.value_change_event(&region.control_id, source.clone(), is_final)
+                });
+                value_event.target_id = Some(region.node.id.clone());
+                value_event
+            })
+            .collect();
+        
+        (events, captured)
+    }
+    
+    fn form_control_at(&self, x: f32, y: f32) -> Option<FormControlRegion> {
+        self.form_control_regions.borrow().iter()
+            .rev()
+            .find(|region| region.hit_frame.contains_point(x, y))
+            .cloned()
+    }
+    
+    fn form_control_region(&self, control_id: &str) -> Option<FormControlRegion> {
+        self.form_control_regions.borrow().iter()
+            .find(|region| region.control_id == control_id)
+            .cloned()
+    }
+    
+    fn text_input_node_id(&self, input_id: &str) -> Option<String> {
+        self.text_input_regions.borrow().iter()
+            .find(|region| region.input_id == input_id)
+            .map(|region| region.node.id.clone())
+    }
+    
+    fn scroll_node_id(&self, event: &Event) -> Option<String> {
+        let scroll_id = event.properties.get("scroll_id")?;
+        
+        self.scroll_regions.borrow().iter()
+            .find(|region| &region.scroll_id == scroll_id)
+            .map(|region| region.node_id.clone())
+    }
+    
     pub fn find_node_at_position(&self, x: f32, y: f32) -> Option<String> {
-        let mut nodes: Vec<(&String, &Rect)> = self.node_layout_map.iter().collect();
+        let node_layout_map = self.node_layout_map.borrow();
+        let mut nodes: Vec<(&String, &Rect)> = node_layout_map.iter().collect();
         
         nodes.reverse();
         
@@ -475,6 +1034,8 @@ impl<T: DrawingContext> Renderer<T> {
             register_event_handler!(EventType::HoverExit, node_events.on_hover_exit, "Hover exit");
             register_event_handler!(EventType::Focus, node_events.on_focus, "Focus");
             register_event_handler!(EventType::Blur, node_events.on_blur, "Blur");
+            register_event_handler!(EventType::ValueChange, node_events.on_value_change, "Value change");
+            register_event_handler!(EventType::Submit, node_events.on_submit, "Submit");
             
             for (direction, handler_id) in &node_events.on_swipe {
                 let direction_str = direction.clone();
@@ -502,6 +1063,18 @@ impl<T: DrawingContext> Renderer<T> {
     }
 }
 
+fn intersect_rects(a: Rect, b: Rect) -> Rect {
+    let x = a.x.max(b.x);
+    let y = a.y.max(b.y);
+    
+    Rect::new(
+        x,
+        y,
+        (a.right().min(b.right()) - x).max(0.0),
+        (a.bottom().min(b.bottom()) - y).max(0.0)
+    )
+}
+
 fn regions_intersect(a: Rect, b: Rect) -> bool {
     !(a.x > b.x + b.width || 
       a.x + a.width < b.x || 
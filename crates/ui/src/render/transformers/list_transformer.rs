@@ -0,0 +1,122 @@
+use crate::components::list::{ListProps, ListScrollAlignment};
+use crate::components::list_state::{with_list_state, ListRow};
+use crate::render::node::RenderNode;
+use crate::components::base_props::{BaseComponentProps, utils::apply_base_props};
+use super::utils::{generate_unique_id, set_optional_prop};
+
+pub fn transform_list(props: &ListProps) -> RenderNode {
+    let mut node = RenderNode::new(&generate_unique_id("list"), "List");
+
+    node.set_prop("list_id", props.list_id.clone());
+    node.set_prop("item_count", props.item_count as i32);
+
+    set_optional_prop(&mut node, "estimated_item_height", &props.estimated_item_height);
+    set_optional_prop(&mut node, "fixed_item_height", &props.fixed_item_height);
+    set_optional_prop(&mut node, "shows_indicators", &props.shows_indicators);
+    set_optional_prop(&mut node, "scrollbar_width", &props.scrollbar_width);
+    set_optional_prop(&mut node, "scrollbar_margin", &props.scrollbar_margin);
+
+    if let Some(color) = &props.scrollbar_color {
+        node.set_prop("scrollbar_color", color.to_css_string());
+    }
+
+    let estimated_item_height = props.estimated_item_height
+        .or(props.fixed_item_height)
+        .unwrap_or(44.0);
+    let overscan = props.overscan.unwrap_or(3);
+    let sticky_headers = props.sticky_headers.unwrap_or(true) && !props.sections.is_empty();
+
+    let section_starts: Vec<usize> = props.sections.iter()
+        .map(|section| section.start_index)
+        .collect();
+    let header_heights: Vec<f32> = props.sections.iter()
+        .map(|section| section.header_height.unwrap_or(estimated_item_height))
+        .collect();
+
+    let (rows, sticky_row, row_ids, content_size, scroll_position) = with_list_state(&props.list_id, |state| {
+        state.configure(
+            props.item_count,
+            section_starts,
+            header_heights,
+            estimated_item_height,
+            props.fixed_item_height
+        );
+
+        if let Some(index) = props.initial_scroll_index {
+            state.apply_initial_scroll(
+                index,
+                props.initial_scroll_alignment.unwrap_or(ListScrollAlignment::Start)
+            );
+        }
+
+        let sticky_row = if sticky_headers {
+            state.sticky_header().map(|(section, _)| ListRow::Header(section))
+        } else {
+            None
+        };
+
+        let mut rows: Vec<ListRow> = state.visible_rows(overscan)
+            .filter_map(|row_index| state.row_at(row_index))
+            .filter(|row| Some(*row) != sticky_row)
+            .collect();
+
+        if let Some(row) = sticky_row {
+            rows.push(row);
+        }
+
+        let row_ids = state.assign_node_ids(&rows);
+
+        (rows, sticky_row, row_ids, state.total_height(), state.scroll_offset())
+    });
+
+    node.set_prop("content_size", content_size);
+    node.set_prop("scroll_position", scroll_position);
+
+    for (row, row_id) in rows.iter().zip(row_ids) {
+        let component = match row {
+            ListRow::Item(index) => props.build_item(*index),
+            ListRow::Header(section) => props.sections.get(*section)
+                .map(|section| section.header.clone()),
+        };
+
+        let Some(component) = component else {
+            continue;
+        };
+
+        let mut child = crate::render::component::render(&component);
+        child.id = row_id;
+        row.write_to(&mut child);
+
+        if Some(*row) == sticky_row {
+            child.set_prop("_list_sticky", true);
+        }
+
+        node.add_child(child);
+    }
+
+    let mut base_props = BaseComponentProps::new();
+
+    base_props.width = props.width;
+    base_props.height = props.height;
+    base_props.min_width = props.min_width;
+    base_props.max_width = props.max_width;
+    base_props.min_height = props.min_height;
+    base_props.max_height = props.max_height;
+
+    base_props.background = props.background_color.clone();
+    base_props.border_width = props.border_width;
+    base_props.border_color = props.border_color.clone();
+    base_props.border_radius = props.border_radius;
+    base_props.border_style = props.border_style.clone();
+
+    base_props.padding = props.padding;
+    base_props.edge_insets = props.edge_insets;
+    base_props.opacity = props.opacity;
+    base_props.clip_to_bounds = Some(true);
+
+    base_props.accessibility_label = props.accessibility_label.clone();
+
+    apply_base_props(&mut node, &base_props);
+
+    node
+}
@@ -8,6 +8,14 @@ pub mod image_transformer;
 pub mod scroll_transformer;
 pub mod spacer_transformer;
 pub mod divider_transformer;
+pub mod list_transformer;
+pub mod text_input_transformer;
+pub mod toggle_transformer;
+pub mod checkbox_transformer;
+pub mod radio_group_transformer;
+pub mod slider_transformer;
+pub mod stepper_transformer;
+pub mod picker_transformer;
 
 // Transformer function exports
 pub use text_transformer::transform_text;
@@ -19,6 +27,14 @@ pub use image_transformer::transform_image;
 pub use scroll_transformer::transform_scroll;
 pub use spacer_transformer::transform_spacer;
 pub use divider_transformer::transform_divider;
+pub use list_transformer::transform_list;
+pub use text_input_transformer::{transform_text_field, transform_text_area};
+pub use toggle_transformer::transform_toggle;
+pub use checkbox_transformer::transform_checkbox;
+pub use radio_group_transformer::transform_radio_group;
+pub use slider_transformer::transform_slider;
+pub use stepper_transformer::transform_stepper;
+pub use picker_transformer::transform_picker;
 
 // Utility exports
 pub use utils::{
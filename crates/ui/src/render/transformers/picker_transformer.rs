@@ -0,0 +1,51 @@
+use crate::components::picker::PickerProps;
+use crate::components::form_control_state::{FormControlConfig, FormControlKind, FormValue};
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::base_props::{BaseComponentProps, utils::apply_base_props};
+use super::utils::{generate_unique_id, set_optional_prop, set_color_prop, apply_form_control_state};
+
+pub fn transform_picker(props: &PickerProps) -> RenderNode {
+    let mut node = RenderNode::new(&generate_unique_id("picker"), "Picker");
+
+    set_optional_prop(&mut node, "placeholder", &props.placeholder);
+    set_optional_prop(&mut node, keys::FONT_SIZE, &props.font_size);
+    set_optional_prop(&mut node, keys::BORDER_RADIUS, &props.border_radius);
+    set_optional_prop(&mut node, "max_visible_options", &props.max_visible_options.map(|count| count as i32));
+    set_color_prop(&mut node, keys::TINT_COLOR, &props.tint_color);
+    set_color_prop(&mut node, keys::TEXT_COLOR, &props.text_color);
+    set_color_prop(&mut node, "placeholder_color", &props.placeholder_color);
+    set_color_prop(&mut node, keys::BACKGROUND, &props.background_color);
+    set_color_prop(&mut node, keys::BORDER_COLOR, &props.border_color);
+
+    apply_form_control_state(
+        &mut node,
+        &props.control_id,
+        FormControlKind::Picker,
+        FormControlConfig {
+            enabled: !props.disabled.unwrap_or(false),
+            options: props.options.clone(),
+            ..Default::default()
+        },
+        props.selected_index.map(|index| FormValue::Selection(Some(index)))
+    );
+
+    if let Some(handler_id) = &props.on_change {
+        node.on_value_change(handler_id);
+    }
+
+    let mut base_props = BaseComponentProps::new();
+
+    base_props.width = props.width;
+    base_props.height = props.height;
+    base_props.padding = props.padding;
+    base_props.edge_insets = props.edge_insets;
+    base_props.opacity = props.opacity;
+
+    base_props.accessibility_label = props.accessibility_label.clone().or_else(|| props.placeholder.clone());
+    base_props.accessibility_hint = props.accessibility_hint.clone();
+
+    apply_base_props(&mut node, &base_props);
+
+    node
+}
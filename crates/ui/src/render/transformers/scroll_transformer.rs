@@ -1,13 +1,40 @@
-use crate::components::scroll::ScrollProps;
+use crate::components::scroll::{ScrollProps, ScrollDirection, DecelerationRate};
+use crate::components::scroll_controller::with_scroll_controller;
 use crate::render::node::RenderNode;
 use crate::components::base_props::{BaseComponentProps, utils::apply_base_props};
-use super::utils::{generate_unique_id, add_children, set_optional_prop};
+use super::utils::{generate_unique_id, default_scroll_id, add_children, set_optional_prop};
 
 pub fn transform_scroll(props: &ScrollProps) -> RenderNode {
     let mut node = RenderNode::new(&generate_unique_id("scroll"), "Scroll");
     
     node.set_prop("direction", format!("{:?}", props.direction));
     
+    let scroll_id = props.scroll_id.clone().unwrap_or_else(default_scroll_id);
+    node.set_prop("scroll_id", scroll_id.clone());
+    
+    let always_bounces = match props.direction {
+        ScrollDirection::Horizontal => props.always_bounces_horizontal,
+        ScrollDirection::Vertical => props.always_bounces_vertical,
+    };
+    
+    let (scroll_position, content_size) = with_scroll_controller(&scroll_id, |controller| {
+        controller.configure(
+            props.direction.clone(),
+            props.scroll_enabled.unwrap_or(true),
+            props.paging_enabled.unwrap_or(false),
+            props.bounces.unwrap_or(true),
+            always_bounces.unwrap_or(false),
+            props.deceleration_rate.as_ref()
+                .unwrap_or(&DecelerationRate::Normal)
+                .per_millisecond()
+        );
+        
+        (controller.offset(), controller.content_extent())
+    });
+    
+    node.set_prop("scroll_position", scroll_position);
+    node.set_prop("content_size", content_size);
+    
     set_optional_prop(&mut node, "shows_indicators", &props.shows_indicators);
     set_optional_prop(&mut node, "scrollbar_width", &props.scrollbar_width);
     set_optional_prop(&mut node, "scrollbar_margin", &props.scrollbar_margin);
@@ -18,6 +45,7 @@ pub fn transform_scroll(props: &ScrollProps) -> RenderNode {
     
     set_optional_prop(&mut node, "scroll_enabled", &props.scroll_enabled);
     set_optional_prop(&mut node, "paging_enabled", &props.paging_enabled);
+    set_optional_prop(&mut node, "bounces", &props.bounces);
     set_optional_prop(&mut node, "always_bounces_horizontal", &props.always_bounces_horizontal);
     set_optional_prop(&mut node, "always_bounces_vertical", &props.always_bounces_vertical);
     
@@ -58,6 +86,7 @@ pub fn transform_scroll(props: &ScrollProps) -> RenderNode {
     base_props.padding = props.padding;
     base_props.edge_insets = props.edge_insets.clone();
     base_props.opacity = props.opacity;
+    base_props.clip_to_bounds = Some(true);
     
     base_props.accessibility_label = props.accessibility_label.clone();
     
@@ -0,0 +1,49 @@
+use crate::components::slider::SliderProps;
+use crate::components::form_control_state::{FormControlConfig, FormControlKind, FormValue};
+use crate::render::node::RenderNode;
+use crate::components::base_props::{BaseComponentProps, utils::apply_base_props};
+use super::utils::{generate_unique_id, set_optional_prop, set_color_prop, apply_form_control_state};
+
+pub fn transform_slider(props: &SliderProps) -> RenderNode {
+    let mut node = RenderNode::new(&generate_unique_id("slider"), "Slider");
+
+    set_optional_prop(&mut node, "track_height", &props.track_height);
+    set_optional_prop(&mut node, "thumb_size", &props.thumb_size);
+    set_optional_prop(&mut node, "shows_ticks", &props.shows_ticks);
+    set_color_prop(&mut node, "track_color", &props.track_color);
+    set_color_prop(&mut node, "fill_color", &props.fill_color);
+    set_color_prop(&mut node, "thumb_color", &props.thumb_color);
+
+    apply_form_control_state(
+        &mut node,
+        &props.control_id,
+        FormControlKind::Slider,
+        FormControlConfig {
+            enabled: !props.disabled.unwrap_or(false),
+            min: props.min,
+            max: props.max,
+            step: props.step,
+            ..Default::default()
+        },
+        props.value.map(FormValue::Number)
+    );
+
+    if let Some(handler_id) = &props.on_change {
+        node.on_value_change(handler_id);
+    }
+
+    let mut base_props = BaseComponentProps::new();
+
+    base_props.width = props.width;
+    base_props.height = props.height;
+    base_props.padding = props.padding;
+    base_props.edge_insets = props.edge_insets;
+    base_props.opacity = props.opacity;
+
+    base_props.accessibility_label = props.accessibility_label.clone();
+    base_props.accessibility_hint = props.accessibility_hint.clone();
+
+    apply_base_props(&mut node, &base_props);
+
+    node
+}
@@ -0,0 +1,138 @@
+use crate::components::text_input::TextInputProps;
+use crate::components::text_input_state::{with_text_input_state, focus_text_input};
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::base_props::{BaseComponentProps, utils::apply_base_props};
+use super::utils::{generate_unique_id, set_optional_prop, apply_validation_state};
+
+pub fn transform_text_field(props: &TextInputProps) -> RenderNode {
+    transform_text_input(props, "TextField")
+}
+
+pub fn transform_text_area(props: &TextInputProps) -> RenderNode {
+    transform_text_input(props, "TextArea")
+}
+
+fn transform_text_input(props: &TextInputProps, type_name: &str) -> RenderNode {
+    let mut node = RenderNode::new(&generate_unique_id(&type_name.to_lowercase()), type_name);
+
+    node.set_prop("input_id", props.input_id.clone());
+    node.set_prop("multiline", props.multiline);
+
+    set_optional_prop(&mut node, "placeholder", &props.placeholder);
+    set_optional_prop(&mut node, "rows", &props.rows.map(|rows| rows as i32));
+    set_optional_prop(&mut node, "editable", &props.editable);
+    set_optional_prop(&mut node, keys::FONT_SIZE, &props.font_size);
+    set_optional_prop(&mut node, "font_family", &props.font_family);
+    set_optional_prop(&mut node, keys::LINE_HEIGHT, &props.line_height);
+
+    let colors = [
+        (keys::TEXT_COLOR, &props.text_color),
+        ("placeholder_color", &props.placeholder_color),
+        ("caret_color", &props.caret_color),
+        ("selection_color", &props.selection_color),
+        ("focus_border_color", &props.focus_border_color),
+    ];
+
+    for (key, color) in colors {
+        if let Some(color) = color {
+            node.set_prop(key, color.to_css_string());
+        }
+    }
+
+    let secure = props.secure_entry.unwrap_or(false);
+    let editable = props.editable.unwrap_or(true);
+
+    let (display_text, value, selection, composition, focused, autofocus) = with_text_input_state(&props.input_id, |state| {
+        state.configure(
+            props.multiline,
+            secure,
+            editable,
+            props.submits_on_enter(),
+            props.max_length
+        );
+        state.sync_value(props.value.as_deref());
+
+        let autofocus = props.autofocus.unwrap_or(false) && state.apply_autofocus();
+
+        let selection = state.selection();
+        let selection = (
+            state.to_display_offset(selection.start()),
+            state.to_display_offset(selection.end()),
+        );
+
+        let composition = state.composition().map(|composition| (
+            state.to_display_offset(composition.start),
+            state.to_display_offset(composition.end),
+        ));
+
+        (
+            state.display_text(),
+            state.value().to_string(),
+            selection,
+            composition,
+            state.is_focused(),
+            autofocus,
+        )
+    });
+
+    if autofocus {
+        focus_text_input(&props.input_id);
+    }
+
+    node.set_prop("display_text", display_text);
+    node.set_prop("value", value);
+    node.set_prop("selection_start", selection.0 as i32);
+    node.set_prop("selection_end", selection.1 as i32);
+    node.set_prop("focused", focused || autofocus);
+
+    if let Some((start, end)) = composition {
+        node.set_prop("composition_start", start as i32);
+        node.set_prop("composition_end", end as i32);
+    }
+
+    apply_validation_state(&mut node, &props.input_id);
+
+    if let Some(handler_id) = &props.on_change {
+        node.on_value_change(handler_id);
+    }
+
+    if let Some(handler_id) = &props.on_submit {
+        node.on_submit(handler_id);
+    }
+
+    if let Some(handler_id) = &props.on_focus {
+        node.on_focus(handler_id);
+    }
+
+    if let Some(handler_id) = &props.on_blur {
+        node.on_blur(handler_id);
+    }
+
+    let mut base_props = BaseComponentProps::new();
+
+    base_props.width = props.width;
+    base_props.height = props.height;
+    base_props.min_width = props.min_width;
+    base_props.max_width = props.max_width;
+    base_props.min_height = props.min_height;
+    base_props.max_height = props.max_height;
+
+    base_props.background = props.background_color.clone();
+    base_props.border_width = props.border_width;
+    base_props.border_color = props.border_color.clone();
+    base_props.border_radius = props.border_radius;
+    base_props.border_style = props.border_style.clone();
+
+    base_props.padding = props.padding;
+    base_props.edge_insets = props.edge_insets;
+    base_props.opacity = props.opacity;
+    base_props.clip_to_bounds = Some(true);
+
+    base_props.accessibility_label = props.accessibility_label.clone()
+        .or_else(|| props.placeholder.clone());
+
+    apply_base_props(&mut node, &base_props);
+
+    node
+}
@@ -0,0 +1,47 @@
+use crate::components::toggle::ToggleProps;
+use crate::components::form_control_state::{FormControlConfig, FormControlKind, FormValue};
+use crate::render::node::RenderNode;
+use crate::render::property::keys;
+use crate::components::base_props::{BaseComponentProps, utils::apply_base_props};
+use super::utils::{generate_unique_id, set_optional_prop, set_color_prop, apply_form_control_state};
+
+pub fn transform_toggle(props: &ToggleProps) -> RenderNode {
+    let mut node = RenderNode::new(&generate_unique_id("toggle"), "Toggle");
+
+    set_optional_prop(&mut node, keys::LABEL, &props.label);
+    set_optional_prop(&mut node, keys::FONT_SIZE, &props.font_size);
+    set_color_prop(&mut node, "on_color", &props.on_color);
+    set_color_prop(&mut node, "off_color", &props.off_color);
+    set_color_prop(&mut node, "thumb_color", &props.thumb_color);
+    set_color_prop(&mut node, keys::TEXT_COLOR, &props.label_color);
+
+    apply_form_control_state(
+        &mut node,
+        &props.control_id,
+        FormControlKind::Toggle,
+        FormControlConfig {
+            enabled: !props.disabled.unwrap_or(false),
+            ..Default::default()
+        },
+        props.is_on.map(FormValue::Bool)
+    );
+
+    if let Some(handler_id) = &props.on_change {
+        node.on_value_change(handler_id);
+    }
+
+    let mut base_props = BaseComponentProps::new();
+
+    base_props.width = props.width;
+    base_props.height = props.height;
+    base_props.padding = props.padding;
+    base_props.edge_insets = props.edge_insets;
+    base_props.opacity = props.opacity;
+
+    base_props.accessibility_label = props.accessibility_label.clone().or_else(|| props.label.clone());
+    base_props.accessibility_hint = props.accessibility_hint.clone();
+
+    apply_base_props(&mut node, &base_props);
+
+    node
+}
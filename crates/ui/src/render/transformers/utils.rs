@@ -2,7 +2,11 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use crate::render::node::RenderNode;
 use crate::render::property::{Property, PropertyBag};
 use crate::components::UIComponent;
-use crate::shared::EdgeInsets;
+use crate::components::form_control_state::{
+    with_form_control_state, FormControlConfig, FormControlKind, FormValue
+};
+use crate::components::validation_state::with_field_validation;
+use crate::shared::{Color, EdgeInsets};
 
 static NODE_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
@@ -11,6 +15,22 @@ pub fn generate_unique_id(prefix: &str) -> String {
     format!("{}-{}", prefix, id)
 }
 
+static RENDER_DEPTH: AtomicUsize = AtomicUsize::new(0);
+static SCROLL_ORDINAL: AtomicUsize = AtomicUsize::new(0);
+
+pub fn with_render_pass<R>(f: impl FnOnce() -> R) -> R {
+    if RENDER_DEPTH.fetch_add(1, Ordering::SeqCst) == 0 {
+        SCROLL_ORDINAL.store(0, Ordering::SeqCst);
+    }
+    let result = f();
+    RENDER_DEPTH.fetch_sub(1, Ordering::SeqCst);
+    result
+}
+
+pub fn default_scroll_id() -> String {
+    format!("scroll-auto-{}", SCROLL_ORDINAL.fetch_add(1, Ordering::SeqCst))
+}
+
 pub fn set_optional_prop<T: Into<Property> + Clone>(node: &mut RenderNode, key: &str, value: &Option<T>) {
     if let Some(val) = value {
         node.set_prop(key, val.clone());
@@ -54,6 +74,36 @@ pub fn set_enum_prop<T: std::fmt::Debug>(node: &mut RenderNode, key: &str, value
     }
 }
 
+pub fn set_color_prop(node: &mut RenderNode, key: &str, color: &Option<Color>) {
+    if let Some(color) = color {
+        node.set_prop(key, color.to_css_string());
+    }
+}
+
+pub fn apply_form_control_state(
+    node: &mut RenderNode,
+    control_id: &str,
+    kind: FormControlKind,
+    config: FormControlConfig,
+    value: Option<FormValue>
+) {
+    node.set_prop("control_id", control_id.to_string());
+
+    with_form_control_state(control_id, kind, |state| {
+        state.configure(config);
+        state.sync_value(value);
+        state.write_to(node);
+    });
+
+    apply_validation_state(node, control_id);
+}
+
+pub fn apply_validation_state(node: &mut RenderNode, field_id: &str) {
+    node.set_prop("validation_id", field_id.to_string());
+
+    with_field_validation(field_id, |validation| validation.write_to(node));
+}
+
 pub fn update_props_from_base(target: &mut RenderNode, base: &RenderNode) {
     for (key, prop) in base.properties.entries() {
         target.set_prop(key, prop.clone());
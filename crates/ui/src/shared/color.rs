@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use milost_core::image::PaletteColor;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "PascalCase")]
@@ -209,6 +210,12 @@ impl Color {
     }
 }
 
+impl From<PaletteColor> for Color {
+    fn from(color: PaletteColor) -> Self {
+        Color::Custom(color.red, color.green, color.blue)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum ThemeMode {
     Light,
@@ -313,4 +320,24 @@ pub mod color_schemes {
             on_error: Color::White,
         }
     }
-}
\ No newline at end of file
+    
+    pub fn from_palette(palette: &[Color]) -> ColorScheme {
+        let Some(primary) = palette.first().cloned() else {
+            return light();
+        };
+        let secondary = palette.get(1).cloned().unwrap_or_else(|| primary.lighten(0.3));
+        
+        ColorScheme {
+            on_primary: primary.contrasting_text_color(),
+            on_secondary: secondary.contrasting_text_color(),
+            primary,
+            secondary,
+            background: Color::White,
+            surface: Color::White,
+            error: Color::Red,
+            on_background: Color::Black,
+            on_surface: Color::Black,
+            on_error: Color::White,
+        }
+    }
+}
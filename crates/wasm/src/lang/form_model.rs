@@ -0,0 +1,661 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Function, Object, Promise, Reflect};
+use regex::Regex;
+use milost_ui::components::{set_field_errors, set_field_pending};
+
+use crate::utils::RegexBuilder;
+use super::{requires, Invariant, Primitives, Str, Task, ValidationError};
+use super::invariant::DEFAULT_INVARIANT_MESSAGE;
+
+#[derive(Clone)]
+enum Rule {
+    Required { message: String },
+    Length { min: Option<usize>, max: Option<usize>, message: String },
+    Pattern { regex: Regex, message: String },
+    Range { kind: String, min: Option<f64>, max: Option<f64>, message: String },
+    Custom { check: Function, message: String },
+}
+
+#[derive(Clone)]
+struct AsyncRule {
+    validator: Function,
+    message: String,
+}
+
+#[derive(Clone)]
+struct CrossFieldInvariant {
+    fields: Vec<String>,
+    check: Function,
+    message: String,
+}
+
+impl CrossFieldInvariant {
+    fn holds(&self, values: &Object) -> Result<bool, JsValue> {
+        Invariant::holds(values, &self.check)
+    }
+}
+
+struct FormField {
+    value: JsValue,
+    initial: JsValue,
+    rules: Vec<Rule>,
+    async_rules: Vec<AsyncRule>,
+    errors: Vec<String>,
+    invariant_errors: Vec<String>,
+    async_errors: Vec<String>,
+    binding: Option<String>,
+    generation: u32,
+    pending: bool,
+}
+
+impl FormField {
+    fn new(value: JsValue) -> Self {
+        Self {
+            initial: value.clone(),
+            value,
+            rules: Vec::new(),
+            async_rules: Vec::new(),
+            errors: Vec::new(),
+            invariant_errors: Vec::new(),
+            async_errors: Vec::new(),
+            binding: None,
+            generation: 0,
+            pending: false,
+        }
+    }
+
+    fn all_errors(&self) -> Vec<String> {
+        self.errors.iter()
+            .chain(&self.invariant_errors)
+            .chain(&self.async_errors)
+            .cloned()
+            .collect()
+    }
+
+    fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.invariant_errors.is_empty() && self.async_errors.is_empty()
+    }
+
+    fn publish(&self) {
+        if let Some(binding) = &self.binding {
+            set_field_errors(binding, self.all_errors());
+            set_field_pending(binding, self.pending);
+        }
+    }
+}
+
+#[derive(Default)]
+struct FormState {
+    order: Vec<String>,
+    fields: HashMap<String, FormField>,
+    invariants: Vec<CrossFieldInvariant>,
+}
+
+impl FormState {
+    fn field(&self, name: &str) -> Result<&FormField, JsValue> {
+        requires(self.fields.contains_key(name), Some(format!("Unknown form field: {}", name)))?;
+        Ok(&self.fields[name])
+    }
+
+    fn field_mut(&mut self, name: &str) -> Result<&mut FormField, JsValue> {
+        requires(self.fields.contains_key(name), Some(format!("Unknown form field: {}", name)))?;
+        Ok(self.fields.get_mut(name).unwrap())
+    }
+
+    fn values(&self) -> Result<Object, JsValue> {
+        let values = Object::new();
+
+        for name in &self.order {
+            Reflect::set(&values, &JsValue::from_str(name), &self.fields[name].value)?;
+        }
+
+        Ok(values)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.fields.values().all(FormField::is_valid)
+    }
+
+    fn is_pending(&self) -> bool {
+        self.fields.values().any(|field| field.pending)
+    }
+
+    fn publish(&self) {
+        for field in self.fields.values() {
+            field.publish();
+        }
+    }
+}
+
+fn is_empty_value(value: &JsValue) -> bool {
+    if value.is_null() || value.is_undefined() {
+        return true;
+    }
+
+    if let Some(text) = value.as_string() {
+        return text.trim().is_empty();
+    }
+
+    Array::is_array(value) && Array::from(value).length() == 0
+}
+
+fn value_length(value: &JsValue) -> Option<usize> {
+    if let Some(text) = value.as_string() {
+        return Some(text.chars().count());
+    }
+
+    Array::is_array(value).then(|| Array::from(value).length() as usize)
+}
+
+fn value_number(value: &JsValue) -> Option<f64> {
+    value.as_f64().or_else(|| value.as_string().and_then(|text| text.trim().parse().ok()))
+}
+
+fn validate_numeric_kind(kind: &str, n: f64) -> Result<bool, JsValue> {
+    Ok(match kind {
+        "u8" => Primitives::validate_u8(n),
+        "u16" => Primitives::validate_u16(n),
+        "u32" => Primitives::validate_u32(n),
+        "u64" => Primitives::validate_u64(n),
+        "usize" => Primitives::validate_usize(n),
+        "i8" => Primitives::validate_i8(n),
+        "i16" => Primitives::validate_i16(n),
+        "i32" => Primitives::validate_i32(n),
+        "i64" => Primitives::validate_i64(n),
+        "isize" => Primitives::validate_isize(n),
+        "f32" => Primitives::validate_f32(n),
+        "f64" => Primitives::validate_f64(n),
+        _ => return Err(JsValue::from_str(&format!("Unknown numeric type: {}", kind))),
+    })
+}
+
+fn check_rule(rule: &Rule, value: &JsValue, values: &Object) -> Result<Option<String>, JsValue> {
+    let empty = is_empty_value(value);
+
+    let failed = match rule {
+        Rule::Required { .. } => empty,
+        _ if empty => false,
+        Rule::Length { min, max, .. } => match value_length(value) {
+            Some(length) => min.is_some_and(|min| length < min) || max.is_some_and(|max| length > max),
+            None => true,
+        },
+        Rule::Pattern { regex, .. } => match value.as_string() {
+            Some(text) => !regex.is_match(&text),
+            None => true,
+        },
+        Rule::Range { kind, min, max, .. } => match value_number(value) {
+            Some(n) => !validate_numeric_kind(kind, n)?
+                || min.is_some_and(|min| n < min)
+                || max.is_some_and(|max| n > max),
+            None => true,
+        },
+        Rule::Custom { check, message } => {
+            let result = check.call2(&JsValue::null(), value, values)?;
+            return Ok(result_message(&result, message));
+        },
+    };
+
+    let message = match rule {
+        Rule::Required { message } |
+        Rule::Length { message, .. } |
+        Rule::Pattern { message, .. } |
+        Rule::Range { message, .. } |
+        Rule::Custom { message, .. } => message,
+    };
+
+    Ok(failed.then(|| message.clone()))
+}
+
+fn result_message(result: &JsValue, default_message: &str) -> Option<String> {
+    if let Some(message) = result.as_string() {
+        return Some(message);
+    }
+
+    match result.as_bool() {
+        Some(false) => Some(default_message.to_string()),
+        _ => None,
+    }
+}
+
+fn then(
+    promise: &Promise,
+    on_resolve: impl FnOnce(JsValue) -> JsValue + 'static,
+    on_reject: impl FnOnce(&JsValue) + 'static,
+) -> Promise {
+    let mut handlers = Some((on_resolve, on_reject));
+
+    Promise::new(&mut |resolve: Function, reject: Function| {
+        let Some((on_resolve, on_reject)) = handlers.take() else {
+            return;
+        };
+
+        let resolved: Closure<dyn FnMut(JsValue)> = Closure::once(move |results: JsValue| {
+            let _ = resolve.call1(&JsValue::null(), &on_resolve(results));
+        });
+        let rejected: Closure<dyn FnMut(JsValue)> = Closure::once(move |error: JsValue| {
+            on_reject(&error);
+            let _ = reject.call1(&JsValue::null(), &error);
+        });
+
+        let _ = promise.then2(&resolved, &rejected);
+        resolved.into_js_value();
+        rejected.into_js_value();
+    })
+}
+
+#[wasm_bindgen]
+pub struct FormModel {
+    state: Rc<RefCell<FormState>>,
+}
+
+#[wasm_bindgen]
+impl FormModel {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> FormModel {
+        FormModel {
+            state: Rc::new(RefCell::new(FormState::default())),
+        }
+    }
+
+    #[wasm_bindgen(js_name = "addField")]
+    pub fn add_field(&mut self, name: &str, initial_value: JsValue) -> Result<(), JsValue> {
+        let mut state = self.state.borrow_mut();
+        requires(!state.fields.contains_key(name), Some(format!("Duplicate form field: {}", name)))?;
+
+        state.order.push(name.to_string());
+        state.fields.insert(name.to_string(), FormField::new(initial_value));
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "bind")]
+    pub fn bind(&mut self, name: &str, component_id: &str) -> Result<(), JsValue> {
+        let mut state = self.state.borrow_mut();
+        let field = state.field_mut(name)?;
+
+        field.binding = Some(component_id.to_string());
+        field.publish();
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "required")]
+    pub fn required(&mut self, name: &str, message: Option<String>) -> Result<(), JsValue> {
+        self.add_rule(name, Rule::Required {
+            message: message.unwrap_or_else(|| "This field is required".to_string()),
+        })
+    }
+
+    #[wasm_bindgen(js_name = "length")]
+    pub fn length(
+        &mut self,
+        name: &str,
+        min: Option<usize>,
+        max: Option<usize>,
+        message: Option<String>,
+    ) -> Result<(), JsValue> {
+        requires(
+            min.zip(max).is_none_or(|(min, max)| min <= max),
+            Some("Minimum length must not exceed maximum length".to_string()),
+        )?;
+
+        let message = message.unwrap_or_else(|| match (min, max) {
+            (Some(min), Some(max)) => format!("Must be between {} and {} characters", min, max),
+            (Some(min), None) => format!("Must be at least {} characters", min),
+            (None, Some(max)) => format!("Must be at most {} characters", max),
+            (None, None) => "Invalid length".to_string(),
+        });
+
+        self.add_rule(name, Rule::Length { min, max, message })
+    }
+
+    #[wasm_bindgen(js_name = "pattern")]
+    pub fn pattern(&mut self, name: &str, builder: &RegexBuilder, message: Option<String>) -> Result<(), JsValue> {
+        let regex = Regex::new(&format!("^(?:{})$", builder.done()))
+            .map_err(|e| JsValue::from_str(&format!("Invalid regex: {}", e)))?;
+
+        self.add_rule(name, Rule::Pattern {
+            regex,
+            message: message.unwrap_or_else(|| "Invalid format".to_string()),
+        })
+    }
+
+    #[wasm_bindgen(js_name = "range")]
+    pub fn range(
+        &mut self,
+        name: &str,
+        kind: &str,
+        min: Option<f64>,
+        max: Option<f64>,
+        message: Option<String>,
+    ) -> Result<(), JsValue> {
+        validate_numeric_kind(kind, 0.0)?;
+
+        let message = message.unwrap_or_else(|| match (min, max) {
+            (Some(min), Some(max)) => format!("Must be a valid {} between {} and {}", kind, min, max),
+            (Some(min), None) => format!("Must be a valid {} of at least {}", kind, min),
+            (None, Some(max)) => format!("Must be a valid {} of at most {}", kind, max),
+            (None, None) => format!("Must be a valid {}", kind),
+        });
+
+        self.add_rule(name, Rule::Range { kind: kind.to_string(), min, max, message })
+    }
+
+    #[wasm_bindgen(js_name = "custom")]
+    pub fn custom(&mut self, name: &str, check: Function, message: Option<String>) -> Result<(), JsValue> {
+        self.add_rule(name, Rule::Custom {
+            check,
+            message: message.unwrap_or_else(|| "Invalid value".to_string()),
+        })
+    }
+
+    #[wasm_bindgen(js_name = "asyncValidator")]
+    pub fn async_validator(&mut self, name: &str, validator: Function, message: Option<String>) -> Result<(), JsValue> {
+        let mut state = self.state.borrow_mut();
+
+        state.field_mut(name)?.async_rules.push(AsyncRule {
+            validator,
+            message: message.unwrap_or_else(|| "Invalid value".to_string()),
+        });
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "invariant")]
+    pub fn invariant(&mut self, fields: Vec<String>, check: Function, message: Option<String>) -> Result<(), JsValue> {
+        let mut state = self.state.borrow_mut();
+
+        for name in &fields {
+            state.field_mut(name)?;
+        }
+
+        state.invariants.push(CrossFieldInvariant {
+            fields,
+            check,
+            message: message.unwrap_or_else(|| DEFAULT_INVARIANT_MESSAGE.to_string()),
+        });
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "setValue")]
+    pub fn set_value(&mut self, name: &str, value: JsValue) -> Result<bool, JsValue> {
+        {
+            let mut state = self.state.borrow_mut();
+            let field = state.field_mut(name)?;
+
+            field.value = value;
+            field.generation += 1;
+            field.pending = false;
+            field.async_errors.clear();
+        }
+
+        self.validate_field(name)
+    }
+
+    #[wasm_bindgen(js_name = "getValue")]
+    pub fn get_value(&self, name: &str) -> JsValue {
+        self.state.borrow().fields.get(name)
+            .map(|field| field.value.clone())
+            .unwrap_or(JsValue::UNDEFINED)
+    }
+
+    #[wasm_bindgen(js_name = "values")]
+    pub fn values(&self) -> Result<Object, JsValue> {
+        self.state.borrow().values()
+    }
+
+    #[wasm_bindgen(js_name = "validateField")]
+    pub fn validate_field(&mut self, name: &str) -> Result<bool, JsValue> {
+        let values = self.values()?;
+
+        self.run_rules(name, &values)?;
+        self.check_invariants(&values)?;
+
+        let state = self.state.borrow();
+        state.publish();
+        Ok(state.field(name)?.is_valid())
+    }
+
+    #[wasm_bindgen(js_name = "validate")]
+    pub fn validate(&mut self) -> Result<bool, JsValue> {
+        let values = self.values()?;
+        let names = self.state.borrow().order.clone();
+
+        for name in &names {
+            self.run_rules(name, &values)?;
+        }
+
+        self.check_invariants(&values)?;
+
+        let state = self.state.borrow();
+        state.publish();
+        Ok(state.is_valid())
+    }
+
+    #[wasm_bindgen(js_name = "validateFieldAsync")]
+    pub fn validate_field_async(&mut self, name: &str) -> Result<Task, JsValue> {
+        self.validate_field(name)?;
+        Ok(Task::new(self.run_async_rules(name)?))
+    }
+
+    #[wasm_bindgen(js_name = "validateAsync")]
+    pub fn validate_async(&mut self) -> Result<Task, JsValue> {
+        self.validate()?;
+
+        let names: Vec<String> = {
+            let state = self.state.borrow();
+            state.order.iter()
+                .filter(|name| !state.fields[*name].async_rules.is_empty())
+                .cloned()
+                .collect()
+        };
+
+        let promises = Array::new();
+
+        for name in &names {
+            let promise = self.run_async_rules(name)?;
+            promises.push(&promise);
+        }
+
+        let state = self.state.clone();
+        let on_resolve = move |_: JsValue| JsValue::from_bool(state.borrow().is_valid());
+
+        Ok(Task::new(then(&Promise::all(&promises), on_resolve, |_: &JsValue| {})))
+    }
+
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(&self) -> bool {
+        self.state.borrow().is_valid()
+    }
+
+    #[wasm_bindgen(js_name = "isPending")]
+    pub fn is_pending(&self) -> bool {
+        self.state.borrow().is_pending()
+    }
+
+    #[wasm_bindgen(js_name = "errors")]
+    pub fn errors(&self, name: &str) -> Vec<ValidationError> {
+        self.state.borrow().fields.get(name)
+            .map(|field| field.all_errors().iter()
+                .map(|message| ValidationError::new(&Str::new(message)))
+                .collect())
+            .unwrap_or_default()
+    }
+
+    #[wasm_bindgen(js_name = "errorMessages")]
+    pub fn error_messages(&self) -> Result<Object, JsValue> {
+        let state = self.state.borrow();
+        let messages = Object::new();
+
+        for name in &state.order {
+            let errors = state.fields[name].all_errors();
+
+            if !errors.is_empty() {
+                let array: Array = errors.iter().map(|error| JsValue::from_str(error)).collect();
+                Reflect::set(&messages, &JsValue::from_str(name), &array)?;
+            }
+        }
+
+        Ok(messages)
+    }
+
+    #[wasm_bindgen(js_name = "reset")]
+    pub fn reset(&mut self) {
+        let mut state = self.state.borrow_mut();
+
+        for field in state.fields.values_mut() {
+            field.value = field.initial.clone();
+            field.generation += 1;
+            field.pending = false;
+            field.errors.clear();
+            field.invariant_errors.clear();
+            field.async_errors.clear();
+        }
+
+        state.publish();
+    }
+
+    #[wasm_bindgen(js_name = "submit")]
+    pub fn submit(&mut self, handler: &Function) -> Result<JsValue, JsValue> {
+        let valid = self.validate()?;
+        requires(!self.is_pending(), Some("Form validation is still pending".to_string()))?;
+        requires(valid, Some("Form validation failed".to_string()))?;
+
+        let values = self.values()?;
+        handler.call1(&JsValue::null(), &values)
+    }
+
+    #[wasm_bindgen(js_name = "toString")]
+    pub fn to_string_js(&self) -> String {
+        let state = self.state.borrow();
+        format!("[FormModel fields={} valid={}]", state.order.len(), state.is_valid())
+    }
+}
+
+impl Default for FormModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormModel {
+    fn add_rule(&mut self, name: &str, rule: Rule) -> Result<(), JsValue> {
+        let mut state = self.state.borrow_mut();
+        state.field_mut(name)?.rules.push(rule);
+        Ok(())
+    }
+
+    fn run_rules(&self, name: &str, values: &Object) -> Result<(), JsValue> {
+        let (rules, value) = {
+            let state = self.state.borrow();
+            let field = state.field(name)?;
+            (field.rules.clone(), field.value.clone())
+        };
+
+        let mut errors = Vec::new();
+
+        for rule in &rules {
+            if let Some(message) = check_rule(rule, &value, values)? {
+                errors.push(message);
+            }
+        }
+
+        self.state.borrow_mut().field_mut(name)?.errors = errors;
+        Ok(())
+    }
+
+    fn check_invariants(&self, values: &Object) -> Result<(), JsValue> {
+        let invariants = self.state.borrow().invariants.clone();
+        let mut violated = Vec::new();
+
+        for invariant in &invariants {
+            if !invariant.holds(values)? {
+                violated.push(invariant);
+            }
+        }
+
+        let mut state = self.state.borrow_mut();
+
+        for field in state.fields.values_mut() {
+            field.invariant_errors.clear();
+        }
+
+        for invariant in violated {
+            for name in &invariant.fields {
+                if let Some(field) = state.fields.get_mut(name) {
+                    field.invariant_errors.push(invariant.message.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn run_async_rules(&mut self, name: &str) -> Result<Promise, JsValue> {
+        let values = self.values()?;
+
+        let (rules, value, generation) = {
+            let mut state = self.state.borrow_mut();
+            let field = state.field_mut(name)?;
+
+            field.generation += 1;
+            field.async_errors.clear();
+
+            if field.async_rules.is_empty() {
+                return Ok(Promise::resolve(&JsValue::from_bool(field.is_valid())));
+            }
+
+            field.pending = true;
+            field.publish();
+
+            (field.async_rules.clone(), field.value.clone(), field.generation)
+        };
+
+        let promises = Array::new();
+        let mut messages = Vec::new();
+
+        for rule in &rules {
+            let result = rule.validator.call2(&JsValue::null(), &value, &values)?;
+            promises.push(&Promise::resolve(&result));
+            messages.push(rule.message.clone());
+        }
+
+        let resolve_state = self.state.clone();
+        let resolve_name = name.to_string();
+        let on_resolve = move |results: JsValue| {
+            let mut state = resolve_state.borrow_mut();
+            let Some(field) = state.fields.get_mut(&resolve_name) else {
+                return JsValue::FALSE;
+            };
+
+            if field.generation == generation {
+                field.pending = false;
+                field.async_errors = Array::from(&results).iter()
+                    .zip(&messages)
+                    .filter_map(|(result, message)| result_message(&result, message))
+                    .collect();
+                field.publish();
+            }
+
+            JsValue::from_bool(field.is_valid())
+        };
+
+        let reject_state = self.state.clone();
+        let reject_name = name.to_string();
+        let on_reject = move |_: &JsValue| {
+            if let Some(field) = reject_state.borrow_mut().fields.get_mut(&reject_name) {
+                if field.generation == generation {
+                    field.pending = false;
+                    field.publish();
+                }
+            }
+        };
+
+        Ok(then(&Promise::all(&promises), on_resolve, on_reject))
+    }
+}
+
+#[wasm_bindgen(js_name = "createFormModel")]
+pub fn create_form_model() -> FormModel {
+    FormModel::new()
+}
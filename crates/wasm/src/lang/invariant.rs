@@ -1,6 +1,8 @@
 use wasm_bindgen::prelude::*;
 use js_sys::{Function, Object, Reflect};
 
+pub(crate) const DEFAULT_INVARIANT_MESSAGE: &str = "Invariant violated";
+
 #[wasm_bindgen]
 pub struct Invariant {
     value: JsValue,
@@ -14,10 +16,9 @@ impl Invariant {
         invariant_fn: Function,
         error_msg: Option<String>,
     ) -> Result<Invariant, JsValue> {
-        let error_message = error_msg.unwrap_or_else(|| "Invariant violated".to_string());
+        let error_message = error_msg.unwrap_or_else(|| DEFAULT_INVARIANT_MESSAGE.to_string());
         
-        let check_result = invariant_fn.call1(&JsValue::null(), &value)?;
-        if !check_result.as_bool().unwrap_or(false) {
+        if !Invariant::holds(&value, &invariant_fn)? {
             let error = Object::new();
             Reflect::set(&error, &"name".into(), &"ContractError".into())?;
             Reflect::set(&error, &"message".into(), &error_message.clone().into())?;
@@ -52,6 +53,13 @@ impl Invariant {
     }
 }
 
+impl Invariant {
+    pub(crate) fn holds(value: &JsValue, invariant_fn: &Function) -> Result<bool, JsValue> {
+        let check_result = invariant_fn.call1(&JsValue::null(), value)?;
+        Ok(check_result.as_bool().unwrap_or(false))
+    }
+}
+
 #[wasm_bindgen(js_name = "createInvariant")]
 pub fn create_invariant(
     value: JsValue,
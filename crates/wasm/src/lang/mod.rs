@@ -26,6 +26,7 @@ mod common;
 mod smart_pointers;
 mod sync_primitives;
 mod computed;
+mod form_model;
 
 use wasm_bindgen::prelude::*;
 
@@ -56,3 +57,4 @@ pub use hash_set::*;
 pub use common::*;
 pub use smart_pointers::*;
 pub use sync_primitives::*;
+pub use form_model::*;
@@ -2,6 +2,7 @@
 
 pub mod utils;
 pub mod lang;
+pub mod machine_learning;
 
 use utils::*;
 use lang::*;
@@ -1,137 +1,145 @@
 use wasm_bindgen::prelude::*;
-use js_sys::{Array, Float64Array, Uint32Array, Object, Reflect, Math};
-use std::collections::{HashMap, HashSet};
+use js_sys::{Array, Float64Array, Uint32Array, Object, Reflect, JSON};
+use milost_core::machine_learning::{
+    self as ml, ConfusionMatrix, Estimator, ForestParams, LogisticParams, TreeParams,
+    Model as CoreModel,
+};
+use milost_core::utils::rng_from_seed;
+use crate::utils::js_error;
 
 #[wasm_bindgen]
 pub struct MachineLearning;
 
 #[wasm_bindgen]
-impl MachineLearning {
-    #[wasm_bindgen(js_name = "kMeans")]
-    pub fn k_means(data: &Float64Array, n_clusters: usize, n_features: usize, max_iterations: Option<usize>) -> Object {
-        let max_iter = max_iterations.unwrap_or(100);
-        
-        let n_samples = data.length() as usize / n_features;
-        if n_samples < n_clusters {
-            let error = Object::new();
-            Reflect::set(&error, &JsValue::from_str("error"), &JsValue::from_str("Number of samples must be greater than number of clusters")).unwrap();
-            return error;
-        }
-        
-        let mut points: Vec<Vec<f64>> = Vec::with_capacity(n_samples);
-        for i in 0..n_samples {
-            let mut point = Vec::with_capacity(n_features);
-            for j in 0..n_features {
-                point.push(data.get_index((i * n_features + j) as u32));
-            }
-            points.push(point);
-        }
-        
-        let mut centroids = Self::initialize_centroids(&points, n_clusters);
-        let mut cluster_assignments = vec![0; n_samples];
-        
-        for _ in 0..max_iter {
-            let mut changed = false;
-            
-            for (i, point) in points.iter().enumerate() {
-                let mut min_distance = f64::MAX;
-                let mut closest_cluster = 0;
-                
-                for (j, centroid) in centroids.iter().enumerate() {
-                    let distance = Self::euclidean_distance(point, centroid);
-                    if distance < min_distance {
-                        min_distance = distance;
-                        closest_cluster = j;
-                    }
-                }
-                
-                if cluster_assignments[i] != closest_cluster {
-                    cluster_assignments[i] = closest_cluster;
-                    changed = true;
-                }
-            }
-            
-            if !changed {
-                break;
-            }
-            
-            let new_centroids = Self::update_centroids(&points, &cluster_assignments, n_clusters, n_features);
-            centroids = new_centroids;
-        }
-        
-        let result = Object::new();
-        
-        let labels = Uint32Array::new_with_length(n_samples as u32);
-        for (i, &cluster) in cluster_assignments.iter().enumerate() {
-            labels.set_index(i as u32, cluster as u32);
-        }
-        
-        let centroids_array = Float64Array::new_with_length((n_clusters * n_features) as u32);
-        for i in 0..n_clusters {
-            for j in 0..n_features {
-                centroids_array.set_index((i * n_features + j) as u32, centroids[i][j]);
-            }
-        }
-        
-        Reflect::set(&result, &JsValue::from_str("labels"), &labels).unwrap();
-        Reflect::set(&result, &JsValue::from_str("centroids"), &centroids_array).unwrap();
-        Reflect::set(&result, &JsValue::from_str("n_clusters"), &JsValue::from_f64(n_clusters as f64)).unwrap();
-        Reflect::set(&result, &JsValue::from_str("n_features"), &JsValue::from_f64(n_features as f64)).unwrap();
-        
-        result
-    }
+pub struct Metrics;
+
+#[wasm_bindgen]
+pub struct Model {
+    inner: CoreModel,
+}
+
+fn rows(data: &Float64Array, n_features: usize) -> Result<Vec<Vec<f64>>, JsValue> {
+    ml::to_rows(&data.to_vec(), n_features).map_err(js_error)
+}
+
+fn rows_to_array(rows: &[Vec<f64>]) -> Float64Array {
+    Float64Array::from(&ml::flatten(rows)[..])
+}
+
+fn labels_to_f64(labels: &Uint32Array) -> Vec<f64> {
+    labels.to_vec().into_iter().map(f64::from).collect()
+}
+
+fn indices_array(indices: &[usize]) -> Uint32Array {
+    indices.iter().map(|&i| i as u32).collect::<Vec<u32>>().as_slice().into()
+}
+
+fn split_object(split: &ml::Split) -> Object {
+    let result = Object::new();
+    Reflect::set(&result, &JsValue::from_str("train"), &indices_array(&split.train)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("test"), &indices_array(&split.test)).unwrap();
+    result
+}
+
+fn estimator_from_js(spec: &JsValue) -> Result<Estimator, JsValue> {
+    let json = match spec.as_string() {
+        Some(json) => json,
+        None => JSON::stringify(spec)?.as_string().unwrap_or_default(),
+    };
     
-    fn initialize_centroids(points: &[Vec<f64>], n_clusters: usize) -> Vec<Vec<f64>> {
-        let n_samples = points.len();
-        let n_features = points[0].len();
-        
-        let mut centroids = Vec::with_capacity(n_clusters);
-        let mut used_indices = HashSet::new();
-        
-        for _ in 0..n_clusters {
-            loop {
-                let index = (js_sys::Math::random() * n_samples as f64) as usize % n_samples;
-                if used_indices.insert(index) {
-                    centroids.push(points[index].clone());
-                    break;
-                }
-            }
-        }
-        
-        centroids
-    }
+    serde_json::from_str(&json).map_err(|e| JsValue::from_str(&format!("Invalid estimator: {}", e)))
+}
+
+fn fit(estimator: Estimator, x: &Float64Array, y: Vec<f64>, n_features: usize, seed: Option<u32>) -> Result<Model, JsValue> {
+    let x = rows(x, n_features)?;
+    let mut rng = rng_from_seed(seed.map(u64::from));
     
-    fn euclidean_distance(a: &[f64], b: &[f64]) -> f64 {
-        let mut sum_sq = 0.0;
-        for i in 0..a.len() {
-            let diff = a[i] - b[i];
-            sum_sq += diff * diff;
-        }
-        sum_sq.sqrt()
+    estimator.fit(&x, &y, &mut rng)
+        .map(|inner| Model { inner })
+        .map_err(js_error)
+}
+
+#[wasm_bindgen]
+impl Model {
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> String {
+        self.inner.kind().to_string()
     }
-    
-    fn update_centroids(points: &[Vec<f64>], cluster_assignments: &[usize], n_clusters: usize, n_features: usize) -> Vec<Vec<f64>> {
-        let mut centroids = vec![vec![0.0; n_features]; n_clusters];
-        let mut counts = vec![0; n_clusters];
+
+    #[wasm_bindgen(getter, js_name = "nFeatures")]
+    pub fn n_features(&self) -> usize {
+        self.inner.n_features()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn classes(&self) -> Option<Uint32Array> {
+        self.inner.classes().map(Uint32Array::from)
+    }
+
+    #[wasm_bindgen(js_name = "predict")]
+    pub fn predict(&self, x: &Float64Array) -> Result<Float64Array, JsValue> {
+        let x = rows(x, self.inner.n_features())?;
+        let predictions = self.inner.predict(&x).map_err(js_error)?;
+        Ok(Float64Array::from(&predictions[..]))
+    }
+
+    #[wasm_bindgen(js_name = "predictProba")]
+    pub fn predict_proba(&self, x: &Float64Array) -> Result<Array, JsValue> {
+        let x = rows(x, self.inner.n_features())?;
+        let probabilities = self.inner.predict_proba(&x).map_err(js_error)?;
+        
+        Ok(probabilities
+            .iter()
+            .map(|row| JsValue::from(Float64Array::from(&row[..])))
+            .collect())
+    }
+
+    #[wasm_bindgen(js_name = "transform")]
+    pub fn transform(&self, x: &Float64Array) -> Result<Float64Array, JsValue> {
+        let x = rows(x, self.inner.n_features())?;
+        self.inner.transform(&x).map(|rows| rows_to_array(&rows)).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "inverseTransform")]
+    pub fn inverse_transform(&self, x: &Float64Array) -> Result<Float64Array, JsValue> {
+        let x = rows(x, self.inner.n_features())?;
+        self.inner.inverse_transform(&x).map(|rows| rows_to_array(&rows)).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "serialize")]
+    pub fn serialize(&self) -> Result<String, JsValue> {
+        self.inner.to_json().map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "deserialize")]
+    pub fn deserialize(json: &str) -> Result<Model, JsValue> {
+        CoreModel::from_json(json)
+            .map(|inner| Model { inner })
+            .map_err(js_error)
+    }
+}
+
+#[wasm_bindgen]
+impl MachineLearning {
+    #[wasm_bindgen(js_name = "train")]
+    pub fn train(estimator: &JsValue, x_train: &Float64Array, y_train: &Float64Array, n_features: usize, seed: Option<u32>) -> Result<Model, JsValue> {
+        fit(estimator_from_js(estimator)?, x_train, y_train.to_vec(), n_features, seed)
+    }
+
+    #[wasm_bindgen(js_name = "kMeans")]
+    pub fn k_means(data: &Float64Array, n_clusters: usize, n_features: usize, max_iterations: Option<usize>, seed: Option<u32>) -> Result<Object, JsValue> {
+        let points = rows(data, n_features)?;
+        let mut rng = rng_from_seed(seed.map(u64::from));
         
-        for (i, point) in points.iter().enumerate() {
-            let cluster = cluster_assignments[i];
-            counts[cluster] += 1;
-            
-            for j in 0..n_features {
-                centroids[cluster][j] += point[j];
-            }
-        }
+        let (model, labels) = ml::KMeans::fit(&points, n_clusters, max_iterations.unwrap_or(100), &mut rng)
+            .map_err(js_error)?;
         
-        for i in 0..n_clusters {
-            if counts[i] > 0 {
-                for j in 0..n_features {
-                    centroids[i][j] /= counts[i] as f64;
-                }
-            }
-        }
+        let result = Object::new();
+        Reflect::set(&result, &JsValue::from_str("labels"), &indices_array(&labels)).unwrap();
+        Reflect::set(&result, &JsValue::from_str("centroids"), &rows_to_array(&model.centroids)).unwrap();
+        Reflect::set(&result, &JsValue::from_str("model"), &Model { inner: CoreModel::KMeans(model) }.into()).unwrap();
         
-        centroids
+        Ok(result)
     }
 
     #[wasm_bindgen(js_name = "decisionTreeClassifier")]
@@ -141,441 +149,166 @@ impl MachineLearning {
         n_features: usize,
         max_depth: Option<usize>,
         min_samples_split: Option<usize>
-    ) -> Object {
-        let max_tree_depth = max_depth.unwrap_or(10);
-        let min_split = min_samples_split.unwrap_or(2);
-        
-        let n_samples = x_train.length() as usize / n_features;
-        
-        if y_train.length() as usize != n_samples {
-            let error = Object::new();
-            Reflect::set(&error, &JsValue::from_str("error"), &JsValue::from_str("Length of y_train must match number of samples in x_train")).unwrap();
-            return error;
-        }
-        
-        let mut x_values: Vec<Vec<f64>> = Vec::with_capacity(n_samples);
-        for i in 0..n_samples {
-            let mut features = Vec::with_capacity(n_features);
-            for j in 0..n_features {
-                features.push(x_train.get_index((i * n_features + j) as u32));
-            }
-            x_values.push(features);
-        }
-        
-        let mut y_values: Vec<u32> = Vec::with_capacity(n_samples);
-        for i in 0..n_samples {
-            y_values.push(y_train.get_index(i as u32));
-        }
-        
-        let tree_root = Self::build_tree(&x_values, &y_values, 0, max_tree_depth, min_split);
-        
-        Self::serialize_tree(&tree_root)
+    ) -> Result<Model, JsValue> {
+        let defaults = TreeParams::default();
+        let params = TreeParams {
+            max_depth: max_depth.unwrap_or(defaults.max_depth),
+            min_samples_split: min_samples_split.unwrap_or(defaults.min_samples_split),
+            max_features: None,
+        };
+        
+        fit(Estimator::DecisionTree(params), x_train, labels_to_f64(y_train), n_features, None)
     }
-    
-    #[wasm_bindgen(js_name = "predictDecisionTree")]
-    pub fn predict_decision_tree(tree: &Object, x_test: &Float64Array, n_features: usize) -> Uint32Array {
-        let n_samples = x_test.length() as usize / n_features;
-        let result = Uint32Array::new_with_length(n_samples as u32);
-        
-        let root_node = Reflect::get(tree, &JsValue::from_str("root")).unwrap();
-        
-        for i in 0..n_samples {
-            let mut features = Vec::with_capacity(n_features);
-            for j in 0..n_features {
-                features.push(x_test.get_index((i * n_features + j) as u32));
-            }
-            
-            let prediction = Self::predict_single_sample(&root_node, &features);
-            result.set_index(i as u32, prediction);
-        }
-        
-        result
+
+    #[wasm_bindgen(js_name = "randomForestClassifier")]
+    pub fn random_forest_classifier(
+        x_train: &Float64Array,
+        y_train: &Uint32Array,
+        n_features: usize,
+        n_trees: Option<usize>,
+        max_depth: Option<usize>,
+        seed: Option<u32>
+    ) -> Result<Model, JsValue> {
+        let defaults = ForestParams::default();
+        let params = ForestParams {
+            n_trees: n_trees.unwrap_or(defaults.n_trees),
+            max_depth: max_depth.unwrap_or(defaults.max_depth),
+            ..defaults
+        };
+        
+        fit(Estimator::RandomForest(params), x_train, labels_to_f64(y_train), n_features, seed)
     }
-    
-    fn predict_single_sample(node: &JsValue, features: &[f64]) -> u32 {
-        if Reflect::has(node, &JsValue::from_str("prediction")).unwrap_or(false) {
-            return Reflect::get(node, &JsValue::from_str("prediction"))
-                .unwrap_or(JsValue::from_f64(0.0))
-                .as_f64()
-                .unwrap_or(0.0) as u32;
-        }
-        
-        let feature_idx = Reflect::get(node, &JsValue::from_str("featureIndex"))
-            .unwrap_or(JsValue::from_f64(0.0))
-            .as_f64()
-            .unwrap_or(0.0) as usize;
-            
-        let threshold = Reflect::get(node, &JsValue::from_str("threshold"))
-            .unwrap_or(JsValue::from_f64(0.0))
-            .as_f64()
-            .unwrap_or(0.0);
-            
-        let feature_value = features[feature_idx];
-        
-        if feature_value <= threshold {
-            let left_child = Reflect::get(node, &JsValue::from_str("left")).unwrap();
-            Self::predict_single_sample(&left_child, features)
-        } else {
-            let right_child = Reflect::get(node, &JsValue::from_str("right")).unwrap();
-            Self::predict_single_sample(&right_child, features)
-        }
+
+    #[wasm_bindgen(js_name = "linearRegression")]
+    pub fn linear_regression(x_train: &Float64Array, y_train: &Float64Array, n_features: usize) -> Result<Model, JsValue> {
+        fit(Estimator::LinearRegression, x_train, y_train.to_vec(), n_features, None)
     }
-    
-    enum TreeNode {
-        Internal {
-            feature_index: usize,
-            threshold: f64,
-            left: Box<TreeNode>,
-            right: Box<TreeNode>,
-        },
-        Leaf {
-            prediction: u32,
-        },
+
+    #[wasm_bindgen(js_name = "logisticRegression")]
+    pub fn logistic_regression(
+        x_train: &Float64Array,
+        y_train: &Uint32Array,
+        n_features: usize,
+        learning_rate: Option<f64>,
+        epochs: Option<usize>
+    ) -> Result<Model, JsValue> {
+        let defaults = LogisticParams::default();
+        let params = LogisticParams {
+            learning_rate: learning_rate.unwrap_or(defaults.learning_rate),
+            epochs: epochs.unwrap_or(defaults.epochs),
+            ..defaults
+        };
+        
+        fit(Estimator::LogisticRegression(params), x_train, labels_to_f64(y_train), n_features, None)
     }
-    
-    fn build_tree(
-        x: &[Vec<f64>], 
-        y: &[u32], 
-        depth: usize, 
-        max_depth: usize, 
-        min_samples_split: usize
-    ) -> TreeNode {
-        if depth >= max_depth || x.len() < min_samples_split || Self::is_pure(y) {
-            return TreeNode::Leaf {
-                prediction: Self::majority_vote(y),
-            };
-        }
-        
-        let (feature_idx, threshold, left_indices, right_indices) = Self::find_best_split(x, y);
-        
-        if left_indices.is_empty() || right_indices.is_empty() {
-            return TreeNode::Leaf {
-                prediction: Self::majority_vote(y),
-            };
-        }
-        
-        let mut x_left = Vec::with_capacity(left_indices.len());
-        let mut y_left = Vec::with_capacity(left_indices.len());
-        
-        for &idx in &left_indices {
-            x_left.push(x[idx].clone());
-            y_left.push(y[idx]);
-        }
-        
-        let mut x_right = Vec::with_capacity(right_indices.len());
-        let mut y_right = Vec::with_capacity(right_indices.len());
-        
-        for &idx in &right_indices {
-            x_right.push(x[idx].clone());
-            y_right.push(y[idx]);
-        }
-        
-        let left_branch = Self::build_tree(&x_left, &y_left, depth + 1, max_depth, min_samples_split);
-        let right_branch = Self::build_tree(&x_right, &y_right, depth + 1, max_depth, min_samples_split);
-        
-        TreeNode::Internal {
-            feature_index: feature_idx,
-            threshold,
-            left: Box::new(left_branch),
-            right: Box::new(right_branch),
-        }
+
+    #[wasm_bindgen(js_name = "kNearestNeighbors")]
+    pub fn k_nearest_neighbors(x_train: &Float64Array, y_train: &Uint32Array, n_features: usize, k: Option<usize>) -> Result<Model, JsValue> {
+        let params = ml::NeighborsParams { k: k.unwrap_or(ml::NeighborsParams::default().k) };
+        fit(Estimator::KNearestNeighbors(params), x_train, labels_to_f64(y_train), n_features, None)
     }
-    
-    fn is_pure(y: &[u32]) -> bool {
-        if y.is_empty() {
-            return true;
-        }
-        
-        let first_label = y[0];
-        y.iter().all(|&label| label == first_label)
+
+    #[wasm_bindgen(js_name = "naiveBayes")]
+    pub fn naive_bayes(x_train: &Float64Array, y_train: &Uint32Array, n_features: usize) -> Result<Model, JsValue> {
+        fit(Estimator::GaussianNaiveBayes, x_train, labels_to_f64(y_train), n_features, None)
     }
-    
-    fn majority_vote(y: &[u32]) -> u32 {
-        let mut counts = HashMap::new();
-        
-        for &label in y {
-            *counts.entry(label).or_insert(0) += 1;
-        }
-        
-        let mut max_count = 0;
-        let mut majority_label = 0;
-        
-        for (label, &count) in &counts {
-            if count > max_count {
-                max_count = count;
-                majority_label = *label;
-            }
-        }
-        
-        majority_label
+
+    #[wasm_bindgen(js_name = "standardScaler")]
+    pub fn standard_scaler(data: &Float64Array, n_features: usize) -> Result<Model, JsValue> {
+        fit(Estimator::StandardScaler, data, Vec::new(), n_features, None)
     }
-    
-    fn find_best_split(x: &[Vec<f64>], y: &[u32]) -> (usize, f64, Vec<usize>, Vec<usize>) {
-        let n_samples = x.len();
-        let n_features = x[0].len();
-        
-        let mut best_gini = f64::MAX;
-        let mut best_feature = 0;
-        let mut best_threshold = 0.0;
-        let mut best_left_indices = Vec::new();
-        let mut best_right_indices = Vec::new();
-        
-        for feature_idx in 0..n_features {
-            let unique_values: Vec<f64> = x.iter()
-                .map(|row| row[feature_idx])
-                .collect::<HashSet<f64>>()
-                .into_iter()
-                .collect();
-                
-            if unique_values.len() <= 1 {
-                continue;
-            }
-            
-            for &value in &unique_values {
-                let mut left_indices = Vec::new();
-                let mut right_indices = Vec::new();
-                
-                for i in 0..n_samples {
-                    if x[i][feature_idx] <= value {
-                        left_indices.push(i);
-                    } else {
-                        right_indices.push(i);
-                    }
-                }
-                
-                if left_indices.is_empty() || right_indices.is_empty() {
-                    continue;
-                }
-                
-                let mut y_left = Vec::with_capacity(left_indices.len());
-                for &idx in &left_indices {
-                    y_left.push(y[idx]);
-                }
-                
-                let mut y_right = Vec::with_capacity(right_indices.len());
-                for &idx in &right_indices {
-                    y_right.push(y[idx]);
-                }
-                
-                let gini = Self::gini_impurity(&y_left, &y_right);
-                
-                if gini < best_gini {
-                    best_gini = gini;
-                    best_feature = feature_idx;
-                    best_threshold = value;
-                    best_left_indices = left_indices;
-                    best_right_indices = right_indices;
-                }
-            }
-        }
-        
-        (best_feature, best_threshold, best_left_indices, best_right_indices)
+
+    #[wasm_bindgen(js_name = "minMaxScaler")]
+    pub fn min_max_scaler(data: &Float64Array, n_features: usize) -> Result<Model, JsValue> {
+        fit(Estimator::MinMaxScaler, data, Vec::new(), n_features, None)
     }
-    
-    fn gini_impurity(left: &[u32], right: &[u32]) -> f64 {
-        let gini_left = Self::calculate_gini(left);
-        let gini_right = Self::calculate_gini(right);
-        
-        let total_samples = left.len() + right.len();
-        let weighted_gini = (left.len() as f64 / total_samples as f64) * gini_left
-            + (right.len() as f64 / total_samples as f64) * gini_right;
-            
-        weighted_gini
+
+    #[wasm_bindgen(js_name = "trainTestSplit")]
+    pub fn train_test_split(n_samples: usize, test_ratio: f64, seed: Option<u32>) -> Result<Object, JsValue> {
+        let mut rng = rng_from_seed(seed.map(u64::from));
+        let split = ml::train_test_split(n_samples, test_ratio, &mut rng).map_err(js_error)?;
+        Ok(split_object(&split))
     }
-    
-    fn calculate_gini(y: &[u32]) -> f64 {
-        if y.is_empty() {
-            return 0.0;
-        }
-        
-        let mut counts = HashMap::new();
-        let total = y.len() as f64;
-        
-        for &label in y {
-            *counts.entry(label).or_insert(0.0) += 1.0;
-        }
-        
-        let mut gini = 1.0;
-        for &count in counts.values() {
-            let p = count / total;
-            gini -= p * p;
-        }
-        
-        gini
+
+    #[wasm_bindgen(js_name = "kFold")]
+    pub fn k_fold(n_samples: usize, folds: usize, seed: Option<u32>) -> Result<Array, JsValue> {
+        let mut rng = rng_from_seed(seed.map(u64::from));
+        let splits = ml::k_fold(n_samples, folds, &mut rng).map_err(js_error)?;
+        Ok(splits.iter().map(|split| JsValue::from(split_object(split))).collect())
     }
-    
-    fn serialize_tree(node: &TreeNode) -> Object {
-        let result = Object::new();
-        
-        match node {
-            TreeNode::Leaf { prediction } => {
-                Reflect::set(&result, &JsValue::from_str("prediction"), &JsValue::from_f64(*prediction as f64)).unwrap();
-            },
-            TreeNode::Internal { feature_index, threshold, left, right } => {
-                Reflect::set(&result, &JsValue::from_str("featureIndex"), &JsValue::from_f64(*feature_index as f64)).unwrap();
-                Reflect::set(&result, &JsValue::from_str("threshold"), &JsValue::from_f64(*threshold)).unwrap();
-                Reflect::set(&result, &JsValue::from_str("left"), &Self::serialize_tree(left)).unwrap();
-                Reflect::set(&result, &JsValue::from_str("right"), &Self::serialize_tree(right)).unwrap();
-            }
-        }
-        
-        result
+
+    #[wasm_bindgen(js_name = "crossValidate")]
+    pub fn cross_validate(
+        estimator: &JsValue,
+        x: &Float64Array,
+        y: &Float64Array,
+        n_features: usize,
+        folds: usize,
+        seed: Option<u32>
+    ) -> Result<Float64Array, JsValue> {
+        let estimator = estimator_from_js(estimator)?;
+        let x = rows(x, n_features)?;
+        let mut rng = rng_from_seed(seed.map(u64::from));
+        
+        let scores = ml::cross_validate(&estimator, &x, &y.to_vec(), folds, &mut rng).map_err(js_error)?;
+        Ok(Float64Array::from(&scores[..]))
     }
+}
 
-    #[wasm_bindgen(js_name = "linearRegression")]
-    pub fn linear_regression(x_train: &Float64Array, y_train: &Float64Array, n_features: usize) -> Object {
-        let n_samples = x_train.length() as usize / n_features;
-        
-        if y_train.length() as usize != n_samples {
-            let error = Object::new();
-            Reflect::set(&error, &JsValue::from_str("error"), &JsValue::from_str("Length of y_train must match number of samples in x_train")).unwrap();
-            return error;
-        }
-        
-        let mut x = Vec::with_capacity(n_samples);
-        for i in 0..n_samples {
-            let mut row = Vec::with_capacity(n_features + 1);
-            row.push(1.0);
-            for j in 0..n_features {
-                row.push(x_train.get_index((i * n_features + j) as u32));
-            }
-            x.push(row);
-        }
-        
-        let mut y = Vec::with_capacity(n_samples);
-        for i in 0..n_samples {
-            y.push(y_train.get_index(i as u32));
-        }
+fn confusion(y_true: &Uint32Array, y_pred: &Uint32Array) -> Result<ConfusionMatrix, JsValue> {
+    ml::confusion_matrix(&y_true.to_vec(), &y_pred.to_vec()).map_err(js_error)
+}
+
+#[wasm_bindgen]
+impl Metrics {
+    #[wasm_bindgen(js_name = "accuracy")]
+    pub fn accuracy(y_true: &Uint32Array, y_pred: &Uint32Array) -> Result<f64, JsValue> {
+        Ok(confusion(y_true, y_pred)?.accuracy())
+    }
+
+    #[wasm_bindgen(js_name = "precision")]
+    pub fn precision(y_true: &Uint32Array, y_pred: &Uint32Array, label: Option<u32>) -> Result<f64, JsValue> {
+        Ok(confusion(y_true, y_pred)?.precision(label))
+    }
+
+    #[wasm_bindgen(js_name = "recall")]
+    pub fn recall(y_true: &Uint32Array, y_pred: &Uint32Array, label: Option<u32>) -> Result<f64, JsValue> {
+        Ok(confusion(y_true, y_pred)?.recall(label))
+    }
+
+    #[wasm_bindgen(js_name = "f1Score")]
+    pub fn f1_score(y_true: &Uint32Array, y_pred: &Uint32Array, label: Option<u32>) -> Result<f64, JsValue> {
+        Ok(confusion(y_true, y_pred)?.f1_score(label))
+    }
+
+    #[wasm_bindgen(js_name = "confusionMatrix")]
+    pub fn confusion_matrix(y_true: &Uint32Array, y_pred: &Uint32Array) -> Result<Object, JsValue> {
+        let matrix = confusion(y_true, y_pred)?;
         
-        let coefficients = Self::compute_coefficients(&x, &y, n_features + 1);
+        let rows: Array = matrix.matrix
+            .iter()
+            .map(|row| JsValue::from(Uint32Array::from(&row.iter().map(|&c| c as u32).collect::<Vec<u32>>()[..])))
+            .collect();
         
         let result = Object::new();
-        let coef_array = Float64Array::new_with_length((n_features + 1) as u32);
-        
-        for (i, &coef) in coefficients.iter().enumerate() {
-            coef_array.set_index(i as u32, coef);
-        }
+        Reflect::set(&result, &JsValue::from_str("labels"), &Uint32Array::from(&matrix.labels[..])).unwrap();
+        Reflect::set(&result, &JsValue::from_str("matrix"), &rows).unwrap();
         
-        Reflect::set(&result, &JsValue::from_str("coefficients"), &coef_array).unwrap();
-        Reflect::set(&result, &JsValue::from_str("intercept"), &JsValue::from_f64(coefficients[0])).unwrap();
-        
-        result
+        Ok(result)
     }
-    
-    #[wasm_bindgen(js_name = "predictLinearRegression")]
-    pub fn predict_linear_regression(model: &Object, x_test: &Float64Array, n_features: usize) -> Float64Array {
-        let n_samples = x_test.length() as usize / n_features;
-        let coefficients = Reflect::get(model, &JsValue::from_str("coefficients")).unwrap();
-        let coef_array = coefficients.dyn_into::<Float64Array>().unwrap();
-        
-        let result = Float64Array::new_with_length(n_samples as u32);
-        
-        for i in 0..n_samples {
-            let mut prediction = coef_array.get_index(0);
-            
-            for j in 0..n_features {
-                prediction += x_test.get_index((i * n_features + j) as u32) * coef_array.get_index(j as u32 + 1);
-            }
-            
-            result.set_index(i as u32, prediction);
-        }
-        
-        result
+
+    #[wasm_bindgen(js_name = "r2Score")]
+    pub fn r2_score(y_true: &Float64Array, y_pred: &Float64Array) -> Result<f64, JsValue> {
+        ml::r2_score(&y_true.to_vec(), &y_pred.to_vec()).map_err(js_error)
     }
-    
-    fn compute_coefficients(x: &[Vec<f64>], y: &[f64], n_features: usize) -> Vec<f64> {
-        let n_samples = x.len();
-        
-        let mut x_transpose = vec![vec![0.0; n_samples]; n_features];
-        for i in 0..n_samples {
-            for j in 0..n_features {
-                x_transpose[j][i] = x[i][j];
-            }
-        }
-        
-        let mut xt_x = vec![vec![0.0; n_features]; n_features];
-        for i in 0..n_features {
-            for j in 0..n_features {
-                let mut sum = 0.0;
-                for k in 0..n_samples {
-                    sum += x_transpose[i][k] * x[k][j];
-                }
-                xt_x[i][j] = sum;
-            }
-        }
-        
-        let xt_x_inv = Self::matrix_inverse(&xt_x);
-        
-        let mut xt_y = vec![0.0; n_features];
-        for i in 0..n_features {
-            let mut sum = 0.0;
-            for j in 0..n_samples {
-                sum += x_transpose[i][j] * y[j];
-            }
-            xt_y[i] = sum;
-        }
-        
-        let mut coefficients = vec![0.0; n_features];
-        for i in 0..n_features {
-            let mut sum = 0.0;
-            for j in 0..n_features {
-                sum += xt_x_inv[i][j] * xt_y[j];
-            }
-            coefficients[i] = sum;
-        }
-        
-        coefficients
+
+    #[wasm_bindgen(js_name = "meanSquaredError")]
+    pub fn mean_squared_error(y_true: &Float64Array, y_pred: &Float64Array) -> Result<f64, JsValue> {
+        ml::mean_squared_error(&y_true.to_vec(), &y_pred.to_vec()).map_err(js_error)
     }
-    
-    fn matrix_inverse(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
-        let n = matrix.len();
-        
-        let mut augmented = vec![vec![0.0; 2 * n]; n];
-        for i in 0..n {
-            for j in 0..n {
-                augmented[i][j] = matrix[i][j];
-            }
-            augmented[i][i + n] = 1.0;
-        }
-        
-        for i in 0..n {
-            let mut max_val = augmented[i][i].abs();
-            let mut max_row = i;
-            
-            for j in i+1..n {
-                let abs_val = augmented[j][i].abs();
-                if abs_val > max_val {
-                    max_val = abs_val;
-                    max_row = j;
-                }
-            }
-            
-            if max_row != i {
-                augmented.swap(i, max_row);
-            }
-            
-            let pivot = augmented[i][i];
-            for j in 0..2*n {
-                augmented[i][j] /= pivot;
-            }
-            
-            for j in 0..n {
-                if j != i {
-                    let factor = augmented[j][i];
-                    for k in 0..2*n {
-                        augmented[j][k] -= factor * augmented[i][k];
-                    }
-                }
-            }
-        }
-        
-        let mut inverse = vec![vec![0.0; n]; n];
-        for i in 0..n {
-            for j in 0..n {
-                inverse[i][j] = augmented[i][j + n];
-            }
-        }
-        
-        inverse
+
+    #[wasm_bindgen(js_name = "meanAbsoluteError")]
+    pub fn mean_absolute_error(y_true: &Float64Array, y_pred: &Float64Array) -> Result<f64, JsValue> {
+        ml::mean_absolute_error(&y_true.to_vec(), &y_pred.to_vec()).map_err(js_error)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+pub mod machine_learning;
+
+pub use machine_learning::*;
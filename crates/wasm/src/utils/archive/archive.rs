@@ -0,0 +1,264 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect, Uint8Array};
+use std::io::Read;
+use milost_core::archive::{
+    open_tar, TarEntry, TarOptions, TarReader, TarWriter, ZipEntry, ZipMethod, ZipOptions, ZipReader, ZipWriter,
+};
+use milost_core::compression;
+use crate::utils::{js_bytes, js_error};
+
+fn seconds(modified_ms: Option<f64>) -> Option<u64> {
+    modified_ms.map(|ms| (ms.max(0.0) / 1000.0) as u64)
+}
+
+fn zip_entry_object(entry: &ZipEntry) -> Object {
+    let result = Object::new();
+    Reflect::set(&result, &JsValue::from_str("name"), &JsValue::from_str(&entry.name)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("size"), &JsValue::from_f64(entry.size as f64)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("compressedSize"), &JsValue::from_f64(entry.compressed_size as f64)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("method"), &JsValue::from_str(entry.compression().map(|m| m.name()).unwrap_or("unknown"))).unwrap();
+    Reflect::set(&result, &JsValue::from_str("crc32"), &JsValue::from_f64(entry.crc32 as f64)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("modified"), &JsValue::from_f64(entry.modified as f64 * 1000.0)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("isDirectory"), &JsValue::from_bool(entry.is_dir())).unwrap();
+    result
+}
+
+fn tar_entry_object(entry: &TarEntry, data: Option<&[u8]>) -> Object {
+    let result = Object::new();
+    Reflect::set(&result, &JsValue::from_str("path"), &JsValue::from_str(&entry.path)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("type"), &JsValue::from_str(entry.kind.name())).unwrap();
+    Reflect::set(&result, &JsValue::from_str("size"), &JsValue::from_f64(entry.size as f64)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("mode"), &JsValue::from_f64(entry.mode as f64)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("modified"), &JsValue::from_f64(entry.modified as f64 * 1000.0)).unwrap();
+    
+    if let Some(link) = &entry.link_name {
+        Reflect::set(&result, &JsValue::from_str("linkName"), &JsValue::from_str(link)).unwrap();
+    }
+    if let Some(data) = data {
+        Reflect::set(&result, &JsValue::from_str("data"), &Uint8Array::from(data)).unwrap();
+    }
+    
+    result
+}
+
+#[wasm_bindgen]
+pub struct ZipArchive {
+    data: Vec<u8>,
+    entries: Vec<ZipEntry>,
+}
+
+#[wasm_bindgen]
+impl ZipArchive {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: &[u8]) -> Result<ZipArchive, JsValue> {
+        let entries = ZipReader::new(data).map_err(js_error)?.entries().to_vec();
+        Ok(ZipArchive { data: data.to_vec(), entries })
+    }
+
+    fn reader(&self) -> Result<ZipReader<'_>, JsValue> {
+        ZipReader::new(&self.data).map_err(js_error)
+    }
+
+    #[wasm_bindgen(getter, js_name = "entryCount")]
+    pub fn entry_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn comment(&self) -> Result<String, JsValue> {
+        Ok(self.reader()?.comment().to_string())
+    }
+
+    pub fn entries(&self) -> Array {
+        self.entries.iter().map(|entry| JsValue::from(zip_entry_object(entry))).collect()
+    }
+
+    pub fn entry(&self, index: usize) -> JsValue {
+        self.entries.get(index).map(|entry| zip_entry_object(entry).into()).unwrap_or(JsValue::NULL)
+    }
+
+    pub fn read(&self, name: &str) -> Result<Uint8Array, JsValue> {
+        js_bytes(self.reader()?.read_file(name))
+    }
+
+    #[wasm_bindgen(js_name = "readAt")]
+    pub fn read_at(&self, index: usize) -> Result<Uint8Array, JsValue> {
+        let entry = self.entries.get(index).ok_or_else(|| js_error("ZIP entry index out of range"))?;
+        js_bytes(self.reader()?.read(entry))
+    }
+
+    #[wasm_bindgen(js_name = "extractAll")]
+    pub fn extract_all(&self) -> Result<Object, JsValue> {
+        let result = Object::new();
+        
+        for (name, data) in self.reader()?.extract_all().map_err(js_error)? {
+            Reflect::set(&result, &JsValue::from_str(&name), &Uint8Array::from(&data[..])).unwrap();
+        }
+        
+        Ok(result)
+    }
+}
+
+#[wasm_bindgen]
+pub struct ZipBuilder {
+    writer: Option<ZipWriter<Vec<u8>>>,
+}
+
+impl Default for ZipBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl ZipBuilder {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        ZipBuilder {
+            writer: Some(ZipWriter::new(Vec::new())),
+        }
+    }
+
+    fn writer(&mut self) -> Result<&mut ZipWriter<Vec<u8>>, JsValue> {
+        self.writer.as_mut().ok_or_else(|| js_error("ZIP archive already finished"))
+    }
+
+    #[wasm_bindgen(js_name = "addFile")]
+    pub fn add_file(
+        &mut self,
+        name: &str,
+        data: &[u8],
+        method: Option<String>,
+        level: Option<u32>,
+        modified: Option<f64>,
+    ) -> Result<(), JsValue> {
+        let method = match method {
+            Some(method) => method.parse::<ZipMethod>().map_err(js_error)?,
+            None => ZipMethod::Deflate,
+        };
+        let options = ZipOptions { method, level, modified: seconds(modified), mode: None };
+        
+        self.writer()?.add_file(name, data, options).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "addDirectory")]
+    pub fn add_directory(&mut self, name: &str, modified: Option<f64>) -> Result<(), JsValue> {
+        let options = ZipOptions { modified: seconds(modified), ..ZipOptions::default() };
+        self.writer()?.add_directory(name, options).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "setComment")]
+    pub fn set_comment(&mut self, comment: &str) -> Result<(), JsValue> {
+        self.writer()?.set_comment(comment);
+        Ok(())
+    }
+
+    pub fn finish(&mut self) -> Result<Uint8Array, JsValue> {
+        let writer = self.writer.take().ok_or_else(|| js_error("ZIP archive already finished"))?;
+        js_bytes(writer.finish())
+    }
+}
+
+#[wasm_bindgen]
+pub struct TarArchive {
+    reader: TarReader<Box<dyn Read>>,
+}
+
+#[wasm_bindgen]
+impl TarArchive {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<u8>) -> TarArchive {
+        TarArchive {
+            reader: open_tar(data),
+        }
+    }
+
+    #[wasm_bindgen(js_name = "nextEntry")]
+    pub fn next_entry(&mut self) -> Result<JsValue, JsValue> {
+        let Some(entry) = self.reader.next_entry().map_err(js_error)? else {
+            return Ok(JsValue::NULL);
+        };
+        
+        let data = self.reader.read_data().map_err(js_error)?;
+        Ok(tar_entry_object(&entry, Some(&data)).into())
+    }
+
+    #[wasm_bindgen(js_name = "nextHeader")]
+    pub fn next_header(&mut self) -> Result<JsValue, JsValue> {
+        match self.reader.next_entry().map_err(js_error)? {
+            Some(entry) => Ok(tar_entry_object(&entry, None).into()),
+            None => Ok(JsValue::NULL),
+        }
+    }
+
+    #[wasm_bindgen(js_name = "readData")]
+    pub fn read_data(&mut self) -> Result<Uint8Array, JsValue> {
+        js_bytes(self.reader.read_data())
+    }
+
+    #[wasm_bindgen(js_name = "extractAll")]
+    pub fn extract_all(&mut self) -> Result<Array, JsValue> {
+        let result = Array::new();
+        
+        for item in &mut self.reader {
+            let (entry, data) = item.map_err(js_error)?;
+            result.push(&tar_entry_object(&entry, Some(&data)));
+        }
+        
+        Ok(result)
+    }
+}
+
+#[wasm_bindgen]
+pub struct TarBuilder {
+    writer: Option<TarWriter<Vec<u8>>>,
+}
+
+impl Default for TarBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl TarBuilder {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        TarBuilder {
+            writer: Some(TarWriter::new(Vec::new())),
+        }
+    }
+
+    fn writer(&mut self) -> Result<&mut TarWriter<Vec<u8>>, JsValue> {
+        self.writer.as_mut().ok_or_else(|| js_error("TAR archive already finished"))
+    }
+
+    #[wasm_bindgen(js_name = "addFile")]
+    pub fn add_file(&mut self, path: &str, data: &[u8], mode: Option<u32>, modified: Option<f64>) -> Result<(), JsValue> {
+        let options = TarOptions { mode, modified: seconds(modified) };
+        self.writer()?.append_file(path, data, options).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "addDirectory")]
+    pub fn add_directory(&mut self, path: &str, mode: Option<u32>, modified: Option<f64>) -> Result<(), JsValue> {
+        let options = TarOptions { mode, modified: seconds(modified) };
+        self.writer()?.append_directory(path, options).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "addSymlink")]
+    pub fn add_symlink(&mut self, path: &str, target: &str, modified: Option<f64>) -> Result<(), JsValue> {
+        let options = TarOptions { mode: None, modified: seconds(modified) };
+        self.writer()?.append_symlink(path, target, options).map_err(js_error)
+    }
+
+    pub fn finish(&mut self, gzip: Option<bool>, level: Option<u32>) -> Result<Uint8Array, JsValue> {
+        let writer = self.writer.take().ok_or_else(|| js_error("TAR archive already finished"))?;
+        let tar = writer.finish().map_err(js_error)?;
+        
+        if gzip.unwrap_or(false) {
+            js_bytes(compression::compress_gzip(&tar, level))
+        } else {
+            Ok(Uint8Array::from(&tar[..]))
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod compression;
 pub mod huffman_node;
+pub mod stream;
 
 pub use huffman_node::*;
-pub use compression::*;
\ No newline at end of file
+pub use compression::*;
+pub use stream::*;
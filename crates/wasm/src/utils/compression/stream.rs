@@ -0,0 +1,211 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Function, Object, Reflect, Uint8Array};
+use web_sys::{TransformStream, TransformStreamDefaultController};
+use std::cell::RefCell;
+use std::rc::Rc;
+use milost_core::compression::{ChunkedStream, CompressionFormat, Progress, StreamDecoder, StreamEncoder, STREAM_CHUNK_SIZE};
+use crate::utils::js_error;
+
+fn progress_object(progress: Progress) -> Object {
+    let result = Object::new();
+    Reflect::set(&result, &JsValue::from_str("bytesIn"), &JsValue::from_f64(progress.bytes_in as f64)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("bytesOut"), &JsValue::from_f64(progress.bytes_out as f64)).unwrap();
+    Reflect::set(&result, &JsValue::from_str("ratio"), &JsValue::from_f64(progress.ratio())).unwrap();
+    result
+}
+
+struct StreamHandle<S: ChunkedStream> {
+    stream: Rc<RefCell<S>>,
+    on_progress: Rc<RefCell<Option<Function>>>,
+}
+
+impl<S: ChunkedStream + 'static> StreamHandle<S> {
+    fn new(stream: S) -> Self {
+        StreamHandle {
+            stream: Rc::new(RefCell::new(stream)),
+            on_progress: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    fn report(stream: &RefCell<S>, on_progress: &RefCell<Option<Function>>) {
+        if let Some(callback) = on_progress.borrow().as_ref() {
+            let progress = stream.borrow().progress();
+            let _ = callback.call1(&JsValue::NULL, &progress_object(progress));
+        }
+    }
+
+    fn write(&self, chunk: &[u8]) -> Result<(), JsValue> {
+        self.stream.borrow_mut().write_chunk(chunk).map_err(js_error)?;
+        Self::report(&self.stream, &self.on_progress);
+        Ok(())
+    }
+
+    fn push(&self, chunk: &[u8]) -> Result<Uint8Array, JsValue> {
+        self.write(chunk)?;
+        self.read(None)
+    }
+
+    fn read(&self, max: Option<usize>) -> Result<Uint8Array, JsValue> {
+        let output = self.stream.borrow_mut().take_output(max).map_err(js_error)?;
+        Ok(Uint8Array::from(&output[..]))
+    }
+
+    fn flush(&self) -> Result<Uint8Array, JsValue> {
+        let output = self.stream.borrow_mut().sync_flush().map_err(js_error)?;
+        Ok(Uint8Array::from(&output[..]))
+    }
+
+    fn finish(&self) -> Result<Uint8Array, JsValue> {
+        let output = self.stream.borrow_mut().finish().map_err(js_error)?;
+        Self::report(&self.stream, &self.on_progress);
+        Ok(Uint8Array::from(&output[..]))
+    }
+
+    fn enqueue(stream: &RefCell<S>, controller: &TransformStreamDefaultController, chunk_size: usize) -> Result<(), JsValue> {
+        loop {
+            let output = stream.borrow_mut().take_output(Some(chunk_size)).map_err(js_error)?;
+            if output.is_empty() {
+                return Ok(());
+            }
+            controller.enqueue_with_chunk(&Uint8Array::from(&output[..]))?;
+        }
+    }
+
+    fn transform_stream(&self, chunk_size: Option<usize>) -> Result<TransformStream, JsValue> {
+        let chunk_size = chunk_size.filter(|&size| size > 0).unwrap_or(STREAM_CHUNK_SIZE);
+        
+        let stream = self.stream.clone();
+        let on_progress = self.on_progress.clone();
+        let transform = Closure::wrap(Box::new(move |chunk: JsValue, controller: TransformStreamDefaultController| -> Result<(), JsValue> {
+            let bytes = Uint8Array::new(&chunk).to_vec();
+            stream.borrow_mut().write_chunk(&bytes).map_err(js_error)?;
+            Self::report(&stream, &on_progress);
+            Self::enqueue(&stream, &controller, chunk_size)
+        }) as Box<dyn FnMut(JsValue, TransformStreamDefaultController) -> Result<(), JsValue>>);
+        
+        let stream = self.stream.clone();
+        let on_progress = self.on_progress.clone();
+        let flush = Closure::wrap(Box::new(move |controller: TransformStreamDefaultController| -> Result<(), JsValue> {
+            Self::enqueue(&stream, &controller, chunk_size)?;
+            stream.borrow_mut().close().map_err(js_error)?;
+            Self::report(&stream, &on_progress);
+            Self::enqueue(&stream, &controller, chunk_size)
+        }) as Box<dyn FnMut(TransformStreamDefaultController) -> Result<(), JsValue>>);
+        
+        let transformer = Object::new();
+        Reflect::set(&transformer, &JsValue::from_str("transform"), &transform.into_js_value()).unwrap();
+        Reflect::set(&transformer, &JsValue::from_str("flush"), &flush.into_js_value()).unwrap();
+        
+        TransformStream::new_with_transformer(&transformer)
+    }
+}
+
+fn parse_format(format: &str) -> Result<CompressionFormat, JsValue> {
+    format.parse().map_err(js_error)
+}
+
+#[wasm_bindgen]
+pub struct StreamCompressor {
+    handle: StreamHandle<StreamEncoder>,
+}
+
+#[wasm_bindgen]
+impl StreamCompressor {
+    #[wasm_bindgen(constructor)]
+    pub fn new(format: &str, level: Option<u32>) -> Result<StreamCompressor, JsValue> {
+        let encoder = StreamEncoder::new(parse_format(format)?, level).map_err(js_error)?;
+        Ok(StreamCompressor { handle: StreamHandle::new(encoder) })
+    }
+
+    pub fn push(&self, chunk: &[u8]) -> Result<Uint8Array, JsValue> {
+        self.handle.push(chunk)
+    }
+
+    pub fn write(&self, chunk: &[u8]) -> Result<(), JsValue> {
+        self.handle.write(chunk)
+    }
+
+    pub fn read(&self, max: Option<usize>) -> Result<Uint8Array, JsValue> {
+        self.handle.read(max)
+    }
+
+    pub fn flush(&self) -> Result<Uint8Array, JsValue> {
+        self.handle.flush()
+    }
+
+    pub fn finish(&self) -> Result<Uint8Array, JsValue> {
+        self.handle.finish()
+    }
+
+    #[wasm_bindgen(getter, js_name = "pendingOutput")]
+    pub fn pending_output(&self) -> usize {
+        self.handle.stream.borrow().pending_output()
+    }
+
+    pub fn progress(&self) -> Object {
+        progress_object(self.handle.stream.borrow().progress())
+    }
+
+    #[wasm_bindgen(js_name = "onProgress")]
+    pub fn on_progress(&self, callback: Option<Function>) {
+        *self.handle.on_progress.borrow_mut() = callback;
+    }
+
+    #[wasm_bindgen(js_name = "toTransformStream")]
+    pub fn to_transform_stream(&self, chunk_size: Option<usize>) -> Result<TransformStream, JsValue> {
+        self.handle.transform_stream(chunk_size)
+    }
+}
+
+#[wasm_bindgen]
+pub struct StreamDecompressor {
+    handle: StreamHandle<StreamDecoder>,
+}
+
+#[wasm_bindgen]
+impl StreamDecompressor {
+    #[wasm_bindgen(constructor)]
+    pub fn new(format: &str) -> Result<StreamDecompressor, JsValue> {
+        let decoder = StreamDecoder::new(parse_format(format)?);
+        Ok(StreamDecompressor { handle: StreamHandle::new(decoder) })
+    }
+
+    pub fn push(&self, chunk: &[u8]) -> Result<Uint8Array, JsValue> {
+        self.handle.push(chunk)
+    }
+
+    pub fn write(&self, chunk: &[u8]) -> Result<(), JsValue> {
+        self.handle.write(chunk)
+    }
+
+    pub fn read(&self, max: Option<usize>) -> Result<Uint8Array, JsValue> {
+        self.handle.read(max)
+    }
+
+    pub fn flush(&self) -> Result<Uint8Array, JsValue> {
+        self.handle.flush()
+    }
+
+    pub fn finish(&self) -> Result<Uint8Array, JsValue> {
+        self.handle.finish()
+    }
+
+    #[wasm_bindgen(getter, js_name = "pendingOutput")]
+    pub fn pending_output(&self) -> usize {
+        self.handle.stream.borrow().pending_output()
+    }
+
+    pub fn progress(&self) -> Object {
+        progress_object(self.handle.stream.borrow().progress())
+    }
+
+    #[wasm_bindgen(js_name = "onProgress")]
+    pub fn on_progress(&self, callback: Option<Function>) {
+        *self.handle.on_progress.borrow_mut() = callback;
+    }
+
+    #[wasm_bindgen(js_name = "toTransformStream")]
+    pub fn to_transform_stream(&self, chunk_size: Option<usize>) -> Result<TransformStream, JsValue> {
+        self.handle.transform_stream(chunk_size)
+    }
+}
@@ -1,11 +1,36 @@
 use wasm_bindgen::prelude::*;
-use js_sys::{Array, Uint8Array};
-use sha2::{Sha256, Sha512, Digest};
-use hmac::{Hmac, Mac, digest::KeyInit};
-use aes_gcm::{
-    aead::{Aead, KeyInit as AeadKeyInit, AeadCore, OsRng},
-    Aes256Gcm, Nonce
-};
+use js_sys::{Object, Reflect, Uint8Array};
+use milost_core::crypto::{self, Argon2Params, AsymmetricKey, HashAlgorithm, Jwk, KeyAlgorithm};
+use crate::utils::{js_bytes, js_error};
+use super::hash::parse_hash;
+
+fn hash_algorithm(hash: Option<String>) -> Result<HashAlgorithm, JsValue> {
+    parse_hash(hash.as_deref().unwrap_or("sha256"))
+}
+
+fn key_algorithm(algorithm: &str) -> Result<KeyAlgorithm, JsValue> {
+    algorithm.parse().map_err(js_error)
+}
+
+fn key_object(key: &AsymmetricKey) -> Object {
+    let result = Object::new();
+    Reflect::set(&result, &JsValue::from_str("algorithm"), &JsValue::from_str(key.algorithm.name())).unwrap();
+    Reflect::set(&result, &JsValue::from_str("publicKey"), &Uint8Array::from(&key.public_key[..])).unwrap();
+    if let Some(secret_key) = &key.secret_key {
+        Reflect::set(&result, &JsValue::from_str("secretKey"), &Uint8Array::from(&secret_key[..])).unwrap();
+    }
+    result
+}
+
+fn asymmetric_key(algorithm: &str, public_key: Option<Vec<u8>>, secret_key: Option<Vec<u8>>) -> Result<AsymmetricKey, JsValue> {
+    let algorithm = key_algorithm(algorithm)?;
+    let key = match (secret_key, public_key) {
+        (Some(secret_key), _) => AsymmetricKey::private(algorithm, &secret_key),
+        (None, Some(public_key)) => AsymmetricKey::public(algorithm, &public_key),
+        (None, None) => return Err(JsValue::from_str("Either a public or a secret key is required")),
+    };
+    key.map_err(js_error)
+}
 
 #[wasm_bindgen]
 pub struct Crypto;
@@ -14,78 +39,144 @@ pub struct Crypto;
 impl Crypto {
     #[wasm_bindgen(js_name = "sha256")]
     pub fn sha256(data: &[u8]) -> Vec<u8> {
-        let mut hasher = Sha256::new();
-        hasher.update(data);
-        hasher.finalize().to_vec()
+        crypto::sha256(data)
     }
 
     #[wasm_bindgen(js_name = "sha512")]
     pub fn sha512(data: &[u8]) -> Vec<u8> {
-        let mut hasher = Sha512::new();
-        hasher.update(data);
-        hasher.finalize().to_vec()
+        crypto::sha512(data)
+    }
+
+    pub fn hash(algorithm: &str, data: &[u8]) -> Result<Uint8Array, JsValue> {
+        Ok(Uint8Array::from(&crypto::hash(parse_hash(algorithm)?, data)[..]))
     }
 
     #[wasm_bindgen(js_name = "hmac256")]
     pub fn hmac_sha256(key: &[u8], data: &[u8]) -> Result<Vec<u8>, JsValue> {
-        let mut mac = <Hmac<Sha256> as KeyInit>::new_from_slice(key)
-            .map_err(|_| JsValue::from_str("Invalid key length"))?;
-        mac.update(data);
-        Ok(mac.finalize().into_bytes().to_vec())
+        crypto::hmac_sha256(key, data).map_err(js_error)
     }
 
     #[wasm_bindgen(js_name = "hmac512")]
     pub fn hmac_sha512(key: &[u8], data: &[u8]) -> Result<Vec<u8>, JsValue> {
-        let mut mac = <Hmac<Sha512> as KeyInit>::new_from_slice(key)
-            .map_err(|_| JsValue::from_str("Invalid key length"))?;
-        mac.update(data);
-        Ok(mac.finalize().into_bytes().to_vec())
+        crypto::hmac_sha512(key, data).map_err(js_error)
     }
 
     #[wasm_bindgen(js_name = "aesGcmEncrypt")]
-    pub fn aes_gcm_encrypt(key: &[u8], plaintext: &[u8]) -> Result<Uint8Array, JsValue> {
-        if key.len() != 32 {
-            return Err(JsValue::from_str("Key must be 32 bytes (256 bits)"));
-        }
+    pub fn aes_gcm_encrypt(key: &[u8], plaintext: &[u8], associated_data: Option<Vec<u8>>) -> Result<Uint8Array, JsValue> {
+        js_bytes(crypto::aes_gcm_encrypt(key, plaintext, &associated_data.unwrap_or_default()))
+    }
 
-        let cipher = Aes256Gcm::new_from_slice(key)
-            .map_err(|_| JsValue::from_str("Failed to create cipher"))?;
+    #[wasm_bindgen(js_name = "aesGcmDecrypt")]
+    pub fn aes_gcm_decrypt(key: &[u8], ciphertext: &[u8], associated_data: Option<Vec<u8>>) -> Result<Uint8Array, JsValue> {
+        js_bytes(crypto::aes_gcm_decrypt(key, ciphertext, &associated_data.unwrap_or_default()))
+    }
+
+    #[wasm_bindgen(js_name = "chacha20Poly1305Encrypt")]
+    pub fn chacha20_poly1305_encrypt(key: &[u8], plaintext: &[u8], associated_data: Option<Vec<u8>>) -> Result<Uint8Array, JsValue> {
+        js_bytes(crypto::chacha20_poly1305_encrypt(key, plaintext, &associated_data.unwrap_or_default()))
+    }
 
-        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
-        let ciphertext = cipher.encrypt(&nonce, plaintext)
-            .map_err(|_| JsValue::from_str("Encryption failed"))?;
+    #[wasm_bindgen(js_name = "chacha20Poly1305Decrypt")]
+    pub fn chacha20_poly1305_decrypt(key: &[u8], ciphertext: &[u8], associated_data: Option<Vec<u8>>) -> Result<Uint8Array, JsValue> {
+        js_bytes(crypto::chacha20_poly1305_decrypt(key, ciphertext, &associated_data.unwrap_or_default()))
+    }
 
-        let mut result = Vec::with_capacity(nonce.len() + ciphertext.len());
-        result.extend_from_slice(&nonce);
-        result.extend_from_slice(&ciphertext);
+    #[wasm_bindgen(js_name = "pbkdf2")]
+    pub fn pbkdf2(password: &[u8], salt: &[u8], iterations: u32, length: usize, hash: Option<String>) -> Result<Uint8Array, JsValue> {
+        js_bytes(crypto::pbkdf2(password, salt, iterations, length, hash_algorithm(hash)?))
+    }
 
-        Ok(Uint8Array::from(&result[..]))
+    #[wasm_bindgen(js_name = "argon2id")]
+    pub fn argon2id(
+        password: &[u8],
+        salt: &[u8],
+        length: Option<usize>,
+        memory_kib: Option<u32>,
+        iterations: Option<u32>,
+        parallelism: Option<u32>,
+    ) -> Result<Uint8Array, JsValue> {
+        let defaults = Argon2Params::default();
+        let params = Argon2Params {
+            memory_kib: memory_kib.unwrap_or(defaults.memory_kib),
+            iterations: iterations.unwrap_or(defaults.iterations),
+            parallelism: parallelism.unwrap_or(defaults.parallelism),
+        };
+        js_bytes(crypto::argon2id(password, salt, params, length.unwrap_or(32)))
     }
 
-    #[wasm_bindgen(js_name = "aesGcmDecrypt")]
-    pub fn aes_gcm_decrypt(key: &[u8], ciphertext: &[u8]) -> Result<Uint8Array, JsValue> {
-        if key.len() != 32 {
-            return Err(JsValue::from_str("Key must be 32 bytes (256 bits)"));
-        }
+    #[wasm_bindgen(js_name = "hkdf")]
+    pub fn hkdf(input_key: &[u8], salt: Option<Vec<u8>>, info: &[u8], length: usize, hash: Option<String>) -> Result<Uint8Array, JsValue> {
+        js_bytes(crypto::hkdf(input_key, salt.as_deref(), info, length, hash_algorithm(hash)?))
+    }
+
+    #[wasm_bindgen(js_name = "generateKeyPair")]
+    pub fn generate_key_pair(algorithm: &str) -> Result<Object, JsValue> {
+        let pair = key_algorithm(algorithm)?.generate_key_pair().map_err(js_error)?;
+        Ok(key_object(&pair.into()))
+    }
+
+    #[wasm_bindgen(js_name = "publicKey")]
+    pub fn public_key(algorithm: &str, secret_key: &[u8]) -> Result<Uint8Array, JsValue> {
+        js_bytes(key_algorithm(algorithm)?.public_key(secret_key))
+    }
+
+    #[wasm_bindgen(js_name = "ed25519Sign")]
+    pub fn ed25519_sign(secret_key: &[u8], message: &[u8]) -> Result<Uint8Array, JsValue> {
+        js_bytes(crypto::ed25519_sign(secret_key, message))
+    }
+
+    #[wasm_bindgen(js_name = "ed25519Verify")]
+    pub fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool, JsValue> {
+        crypto::ed25519_verify(public_key, message, signature).map_err(js_error)
+    }
 
-        if ciphertext.len() < 12 {
-            return Err(JsValue::from_str("Invalid ciphertext length"));
+    #[wasm_bindgen(js_name = "x25519SharedSecret")]
+    pub fn x25519_shared_secret(secret_key: &[u8], public_key: &[u8]) -> Result<Uint8Array, JsValue> {
+        js_bytes(crypto::x25519_shared_secret(secret_key, public_key))
+    }
+
+    #[wasm_bindgen(js_name = "exportJwk")]
+    pub fn export_jwk(algorithm: &str, public_key: Option<Vec<u8>>, secret_key: Option<Vec<u8>>) -> Result<String, JsValue> {
+        let key = asymmetric_key(algorithm, public_key, secret_key)?;
+        Jwk::from_key(&key).to_json().map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "importJwk")]
+    pub fn import_jwk(json: &str) -> Result<Object, JsValue> {
+        let jwk = Jwk::from_json(json).map_err(js_error)?;
+        if jwk.is_symmetric() {
+            let result = Object::new();
+            let key = jwk.to_symmetric_key().map_err(js_error)?;
+            Reflect::set(&result, &JsValue::from_str("algorithm"), &jwk.alg.map(JsValue::from).unwrap_or(JsValue::NULL)).unwrap();
+            Reflect::set(&result, &JsValue::from_str("key"), &Uint8Array::from(&key[..])).unwrap();
+            return Ok(result);
         }
+        Ok(key_object(&jwk.to_key().map_err(js_error)?))
+    }
 
-        let cipher = Aes256Gcm::new_from_slice(key)
-            .map_err(|_| JsValue::from_str("Failed to create cipher"))?;
+    #[wasm_bindgen(js_name = "exportSymmetricJwk")]
+    pub fn export_symmetric_jwk(key: &[u8], algorithm: Option<String>) -> Result<String, JsValue> {
+        Jwk::symmetric(key, algorithm.as_deref()).to_json().map_err(js_error)
+    }
 
-        let nonce = Nonce::from_slice(&ciphertext[..12]);
-        let plaintext = cipher.decrypt(nonce, &ciphertext[12..])
-            .map_err(|_| JsValue::from_str("Decryption failed"))?;
+    #[wasm_bindgen(js_name = "exportPem")]
+    pub fn export_pem(algorithm: &str, public_key: Option<Vec<u8>>, secret_key: Option<Vec<u8>>) -> Result<String, JsValue> {
+        let key = asymmetric_key(algorithm, public_key, secret_key)?;
+        if key.is_private() {
+            crypto::encode_private_key_pem(&key).map_err(js_error)
+        } else {
+            Ok(crypto::encode_public_key_pem(&key))
+        }
+    }
 
-        Ok(Uint8Array::from(&plaintext[..]))
+    #[wasm_bindgen(js_name = "importPem")]
+    pub fn import_pem(pem: &str) -> Result<Object, JsValue> {
+        crypto::decode_pem(pem).map(|key| key_object(&key)).map_err(js_error)
     }
     
     #[wasm_bindgen(js_name = "randomBytes")]
     pub fn random_bytes(length: usize) -> Uint8Array {
-        let mut bytes = vec![0u8; length];
-        getrandom::getrandom(&mut bytes).expect("Failed to generate random bytes");
+        let bytes = crypto::random_bytes(length).expect("Failed to generate random bytes");
         Uint8Array::from(&bytes[..])
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,121 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Object, Reflect, Uint8Array};
+use milost_core::crypto::{self, Argon2Params, PasswordKdf, SealKey, SealOptions};
+use crate::utils::{js_bytes, js_error};
+
+enum Secret {
+    Key(Vec<u8>),
+    Password(String),
+}
+
+impl Secret {
+    fn from_js(secret: &JsValue) -> Result<Secret, JsValue> {
+        if let Some(password) = secret.as_string() {
+            return Ok(Secret::Password(password));
+        }
+        if secret.is_instance_of::<Uint8Array>() {
+            return Ok(Secret::Key(Uint8Array::new(secret).to_vec()));
+        }
+        Err(JsValue::from_str("Secret must be a password string or a Uint8Array key"))
+    }
+
+    fn seal_key(&self) -> SealKey<'_> {
+        match self {
+            Secret::Key(key) => SealKey::Key(key),
+            Secret::Password(password) => SealKey::Password(password.as_bytes()),
+        }
+    }
+}
+
+fn option_string(options: &Object, name: &str) -> Result<Option<String>, JsValue> {
+    let value = Reflect::get(options, &JsValue::from_str(name))?;
+    if value.is_undefined() || value.is_null() {
+        return Ok(None);
+    }
+    value.as_string().map(Some).ok_or_else(|| JsValue::from_str(&format!("Option '{}' must be a string", name)))
+}
+
+fn option_u32(options: &Object, name: &str) -> Result<Option<u32>, JsValue> {
+    let value = Reflect::get(options, &JsValue::from_str(name))?;
+    if value.is_undefined() || value.is_null() {
+        return Ok(None);
+    }
+    value.as_f64()
+        .filter(|number| number.fract() == 0.0 && *number >= 0.0 && *number <= u32::MAX as f64)
+        .map(|number| Some(number as u32))
+        .ok_or_else(|| JsValue::from_str(&format!("Option '{}' must be a non-negative integer", name)))
+}
+
+fn seal_options(options: Option<Object>) -> Result<SealOptions, JsValue> {
+    let mut result = SealOptions::default();
+    let Some(options) = options else {
+        return Ok(result);
+    };
+
+    if let Some(cipher) = option_string(&options, "cipher")? {
+        result.cipher = cipher.parse().map_err(js_error)?;
+    }
+    if let Some(compression) = option_string(&options, "compression")? {
+        result.compression = compression.parse().map_err(js_error)?;
+    }
+    result.level = option_u32(&options, "level")?;
+
+    if let Some(kdf) = option_string(&options, "kdf")? {
+        result.kdf = kdf.parse().map_err(js_error)?;
+    }
+    result.kdf = match result.kdf {
+        PasswordKdf::Pbkdf2 { iterations } => PasswordKdf::Pbkdf2 {
+            iterations: option_u32(&options, "iterations")?.unwrap_or(iterations),
+        },
+        PasswordKdf::Argon2id(params) => PasswordKdf::Argon2id(Argon2Params {
+            memory_kib: option_u32(&options, "memoryKib")?.unwrap_or(params.memory_kib),
+            iterations: option_u32(&options, "iterations")?.unwrap_or(params.iterations),
+            parallelism: option_u32(&options, "parallelism")?.unwrap_or(params.parallelism),
+        }),
+    };
+
+    Ok(result)
+}
+
+#[wasm_bindgen]
+pub struct Envelope;
+
+#[wasm_bindgen]
+impl Envelope {
+    pub fn seal(data: &[u8], secret: &JsValue, options: Option<Object>) -> Result<Uint8Array, JsValue> {
+        let secret = Secret::from_js(secret)?;
+        js_bytes(crypto::seal(data, secret.seal_key(), &seal_options(options)?))
+    }
+
+    pub fn open(envelope: &[u8], secret: &JsValue) -> Result<Uint8Array, JsValue> {
+        let secret = Secret::from_js(secret)?;
+        js_bytes(crypto::open(envelope, secret.seal_key()))
+    }
+
+    pub fn inspect(envelope: &[u8]) -> Result<Object, JsValue> {
+        let header = crypto::read_envelope_header(envelope).map_err(js_error)?;
+
+        let result = Object::new();
+        Reflect::set(&result, &JsValue::from_str("cipher"), &JsValue::from_str(header.cipher.name())).unwrap();
+        Reflect::set(&result, &JsValue::from_str("compression"), &JsValue::from_str(header.compression.name())).unwrap();
+        Reflect::set(&result, &JsValue::from_str("passwordProtected"), &JsValue::from_bool(header.is_password_protected())).unwrap();
+
+        match header.kdf {
+            Some(PasswordKdf::Pbkdf2 { iterations }) => {
+                Reflect::set(&result, &JsValue::from_str("kdf"), &JsValue::from_str("pbkdf2")).unwrap();
+                Reflect::set(&result, &JsValue::from_str("iterations"), &JsValue::from(iterations)).unwrap();
+            }
+            Some(PasswordKdf::Argon2id(params)) => {
+                Reflect::set(&result, &JsValue::from_str("kdf"), &JsValue::from_str("argon2id")).unwrap();
+                Reflect::set(&result, &JsValue::from_str("memoryKib"), &JsValue::from(params.memory_kib)).unwrap();
+                Reflect::set(&result, &JsValue::from_str("iterations"), &JsValue::from(params.iterations)).unwrap();
+                Reflect::set(&result, &JsValue::from_str("parallelism"), &JsValue::from(params.parallelism)).unwrap();
+            }
+            None => {
+                Reflect::set(&result, &JsValue::from_str("kdf"), &JsValue::NULL).unwrap();
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,64 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Uint8Array;
+use milost_core::crypto::{self, HashAlgorithm};
+use crate::utils::js_error;
+
+pub(crate) fn parse_hash(algorithm: &str) -> Result<HashAlgorithm, JsValue> {
+    algorithm.parse().map_err(js_error)
+}
+
+#[wasm_bindgen]
+pub struct Hasher {
+    inner: Option<crypto::Hasher>,
+}
+
+#[wasm_bindgen]
+impl Hasher {
+    #[wasm_bindgen(constructor)]
+    pub fn new(algorithm: &str) -> Result<Hasher, JsValue> {
+        Ok(Hasher { inner: Some(crypto::Hasher::new(parse_hash(algorithm)?)) })
+    }
+
+    fn hasher(&mut self) -> Result<&mut crypto::Hasher, JsValue> {
+        self.inner.as_mut().ok_or_else(|| JsValue::from_str("Hasher already finalized"))
+    }
+
+    pub fn update(&mut self, data: &[u8]) -> Result<(), JsValue> {
+        self.hasher()?.update(data);
+        Ok(())
+    }
+
+    pub fn digest(&mut self) -> Result<Uint8Array, JsValue> {
+        let hasher = self.inner.take().ok_or_else(|| JsValue::from_str("Hasher already finalized"))?;
+        Ok(Uint8Array::from(&hasher.finalize()[..]))
+    }
+
+    #[wasm_bindgen(js_name = "digestHex")]
+    pub fn digest_hex(&mut self) -> Result<String, JsValue> {
+        Ok(self.digest()?.to_vec().iter().map(|byte| format!("{:02x}", byte)).collect())
+    }
+
+    pub fn reset(&mut self) -> Result<(), JsValue> {
+        let algorithm = match &self.inner {
+            Some(hasher) => hasher.algorithm(),
+            None => return Err(JsValue::from_str("Hasher already finalized")),
+        };
+        self.inner = Some(crypto::Hasher::new(algorithm));
+        Ok(())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn algorithm(&self) -> Option<String> {
+        self.inner.as_ref().map(|hasher| hasher.algorithm().name().to_string())
+    }
+
+    #[wasm_bindgen(getter, js_name = "bytesProcessed")]
+    pub fn bytes_processed(&self) -> f64 {
+        self.inner.as_ref().map_or(0.0, |hasher| hasher.bytes_processed() as f64)
+    }
+
+    #[wasm_bindgen(getter, js_name = "outputLength")]
+    pub fn output_length(&self) -> Option<usize> {
+        self.inner.as_ref().map(|hasher| hasher.algorithm().output_len())
+    }
+}
@@ -1,3 +1,9 @@
 pub mod crypto;
+pub mod envelope;
+pub mod hash;
+pub mod stream;
 
-pub use crypto::Crypto;
\ No newline at end of file
+pub use crypto::Crypto;
+pub use envelope::Envelope;
+pub use hash::Hasher;
+pub use stream::{StreamDecryption, StreamEncryption};
@@ -0,0 +1,139 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Object, Reflect, Uint8Array};
+use web_sys::{TransformStream, TransformStreamDefaultController};
+use std::cell::RefCell;
+use std::rc::Rc;
+use milost_core::crypto::{AeadAlgorithm, StreamDecryptor, StreamEncryptor, StreamHeader};
+use crate::utils::{js_bytes, js_error};
+
+trait SegmentStream {
+    fn push(&mut self, chunk: &[u8]) -> Result<Vec<u8>, JsValue>;
+    fn finish(&mut self) -> Result<Vec<u8>, JsValue>;
+}
+
+impl SegmentStream for StreamEncryptor {
+    fn push(&mut self, chunk: &[u8]) -> Result<Vec<u8>, JsValue> {
+        StreamEncryptor::push(self, chunk).map_err(js_error)
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>, JsValue> {
+        StreamEncryptor::finish(self).map_err(js_error)
+    }
+}
+
+impl SegmentStream for StreamDecryptor {
+    fn push(&mut self, chunk: &[u8]) -> Result<Vec<u8>, JsValue> {
+        StreamDecryptor::push(self, chunk).map_err(js_error)
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>, JsValue> {
+        StreamDecryptor::finish(self).map_err(js_error)
+    }
+}
+
+fn enqueue(controller: &TransformStreamDefaultController, output: Vec<u8>) -> Result<(), JsValue> {
+    if !output.is_empty() {
+        controller.enqueue_with_chunk(&Uint8Array::from(&output[..]))?;
+    }
+    Ok(())
+}
+
+fn transform_stream<S: SegmentStream + 'static>(stream: &Rc<RefCell<S>>) -> Result<TransformStream, JsValue> {
+    let state = stream.clone();
+    let transform = Closure::wrap(Box::new(move |chunk: JsValue, controller: TransformStreamDefaultController| -> Result<(), JsValue> {
+        let output = state.borrow_mut().push(&Uint8Array::new(&chunk).to_vec())?;
+        enqueue(&controller, output)
+    }) as Box<dyn FnMut(JsValue, TransformStreamDefaultController) -> Result<(), JsValue>>);
+
+    let state = stream.clone();
+    let flush = Closure::wrap(Box::new(move |controller: TransformStreamDefaultController| -> Result<(), JsValue> {
+        let output = state.borrow_mut().finish()?;
+        enqueue(&controller, output)
+    }) as Box<dyn FnMut(TransformStreamDefaultController) -> Result<(), JsValue>>);
+
+    let transformer = Object::new();
+    Reflect::set(&transformer, &JsValue::from_str("transform"), &transform.into_js_value()).unwrap();
+    Reflect::set(&transformer, &JsValue::from_str("flush"), &flush.into_js_value()).unwrap();
+
+    TransformStream::new_with_transformer(&transformer)
+}
+
+fn header_object(header: StreamHeader) -> Object {
+    let result = Object::new();
+    Reflect::set(&result, &JsValue::from_str("algorithm"), &JsValue::from_str(header.algorithm.name())).unwrap();
+    Reflect::set(&result, &JsValue::from_str("segmentSize"), &JsValue::from_f64(header.segment_size as f64)).unwrap();
+    result
+}
+
+#[wasm_bindgen]
+pub struct StreamEncryption {
+    inner: Rc<RefCell<StreamEncryptor>>,
+}
+
+#[wasm_bindgen]
+impl StreamEncryption {
+    #[wasm_bindgen(constructor)]
+    pub fn new(key: &[u8], algorithm: Option<String>, segment_size: Option<usize>) -> Result<StreamEncryption, JsValue> {
+        let algorithm: AeadAlgorithm = algorithm.as_deref().unwrap_or("aes-256-gcm").parse().map_err(js_error)?;
+        let encryptor = StreamEncryptor::new(algorithm, key, segment_size).map_err(js_error)?;
+        Ok(StreamEncryption { inner: Rc::new(RefCell::new(encryptor)) })
+    }
+
+    pub fn push(&self, chunk: &[u8]) -> Result<Uint8Array, JsValue> {
+        js_bytes(self.inner.borrow_mut().push(chunk))
+    }
+
+    pub fn finish(&self) -> Result<Uint8Array, JsValue> {
+        js_bytes(self.inner.borrow_mut().finish())
+    }
+
+    #[wasm_bindgen(getter, js_name = "isFinished")]
+    pub fn is_finished(&self) -> bool {
+        self.inner.borrow().is_finished()
+    }
+
+    pub fn header(&self) -> Object {
+        header_object(self.inner.borrow().header())
+    }
+
+    #[wasm_bindgen(js_name = "toTransformStream")]
+    pub fn to_transform_stream(&self) -> Result<TransformStream, JsValue> {
+        transform_stream(&self.inner)
+    }
+}
+
+#[wasm_bindgen]
+pub struct StreamDecryption {
+    inner: Rc<RefCell<StreamDecryptor>>,
+}
+
+#[wasm_bindgen]
+impl StreamDecryption {
+    #[wasm_bindgen(constructor)]
+    pub fn new(key: &[u8]) -> Result<StreamDecryption, JsValue> {
+        let decryptor = StreamDecryptor::new(key).map_err(js_error)?;
+        Ok(StreamDecryption { inner: Rc::new(RefCell::new(decryptor)) })
+    }
+
+    pub fn push(&self, chunk: &[u8]) -> Result<Uint8Array, JsValue> {
+        js_bytes(self.inner.borrow_mut().push(chunk))
+    }
+
+    pub fn finish(&self) -> Result<Uint8Array, JsValue> {
+        js_bytes(self.inner.borrow_mut().finish())
+    }
+
+    #[wasm_bindgen(getter, js_name = "isFinished")]
+    pub fn is_finished(&self) -> bool {
+        self.inner.borrow().is_finished()
+    }
+
+    pub fn header(&self) -> Option<Object> {
+        self.inner.borrow().header().map(header_object)
+    }
+
+    #[wasm_bindgen(js_name = "toTransformStream")]
+    pub fn to_transform_stream(&self) -> Result<TransformStream, JsValue> {
+        transform_stream(&self.inner)
+    }
+}
@@ -0,0 +1,94 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect, Uint32Array, Uint8ClampedArray};
+use milost_core::image::{self, DominantColorOptions, ImageHash, ImageHashAlgorithm, PaletteColor};
+use milost_ui::Color;
+use crate::utils::js_error;
+
+pub(crate) fn hash_algorithm(algorithm: Option<String>) -> Result<ImageHashAlgorithm, JsValue> {
+    algorithm.map_or(Ok(ImageHashAlgorithm::default()), |name| name.parse().map_err(js_error))
+}
+
+pub(crate) fn palette_to_array(colors: &[PaletteColor]) -> Array {
+    colors
+        .iter()
+        .map(|color| {
+            let entry = Object::new();
+            Reflect::set(&entry, &JsValue::from_str("r"), &JsValue::from(color.red)).unwrap();
+            Reflect::set(&entry, &JsValue::from_str("g"), &JsValue::from(color.green)).unwrap();
+            Reflect::set(&entry, &JsValue::from_str("b"), &JsValue::from(color.blue)).unwrap();
+            Reflect::set(&entry, &JsValue::from_str("hex"), &JsValue::from_str(&color.hex())).unwrap();
+            Reflect::set(&entry, &JsValue::from_str("css"), &JsValue::from_str(&Color::from(*color).to_css_string())).unwrap();
+            Reflect::set(&entry, &JsValue::from_str("proportion"), &JsValue::from_f64(color.proportion)).unwrap();
+            JsValue::from(entry)
+        })
+        .collect()
+}
+
+pub(crate) fn dominant_color_options(max_samples: Option<usize>, seed: Option<u32>) -> DominantColorOptions {
+    let defaults = DominantColorOptions::default();
+    DominantColorOptions {
+        max_samples: max_samples.unwrap_or(defaults.max_samples),
+        seed: seed.map_or(defaults.seed, u64::from),
+        ..defaults
+    }
+}
+
+fn parse_hash(hash: &str) -> Result<ImageHash, JsValue> {
+    ImageHash::from_hex(hash).map_err(js_error)
+}
+
+#[wasm_bindgen]
+pub struct ImageAnalysis;
+
+#[wasm_bindgen]
+impl ImageAnalysis {
+    pub fn histogram(image_data: &Uint8ClampedArray) -> Object {
+        let histogram = image::histogram(&image_data.to_vec());
+        let result = Object::new();
+        let channels = [
+            ("red", &histogram.red),
+            ("green", &histogram.green),
+            ("blue", &histogram.blue),
+            ("alpha", &histogram.alpha),
+            ("luminance", &histogram.luminance),
+        ];
+        for (name, counts) in channels {
+            Reflect::set(&result, &JsValue::from_str(name), &Uint32Array::from(&counts[..])).unwrap();
+        }
+        result
+    }
+
+    #[wasm_bindgen(js_name = "averageColor")]
+    pub fn average_color(image_data: &Uint8ClampedArray) -> Option<Vec<u8>> {
+        image::average_color(&image_data.to_vec()).map(|color| color.to_vec())
+    }
+
+    #[wasm_bindgen(js_name = "dominantColors")]
+    pub fn dominant_colors(image_data: &Uint8ClampedArray, count: usize, max_samples: Option<usize>,
+                           seed: Option<u32>) -> Result<Array, JsValue> {
+        let colors = image::dominant_colors(&image_data.to_vec(), count, dominant_color_options(max_samples, seed))
+            .map_err(js_error)?;
+        Ok(palette_to_array(&colors))
+    }
+
+    #[wasm_bindgen(js_name = "imageHash")]
+    pub fn image_hash(image_data: &Uint8ClampedArray, width: u32, height: u32, algorithm: Option<String>) -> Result<String, JsValue> {
+        image::image_hash(&image_data.to_vec(), width, height, hash_algorithm(algorithm)?)
+            .map(|hash| hash.to_hex())
+            .map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "hammingDistance")]
+    pub fn hamming_distance(a: &str, b: &str) -> Result<u32, JsValue> {
+        Ok(image::hamming_distance(&parse_hash(a)?, &parse_hash(b)?))
+    }
+
+    #[wasm_bindgen(js_name = "hashSimilarity")]
+    pub fn hash_similarity(a: &str, b: &str) -> Result<f64, JsValue> {
+        Ok(parse_hash(a)?.similarity(&parse_hash(b)?))
+    }
+
+    pub fn ssim(a: &Uint8ClampedArray, b: &Uint8ClampedArray, width: u32, height: u32) -> Result<f64, JsValue> {
+        image::ssim(&a.to_vec(), &b.to_vec(), width, height).map_err(js_error)
+    }
+}
@@ -0,0 +1,214 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Uint8Array, Uint8ClampedArray};
+use milost_core::image::{self, Filter, Image, ImageFormat, Transform};
+use crate::utils::{js_bytes, js_error};
+use super::analysis::{dominant_color_options, hash_algorithm, palette_to_array};
+use super::filters::{color_matrix_filter, convolve_filter, curves_filter, levels_filter, lut_filter};
+use super::image_processing::{resize_filter, warp_options};
+
+#[wasm_bindgen]
+pub struct ImageHandle {
+    inner: Image,
+}
+
+#[wasm_bindgen]
+impl ImageHandle {
+    pub fn decode(data: &[u8], auto_orient: Option<bool>) -> Result<ImageHandle, JsValue> {
+        let inner = Image::decode_with(data, auto_orient.unwrap_or(true)).map_err(js_error)?;
+        Ok(ImageHandle { inner })
+    }
+
+    #[wasm_bindgen(js_name = "fromRgba")]
+    pub fn from_rgba(data: &Uint8ClampedArray, width: u32, height: u32) -> Result<ImageHandle, JsValue> {
+        let inner = Image::from_rgba(width, height, data.to_vec()).map_err(js_error)?;
+        Ok(ImageHandle { inner })
+    }
+
+    #[wasm_bindgen(js_name = "detectFormat")]
+    pub fn detect_format(data: &[u8]) -> Option<String> {
+        image::detect_image_format(data).map(|format| format.name().to_string())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> u32 {
+        self.inner.width()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn height(&self) -> u32 {
+        self.inner.height()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn format(&self) -> Option<String> {
+        self.inner.source_format().map(|format| format.name().to_string())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn orientation(&self) -> u8 {
+        self.inner.orientation()
+    }
+
+    #[wasm_bindgen(js_name = "clone")]
+    pub fn duplicate(&self) -> ImageHandle {
+        ImageHandle { inner: self.inner.clone() }
+    }
+
+    pub fn resize(self, width: u32, height: u32, filter: Option<String>) -> Result<ImageHandle, JsValue> {
+        let inner = self.inner.resize(width, height, resize_filter(filter)?).map_err(js_error)?;
+        Ok(ImageHandle { inner })
+    }
+
+    #[wasm_bindgen(js_name = "resizeToFit")]
+    pub fn resize_to_fit(self, max_width: u32, max_height: u32, filter: Option<String>) -> Result<ImageHandle, JsValue> {
+        let inner = self.inner.resize_to_fit(max_width, max_height, resize_filter(filter)?).map_err(js_error)?;
+        Ok(ImageHandle { inner })
+    }
+
+    pub fn crop(self, x: u32, y: u32, width: u32, height: u32) -> Result<ImageHandle, JsValue> {
+        let inner = self.inner.crop(x, y, width, height).map_err(js_error)?;
+        Ok(ImageHandle { inner })
+    }
+
+    pub fn rotate(self, angle_degrees: f64, filter: Option<String>, edge_mode: Option<String>) -> Result<ImageHandle, JsValue> {
+        let inner = self.inner.rotate(angle_degrees, warp_options(filter, edge_mode)?).map_err(js_error)?;
+        Ok(ImageHandle { inner })
+    }
+
+    pub fn warp(self, matrix: &[f64], width: u32, height: u32, filter: Option<String>, edge_mode: Option<String>) -> Result<ImageHandle, JsValue> {
+        let transform = Transform::from_slice(matrix).map_err(js_error)?;
+        let inner = self.inner.warp(&transform, width, height, warp_options(filter, edge_mode)?).map_err(js_error)?;
+        Ok(ImageHandle { inner })
+    }
+
+    #[wasm_bindgen(js_name = "flipHorizontal")]
+    pub fn flip_horizontal(self) -> ImageHandle {
+        ImageHandle { inner: self.inner.flip_horizontal() }
+    }
+
+    #[wasm_bindgen(js_name = "flipVertical")]
+    pub fn flip_vertical(self) -> ImageHandle {
+        ImageHandle { inner: self.inner.flip_vertical() }
+    }
+
+    pub fn grayscale(self) -> ImageHandle {
+        ImageHandle { inner: self.inner.grayscale() }
+    }
+
+    pub fn invert(self) -> ImageHandle {
+        ImageHandle { inner: self.inner.invert() }
+    }
+
+    pub fn brightness(self, factor: f64) -> ImageHandle {
+        ImageHandle { inner: self.inner.brightness(factor) }
+    }
+
+    pub fn blur(self, radius: u32) -> ImageHandle {
+        ImageHandle { inner: self.inner.blur(radius) }
+    }
+
+    #[wasm_bindgen(js_name = "edgeDetection")]
+    pub fn edge_detection(self) -> ImageHandle {
+        ImageHandle { inner: self.inner.edge_detection() }
+    }
+
+    fn filtered(self, filter: Filter) -> Result<ImageHandle, JsValue> {
+        let inner = self.inner.filter(&filter).map_err(js_error)?;
+        Ok(ImageHandle { inner })
+    }
+
+    #[wasm_bindgen(js_name = "gaussianBlur")]
+    pub fn gaussian_blur(self, sigma: f64) -> Result<ImageHandle, JsValue> {
+        self.filtered(Filter::GaussianBlur(sigma))
+    }
+
+    pub fn sharpen(self, amount: f64) -> Result<ImageHandle, JsValue> {
+        self.filtered(Filter::Sharpen(amount))
+    }
+
+    #[wasm_bindgen(js_name = "unsharpMask")]
+    pub fn unsharp_mask(self, sigma: f64, amount: f64, threshold: Option<u8>) -> Result<ImageHandle, JsValue> {
+        self.filtered(Filter::UnsharpMask { sigma, amount, threshold: threshold.unwrap_or(0) })
+    }
+
+    pub fn convolve(self, kernel: &[f64], normalize: Option<bool>, edge_mode: Option<String>) -> Result<ImageHandle, JsValue> {
+        let filter = convolve_filter(kernel, normalize, edge_mode)?;
+        self.filtered(filter)
+    }
+
+    #[wasm_bindgen(js_name = "colorMatrix")]
+    pub fn color_matrix(self, matrix: &[f64]) -> Result<ImageHandle, JsValue> {
+        let filter = color_matrix_filter(matrix)?;
+        self.filtered(filter)
+    }
+
+    pub fn contrast(self, amount: f64) -> Result<ImageHandle, JsValue> {
+        self.filtered(Filter::Contrast(amount))
+    }
+
+    pub fn saturate(self, amount: f64) -> Result<ImageHandle, JsValue> {
+        self.filtered(Filter::Saturate(amount))
+    }
+
+    #[wasm_bindgen(js_name = "hueRotate")]
+    pub fn hue_rotate(self, angle_degrees: f64) -> Result<ImageHandle, JsValue> {
+        self.filtered(Filter::HueRotate(angle_degrees))
+    }
+
+    pub fn sepia(self, amount: Option<f64>) -> Result<ImageHandle, JsValue> {
+        self.filtered(Filter::Sepia(amount.unwrap_or(1.0)))
+    }
+
+    pub fn gamma(self, gamma: f64) -> Result<ImageHandle, JsValue> {
+        self.filtered(Filter::Gamma(gamma))
+    }
+
+    pub fn levels(self, input_black: f64, input_white: f64, gamma: Option<f64>,
+                  output_black: Option<f64>, output_white: Option<f64>) -> Result<ImageHandle, JsValue> {
+        let filter = levels_filter(input_black, input_white, gamma, output_black, output_white)?;
+        self.filtered(filter)
+    }
+
+    pub fn curves(self, points: &[f64], channel: Option<String>) -> Result<ImageHandle, JsValue> {
+        let filter = curves_filter(points, channel)?;
+        self.filtered(filter)
+    }
+
+    #[wasm_bindgen(js_name = "applyLut")]
+    pub fn apply_lut(self, cube: &str, intensity: Option<f64>) -> Result<ImageHandle, JsValue> {
+        let filter = lut_filter(cube, intensity)?;
+        self.filtered(filter)
+    }
+
+    #[wasm_bindgen(js_name = "applyCss")]
+    pub fn apply_css(self, css: &str) -> Result<ImageHandle, JsValue> {
+        let filters = image::parse_css_filters(css).map_err(js_error)?;
+        let inner = self.inner.filters(&filters).map_err(js_error)?;
+        Ok(ImageHandle { inner })
+    }
+
+    #[wasm_bindgen(js_name = "perceptualHash")]
+    pub fn perceptual_hash(&self, algorithm: Option<String>) -> Result<String, JsValue> {
+        image::image_hash(self.inner.rgba(), self.inner.width(), self.inner.height(), hash_algorithm(algorithm)?)
+            .map(|hash| hash.to_hex())
+            .map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "dominantColors")]
+    pub fn dominant_colors(&self, count: usize, max_samples: Option<usize>, seed: Option<u32>) -> Result<Array, JsValue> {
+        let colors = image::dominant_colors(self.inner.rgba(), count, dominant_color_options(max_samples, seed))
+            .map_err(js_error)?;
+        Ok(palette_to_array(&colors))
+    }
+
+    /// `quality` (1-100) applies to JPEG only; PNG, WebP, GIF and BMP are lossless and reject it.
+    pub fn encode(&self, format: &str, quality: Option<u8>) -> Result<Uint8Array, JsValue> {
+        let format: ImageFormat = format.parse().map_err(js_error)?;
+        js_bytes(self.inner.encode(format, quality))
+    }
+
+    #[wasm_bindgen(js_name = "toRgba")]
+    pub fn to_rgba(&self) -> Uint8ClampedArray {
+        Uint8ClampedArray::from(self.inner.rgba())
+    }
+}
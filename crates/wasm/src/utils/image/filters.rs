@@ -0,0 +1,131 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Uint8ClampedArray;
+use milost_core::image::{self, ColorLut, ColorMatrix, Curve, Curves, EdgeMode, Filter, Kernel, Levels};
+use crate::utils::js_error;
+
+pub(crate) fn convolve_filter(kernel: &[f64], normalize: Option<bool>, edge_mode: Option<String>) -> Result<Filter, JsValue> {
+    let mut kernel = Kernel::from_slice(kernel).map_err(js_error)?;
+    if normalize.unwrap_or(false) {
+        kernel = kernel.normalized();
+    }
+    let edge = edge_mode.map_or(Ok(EdgeMode::Clamp), |name| name.parse().map_err(js_error))?;
+    Ok(Filter::Convolve(kernel, edge))
+}
+
+pub(crate) fn color_matrix_filter(matrix: &[f64]) -> Result<Filter, JsValue> {
+    ColorMatrix::from_slice(matrix).map(Filter::ColorMatrix).map_err(js_error)
+}
+
+pub(crate) fn levels_filter(input_black: f64, input_white: f64, gamma: Option<f64>,
+                            output_black: Option<f64>, output_white: Option<f64>) -> Result<Filter, JsValue> {
+    let levels = Levels {
+        input_black,
+        input_white,
+        gamma: gamma.unwrap_or(1.0),
+        output_black: output_black.unwrap_or(0.0),
+        output_white: output_white.unwrap_or(1.0),
+    };
+    levels.validate().map_err(js_error)?;
+    Ok(Filter::Levels(levels))
+}
+
+pub(crate) fn curves_filter(points: &[f64], channel: Option<String>) -> Result<Filter, JsValue> {
+    let curve = Curve::from_slice(points).map_err(js_error)?;
+    let mut curves = Curves::default();
+    match channel.as_deref().map(str::to_ascii_lowercase).as_deref() {
+        None | Some("rgb") => curves.rgb = curve,
+        Some("red") | Some("r") => curves.red = curve,
+        Some("green") | Some("g") => curves.green = curve,
+        Some("blue") | Some("b") => curves.blue = curve,
+        Some(other) => return Err(JsValue::from_str(&format!("Unknown curve channel: {}", other))),
+    }
+    Ok(Filter::Curves(curves))
+}
+
+pub(crate) fn lut_filter(cube: &str, intensity: Option<f64>) -> Result<Filter, JsValue> {
+    let lut = ColorLut::parse_cube(cube).map_err(js_error)?;
+    Ok(Filter::ColorLut(lut, intensity.unwrap_or(1.0)))
+}
+
+fn apply(image_data: &Uint8ClampedArray, width: u32, height: u32, filter: Filter) -> Result<Uint8ClampedArray, JsValue> {
+    filter.apply(&image_data.to_vec(), width, height)
+        .map(|data| Uint8ClampedArray::from(data.as_slice()))
+        .map_err(js_error)
+}
+
+#[wasm_bindgen]
+pub struct ImageFilters;
+
+#[wasm_bindgen]
+impl ImageFilters {
+    #[wasm_bindgen(js_name = "gaussianBlur")]
+    pub fn gaussian_blur(image_data: &Uint8ClampedArray, width: u32, height: u32, sigma: f64) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, Filter::GaussianBlur(sigma))
+    }
+
+    pub fn sharpen(image_data: &Uint8ClampedArray, width: u32, height: u32, amount: f64) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, Filter::Sharpen(amount))
+    }
+
+    #[wasm_bindgen(js_name = "unsharpMask")]
+    pub fn unsharp_mask(image_data: &Uint8ClampedArray, width: u32, height: u32, sigma: f64, amount: f64,
+                        threshold: Option<u8>) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, Filter::UnsharpMask { sigma, amount, threshold: threshold.unwrap_or(0) })
+    }
+
+    pub fn convolve(image_data: &Uint8ClampedArray, width: u32, height: u32, kernel: &[f64],
+                    normalize: Option<bool>, edge_mode: Option<String>) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, convolve_filter(kernel, normalize, edge_mode)?)
+    }
+
+    #[wasm_bindgen(js_name = "colorMatrix")]
+    pub fn color_matrix(image_data: &Uint8ClampedArray, width: u32, height: u32, matrix: &[f64]) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, color_matrix_filter(matrix)?)
+    }
+
+    pub fn contrast(image_data: &Uint8ClampedArray, width: u32, height: u32, amount: f64) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, Filter::Contrast(amount))
+    }
+
+    pub fn saturate(image_data: &Uint8ClampedArray, width: u32, height: u32, amount: f64) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, Filter::Saturate(amount))
+    }
+
+    #[wasm_bindgen(js_name = "hueRotate")]
+    pub fn hue_rotate(image_data: &Uint8ClampedArray, width: u32, height: u32, angle_degrees: f64) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, Filter::HueRotate(angle_degrees))
+    }
+
+    pub fn sepia(image_data: &Uint8ClampedArray, width: u32, height: u32, amount: Option<f64>) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, Filter::Sepia(amount.unwrap_or(1.0)))
+    }
+
+    pub fn gamma(image_data: &Uint8ClampedArray, width: u32, height: u32, gamma: f64) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, Filter::Gamma(gamma))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn levels(image_data: &Uint8ClampedArray, width: u32, height: u32, input_black: f64, input_white: f64,
+                  gamma: Option<f64>, output_black: Option<f64>, output_white: Option<f64>) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, levels_filter(input_black, input_white, gamma, output_black, output_white)?)
+    }
+
+    pub fn curves(image_data: &Uint8ClampedArray, width: u32, height: u32, points: &[f64],
+                  channel: Option<String>) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, curves_filter(points, channel)?)
+    }
+
+    #[wasm_bindgen(js_name = "applyLut")]
+    pub fn apply_lut(image_data: &Uint8ClampedArray, width: u32, height: u32, cube: &str,
+                     intensity: Option<f64>) -> Result<Uint8ClampedArray, JsValue> {
+        apply(image_data, width, height, lut_filter(cube, intensity)?)
+    }
+
+    #[wasm_bindgen(js_name = "applyCss")]
+    pub fn apply_css(image_data: &Uint8ClampedArray, width: u32, height: u32, css: &str) -> Result<Uint8ClampedArray, JsValue> {
+        let filters = image::parse_css_filters(css).map_err(js_error)?;
+        image::apply_filters(&image_data.to_vec(), width, height, &filters)
+            .map(|data| Uint8ClampedArray::from(data.as_slice()))
+            .map_err(js_error)
+    }
+}
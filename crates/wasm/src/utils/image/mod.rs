@@ -1,3 +1,9 @@
 pub mod image_processing;
+pub mod codec;
+pub mod filters;
+pub mod analysis;
 
-pub use image_processing::*;
\ No newline at end of file
+pub use image_processing::*;
+pub use codec::*;
+pub use filters::*;
+pub use analysis::*;
@@ -0,0 +1,305 @@
+use wasm_bindgen::prelude::*;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+use milost_core::math;
+use crate::utils::js_error;
+
+const DEFAULT_PRIME_ROUNDS: usize = 20;
+
+fn non_zero(divisor: &BigInt) -> Result<(), JsValue> {
+    if divisor.is_zero() {
+        return Err(JsValue::from_str("Division by zero"));
+    }
+    Ok(())
+}
+
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct BigInteger {
+    value: BigInt,
+}
+
+impl From<BigInt> for BigInteger {
+    fn from(value: BigInt) -> Self {
+        BigInteger { value }
+    }
+}
+
+#[wasm_bindgen]
+impl BigInteger {
+    #[wasm_bindgen(constructor)]
+    pub fn new(text: &str, radix: Option<u32>) -> Result<BigInteger, JsValue> {
+        math::parse_bigint(text, radix.unwrap_or(10)).map(BigInteger::from).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "fromNumber")]
+    pub fn from_number(value: f64) -> Result<BigInteger, JsValue> {
+        math::bigint_from_f64(value).map(BigInteger::from).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "fromBigInt")]
+    pub fn from_big_int(value: &js_sys::BigInt) -> Result<BigInteger, JsValue> {
+        let text = value.to_string(10).map_err(JsValue::from)?;
+        BigInteger::new(&String::from(text), None)
+    }
+
+    #[wasm_bindgen(js_name = "toBigInt")]
+    pub fn to_big_int(&self) -> Result<js_sys::BigInt, JsValue> {
+        js_sys::BigInt::new(&JsValue::from_str(&self.value.to_string())).map_err(JsValue::from)
+    }
+
+    #[wasm_bindgen(js_name = "toString")]
+    pub fn to_string_radix(&self, radix: Option<u32>) -> Result<String, JsValue> {
+        let radix = radix.unwrap_or(10);
+        if !(2..=36).contains(&radix) {
+            return Err(JsValue::from_str("Radix must be between 2 and 36"));
+        }
+        Ok(self.value.to_str_radix(radix))
+    }
+
+    #[wasm_bindgen(js_name = "toNumber")]
+    pub fn to_number(&self) -> f64 {
+        self.value.to_f64().unwrap_or(f64::NAN)
+    }
+
+    pub fn add(&self, other: &BigInteger) -> BigInteger {
+        (&self.value + &other.value).into()
+    }
+
+    pub fn subtract(&self, other: &BigInteger) -> BigInteger {
+        (&self.value - &other.value).into()
+    }
+
+    pub fn multiply(&self, other: &BigInteger) -> BigInteger {
+        (&self.value * &other.value).into()
+    }
+
+    pub fn divide(&self, other: &BigInteger) -> Result<BigInteger, JsValue> {
+        non_zero(&other.value)?;
+        Ok((&self.value / &other.value).into())
+    }
+
+    pub fn remainder(&self, other: &BigInteger) -> Result<BigInteger, JsValue> {
+        non_zero(&other.value)?;
+        Ok((&self.value % &other.value).into())
+    }
+
+    #[wasm_bindgen(js_name = "mod")]
+    pub fn modulo(&self, other: &BigInteger) -> Result<BigInteger, JsValue> {
+        non_zero(&other.value)?;
+        Ok(self.value.mod_floor(&other.value).into())
+    }
+
+    pub fn pow(&self, exponent: u32) -> BigInteger {
+        self.value.pow(exponent).into()
+    }
+
+    pub fn negate(&self) -> BigInteger {
+        (-&self.value).into()
+    }
+
+    pub fn abs(&self) -> BigInteger {
+        self.value.abs().into()
+    }
+
+    pub fn sign(&self) -> i32 {
+        if self.value.is_zero() { 0 } else if self.value.is_negative() { -1 } else { 1 }
+    }
+
+    pub fn compare(&self, other: &BigInteger) -> i32 {
+        self.value.cmp(&other.value) as i32
+    }
+
+    pub fn equals(&self, other: &BigInteger) -> bool {
+        self.value == other.value
+    }
+
+    #[wasm_bindgen(js_name = "isZero")]
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    pub fn gcd(&self, other: &BigInteger) -> BigInteger {
+        self.value.gcd(&other.value).into()
+    }
+
+    pub fn lcm(&self, other: &BigInteger) -> BigInteger {
+        self.value.lcm(&other.value).into()
+    }
+
+    #[wasm_bindgen(js_name = "modPow")]
+    pub fn mod_pow(&self, exponent: &BigInteger, modulus: &BigInteger) -> Result<BigInteger, JsValue> {
+        math::mod_pow(&self.value, &exponent.value, &modulus.value).map(BigInteger::from).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "modInverse")]
+    pub fn mod_inverse(&self, modulus: &BigInteger) -> Result<BigInteger, JsValue> {
+        math::mod_inverse(&self.value, &modulus.value).map(BigInteger::from).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "isProbablePrime")]
+    pub fn is_probable_prime(&self, rounds: Option<usize>) -> bool {
+        math::is_probable_prime(&self.value, rounds.unwrap_or(DEFAULT_PRIME_ROUNDS))
+    }
+
+    #[wasm_bindgen(js_name = "nextProbablePrime")]
+    pub fn next_probable_prime(&self, rounds: Option<usize>) -> BigInteger {
+        math::next_probable_prime(&self.value, rounds.unwrap_or(DEFAULT_PRIME_ROUNDS)).into()
+    }
+
+    pub fn sqrt(&self) -> Result<BigInteger, JsValue> {
+        if self.value.is_negative() {
+            return Err(JsValue::from_str("Cannot take the square root of a negative number"));
+        }
+        Ok(self.value.sqrt().into())
+    }
+
+    #[wasm_bindgen(js_name = "bitLength")]
+    pub fn bit_length(&self) -> f64 {
+        self.value.bits() as f64
+    }
+
+    #[wasm_bindgen(js_name = "shiftLeft")]
+    pub fn shift_left(&self, bits: u32) -> BigInteger {
+        (&self.value << bits).into()
+    }
+
+    #[wasm_bindgen(js_name = "shiftRight")]
+    pub fn shift_right(&self, bits: u32) -> BigInteger {
+        (&self.value >> bits).into()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Rational {
+    value: BigRational,
+}
+
+impl From<BigRational> for Rational {
+    fn from(value: BigRational) -> Self {
+        Rational { value }
+    }
+}
+
+#[wasm_bindgen]
+impl Rational {
+    #[wasm_bindgen(constructor)]
+    pub fn new(numerator: &BigInteger, denominator: &BigInteger) -> Result<Rational, JsValue> {
+        non_zero(&denominator.value)?;
+        Ok(BigRational::new(numerator.value.clone(), denominator.value.clone()).into())
+    }
+
+    #[wasm_bindgen(js_name = "fromString")]
+    pub fn from_string(text: &str) -> Result<Rational, JsValue> {
+        math::parse_rational(text).map(Rational::from).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "fromNumber")]
+    pub fn from_number(value: f64) -> Result<Rational, JsValue> {
+        math::rational_from_f64(value).map(Rational::from).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "fromInteger")]
+    pub fn from_integer(value: &BigInteger) -> Rational {
+        BigRational::from_integer(value.value.clone()).into()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn numerator(&self) -> BigInteger {
+        self.value.numer().clone().into()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn denominator(&self) -> BigInteger {
+        self.value.denom().clone().into()
+    }
+
+    #[wasm_bindgen(js_name = "toString")]
+    pub fn to_string_js(&self) -> String {
+        self.value.to_string()
+    }
+
+    #[wasm_bindgen(js_name = "toNumber")]
+    pub fn to_number(&self) -> f64 {
+        self.value.to_f64().unwrap_or(f64::NAN)
+    }
+
+    #[wasm_bindgen(js_name = "toDecimal")]
+    pub fn to_decimal(&self, places: Option<u32>) -> String {
+        math::rational_to_decimal(&self.value, places.unwrap_or(10))
+    }
+
+    pub fn add(&self, other: &Rational) -> Rational {
+        (&self.value + &other.value).into()
+    }
+
+    pub fn subtract(&self, other: &Rational) -> Rational {
+        (&self.value - &other.value).into()
+    }
+
+    pub fn multiply(&self, other: &Rational) -> Rational {
+        (&self.value * &other.value).into()
+    }
+
+    pub fn divide(&self, other: &Rational) -> Result<Rational, JsValue> {
+        non_zero(other.value.numer())?;
+        Ok((&self.value / &other.value).into())
+    }
+
+    pub fn negate(&self) -> Rational {
+        (-&self.value).into()
+    }
+
+    pub fn abs(&self) -> Rational {
+        self.value.abs().into()
+    }
+
+    pub fn reciprocal(&self) -> Result<Rational, JsValue> {
+        non_zero(self.value.numer())?;
+        Ok(self.value.recip().into())
+    }
+
+    pub fn pow(&self, exponent: i32) -> Result<Rational, JsValue> {
+        if exponent < 0 {
+            non_zero(self.value.numer())?;
+        }
+        Ok(self.value.pow(exponent).into())
+    }
+
+    pub fn compare(&self, other: &Rational) -> i32 {
+        self.value.cmp(&other.value) as i32
+    }
+
+    pub fn equals(&self, other: &Rational) -> bool {
+        self.value == other.value
+    }
+
+    #[wasm_bindgen(js_name = "isInteger")]
+    pub fn is_integer(&self) -> bool {
+        self.value.is_integer()
+    }
+
+    pub fn floor(&self) -> BigInteger {
+        self.value.floor().to_integer().into()
+    }
+
+    pub fn ceil(&self) -> BigInteger {
+        self.value.ceil().to_integer().into()
+    }
+
+    pub fn round(&self) -> BigInteger {
+        self.value.round().to_integer().into()
+    }
+
+    pub fn trunc(&self) -> BigInteger {
+        self.value.trunc().to_integer().into()
+    }
+
+    #[wasm_bindgen(js_name = "limitDenominator")]
+    pub fn limit_denominator(&self, max_denominator: &BigInteger) -> Result<Rational, JsValue> {
+        math::limit_denominator(&self.value, &max_denominator.value).map(Rational::from).map_err(js_error)
+    }
+}
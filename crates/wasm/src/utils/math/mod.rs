@@ -1,3 +1,11 @@
 pub mod math_lib;
+pub mod statistics;
+pub mod numerical;
+pub mod signal;
+pub mod bignum;
 
-pub use math_lib::*;
\ No newline at end of file
+pub use math_lib::*;
+pub use statistics::*;
+pub use numerical::*;
+pub use signal::*;
+pub use bignum::*;
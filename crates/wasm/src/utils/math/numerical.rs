@@ -0,0 +1,182 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Float64Array, Function, Object, Reflect};
+use milost_core::math::{self, BatchedGradient, IntegrationOptions, Minimum, OdeOptions, OdeSolution, OptimizeOptions, RootOptions};
+use super::math_lib::call_f64;
+use crate::utils::js_error;
+
+fn to_vec(value: JsValue) -> Vec<f64> {
+    if let Some(number) = value.as_f64() {
+        return vec![number];
+    }
+    Float64Array::new(&value).to_vec()
+}
+
+fn call_batch(f: &Function, xs: &[f64]) -> Vec<f64> {
+    f.call1(&JsValue::NULL, &Float64Array::from(xs))
+        .map(to_vec)
+        .unwrap_or_else(|_| vec![f64::NAN; xs.len()])
+}
+
+fn call_point(f: &Function, point: &[f64]) -> f64 {
+    f.call1(&JsValue::NULL, &Float64Array::from(point))
+        .ok()
+        .and_then(|value| value.as_f64())
+        .unwrap_or(f64::NAN)
+}
+
+fn call_points(f: &Function, points: &[Vec<f64>], batched: bool) -> Vec<f64> {
+    if !batched {
+        return points.iter().map(|point| call_point(f, point)).collect();
+    }
+    let array: Array = points.iter().map(|point| JsValue::from(Float64Array::from(&point[..]))).collect();
+    f.call1(&JsValue::NULL, &array)
+        .map(to_vec)
+        .unwrap_or_else(|_| vec![f64::NAN; points.len()])
+}
+
+fn call_vector(f: &Function, point: &[f64]) -> Vec<f64> {
+    f.call1(&JsValue::NULL, &Float64Array::from(point))
+        .map(to_vec)
+        .unwrap_or_default()
+}
+
+fn call_derivative(f: &Function, t: f64, y: &[f64]) -> Vec<f64> {
+    f.call2(&JsValue::NULL, &JsValue::from_f64(t), &Float64Array::from(y))
+        .map(to_vec)
+        .unwrap_or_else(|_| vec![f64::NAN; y.len()])
+}
+
+fn set(target: &Object, key: &str, value: &JsValue) {
+    Reflect::set(target, &JsValue::from_str(key), value).unwrap();
+}
+
+fn minimum_object(minimum: Minimum) -> Object {
+    let result = Object::new();
+    set(&result, "point", &Float64Array::from(&minimum.point[..]));
+    set(&result, "value", &JsValue::from_f64(minimum.value));
+    set(&result, "iterations", &JsValue::from(minimum.iterations as u32));
+    set(&result, "evaluations", &JsValue::from(minimum.evaluations as u32));
+    set(&result, "converged", &JsValue::from_bool(minimum.converged));
+    result
+}
+
+fn solution_object(times: &[f64], states: &[Vec<f64>], solution: &OdeSolution) -> Object {
+    let result = Object::new();
+    let flat: Vec<f64> = states.iter().flatten().copied().collect();
+    set(&result, "t", &Float64Array::from(times));
+    set(&result, "y", &Float64Array::from(&flat[..]));
+    set(&result, "dimension", &JsValue::from(solution.dimension() as u32));
+    set(&result, "evaluations", &JsValue::from(solution.evaluations as u32));
+    set(&result, "rejectedSteps", &JsValue::from(solution.rejected_steps as u32));
+    result
+}
+
+fn optimize_options(tolerance: Option<f64>, max_iterations: Option<usize>, initial_step: Option<f64>) -> OptimizeOptions {
+    let defaults = OptimizeOptions::default();
+    OptimizeOptions {
+        tolerance: tolerance.unwrap_or(defaults.tolerance),
+        max_iterations: max_iterations.unwrap_or(defaults.max_iterations),
+        initial_step,
+    }
+}
+
+#[wasm_bindgen]
+pub struct Numerical;
+
+#[wasm_bindgen]
+impl Numerical {
+    pub fn integrate(f: &Function, a: f64, b: f64, batched: Option<bool>, abs_tolerance: Option<f64>,
+                     rel_tolerance: Option<f64>, max_intervals: Option<usize>) -> Result<Object, JsValue> {
+        let defaults = IntegrationOptions::default();
+        let options = IntegrationOptions {
+            abs_tolerance: abs_tolerance.unwrap_or(defaults.abs_tolerance),
+            rel_tolerance: rel_tolerance.unwrap_or(defaults.rel_tolerance),
+            max_intervals: max_intervals.unwrap_or(defaults.max_intervals),
+        };
+        let integral = if batched.unwrap_or(false) {
+            math::integrate_batched(|xs| call_batch(f, xs), a, b, options)
+        } else {
+            math::integrate(|x| call_f64(f, x, f64::NAN), a, b, options)
+        }
+        .map_err(js_error)?;
+
+        let result = Object::new();
+        set(&result, "value", &JsValue::from_f64(integral.value));
+        set(&result, "errorEstimate", &JsValue::from_f64(integral.error_estimate));
+        set(&result, "evaluations", &JsValue::from(integral.evaluations as u32));
+        set(&result, "intervals", &JsValue::from(integral.intervals as u32));
+        set(&result, "converged", &JsValue::from_bool(integral.converged));
+        Ok(result)
+    }
+
+    #[wasm_bindgen(js_name = "bracketRoot")]
+    pub fn bracket_root(f: &Function, a: f64, b: f64, max_expansions: Option<usize>) -> Result<Float64Array, JsValue> {
+        let (low, high) = math::bracket_root(|x| call_f64(f, x, f64::NAN), a, b, max_expansions.unwrap_or(50)).map_err(js_error)?;
+        Ok(Float64Array::from(&[low, high][..]))
+    }
+
+    pub fn brent(f: &Function, a: f64, b: f64, tolerance: Option<f64>, max_iterations: Option<usize>) -> Result<Object, JsValue> {
+        let defaults = RootOptions::default();
+        let options = RootOptions {
+            tolerance: tolerance.unwrap_or(defaults.tolerance),
+            max_iterations: max_iterations.unwrap_or(defaults.max_iterations),
+        };
+        let root = math::brent(|x| call_f64(f, x, f64::NAN), a, b, options).map_err(js_error)?;
+
+        let result = Object::new();
+        set(&result, "x", &JsValue::from_f64(root.x));
+        set(&result, "value", &JsValue::from_f64(root.value));
+        set(&result, "iterations", &JsValue::from(root.iterations as u32));
+        Ok(result)
+    }
+
+    #[wasm_bindgen(js_name = "nelderMead")]
+    pub fn nelder_mead(f: &Function, x0: &[f64], batched: Option<bool>, tolerance: Option<f64>,
+                       max_iterations: Option<usize>, initial_step: Option<f64>) -> Result<Object, JsValue> {
+        let batched = batched.unwrap_or(false);
+        let options = optimize_options(tolerance, max_iterations, initial_step);
+        math::nelder_mead_batched(|points| call_points(f, points, batched), x0, options)
+            .map(minimum_object)
+            .map_err(js_error)
+    }
+
+    pub fn bfgs(f: &Function, x0: &[f64], gradient: Option<Function>, batched: Option<bool>,
+                tolerance: Option<f64>, max_iterations: Option<usize>) -> Result<Object, JsValue> {
+        let batched = batched.unwrap_or(false);
+        let options = optimize_options(tolerance, max_iterations, None);
+        let mut gradient = gradient.map(|gradient| move |x: &[f64]| call_vector(&gradient, x));
+        math::bfgs_batched(
+            |points| call_points(f, points, batched),
+            gradient.as_mut().map(|gradient| gradient as BatchedGradient),
+            x0,
+            options,
+        )
+        .map(minimum_object)
+        .map_err(js_error)
+    }
+
+    pub fn rk4(f: &Function, t0: f64, y0: &[f64], t_end: f64, steps: usize) -> Result<Object, JsValue> {
+        let solution = math::rk4(|t, y| call_derivative(f, t, y), t0, y0, t_end, steps).map_err(js_error)?;
+        Ok(solution_object(&solution.t, &solution.y, &solution))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn rk45(f: &Function, t0: f64, y0: &[f64], t_end: f64, rel_tolerance: Option<f64>, abs_tolerance: Option<f64>,
+                max_step: Option<f64>, t_eval: Option<Vec<f64>>) -> Result<Object, JsValue> {
+        let defaults = OdeOptions::default();
+        let options = OdeOptions {
+            rel_tolerance: rel_tolerance.unwrap_or(defaults.rel_tolerance),
+            abs_tolerance: abs_tolerance.unwrap_or(defaults.abs_tolerance),
+            max_step,
+            ..defaults
+        };
+        let solution = math::rk45(|t, y| call_derivative(f, t, y), t0, y0, t_end, options).map_err(js_error)?;
+        match t_eval {
+            Some(times) => {
+                let states = solution.evaluate_many(&times).map_err(js_error)?;
+                Ok(solution_object(&times, &states, &solution))
+            }
+            None => Ok(solution_object(&solution.t, &solution.y, &solution)),
+        }
+    }
+}
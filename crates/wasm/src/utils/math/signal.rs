@@ -0,0 +1,210 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Float64Array, Object, Reflect, Uint32Array};
+use num_complex::Complex64;
+use milost_core::math::{self, Biquad, ConvolutionMode, FilterType, PeakOptions, StftOptions, Window};
+use super::math_lib::complex_object;
+use crate::utils::js_error;
+
+fn set(target: &Object, key: &str, value: &JsValue) {
+    Reflect::set(target, &JsValue::from_str(key), value).unwrap();
+}
+
+fn to_f64_array<E: std::fmt::Display>(result: Result<Vec<f64>, E>) -> Result<Float64Array, JsValue> {
+    result
+        .map(|values| Float64Array::from(&values[..]))
+        .map_err(js_error)
+}
+
+fn window(name: Option<String>) -> Result<Window, JsValue> {
+    name.map_or(Ok(Window::default()), |name| name.parse().map_err(js_error))
+}
+
+fn filter_type(name: &str) -> Result<FilterType, JsValue> {
+    name.parse().map_err(js_error)
+}
+
+fn convolution_mode(name: Option<String>) -> Result<ConvolutionMode, JsValue> {
+    name.map_or(Ok(ConvolutionMode::default()), |name| name.parse().map_err(js_error))
+}
+
+fn stft_options(frame_size: Option<usize>, hop_size: Option<usize>, window_name: Option<String>,
+                center: Option<bool>) -> Result<StftOptions, JsValue> {
+    let defaults = StftOptions::default();
+    Ok(StftOptions {
+        frame_size: frame_size.unwrap_or(defaults.frame_size),
+        hop_size,
+        window: window(window_name)?,
+        center: center.unwrap_or(defaults.center),
+    })
+}
+
+fn to_complex(real: &[f64], imag: &[f64]) -> Result<Vec<Complex64>, JsValue> {
+    if real.len() != imag.len() {
+        return Err(JsValue::from_str("Real and imaginary arrays must have the same length"));
+    }
+    Ok(real.iter().zip(imag).map(|(&re, &im)| Complex64::new(re, im)).collect())
+}
+
+fn to_sections(sos: &[f64]) -> Result<Vec<Biquad>, JsValue> {
+    if sos.is_empty() || !sos.len().is_multiple_of(6) {
+        return Err(JsValue::from_str("Second-order sections must be a non-empty array of [b0, b1, b2, a0, a1, a2] rows"));
+    }
+    sos.chunks(6)
+        .map(|row| Biquad::new([row[0], row[1], row[2]], [row[3], row[4], row[5]]).map_err(js_error))
+        .collect()
+}
+
+fn response_object(response: &[Complex64]) -> Object {
+    let result = Object::new();
+    let magnitude: Vec<f64> = response.iter().map(|value| value.norm()).collect();
+    let phase: Vec<f64> = response.iter().map(|value| value.arg()).collect();
+    set(&result, "magnitude", &Float64Array::from(&magnitude[..]));
+    set(&result, "phase", &Float64Array::from(&phase[..]));
+    result
+}
+
+#[wasm_bindgen]
+pub struct Signal;
+
+#[wasm_bindgen]
+impl Signal {
+    pub fn rfft(signal: &[f64]) -> Object {
+        let (real, imag): (Vec<f64>, Vec<f64>) = math::rfft(signal).iter().map(|c| (c.re, c.im)).unzip();
+        complex_object(&real, &imag)
+    }
+
+    pub fn irfft(real: &[f64], imag: &[f64], length: usize) -> Result<Float64Array, JsValue> {
+        to_f64_array(math::irfft(&to_complex(real, imag)?, length))
+    }
+
+    #[wasm_bindgen(js_name = "rfftFrequencies")]
+    pub fn rfft_frequencies(length: usize, sample_rate: f64) -> Float64Array {
+        Float64Array::from(&math::rfft_frequencies(length, sample_rate)[..])
+    }
+
+    pub fn window(name: &str, length: usize, periodic: Option<bool>) -> Result<Float64Array, JsValue> {
+        let window: Window = name.parse().map_err(js_error)?;
+        Ok(Float64Array::from(&window.coefficients(length, periodic.unwrap_or(false))[..]))
+    }
+
+    pub fn stft(signal: &[f64], frame_size: Option<usize>, hop_size: Option<usize>, window: Option<String>,
+                center: Option<bool>) -> Result<Object, JsValue> {
+        let stft = math::stft(signal, stft_options(frame_size, hop_size, window, center)?).map_err(js_error)?;
+        let (real, imag): (Vec<f64>, Vec<f64>) = stft.frames.iter().flatten().map(|c| (c.re, c.im)).unzip();
+        let result = complex_object(&real, &imag);
+        set(&result, "frames", &JsValue::from(stft.frames.len() as u32));
+        set(&result, "bins", &JsValue::from((stft.options.frame_size / 2 + 1) as u32));
+        Ok(result)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn istft(real: &[f64], imag: &[f64], signal_length: usize, frame_size: Option<usize>, hop_size: Option<usize>,
+                 window: Option<String>, center: Option<bool>) -> Result<Float64Array, JsValue> {
+        let options = stft_options(frame_size, hop_size, window, center)?;
+        let bins = options.frame_size / 2 + 1;
+        let spectrum = to_complex(real, imag)?;
+        if spectrum.is_empty() || spectrum.len() % bins != 0 {
+            return Err(JsValue::from_str(&format!("Spectrum length must be a multiple of {} bins", bins)));
+        }
+        let stft = math::Stft { frames: spectrum.chunks(bins).map(<[Complex64]>::to_vec).collect(), options, signal_length };
+        to_f64_array(stft.inverse())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn spectrogram(signal: &[f64], sample_rate: f64, frame_size: Option<usize>, hop_size: Option<usize>,
+                       window: Option<String>, decibels: Option<bool>, dynamic_range: Option<f64>) -> Result<Object, JsValue> {
+        let options = stft_options(frame_size, hop_size, window, Some(true))?;
+        let spectrogram = math::spectrogram(signal, sample_rate, options).map_err(js_error)?;
+        let values = if decibels.unwrap_or(false) { spectrogram.decibels(dynamic_range) } else { spectrogram.power.clone() };
+        let flat: Vec<f64> = values.into_iter().flatten().collect();
+
+        let result = Object::new();
+        set(&result, "times", &Float64Array::from(&spectrogram.times[..]));
+        set(&result, "frequencies", &Float64Array::from(&spectrogram.frequencies[..]));
+        set(&result, "data", &Float64Array::from(&flat[..]));
+        set(&result, "frames", &JsValue::from(spectrogram.times.len() as u32));
+        set(&result, "bins", &JsValue::from(spectrogram.frequencies.len() as u32));
+        Ok(result)
+    }
+
+    #[wasm_bindgen(js_name = "designFir")]
+    pub fn design_fir(taps: usize, filter_type_name: &str, cutoffs: &[f64], sample_rate: f64,
+                      window_name: Option<String>) -> Result<Float64Array, JsValue> {
+        let window = window_name.map_or(Ok(Window::Hamming), |name| name.parse().map_err(js_error))?;
+        to_f64_array(math::design_fir(taps, filter_type(filter_type_name)?, cutoffs, sample_rate, window))
+    }
+
+    pub fn butterworth(order: usize, filter_type_name: &str, cutoffs: &[f64], sample_rate: f64) -> Result<Float64Array, JsValue> {
+        let sections = math::butterworth(order, filter_type(filter_type_name)?, cutoffs, sample_rate).map_err(js_error)?;
+        let flat: Vec<f64> = sections.iter().flat_map(|section| section.b.into_iter().chain(section.a)).collect();
+        Ok(Float64Array::from(&flat[..]))
+    }
+
+    pub fn lfilter(b: &[f64], a: &[f64], signal: &[f64]) -> Result<Float64Array, JsValue> {
+        to_f64_array(math::lfilter(b, a, signal))
+    }
+
+    pub fn filtfilt(b: &[f64], a: &[f64], signal: &[f64]) -> Result<Float64Array, JsValue> {
+        to_f64_array(math::filtfilt(b, a, signal))
+    }
+
+    #[wasm_bindgen(js_name = "firFilter")]
+    pub fn fir_filter(coefficients: &[f64], signal: &[f64]) -> Result<Float64Array, JsValue> {
+        to_f64_array(math::fir_filter(coefficients, signal))
+    }
+
+    #[wasm_bindgen(js_name = "sosFilter")]
+    pub fn sos_filter(sos: &[f64], signal: &[f64], zero_phase: Option<bool>) -> Result<Float64Array, JsValue> {
+        let sections = to_sections(sos)?;
+        if zero_phase.unwrap_or(false) {
+            to_f64_array(math::sos_filtfilt(&sections, signal))
+        } else {
+            Ok(Float64Array::from(&math::sos_filter(&sections, signal)[..]))
+        }
+    }
+
+    #[wasm_bindgen(js_name = "frequencyResponse")]
+    pub fn frequency_response(b: &[f64], a: &[f64], frequencies: &[f64], sample_rate: f64) -> Object {
+        response_object(&math::frequency_response(b, a, frequencies, sample_rate))
+    }
+
+    #[wasm_bindgen(js_name = "sosFrequencyResponse")]
+    pub fn sos_frequency_response(sos: &[f64], frequencies: &[f64], sample_rate: f64) -> Result<Object, JsValue> {
+        Ok(response_object(&math::sos_frequency_response(&to_sections(sos)?, frequencies, sample_rate)))
+    }
+
+    pub fn convolve(a: &[f64], b: &[f64], mode: Option<String>) -> Result<Float64Array, JsValue> {
+        to_f64_array(math::convolve(a, b, convolution_mode(mode)?))
+    }
+
+    pub fn correlate(a: &[f64], b: &[f64], mode: Option<String>) -> Result<Float64Array, JsValue> {
+        to_f64_array(math::correlate(a, b, convolution_mode(mode)?))
+    }
+
+    pub fn resample(signal: &[f64], length: usize) -> Result<Float64Array, JsValue> {
+        to_f64_array(math::resample(signal, length))
+    }
+
+    #[wasm_bindgen(js_name = "resamplePoly")]
+    pub fn resample_poly(signal: &[f64], up: usize, down: usize) -> Result<Float64Array, JsValue> {
+        to_f64_array(math::resample_poly(signal, up, down))
+    }
+
+    #[wasm_bindgen(js_name = "findPeaks")]
+    pub fn find_peaks(signal: &[f64], min_height: Option<f64>, min_prominence: Option<f64>, min_distance: Option<usize>) -> Object {
+        let peaks = math::find_peaks(signal, PeakOptions { min_height, min_prominence, min_distance });
+        let indices: Vec<u32> = peaks.iter().map(|peak| peak.index as u32).collect();
+        let values: Vec<f64> = peaks.iter().map(|peak| peak.value).collect();
+        let prominences: Vec<f64> = peaks.iter().map(|peak| peak.prominence).collect();
+        let left_bases: Vec<u32> = peaks.iter().map(|peak| peak.left_base as u32).collect();
+        let right_bases: Vec<u32> = peaks.iter().map(|peak| peak.right_base as u32).collect();
+
+        let result = Object::new();
+        set(&result, "indices", &Uint32Array::from(&indices[..]));
+        set(&result, "values", &Float64Array::from(&values[..]));
+        set(&result, "prominences", &Float64Array::from(&prominences[..]));
+        set(&result, "leftBases", &Uint32Array::from(&left_bases[..]));
+        set(&result, "rightBases", &Uint32Array::from(&right_bases[..]));
+        result
+    }
+}
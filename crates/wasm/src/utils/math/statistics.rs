@@ -0,0 +1,198 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Float64Array, Object, Reflect};
+use milost_core::math::{self, Alternative, Distribution, QuantileMethod, TestResult, VarianceMode};
+use crate::utils::js_error;
+
+pub(crate) fn quantile_method(method: Option<String>) -> Result<QuantileMethod, JsValue> {
+    method.map_or(Ok(QuantileMethod::default()), |name| name.parse().map_err(js_error))
+}
+
+pub(crate) fn variance_mode(population: Option<bool>) -> VarianceMode {
+    if population.unwrap_or(false) { VarianceMode::Population } else { VarianceMode::Sample }
+}
+
+fn alternative(name: Option<String>) -> Result<Alternative, JsValue> {
+    name.map_or(Ok(Alternative::default()), |name| name.parse().map_err(js_error))
+}
+
+fn test_result<E: std::fmt::Display>(result: Result<TestResult, E>) -> Result<Object, JsValue> {
+    let result = result.map_err(js_error)?;
+    let object = Object::new();
+    Reflect::set(&object, &JsValue::from_str("statistic"), &JsValue::from_f64(result.statistic)).unwrap();
+    Reflect::set(&object, &JsValue::from_str("pValue"), &JsValue::from_f64(result.p_value)).unwrap();
+    if let Some(df) = result.degrees_of_freedom {
+        Reflect::set(&object, &JsValue::from_str("degreesOfFreedom"), &JsValue::from_f64(df)).unwrap();
+    }
+    Ok(object)
+}
+
+#[wasm_bindgen]
+pub struct Statistics;
+
+#[wasm_bindgen]
+impl Statistics {
+    pub fn mean(data: &[f64]) -> Option<f64> {
+        math::mean(data)
+    }
+
+    pub fn median(data: &[f64]) -> Option<f64> {
+        math::median(data)
+    }
+
+    pub fn quantile(data: &[f64], p: f64, method: Option<String>) -> Result<f64, JsValue> {
+        math::quantile(data, p, quantile_method(method)?).map_err(js_error)
+    }
+
+    pub fn quantiles(data: &[f64], probabilities: &[f64], method: Option<String>) -> Result<Float64Array, JsValue> {
+        math::quantiles(data, probabilities, quantile_method(method)?)
+            .map(|values| Float64Array::from(&values[..]))
+            .map_err(js_error)
+    }
+
+    pub fn variance(data: &[f64], population: Option<bool>) -> Option<f64> {
+        math::variance(data, variance_mode(population))
+    }
+
+    #[wasm_bindgen(js_name = "stdDev")]
+    pub fn std_dev(data: &[f64], population: Option<bool>) -> Option<f64> {
+        math::std_dev(data, variance_mode(population))
+    }
+
+    pub fn skewness(data: &[f64], population: Option<bool>) -> Option<f64> {
+        math::skewness(data, variance_mode(population))
+    }
+
+    pub fn kurtosis(data: &[f64], population: Option<bool>) -> Option<f64> {
+        math::kurtosis(data, variance_mode(population))
+    }
+
+    pub fn covariance(x: &[f64], y: &[f64], population: Option<bool>) -> Result<f64, JsValue> {
+        math::covariance(x, y, variance_mode(population)).map_err(js_error)
+    }
+
+    pub fn pearson(x: &[f64], y: &[f64]) -> Result<f64, JsValue> {
+        math::pearson(x, y).map_err(js_error)
+    }
+
+    pub fn spearman(x: &[f64], y: &[f64]) -> Result<f64, JsValue> {
+        math::spearman(x, y).map_err(js_error)
+    }
+
+    pub fn rank(data: &[f64]) -> Float64Array {
+        Float64Array::from(&math::rank(data)[..])
+    }
+
+    #[wasm_bindgen(js_name = "tTest")]
+    pub fn t_test(data: &[f64], population_mean: f64, alternative_hypothesis: Option<String>) -> Result<Object, JsValue> {
+        test_result(math::one_sample_t_test(data, population_mean, alternative(alternative_hypothesis)?))
+    }
+
+    #[wasm_bindgen(js_name = "pairedTTest")]
+    pub fn paired_t_test(a: &[f64], b: &[f64], alternative_hypothesis: Option<String>) -> Result<Object, JsValue> {
+        test_result(math::paired_t_test(a, b, alternative(alternative_hypothesis)?))
+    }
+
+    #[wasm_bindgen(js_name = "twoSampleTTest")]
+    pub fn two_sample_t_test(a: &[f64], b: &[f64], equal_variance: Option<bool>,
+                             alternative_hypothesis: Option<String>) -> Result<Object, JsValue> {
+        test_result(math::two_sample_t_test(a, b, equal_variance.unwrap_or(false), alternative(alternative_hypothesis)?))
+    }
+
+    #[wasm_bindgen(js_name = "chiSquareGoodnessOfFit")]
+    pub fn chi_square_goodness_of_fit(observed: &[f64], expected: Option<Vec<f64>>) -> Result<Object, JsValue> {
+        test_result(math::chi_square_goodness_of_fit(observed, expected.as_deref()))
+    }
+
+    #[wasm_bindgen(js_name = "chiSquareIndependence")]
+    pub fn chi_square_independence(table: &[f64], rows: usize, cols: usize) -> Result<Object, JsValue> {
+        if cols == 0 || table.len() != rows * cols {
+            return Err(JsValue::from_str("Table length must equal rows * cols"));
+        }
+        let table: Vec<Vec<f64>> = table.chunks(cols).map(<[f64]>::to_vec).collect();
+        test_result(math::chi_square_independence(&table))
+    }
+
+    #[wasm_bindgen(js_name = "mannWhitneyU")]
+    pub fn mann_whitney_u(a: &[f64], b: &[f64], alternative_hypothesis: Option<String>) -> Result<Object, JsValue> {
+        test_result(math::mann_whitney_u(a, b, alternative(alternative_hypothesis)?))
+    }
+}
+
+#[wasm_bindgen]
+pub struct ProbabilityDistribution {
+    inner: Box<dyn Distribution>,
+}
+
+#[wasm_bindgen]
+impl ProbabilityDistribution {
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: &str, parameters: &[f64]) -> Result<ProbabilityDistribution, JsValue> {
+        let inner = math::distribution(name, parameters).map_err(js_error)?;
+        Ok(ProbabilityDistribution { inner })
+    }
+
+    pub fn normal(mean: Option<f64>, std_dev: Option<f64>) -> Result<ProbabilityDistribution, JsValue> {
+        ProbabilityDistribution::new("normal", &[mean.unwrap_or(0.0), std_dev.unwrap_or(1.0)])
+    }
+
+    #[wasm_bindgen(js_name = "studentT")]
+    pub fn student_t(degrees_of_freedom: f64) -> Result<ProbabilityDistribution, JsValue> {
+        ProbabilityDistribution::new("t", &[degrees_of_freedom])
+    }
+
+    #[wasm_bindgen(js_name = "chiSquared")]
+    pub fn chi_squared(degrees_of_freedom: f64) -> Result<ProbabilityDistribution, JsValue> {
+        ProbabilityDistribution::new("chi-squared", &[degrees_of_freedom])
+    }
+
+    pub fn binomial(trials: u32, probability: f64) -> Result<ProbabilityDistribution, JsValue> {
+        ProbabilityDistribution::new("binomial", &[trials as f64, probability])
+    }
+
+    pub fn poisson(lambda: f64) -> Result<ProbabilityDistribution, JsValue> {
+        ProbabilityDistribution::new("poisson", &[lambda])
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[wasm_bindgen(getter, js_name = "isDiscrete")]
+    pub fn is_discrete(&self) -> bool {
+        self.inner.is_discrete()
+    }
+
+    pub fn pdf(&self, x: f64) -> f64 {
+        self.inner.pdf(x)
+    }
+
+    pub fn cdf(&self, x: f64) -> f64 {
+        self.inner.cdf(x)
+    }
+
+    pub fn survival(&self, x: f64) -> f64 {
+        self.inner.survival(x)
+    }
+
+    pub fn quantile(&self, p: f64) -> Result<f64, JsValue> {
+        self.inner.quantile(p).map_err(js_error)
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.inner.mean()
+    }
+
+    pub fn variance(&self) -> f64 {
+        self.inner.variance()
+    }
+
+    #[wasm_bindgen(js_name = "stdDev")]
+    pub fn std_dev(&self) -> f64 {
+        self.inner.std_dev()
+    }
+
+    pub fn sample(&self, count: usize, seed: Option<u32>) -> Float64Array {
+        Float64Array::from(&self.inner.sample_n(count, seed.map(u64::from))[..])
+    }
+}
@@ -1,6 +1,6 @@
-
 pub mod regex_builder;
 pub mod algorithms;
+pub mod archive;
 pub mod compression;
 pub mod crypto;
 pub mod image;
@@ -10,3 +10,16 @@ pub mod text;
 
 pub use regex_builder::*;
 
+use std::fmt::Display;
+use js_sys::Uint8Array;
+use wasm_bindgen::JsValue;
+
+pub(crate) fn js_error<E: Display>(error: E) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+pub(crate) fn js_bytes<E: Display>(result: Result<Vec<u8>, E>) -> Result<Uint8Array, JsValue> {
+    result
+        .map(|bytes| Uint8Array::from(&bytes[..]))
+        .map_err(js_error)
+}
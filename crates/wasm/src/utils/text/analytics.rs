@@ -0,0 +1,192 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Float64Array, Object, Reflect, Uint32Array};
+use milost_core::text::{self, Analyzer, AnalyzerOptions, Bm25, Bm25Options, FuzzyOptions, SearchHit, Stemmer, TfIdf, TfIdfOptions};
+use super::text_processing::string_array;
+use crate::utils::js_error;
+
+const DEFAULT_LIMIT: usize = 10;
+
+fn set(target: &Object, key: &str, value: &JsValue) {
+    Reflect::set(target, &JsValue::from_str(key), value).unwrap();
+}
+
+fn stemmer(name: Option<String>) -> Result<Option<Stemmer>, JsValue> {
+    name.map(|name| name.parse().map_err(js_error)).transpose()
+}
+
+fn analyzer(stemmer_name: Option<String>, stop_words: Option<Vec<String>>,
+            ngram_range: (usize, usize)) -> Result<Analyzer, JsValue> {
+    Analyzer::new(AnalyzerOptions {
+        stemmer: stemmer(stemmer_name)?,
+        stop_words: stop_words.unwrap_or_default(),
+        ngram_range,
+        ..AnalyzerOptions::default()
+    })
+    .map_err(js_error)
+}
+
+fn hits_array(hits: &[SearchHit]) -> Array {
+    hits.iter()
+        .map(|hit| {
+            let result = Object::new();
+            set(&result, "index", &JsValue::from_f64(hit.index as f64));
+            set(&result, "score", &JsValue::from_f64(hit.score));
+            JsValue::from(result)
+        })
+        .collect()
+}
+
+#[wasm_bindgen]
+pub struct TextAnalytics;
+
+#[wasm_bindgen]
+impl TextAnalytics {
+    #[wasm_bindgen(js_name = "segmentWords")]
+    pub fn segment_words(text: &str) -> Array {
+        text::segment_words(text).into_iter().map(JsValue::from_str).collect()
+    }
+
+    #[wasm_bindgen(js_name = "foldDiacritics")]
+    pub fn fold_diacritics(text: &str) -> String {
+        text::fold_diacritics(text)
+    }
+
+    pub fn stem(word: &str, language: Option<String>) -> Result<String, JsValue> {
+        Ok(stemmer(language)?.unwrap_or_default().stem(word))
+    }
+
+    #[wasm_bindgen(js_name = "charNgrams")]
+    pub fn char_ngrams(text: &str, n: usize, pad: Option<bool>) -> Array {
+        string_array(&text::char_ngrams(text, n, pad.unwrap_or(false)))
+    }
+
+    #[wasm_bindgen(js_name = "wordNgrams")]
+    pub fn word_ngrams(tokens: Vec<String>, n: usize) -> Array {
+        string_array(&text::word_ngrams(&tokens, n))
+    }
+
+    pub fn analyze(text: &str, stemmer: Option<String>, stop_words: Option<Vec<String>>,
+                   min_n: Option<usize>, max_n: Option<usize>) -> Result<Array, JsValue> {
+        let min_n = min_n.unwrap_or(1);
+        let analyzer = analyzer(stemmer, stop_words, (min_n, max_n.unwrap_or(min_n)))?;
+        Ok(string_array(&analyzer.analyze(text)))
+    }
+
+    pub fn jaro(a: &str, b: &str) -> f64 {
+        text::jaro(a, b)
+    }
+
+    #[wasm_bindgen(js_name = "jaroWinkler")]
+    pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+        text::jaro_winkler(a, b)
+    }
+
+    #[wasm_bindgen(js_name = "damerauLevenshteinDistance")]
+    pub fn damerau_levenshtein_distance(a: &str, b: &str) -> u32 {
+        text::damerau_levenshtein_distance(a, b) as u32
+    }
+}
+
+#[wasm_bindgen]
+pub struct TfIdfModel {
+    model: TfIdf,
+}
+
+#[wasm_bindgen]
+impl TfIdfModel {
+    #[wasm_bindgen(constructor)]
+    pub fn new(documents: Vec<String>, stemmer: Option<String>, stop_words: Option<Vec<String>>,
+               sublinear_tf: Option<bool>) -> Result<TfIdfModel, JsValue> {
+        let options = TfIdfOptions {
+            sublinear_tf: sublinear_tf.unwrap_or(false),
+            ..TfIdfOptions::default()
+        };
+        let model = TfIdf::fit(&documents, analyzer(stemmer, stop_words, (1, 1))?, options);
+        Ok(TfIdfModel { model })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.model.len()
+    }
+
+    #[wasm_bindgen(getter, js_name = "vocabularySize")]
+    pub fn vocabulary_size(&self) -> usize {
+        self.model.vocabulary_size()
+    }
+
+    pub fn terms(&self) -> Array {
+        string_array(self.model.terms())
+    }
+
+    pub fn search(&self, query: &str, limit: Option<usize>) -> Array {
+        hits_array(&self.model.search(query, limit.unwrap_or(DEFAULT_LIMIT)))
+    }
+
+    pub fn similarity(&self, a: &str, b: &str) -> f64 {
+        self.model.similarity(a, b)
+    }
+
+    pub fn vectorize(&self, text: &str) -> Object {
+        let vector = self.model.transform(text);
+        let indices: Vec<u32> = vector.indices.iter().map(|&index| index as u32).collect();
+        let result = Object::new();
+        set(&result, "indices", &Uint32Array::from(&indices[..]));
+        set(&result, "values", &Float64Array::from(&vector.values[..]));
+        result
+    }
+
+    #[wasm_bindgen(js_name = "topTerms")]
+    pub fn top_terms(&self, index: usize, limit: Option<usize>) -> Result<Array, JsValue> {
+        let terms = self.model.top_terms(index, limit.unwrap_or(DEFAULT_LIMIT)).map_err(js_error)?;
+        Ok(terms
+            .iter()
+            .map(|(term, score)| {
+                let result = Object::new();
+                set(&result, "term", &JsValue::from_str(term));
+                set(&result, "score", &JsValue::from_f64(*score));
+                JsValue::from(result)
+            })
+            .collect())
+    }
+}
+
+#[wasm_bindgen]
+pub struct SearchIndex {
+    index: Bm25,
+}
+
+#[wasm_bindgen]
+impl SearchIndex {
+    #[wasm_bindgen(constructor)]
+    pub fn new(documents: Vec<String>, stemmer: Option<String>, stop_words: Option<Vec<String>>,
+               k1: Option<f64>, b: Option<f64>) -> Result<SearchIndex, JsValue> {
+        let defaults = Bm25Options::default();
+        let options = Bm25Options { k1: k1.unwrap_or(defaults.k1), b: b.unwrap_or(defaults.b) };
+        let index = Bm25::new(&documents, analyzer(stemmer, stop_words, (1, 1))?, options).map_err(js_error)?;
+        Ok(SearchIndex { index })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn add(&mut self, document: &str) -> usize {
+        self.index.add(document)
+    }
+
+    pub fn score(&self, query: &str, index: usize) -> Result<f64, JsValue> {
+        self.index.score(query, index).map_err(js_error)
+    }
+
+    pub fn search(&self, query: &str, limit: Option<usize>, fuzzy: Option<bool>, max_edits: Option<usize>,
+                  prefix: Option<bool>) -> Array {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT);
+        if !fuzzy.unwrap_or(false) {
+            return hits_array(&self.index.search(query, limit));
+        }
+        let options = FuzzyOptions { max_edits, prefix: prefix.unwrap_or(false) };
+        hits_array(&self.index.search_fuzzy(query, limit, &options))
+    }
+}
@@ -1,3 +1,5 @@
 pub mod text_processing;
+pub mod analytics;
 
-pub use text_processing::*;
\ No newline at end of file
+pub use text_processing::*;
+pub use analytics::*;
\ No newline at end of file